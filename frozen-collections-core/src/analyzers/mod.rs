@@ -1,9 +1,13 @@
 //! Logic to analyze collection input data to assess the best implementation choices.
 
 pub use hash_code_analyzer::*;
+pub use perfect_hash_analyzer::*;
 pub use scalar_key_analyzer::*;
+pub use simd_probe_analyzer::*;
 pub use slice_key_analyzer::*;
 
 mod hash_code_analyzer;
+mod perfect_hash_analyzer;
 mod scalar_key_analyzer;
+mod simd_probe_analyzer;
 mod slice_key_analyzer;
@@ -0,0 +1,183 @@
+use crate::hash_tables::{perfect_hash_bucket, perfect_hash_slot};
+use crate::utils::BitVec;
+use alloc::vec;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Below this size, regular hash tables rarely have enough collisions to matter.
+pub const PERFECT_HASH_MIN_INPUT_SIZE: usize = 1000;
+
+/// Above this size, finding pilots takes an unreasonable amount of time.
+pub const PERFECT_HASH_MAX_INPUT_SIZE: usize = 1 << 22;
+
+/// The layout of a minimal perfect hash table for a batch of hash codes.
+#[derive(Debug)]
+pub struct PerfectHashAnalysisResult {
+    /// The pilot value selected for each bucket.
+    pub pilots: Vec<u32>,
+
+    /// The slot assigned to each hash code, in the order the hash codes were supplied.
+    pub slots: Vec<usize>,
+}
+
+/// Decide whether a minimal perfect hash table is worthwhile for a batch of hash codes, and if so compute its layout.
+///
+/// Small inputs are served just as well by a regular hash table, while very large inputs take too long to
+/// process, so only inputs within a reasonable size window are considered.
+#[mutants::skip]
+pub fn analyze_perfect_hash_codes(hash_codes: &[u64]) -> Option<PerfectHashAnalysisResult> {
+    if !(PERFECT_HASH_MIN_INPUT_SIZE..=PERFECT_HASH_MAX_INPUT_SIZE).contains(&hash_codes.len()) {
+        return None;
+    }
+
+    find_perfect_hash(hash_codes)
+}
+
+/// Compute the layout of a minimal perfect hash table for a batch of hash codes.
+///
+/// This fails if the hash codes aren't unique or if a pilot value can't be found for every bucket.
+pub fn find_perfect_hash(hash_codes: &[u64]) -> Option<PerfectHashAnalysisResult> {
+    // the average number of hash codes sharing a bucket
+    const AVERAGE_BUCKET_SIZE: usize = 4;
+
+    // the number of pilot values to try per entry before giving up on a bucket
+    const PILOT_ATTEMPTS_PER_ENTRY: usize = 32;
+
+    // the minimum number of pilot values to try before giving up on a bucket
+    const MIN_PILOT_ATTEMPTS: usize = 1 << 16;
+
+    let num_slots = hash_codes.len();
+    if num_slots == 0 {
+        return Some(PerfectHashAnalysisResult {
+            pilots: Vec::new(),
+            slots: Vec::new(),
+        });
+    }
+
+    let mut unique_codes = hash_codes.to_vec();
+    unique_codes.sort_unstable();
+    unique_codes.dedup();
+    if unique_codes.len() != num_slots {
+        // identical hash codes always land in the same slot
+        return None;
+    }
+
+    // group the hash codes by bucket
+    let num_buckets = num_slots.div_ceil(AVERAGE_BUCKET_SIZE);
+    let mut bucket_starts = vec![0; num_buckets + 1];
+    for code in hash_codes {
+        bucket_starts[perfect_hash_bucket(*code, num_buckets) + 1] += 1;
+    }
+
+    for i in 0..num_buckets {
+        bucket_starts[i + 1] += bucket_starts[i];
+    }
+
+    let mut next = bucket_starts.clone();
+    let mut bucket_members = vec![0; num_slots];
+    for (index, code) in hash_codes.iter().enumerate() {
+        let bucket = perfect_hash_bucket(*code, num_buckets);
+        bucket_members[next[bucket]] = index;
+        next[bucket] += 1;
+    }
+
+    // place the largest buckets first, while the table still has plenty of room
+    let mut bucket_order: Vec<usize> = (0..num_buckets).collect();
+    bucket_order.sort_by_key(|b| core::cmp::Reverse(bucket_starts[b + 1] - bucket_starts[*b]));
+
+    let max_pilot = u32::try_from(num_slots.saturating_mul(PILOT_ATTEMPTS_PER_ENTRY).max(MIN_PILOT_ATTEMPTS)).unwrap_or(u32::MAX);
+
+    let mut pilots = vec![0; num_buckets];
+    let mut slots = vec![0; num_slots];
+    let mut taken = BitVec::with_capacity(num_slots);
+    taken.clear_all();
+    let mut candidates = Vec::new();
+
+    for bucket in bucket_order {
+        let members = &bucket_members[bucket_starts[bucket]..bucket_starts[bucket + 1]];
+        if members.is_empty() {
+            // the remaining buckets are all empty
+            break;
+        }
+
+        let mut found = false;
+        for pilot in 0..max_pilot {
+            candidates.clear();
+            for index in members {
+                let slot = perfect_hash_slot(hash_codes[*index], pilot, num_slots);
+                if taken.get(slot) || candidates.contains(&slot) {
+                    break;
+                }
+
+                candidates.push(slot);
+            }
+
+            if candidates.len() == members.len() {
+                for (index, slot) in members.iter().zip(&candidates) {
+                    taken.set(*slot);
+                    slots[*index] = *slot;
+                }
+
+                pilots[bucket] = pilot;
+                found = true;
+                break;
+            }
+        }
+
+        if !found {
+            return None;
+        }
+    }
+
+    Some(PerfectHashAnalysisResult { pilots, slots })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn check_layout(hash_codes: &[u64], result: &PerfectHashAnalysisResult) {
+        let mut seen = vec![false; hash_codes.len()];
+        for (code, slot) in hash_codes.iter().zip(&result.slots) {
+            let bucket = perfect_hash_bucket(*code, result.pilots.len());
+            assert_eq!(*slot, perfect_hash_slot(*code, result.pilots[bucket], hash_codes.len()));
+            assert!(!seen[*slot]);
+            seen[*slot] = true;
+        }
+    }
+
+    #[test]
+    fn finds_minimal_perfect_hash() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for len in [0, 1, 2, 3, 10, 100, 1000, 5000] {
+            let hash_codes: Vec<u64> = (0..len).map(|_| rng.random()).collect();
+            let result = find_perfect_hash(&hash_codes).unwrap();
+            assert_eq!(hash_codes.len(), result.slots.len());
+            check_layout(&hash_codes, &result);
+        }
+    }
+
+    #[test]
+    fn handles_sequential_hash_codes() {
+        let hash_codes: Vec<u64> = (0..2000).collect();
+        let result = find_perfect_hash(&hash_codes).unwrap();
+        check_layout(&hash_codes, &result);
+    }
+
+    #[test]
+    fn fails_with_duplicate_hash_codes() {
+        assert!(find_perfect_hash(&[1, 2, 3, 2]).is_none());
+    }
+
+    #[test]
+    fn only_analyzes_large_inputs() {
+        let hash_codes: Vec<u64> = (0..999).collect();
+        assert!(analyze_perfect_hash_codes(&hash_codes).is_none());
+
+        let hash_codes: Vec<u64> = (0..1000).collect();
+        assert!(analyze_perfect_hash_codes(&hash_codes).is_some());
+    }
+}
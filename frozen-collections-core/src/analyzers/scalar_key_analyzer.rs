@@ -11,18 +11,29 @@ pub enum ScalarKeyAnalysisResult {
 
     /// All keys are in a sparse range.
     SparseRange,
+
+    /// All keys are in a range sparse enough to be tracked in a bitset.
+    BitsetRange,
 }
 
 /// Look for well-known patterns we can optimize for with integer keys.
+///
+/// When `allow_bitset` is true, sparse keys are tracked in a bitset rather than a lookup table. This is
+/// worthwhile when the values take little or no space, as is the case for sets.
 #[mutants::skip]
-pub fn analyze_scalar_keys(keys: impl Iterator<Item: Scalar>) -> ScalarKeyAnalysisResult {
+pub fn analyze_scalar_keys(keys: impl Iterator<Item: Scalar>, allow_bitset: bool) -> ScalarKeyAnalysisResult {
     const MAX_SPARSE_MULTIPLIER: usize = 10;
+    const MAX_BITSET_MULTIPLIER: usize = 64;
     const ALWAYS_SPARSE_THRESHOLD: usize = 128;
 
     let mut min = usize::MAX;
     let mut max = usize::MIN;
     let mut count = 0;
     for key in keys {
+        if key.index_is_shared() {
+            return ScalarKeyAnalysisResult::General;
+        }
+
         min = min.min(key.index());
         max = max.max(key.index());
         count += 1;
@@ -35,6 +46,11 @@ pub fn analyze_scalar_keys(keys: impl Iterator<Item: Scalar>) -> ScalarKeyAnalys
     let needed_count = max - min + 1;
     if needed_count == count {
         ScalarKeyAnalysisResult::DenseRange
+    } else if allow_bitset
+        && u32::try_from(count).is_ok()
+        && (needed_count <= ALWAYS_SPARSE_THRESHOLD || needed_count <= count.saturating_mul(MAX_BITSET_MULTIPLIER))
+    {
+        ScalarKeyAnalysisResult::BitsetRange
     } else if needed_count <= ALWAYS_SPARSE_THRESHOLD || needed_count < count.saturating_mul(MAX_SPARSE_MULTIPLIER) {
         ScalarKeyAnalysisResult::SparseRange
     } else {
@@ -50,24 +66,51 @@ mod tests {
     #[test]
     fn test_analyze_scalar_keys_empty() {
         let keys = Vec::<i32>::new().into_iter();
-        assert_eq!(analyze_scalar_keys(keys), ScalarKeyAnalysisResult::General);
+        assert_eq!(analyze_scalar_keys(keys, false), ScalarKeyAnalysisResult::General);
     }
 
     #[test]
     fn test_analyze_scalar_keys_dense_range() {
         let keys = 1..=5;
-        assert_eq!(analyze_scalar_keys(keys), ScalarKeyAnalysisResult::DenseRange);
+        assert_eq!(analyze_scalar_keys(keys, false), ScalarKeyAnalysisResult::DenseRange);
     }
 
     #[test]
     fn test_analyze_scalar_keys_sparse_range() {
         let keys = vec![1, 3, 5, 7, 128].into_iter();
-        assert_eq!(analyze_scalar_keys(keys), ScalarKeyAnalysisResult::SparseRange);
+        assert_eq!(analyze_scalar_keys(keys, false), ScalarKeyAnalysisResult::SparseRange);
     }
 
     #[test]
     fn test_analyze_scalar_keys_general() {
         let keys = vec![1, 2, 4, 8, 129].into_iter();
-        assert_eq!(analyze_scalar_keys(keys), ScalarKeyAnalysisResult::General);
+        assert_eq!(analyze_scalar_keys(keys, false), ScalarKeyAnalysisResult::General);
+    }
+
+    #[test]
+    fn test_analyze_scalar_keys_bitset_range() {
+        let keys = vec![1, 3, 5, 7, 128].into_iter();
+        assert_eq!(analyze_scalar_keys(keys, true), ScalarKeyAnalysisResult::BitsetRange);
+
+        let keys = vec![1, 2, 4, 8, 129].into_iter();
+        assert_eq!(analyze_scalar_keys(keys, true), ScalarKeyAnalysisResult::BitsetRange);
+
+        let keys = vec![1, 2, 4, 8, 1000].into_iter();
+        assert_eq!(analyze_scalar_keys(keys, true), ScalarKeyAnalysisResult::General);
+
+        let keys = 1..=5;
+        assert_eq!(analyze_scalar_keys(keys, true), ScalarKeyAnalysisResult::DenseRange);
+    }
+
+    #[test]
+    fn test_analyze_scalar_keys_shared_index() {
+        let keys = vec![1_u128, 2, 3].into_iter();
+        assert_eq!(analyze_scalar_keys(keys, false), ScalarKeyAnalysisResult::DenseRange);
+
+        let keys = vec![1_u128, 2, u128::MAX].into_iter();
+        assert_eq!(analyze_scalar_keys(keys, false), ScalarKeyAnalysisResult::General);
+
+        let keys = vec![i128::MIN, -1, 0].into_iter();
+        assert_eq!(analyze_scalar_keys(keys, false), ScalarKeyAnalysisResult::General);
     }
 }
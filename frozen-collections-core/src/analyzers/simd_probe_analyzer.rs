@@ -0,0 +1,33 @@
+/// Decide whether a collection is small enough to be best served by SIMD tag probing.
+///
+/// `max_key_len` is the length of the longest key for string keys, and `None` for scalar keys.
+/// Long strings are left to hash tables since comparing them dominates the cost of a lookup anyway.
+#[mutants::skip]
+pub fn analyze_simd_probing(num_keys: usize, max_key_len: Option<usize>) -> bool {
+    // below this size, a linear scan is just as fast
+    const MIN_KEYS: usize = 8;
+
+    // above this size, probing needs more than four groups of tags
+    const MAX_KEYS: usize = 64;
+
+    // the longest string key considered to be short
+    const MAX_KEY_LEN: usize = 16;
+
+    (MIN_KEYS..=MAX_KEYS).contains(&num_keys) && max_key_len.is_none_or(|len| len <= MAX_KEY_LEN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyze_simd_probing() {
+        assert!(!analyze_simd_probing(7, None));
+        assert!(analyze_simd_probing(8, None));
+        assert!(analyze_simd_probing(64, None));
+        assert!(!analyze_simd_probing(65, None));
+
+        assert!(analyze_simd_probing(8, Some(16)));
+        assert!(!analyze_simd_probing(8, Some(17)));
+    }
+}
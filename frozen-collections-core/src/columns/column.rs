@@ -0,0 +1,167 @@
+use core::fmt::{Debug, Formatter, Result};
+use core::ops::{Index, IndexMut};
+use core::slice;
+
+#[cfg(not(feature = "std"))]
+use {alloc::boxed::Box, alloc::vec::Vec};
+
+/// Values stored at the slots of a [`KeyIndex`](crate::columns::KeyIndex).
+///
+/// A column holds one value for every key of the index it was created from, at the slot the index
+/// assigns to the key. Columns are created with [`KeyIndex::column`](crate::columns::KeyIndex::column),
+/// and values are looked up either by slot or, through the index, by key.
+///
+/// A column remembers the index it was created from, and looking up its values by key through
+/// any other index panics.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Column<V> {
+    index_id: usize,
+    values: Box<[V]>,
+}
+
+impl<V> Column<V> {
+    pub(crate) fn new(index_id: usize, values: Vec<V>) -> Self {
+        Self {
+            index_id,
+            values: values.into_boxed_slice(),
+        }
+    }
+
+    /// Returns the identity of the index the column was created from.
+    pub(crate) const fn index_id(&self) -> usize {
+        self.index_id
+    }
+
+    /// Returns a reference to the value at the given slot.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, slot: usize) -> Option<&V> {
+        self.values.get(slot)
+    }
+
+    /// Returns a mutable reference to the value at the given slot.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, slot: usize) -> Option<&mut V> {
+        self.values.get_mut(slot)
+    }
+
+    #[doc = include_str!("../doc_snippets/len.md")]
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[doc = include_str!("../doc_snippets/is_empty.md")]
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// An iterator visiting all values in slot order.
+    pub fn iter(&self) -> slice::Iter<'_, V> {
+        self.values.iter()
+    }
+
+    /// An iterator visiting all values mutably in slot order.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, V> {
+        self.values.iter_mut()
+    }
+
+    /// Returns the values in slot order.
+    #[must_use]
+    pub const fn as_slice(&self) -> &[V] {
+        &self.values
+    }
+
+    /// Creates a new column for the same index by transforming the value at every slot.
+    #[must_use]
+    pub fn map<U>(&self, f: impl FnMut(&V) -> U) -> Column<U> {
+        Column {
+            index_id: self.index_id,
+            values: self.values.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<V> Index<usize> for Column<V> {
+    type Output = V;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+
+impl<V> IndexMut<usize> for Column<V> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.values[index]
+    }
+}
+
+impl<V> IntoIterator for Column<V> {
+    type Item = V;
+    type IntoIter = alloc::vec::IntoIter<V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_vec().into_iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a Column<V> {
+    type Item = &'a V;
+    type IntoIter = slice::Iter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a mut Column<V> {
+    type Item = &'a mut V;
+    type IntoIter = slice::IterMut<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<V> Debug for Column<V>
+where
+    V: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.values.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column() {
+        let mut column = Column::new(0, vec![10, 20, 30]);
+        assert_eq!(3, column.len());
+        assert!(!column.is_empty());
+        assert_eq!(Some(&20), column.get(1));
+        assert_eq!(None, column.get(3));
+
+        column[1] += 1;
+        *column.get_mut(2).unwrap() += 2;
+        assert_eq!(&[10, 21, 32], column.as_slice());
+        assert_eq!(
+            vec!["10", "21", "32"],
+            column.map(ToString::to_string).into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!("[10, 21, 32]", format!("{column:?}"));
+
+        for value in &mut column.iter_mut() {
+            *value = 0;
+        }
+
+        assert_eq!(0, (&column).into_iter().sum::<i32>());
+        assert!(Column::<i32>::new(0, vec![]).is_empty());
+    }
+}
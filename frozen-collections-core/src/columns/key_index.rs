@@ -0,0 +1,258 @@
+use crate::columns::Column;
+use crate::traits::{Len, MapIteration, MapQuery};
+use crate::utils::BitVec;
+
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(not(feature = "std"))]
+use {alloc::format, alloc::string::String, alloc::vec::Vec};
+
+/// Maps every key of a frozen collection to a dense slot, so that values can be stored in [`Column`]s.
+///
+/// Associating several independent sets of values with the same keys would normally take one frozen
+/// map per set of values, each of them analyzing and storing the keys anew. A key index does this once:
+/// it assigns each of its keys a distinct slot in `0..len`, and any number of columns then hold a value
+/// at each of these slots.
+///
+/// A key index can be created:
+///
+/// - From any of the `Fz*Set` types, or from the sets generated by the `fz_*_set!` macros, which keeps the layout
+///   of the set and assigns slots in the set's iteration order.
+/// - From a frozen map whose values are the slots of its keys, such as a map generated by one of the macros,
+///   with [`KeyIndex::from_slots`].
+/// - By splitting an [`FzHashMap`](crate::fz_maps::FzHashMap) into an index and a column of its values, with
+///   [`FzHashMap::into_index_and_column`](crate::fz_maps::FzHashMap::into_index_and_column).
+///
+/// # Example
+///
+/// ```
+/// # use frozen_collections_core::columns::KeyIndex;
+/// # use frozen_collections_core::fz_sets::FzStringSet;
+/// let index = KeyIndex::from(FzStringSet::new(vec!["us", "eu", "ap"]));
+///
+/// let prices = index.column(|region| match region.as_ref() {
+///     "us" => 10,
+///     "eu" => 12,
+///     _ => 15,
+/// });
+///
+/// let names = index.column(|region| region.to_uppercase());
+///
+/// assert_eq!(Some(&12), index.get(&prices, &"eu"));
+/// assert_eq!(Some(&"AP".to_string()), index.get(&names, &"ap"));
+/// assert_eq!(None, index.get(&prices, &"sa"));
+/// ```
+///
+/// Each index has a distinct identity, which is shared by its clones and recorded in the columns it creates.
+/// Looking up a value by key in a column created from a different index panics, even if the two indexes
+/// hold the same keys, since their keys might not have been assigned the same slots. Identities are handed
+/// out by an atomic counter, so on targets without pointer-sized atomics all indexes share one identity and
+/// this check is skipped.
+#[derive(Clone, Debug)]
+pub struct KeyIndex<M> {
+    slots: M,
+    id: usize,
+}
+
+/// Returns a new identity for a key index.
+#[cfg(target_has_atomic = "ptr")]
+fn next_id() -> usize {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Returns the identity shared by all key indexes on targets which can't count them without atomics.
+#[cfg(not(target_has_atomic = "ptr"))]
+const fn next_id() -> usize {
+    0
+}
+
+impl<M> KeyIndex<M> {
+    /// Wraps a map whose values are known to be the numbers from 0 to the number of keys.
+    pub(crate) fn new(slots: M) -> Self {
+        Self { slots, id: next_id() }
+    }
+
+    /// Returns the identity recorded in the columns created from this index.
+    pub(crate) const fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Creates a key index from a map holding the slot of every key.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the slots aren't the numbers from 0 to the number of keys, in any order.
+    pub fn from_slots<K>(slots: M) -> Result<Self, String>
+    where
+        M: MapIteration<K, usize> + Len,
+    {
+        let len = slots.len();
+        let mut taken = BitVec::with_capacity(len);
+        taken.clear_all();
+
+        for &slot in slots.values() {
+            if slot >= len {
+                return Err(format!("slot {slot} is out of range for {len} keys"));
+            } else if taken.get(slot) {
+                return Err(format!("slot {slot} is assigned to more than one key"));
+            }
+
+            taken.set(slot);
+        }
+
+        Ok(Self::new(slots))
+    }
+
+    /// Returns the slot of a key.
+    #[inline]
+    #[must_use]
+    pub fn slot<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: ?Sized,
+        M: MapQuery<Q, usize>,
+    {
+        self.slots.get(key).copied()
+    }
+
+    /// Returns a reference to the value of a key in a column created from this index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column wasn't created from this index or one of its clones.
+    #[inline]
+    #[must_use]
+    pub fn get<'a, Q, V>(&self, column: &'a Column<V>, key: &Q) -> Option<&'a V>
+    where
+        Q: ?Sized,
+        M: MapQuery<Q, usize>,
+    {
+        self.check_column(column);
+        column.get(self.slot(key)?)
+    }
+
+    /// Returns a mutable reference to the value of a key in a column created from this index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column wasn't created from this index or one of its clones.
+    #[inline]
+    #[must_use]
+    pub fn get_mut<'a, Q, V>(&self, column: &'a mut Column<V>, key: &Q) -> Option<&'a mut V>
+    where
+        Q: ?Sized,
+        M: MapQuery<Q, usize>,
+    {
+        self.check_column(column);
+        column.get_mut(self.slot(key)?)
+    }
+
+    #[inline]
+    fn check_column<V>(&self, column: &Column<V>) {
+        assert!(column.index_id() == self.id, "the column was created from a different key index");
+    }
+
+    /// Creates a column holding a value for every key of the index.
+    ///
+    /// The function is called once for every key, in no particular order.
+    #[must_use]
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "Guaranteed not to panic since every slot is assigned to a key"
+    )]
+    pub fn column<K, V>(&self, mut f: impl FnMut(&K) -> V) -> Column<V>
+    where
+        M: MapIteration<K, usize> + Len,
+    {
+        let mut values: Vec<Option<V>> = (0..self.slots.len()).map(|_| None).collect();
+        for (key, &slot) in self.slots.iter() {
+            values[slot] = Some(f(key));
+        }
+
+        Column::new(
+            self.id,
+            values
+                .into_iter()
+                .map(|value| value.expect("every slot is assigned to a key"))
+                .collect(),
+        )
+    }
+
+    /// Returns the map holding the slot of every key.
+    #[must_use]
+    pub const fn slots(&self) -> &M {
+        &self.slots
+    }
+
+    #[doc = include_str!("../doc_snippets/len.md")]
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize
+    where
+        M: Len,
+    {
+        self.slots.len()
+    }
+
+    #[doc = include_str!("../doc_snippets/is_empty.md")]
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    where
+        M: Len,
+    {
+        self.slots.is_empty()
+    }
+}
+
+impl<M> Len for KeyIndex<M>
+where
+    M: Len,
+{
+    fn len(&self) -> usize {
+        self.slots.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fz_maps::FzOrderedMap;
+
+    #[test]
+    fn test_from_slots() {
+        let index = KeyIndex::from_slots(FzOrderedMap::new(vec![("c", 0), ("a", 2), ("b", 1)])).unwrap();
+        assert_eq!(3, index.len());
+        assert_eq!(Some(2), index.slot(&"a"));
+        assert_eq!(None, index.slot(&"d"));
+
+        let mut column = index.column(|key| key.to_uppercase());
+        assert_eq!(vec!["C", "B", "A"], column.as_slice());
+        assert_eq!(Some(&"B".to_string()), index.get(&column, &"b"));
+
+        index.get_mut(&mut column, &"b").unwrap().push('!');
+        assert_eq!("B!", column[1]);
+
+        let r = KeyIndex::from_slots(FzOrderedMap::new(vec![("a", 0), ("b", 2)]));
+        assert_eq!("slot 2 is out of range for 2 keys", r.unwrap_err());
+
+        let r = KeyIndex::from_slots(FzOrderedMap::new(vec![("a", 1), ("b", 1)]));
+        assert_eq!("slot 1 is assigned to more than one key", r.unwrap_err());
+
+        let index = KeyIndex::from_slots(FzOrderedMap::<i32, usize>::new(vec![])).unwrap();
+        assert!(index.is_empty());
+        assert!(index.column(|_| 0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "the column was created from a different key index")]
+    fn test_column_from_other_index() {
+        let index = KeyIndex::from_slots(FzOrderedMap::new(vec![("a", 0), ("b", 1)])).unwrap();
+        let other = KeyIndex::from_slots(FzOrderedMap::new(vec![("a", 1), ("b", 0)])).unwrap();
+
+        let column = index.column(|key| key.to_uppercase());
+        assert_eq!(Some(&"A".to_string()), index.clone().get(&column, &"a"));
+        _ = other.get(&column, &"a");
+    }
+}
@@ -0,0 +1,7 @@
+//! Dense key indexes shared by any number of columns of values.
+
+pub use column::*;
+pub use key_index::*;
+
+mod column;
+mod key_index;
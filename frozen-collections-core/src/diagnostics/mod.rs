@@ -0,0 +1,156 @@
+//! Reports describing how frozen collections store their entries and look up keys.
+
+use core::ops::Range;
+
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
+/// The strategy a collection uses to look up keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Strategy {
+    /// Keys are compared one after the other.
+    Scan,
+
+    /// Keys are used directly as indices into a table holding every key in a contiguous range.
+    DenseScalarLookup,
+
+    /// Keys are used as indices into a lookup table which refers to the entries.
+    SparseScalarLookup,
+
+    /// Keys are used as indices into a bitset, and the number of bits set before a key's bit locates its entry.
+    BitsetScalarLookup,
+
+    /// Keys are found with a binary search over entries laid out in Eytzinger order.
+    EytzingerSearch,
+
+    /// Keys are hashed to a slot holding the entries with the same hash code.
+    Hash,
+
+    /// Keys are hashed with a minimal perfect hash function, so every lookup inspects a single entry.
+    PerfectHash,
+
+    /// Keys are hashed to one-byte tags which are compared in parallel.
+    SimdProbe,
+}
+
+/// How a hashed collection derives hash codes from keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyHashing {
+    /// Whole keys are hashed.
+    Whole,
+
+    /// The values of scalar keys are used as hash codes.
+    Scalar,
+
+    /// The lengths of keys are used as hash codes.
+    Length,
+
+    /// A range of each key, counting from the start of the key, is hashed.
+    LeftSubslice,
+
+    /// A range of each key, counting from the end of the key, is hashed.
+    RightSubslice,
+}
+
+/// A report describing how a collection stores its entries and looks up keys.
+///
+/// This is returned by the `diagnostics` method of the frozen collections, and is
+/// intended to help understand lookup performance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Diagnostics {
+    /// The strategy used to look up keys.
+    pub strategy: Strategy,
+
+    /// How hash codes are derived from keys, for hashed strategies.
+    pub key_hashing: Option<KeyHashing>,
+
+    /// The range of each key which is hashed, when only part of each key is hashed.
+    pub subslice_range: Option<Range<usize>>,
+
+    /// The number of entries in the collection.
+    pub len: usize,
+
+    /// The number of hash slots, or the size of the lookup table for the scalar lookup strategies.
+    pub slots: usize,
+
+    /// The number of entries which share a hash slot or tag with another entry.
+    pub collisions: usize,
+
+    /// The largest number of entries which may be compared against a key during a lookup.
+    pub longest_chain: usize,
+
+    /// The approximate number of bytes allocated on the heap by the collection.
+    ///
+    /// This doesn't include memory owned by the keys and values themselves.
+    pub heap_bytes: usize,
+}
+
+impl Diagnostics {
+    pub(crate) const fn new(strategy: Strategy, len: usize) -> Self {
+        Self {
+            strategy,
+            key_hashing: None,
+            subslice_range: None,
+            len,
+            slots: 0,
+            collisions: 0,
+            longest_chain: if len > 0 { 1 } else { 0 },
+            heap_bytes: 0,
+        }
+    }
+
+    /// Fills in the collisions and longest chain from the number of entries sharing each slot.
+    pub(crate) fn with_chains(mut self, chains: impl Iterator<Item = usize>) -> Self {
+        self.collisions = 0;
+        self.longest_chain = 0;
+        for chain in chains {
+            self.collisions += chain.saturating_sub(1);
+            self.longest_chain = self.longest_chain.max(chain);
+        }
+
+        self
+    }
+}
+
+/// The number of bytes a collection's storage occupies on the heap.
+pub(crate) trait HeapBytes {
+    fn heap_bytes(&self) -> usize;
+}
+
+impl<T> HeapBytes for Box<[T]> {
+    fn heap_bytes(&self) -> usize {
+        size_of_val(&**self)
+    }
+}
+
+impl<T, const N: usize> HeapBytes for [T; N] {
+    fn heap_bytes(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chains() {
+        let d = Diagnostics::new(Strategy::Hash, 6).with_chains([0, 1, 3, 2, 0].into_iter());
+        assert_eq!(3, d.collisions);
+        assert_eq!(3, d.longest_chain);
+
+        let d = Diagnostics::new(Strategy::Hash, 0).with_chains([0, 0].into_iter());
+        assert_eq!(0, d.collisions);
+        assert_eq!(0, d.longest_chain);
+    }
+
+    #[test]
+    fn heap_bytes() {
+        let b: Box<[u32]> = Box::new([1, 2, 3]);
+        assert_eq!(12, b.heap_bytes());
+        assert_eq!(0, [1u32, 2, 3].heap_bytes());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::diagnostics::Diagnostics;
+use core::fmt::{Display, Formatter, Result};
+
+#[cfg(not(feature = "std"))]
+use {alloc::string::String, alloc::vec::Vec};
+
+/// Explains how a collection was laid out by [`CollectionEmitter`](crate::emit::CollectionEmitter).
+///
+/// The emitter analyzes the entries of a collection to pick the fastest lookup strategy. This report
+/// describes the outcome of that analysis, which helps when tuning the data given to the emitter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AnalysisReport {
+    /// The name of the generated collection type, such as `InlineHashMap`.
+    pub collection_type: String,
+
+    /// The layout of the generated collection.
+    ///
+    /// This is `None` when some keys aren't literals, in which case the analysis happens at runtime
+    /// when the collection is created. The layout never includes heap bytes since the generated
+    /// collections hold their entries inline.
+    pub layout: Option<Diagnostics>,
+
+    /// Why the lookup strategy was chosen, and why the alternatives were rejected.
+    pub reasons: Vec<String>,
+}
+
+impl AnalysisReport {
+    pub(crate) const fn new(collection_type: String, layout: Option<Diagnostics>) -> Self {
+        Self {
+            collection_type,
+            layout,
+            reasons: Vec::new(),
+        }
+    }
+}
+
+impl Display for AnalysisReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "Collection type: `{}`", self.collection_type)?;
+        writeln!(f)?;
+
+        if let Some(layout) = &self.layout {
+            writeln!(f, "- Strategy: {:?}", layout.strategy)?;
+
+            if let Some(key_hashing) = layout.key_hashing {
+                write!(f, "- Key hashing: {key_hashing:?}")?;
+                if let Some(range) = &layout.subslice_range {
+                    write!(f, " over bytes {range:?}")?;
+                }
+
+                writeln!(f)?;
+            }
+
+            writeln!(f, "- Entries: {}", layout.len)?;
+            if layout.slots > 0 {
+                writeln!(f, "- Slots: {}", layout.slots)?;
+            }
+
+            writeln!(f, "- Collisions: {}", layout.collisions)?;
+            writeln!(f, "- Longest chain: {}", layout.longest_chain)?;
+        } else {
+            writeln!(f, "- Strategy: chosen at runtime")?;
+        }
+
+        if !self.reasons.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "Reasons:")?;
+            writeln!(f)?;
+
+            for reason in &self.reasons {
+                writeln!(f, "- {reason}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
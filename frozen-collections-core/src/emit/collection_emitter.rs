@@ -1,9 +1,12 @@
-use crate::analyzers::{ScalarKeyAnalysisResult, SliceKeyAnalysisResult, analyze_scalar_keys, analyze_slice_keys};
+use crate::analyzers::{ScalarKeyAnalysisResult, SliceKeyAnalysisResult, analyze_scalar_keys, analyze_simd_probing, analyze_slice_keys};
+use crate::emit::analysis_report::AnalysisReport;
 use crate::emit::collection_entry::CollectionEntry;
 use crate::emit::generator::{Generator, Output};
 use crate::hashers::{BridgeHasher, LeftRangeHasher, LengthHasher, RightRangeHasher, ScalarHasher};
-use crate::traits::{Hasher, Scalar};
+use crate::keys::AsciiCaseInsensitive;
+use crate::traits::{Hasher, Len, Scalar};
 use crate::utils::{DeduppedVec, SortedAndDeduppedVec};
+use alloc::borrow::Cow;
 use foldhash::fast::FixedState;
 use proc_macro2::{Literal, TokenStream};
 use quote::{ToTokens, format_ident, quote};
@@ -13,7 +16,7 @@ use syn::{Type, Visibility, parse_quote};
 use crate::emit::NonLiteralKey;
 
 #[cfg(not(feature = "std"))]
-use {alloc::string::String, alloc::string::ToString, alloc::vec::Vec};
+use {alloc::format, alloc::string::String, alloc::string::ToString, alloc::vec::Vec};
 
 /// Emits frozen collection source code for use within a Rust build script.
 ///
@@ -80,7 +83,7 @@ use {alloc::string::String, alloc::string::ToString, alloc::vec::Vec};
 #[derive(Clone, Debug)]
 #[expect(clippy::struct_excessive_bools, reason = "Analysis is misguided")]
 pub struct CollectionEmitter {
-    key_type: Type,
+    pub(crate) key_type: Type,
     pub(crate) value_type: Option<Type>,
     symbol_name: Option<String>,
     alias_name: Option<String>,
@@ -89,6 +92,10 @@ pub struct CollectionEmitter {
     visibility: Visibility,
     is_mutable: bool,
     is_static: bool,
+    report: bool,
+    ignore_ascii_case: bool,
+    seed: Option<u64>,
+    pub(crate) hasher: Option<Type>,
 
     #[cfg(feature = "macros")]
     pub(crate) inferred_key_type: bool,
@@ -123,6 +130,10 @@ impl CollectionEmitter {
             visibility: Visibility::Inherited,
             is_static: false,
             is_mutable: false,
+            report: false,
+            ignore_ascii_case: false,
+            seed: None,
+            hasher: None,
 
             #[cfg(feature = "macros")]
             inferred_key_type: false,
@@ -218,13 +229,76 @@ impl CollectionEmitter {
         self
     }
 
+    /// Specifies whether the analysis report is attached to the generated code as a doc comment.
+    ///
+    /// The report explains which lookup strategy was chosen and why. It is attached to the alias
+    /// type if there is one, or otherwise to the static instance. Collections emitted as an expression
+    /// or as a local variable without an alias don't carry the report.
+    ///
+    /// The report is always available from the `emit_*_with_report` functions.
+    #[must_use]
+    pub const fn analysis_report(mut self, report: bool) -> Self {
+        self.report = report;
+        self
+    }
+
+    /// Specifies whether the keys of string collections are compared and hashed without regard to ASCII case.
+    ///
+    /// The keys of the generated collection are wrapped in [`AsciiCaseInsensitive`], and the collection is
+    /// looked up with plain strings, which are folded the same way. Keys which only differ by case are
+    /// considered duplicates. This setting only affects
+    /// [`emit_string_collection`](Self::emit_string_collection) and its variants.
+    #[must_use]
+    pub const fn ignore_ascii_case(mut self, ignore_ascii_case: bool) -> Self {
+        self.ignore_ascii_case = ignore_ascii_case;
+        self
+    }
+
+    /// Specifies the seed used to hash the keys of hash and string collections.
+    ///
+    /// By default, a random seed is picked when this crate is built, so the layout, iteration order, and
+    /// generated code of these collections vary between builds. Pinning the seed makes the output
+    /// reproducible. A seed can't be combined with a custom [`hasher`](Self::hasher).
+    ///
+    /// Collections whose keys aren't literals, which the macros lay out at runtime, are created with a
+    /// [`FixedState`] hasher using the seed rather than with the default `BuildHasher`.
+    #[must_use]
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Specifies a custom `BuildHasher` used to hash the keys of hash and string collections.
+    ///
+    /// The type must implement [`BuildHasher`](core::hash::BuildHasher) and [`Default`]. The emitted collection is a
+    /// [`HashMap`](crate::maps::HashMap) or [`HashSet`](crate::sets::HashSet) whose hasher is a
+    /// [`BridgeHasher`] wrapping the custom type. Since the hasher can't be run while the code is being generated,
+    /// the layout of the collection is computed when it is created at runtime, and static instances are therefore
+    /// wrapped in a `LazyLock`. When [`ignore_ascii_case`](Self::ignore_ascii_case) is set, the emitted collection is
+    /// instead an [`FzStringMap`](crate::fz_maps::FzStringMap) or [`FzStringSet`](crate::fz_sets::FzStringSet) of
+    /// [`AsciiCaseInsensitive`] keys using the hasher.
+    ///
+    /// A custom hasher can't be combined with a [`seed`](Self::seed), since the hasher is created with `Default`.
+    ///
+    /// This setting only affects [`emit_hash_collection`](Self::emit_hash_collection),
+    /// [`emit_string_collection`](Self::emit_string_collection), and their variants.
+    #[must_use]
+    pub fn hasher(mut self, hasher: &Type) -> Self {
+        self.hasher = Some(hasher.clone());
+        self
+    }
+
+    fn get_seed(&self) -> u64 {
+        self.seed.unwrap_or_else(Self::default_seed)
+    }
+
     #[cfg(test)]
-    const fn get_seed() -> u64 {
+    const fn default_seed() -> u64 {
         0x_dead_beef
     }
 
     #[cfg(not(test))]
-    const fn get_seed() -> u64 {
+    const fn default_seed() -> u64 {
         const_random::const_random!(u64)
     }
 
@@ -235,27 +309,47 @@ impl CollectionEmitter {
     /// # Errors
     ///
     /// This function fails if the emitter was misconfigured.
-    pub fn emit_hash_collection<K>(&self, mut entries: Vec<CollectionEntry<K>>) -> Result<TokenStream, String>
+    pub fn emit_hash_collection<K>(&self, entries: Vec<CollectionEntry<K>>) -> Result<TokenStream, String>
+    where
+        K: core::hash::Hash + Eq,
+    {
+        self.emit_hash_collection_with_report(entries).map(|(tokens, _)| tokens)
+    }
+
+    /// Emits a frozen hash collection, along with a report explaining how the collection was laid out.
+    ///
+    /// If the emitter's value type has been set, this emits a map. Otherwise, it emits a set.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the emitter was misconfigured.
+    pub fn emit_hash_collection_with_report<K>(&self, mut entries: Vec<CollectionEntry<K>>) -> Result<(TokenStream, AnalysisReport), String>
     where
         K: core::hash::Hash + Eq,
     {
         self.clean_values(&mut entries);
 
-        let seed = Self::get_seed();
+        if let Some(hasher) = &self.hasher {
+            return self.emit_custom_hasher_collection(entries, hasher, false);
+        }
+
+        let seed = self.get_seed();
         let hasher = BridgeHasher::new(FixedState::with_seed(seed));
         let entries = DeduppedVec::using_hash(entries, |x| hasher.hash_one(&x.key), |x, y| x.key == y.key);
 
         let generator = self.preflight(entries.len())?;
         let output = if entries.len() < 4 {
-            generator.gen_inline_scan(entries)
+            explain(generator.gen_inline_scan(entries), &[FEW_ENTRIES])
         } else {
             let seed = Generator::inject_underscores(seed.to_token_stream());
-            generator.gen_inline_hash(
+            let output = generator.gen_inline_hash(
                 entries,
                 &hasher,
                 &quote!(::frozen_collections::hashers::BridgeHasher<::frozen_collections::foldhash::FixedState>),
                 &quote!(::frozen_collections::hashers::BridgeHasher::new(::frozen_collections::foldhash::FixedState::with_seed(#seed))),
-            )
+            );
+
+            explain(output, &[ENOUGH_ENTRIES_TO_HASH])
         };
 
         Ok(self.postflight(output))
@@ -268,8 +362,24 @@ impl CollectionEmitter {
     /// # Errors
     ///
     /// This function fails if the emitter was misconfigured.
-    #[cfg(feature = "emit")]
-    pub fn emit_ordered_collection<K>(&self, mut entries: Vec<CollectionEntry<K>>) -> Result<TokenStream, String>
+    pub fn emit_ordered_collection<K>(&self, entries: Vec<CollectionEntry<K>>) -> Result<TokenStream, String>
+    where
+        K: Ord,
+    {
+        self.emit_ordered_collection_with_report(entries).map(|(tokens, _)| tokens)
+    }
+
+    /// Emits a frozen ordered collection, along with a report explaining how the collection was laid out.
+    ///
+    /// If the emitter's value type has been set, this emits a map. Otherwise, it emits a set.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the emitter was misconfigured.
+    pub fn emit_ordered_collection_with_report<K>(
+        &self,
+        mut entries: Vec<CollectionEntry<K>>,
+    ) -> Result<(TokenStream, AnalysisReport), String>
     where
         K: Ord,
     {
@@ -278,12 +388,7 @@ impl CollectionEmitter {
         let entries = SortedAndDeduppedVec::new(entries, |x, y| x.key.cmp(&y.key));
 
         let generator = self.preflight(entries.len())?;
-        let output = if entries.len() < 4 {
-            generator.gen_inline_scan(entries.into())
-        } else {
-            generator.gen_inline_eytzinger_search(entries)
-        };
-
+        let output = explain(generator.gen_inline_eytzinger_search(entries), &[ORDERED_QUERIES]);
         Ok(self.postflight(output))
     }
 
@@ -294,7 +399,24 @@ impl CollectionEmitter {
     /// # Errors
     ///
     /// This function fails if the emitter was misconfigured.
-    pub fn emit_scalar_collection<K>(&self, mut entries: Vec<CollectionEntry<K>>) -> Result<TokenStream, String>
+    pub fn emit_scalar_collection<K>(&self, entries: Vec<CollectionEntry<K>>) -> Result<TokenStream, String>
+    where
+        K: Scalar,
+    {
+        self.emit_scalar_collection_with_report(entries).map(|(tokens, _)| tokens)
+    }
+
+    /// Emits a frozen scalar collection, along with a report explaining how the collection was laid out.
+    ///
+    /// If the emitter's value type has been set, this emits a map. Otherwise, it emits a set.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the emitter was misconfigured.
+    pub fn emit_scalar_collection_with_report<K>(
+        &self,
+        mut entries: Vec<CollectionEntry<K>>,
+    ) -> Result<(TokenStream, AnalysisReport), String>
     where
         K: Scalar,
     {
@@ -302,21 +424,54 @@ impl CollectionEmitter {
 
         let entries = SortedAndDeduppedVec::new(entries, |x, y| x.key.cmp(&y.key));
 
-        let analysis = analyze_scalar_keys(entries.iter().map(|x| x.key));
+        let analysis = analyze_scalar_keys(entries.iter().map(|x| x.key), self.value_type.is_none());
 
         let generator = self.preflight(entries.len())?;
         let output = match analysis {
-            ScalarKeyAnalysisResult::DenseRange => generator.gen_inline_dense_scalar_lookup(entries),
-            ScalarKeyAnalysisResult::SparseRange => generator.gen_inline_sparse_scalar_lookup(entries),
+            ScalarKeyAnalysisResult::DenseRange => explain(
+                generator.gen_inline_dense_scalar_lookup(entries),
+                &["the keys form a contiguous range, so they are used directly as indices into the entries"],
+            ),
+            ScalarKeyAnalysisResult::SparseRange => explain(
+                generator.gen_inline_sparse_scalar_lookup(entries),
+                &["the keys span a range small enough for a lookup table, so no hashing is needed"],
+            ),
+            ScalarKeyAnalysisResult::BitsetRange => explain(
+                generator.gen_inline_bitset_scalar_lookup(entries),
+                &["the values span a range small enough for a bitset, which takes less space than a lookup table"],
+            ),
             ScalarKeyAnalysisResult::General => {
+                const TOO_SPREAD_OUT: &str = "the keys are too spread out for a lookup table";
+
                 if entries.len() < 8 {
-                    generator.gen_inline_scan(entries.into())
+                    explain(
+                        generator.gen_inline_scan(entries.into()),
+                        &[TOO_SPREAD_OUT, "there are fewer than 8 entries, so scanning is faster than hashing"],
+                    )
+                } else if analyze_simd_probing(entries.len(), None) {
+                    let output = generator.gen_inline_simd_probe(
+                        entries.into(),
+                        &ScalarHasher,
+                        &quote! { ::frozen_collections::hashers::ScalarHasher },
+                        &quote! { ::frozen_collections::hashers::ScalarHasher {} },
+                    );
+
+                    explain(output, &[TOO_SPREAD_OUT, SIMD_PROBING])
                 } else {
-                    generator.gen_inline_hash(
+                    let output = generator.gen_inline_hash(
                         entries.into(),
                         &ScalarHasher,
                         &quote! { ::frozen_collections::hashers::ScalarHasher },
                         &quote! { ::frozen_collections::hashers::ScalarHasher {} },
+                    );
+
+                    explain(
+                        output,
+                        &[
+                            TOO_SPREAD_OUT,
+                            TOO_MANY_FOR_SIMD_PROBING,
+                            "the keys are used as their own hash codes",
+                        ],
                     )
                 }
             }
@@ -332,18 +487,89 @@ impl CollectionEmitter {
     /// # Errors
     ///
     /// This function fails if the emitter was misconfigured.
-    pub fn emit_string_collection(self, mut entries: Vec<CollectionEntry<String>>) -> Result<TokenStream, String> {
+    pub fn emit_string_collection(self, entries: Vec<CollectionEntry<String>>) -> Result<TokenStream, String> {
+        self.emit_string_collection_with_report(entries).map(|(tokens, _)| tokens)
+    }
+
+    /// Emits a frozen string collection, along with a report explaining how the collection was laid out.
+    ///
+    /// If the emitter's value type has been set, this emits a map. Otherwise, it emits a set.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the emitter was misconfigured.
+    pub fn emit_string_collection_with_report(
+        self,
+        mut entries: Vec<CollectionEntry<String>>,
+    ) -> Result<(TokenStream, AnalysisReport), String> {
+        self.clean_values(&mut entries);
+
+        if let Some(hasher) = &self.hasher {
+            return self.emit_custom_hasher_collection(entries, hasher, self.ignore_ascii_case);
+        }
+
+        if self.ignore_ascii_case {
+            let entries = entries.into_iter().map(CollectionEntry::ignore_ascii_case).collect();
+            self.emit_slice_collection(entries, true, |key: &AsciiCaseInsensitive<String>| {
+                Cow::Owned(key.0.to_ascii_lowercase().into_bytes())
+            })
+        } else {
+            self.emit_slice_collection(entries, false, |key: &String| Cow::Borrowed(key.as_bytes()))
+        }
+    }
+
+    /// Emits a frozen byte slice collection.
+    ///
+    /// If the emitter's value type has been set, this emits a map. Otherwise, it emits a set.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the emitter was misconfigured.
+    pub fn emit_bytes_collection(self, entries: Vec<CollectionEntry<Vec<u8>>>) -> Result<TokenStream, String> {
+        self.emit_bytes_collection_with_report(entries).map(|(tokens, _)| tokens)
+    }
+
+    /// Emits a frozen byte slice collection, along with a report explaining how the collection was laid out.
+    ///
+    /// If the emitter's value type has been set, this emits a map. Otherwise, it emits a set.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the emitter was misconfigured.
+    pub fn emit_bytes_collection_with_report(
+        self,
+        mut entries: Vec<CollectionEntry<Vec<u8>>>,
+    ) -> Result<(TokenStream, AnalysisReport), String> {
         self.clean_values(&mut entries);
+        self.emit_slice_collection(entries, false, |key: &Vec<u8>| Cow::Borrowed(key))
+    }
 
+    /// Emits a collection of string or byte keys, hashing the bytes returned by `key_bytes` during analysis.
+    fn emit_slice_collection<K>(
+        self,
+        entries: Vec<CollectionEntry<K>>,
+        ignore_ascii_case: bool,
+        key_bytes: fn(&K) -> Cow<'_, [u8]>,
+    ) -> Result<(TokenStream, AnalysisReport), String>
+    where
+        K: Ord + Len + core::hash::Hash,
+        LeftRangeHasher<FixedState>: Hasher<K>,
+        RightRangeHasher<FixedState>: Hasher<K>,
+    {
         let entries = DeduppedVec::using_cmp(entries, |x, y| x.key.cmp(&y.key));
 
-        let generator = self.preflight(entries.len())?;
+        let mut generator = self.preflight(entries.len())?;
+        if ignore_ascii_case {
+            generator = generator.ignore_ascii_case();
+        }
+
         let output = if entries.len() < 4 {
-            generator.gen_inline_scan(entries)
+            explain(generator.gen_inline_scan(entries), &[FEW_ENTRIES])
         } else {
-            let iter = entries.iter().map(|x| x.key.as_bytes());
+            let keys: Vec<_> = entries.iter().map(|x| key_bytes(&x.key)).collect();
+            let iter = keys.iter().map(AsRef::as_ref);
 
-            let seed = Self::get_seed();
+            let seed = self.get_seed();
             let bh = FixedState::with_seed(seed);
             let analysis = analyze_slice_keys(iter, &bh);
 
@@ -354,11 +580,19 @@ impl CollectionEmitter {
                     let range_start = Generator::inject_underscores(Literal::usize_unsuffixed(range.start).to_token_stream());
                     let range_end = Generator::inject_underscores(Literal::usize_unsuffixed(range.end).to_token_stream());
 
-                    generator.gen_inline_hash(
+                    let output = generator.gen_inline_hash(
                         entries,
                         &hasher,
                         &quote! {::frozen_collections::hashers::InlineLeftRangeHasher::<#range_start, #range_end, ::frozen_collections::foldhash::FixedState> },
-                        &quote! {::frozen_collections::hashers::InlineLeftRangeHasher::<#range_start, #range_end, ::frozen_collections::foldhash::FixedState>::new(::frozen_collections::foldhash::FixedState::with_seed(#seed))})
+                        &quote! {::frozen_collections::hashers::InlineLeftRangeHasher::<#range_start, #range_end, ::frozen_collections::foldhash::FixedState>::new(::frozen_collections::foldhash::FixedState::with_seed(#seed))});
+
+                    explain(
+                        output,
+                        &[
+                            ENOUGH_ENTRIES_TO_HASH,
+                            "a short range of bytes, counting from the start of the keys, tells the keys apart well enough to hash only that range",
+                        ],
+                    )
                 }
 
                 SliceKeyAnalysisResult::RightHandSubslice(range) => {
@@ -367,32 +601,63 @@ impl CollectionEmitter {
                     let range_start = Generator::inject_underscores(Literal::usize_unsuffixed(range.start).to_token_stream());
                     let range_end = Generator::inject_underscores(Literal::usize_unsuffixed(range.end).to_token_stream());
 
-                    generator.gen_inline_hash(
+                    let output = generator.gen_inline_hash(
                         entries,
                         &hasher,
                         &quote! {::frozen_collections::hashers::InlineRightRangeHasher::<#range_start, #range_end, ::frozen_collections::foldhash::FixedState> },
-                        &quote! {::frozen_collections::hashers::InlineRightRangeHasher::<#range_start, #range_end, ::frozen_collections::foldhash::FixedState>::new(::frozen_collections::foldhash::FixedState::with_seed(#seed))})
+                        &quote! {::frozen_collections::hashers::InlineRightRangeHasher::<#range_start, #range_end, ::frozen_collections::foldhash::FixedState>::new(::frozen_collections::foldhash::FixedState::with_seed(#seed))});
+
+                    explain(
+                        output,
+                        &[
+                            ENOUGH_ENTRIES_TO_HASH,
+                            "no range of bytes counting from the start of the keys tells them apart, but a short range counting from the end does, so only that range is hashed",
+                        ],
+                    )
                 }
 
                 SliceKeyAnalysisResult::Length => {
                     let hasher = LengthHasher;
 
-                    generator.gen_inline_hash(
+                    let output = generator.gen_inline_hash(
                         entries,
                         &hasher,
                         &quote! { ::frozen_collections::hashers::LengthHasher },
                         &quote! { ::frozen_collections::hashers::LengthHasher },
+                    );
+
+                    explain(
+                        output,
+                        &[
+                            ENOUGH_ENTRIES_TO_HASH,
+                            "the lengths of the keys tell them apart well enough to be used as hash codes",
+                        ],
                     )
                 }
 
                 SliceKeyAnalysisResult::General => {
                     let hasher = BridgeHasher::new(bh);
                     let seed = Generator::inject_underscores(seed.to_token_stream());
-
-                    generator.gen_inline_hash(entries,
-                                              &hasher,
-                                              &quote!(::frozen_collections::hashers::BridgeHasher<::frozen_collections::foldhash::FixedState>),
-                                              &quote!(::frozen_collections::hashers::BridgeHasher::new(::frozen_collections::foldhash::FixedState::with_seed(#seed))))
+                    let hasher_type = quote!(::frozen_collections::hashers::BridgeHasher<::frozen_collections::foldhash::FixedState>);
+                    let hasher_ctor = quote!(::frozen_collections::hashers::BridgeHasher::new(::frozen_collections::foldhash::FixedState::with_seed(#seed)));
+
+                    let max_key_len = entries.iter().map(|x| x.key.len()).max().unwrap_or_default();
+                    if analyze_simd_probing(entries.len(), Some(max_key_len)) {
+                        explain(
+                            generator.gen_inline_simd_probe(entries, &hasher, &hasher_type, &hasher_ctor),
+                            &[ENOUGH_ENTRIES_TO_HASH, NO_SUBSLICE, SIMD_PROBING],
+                        )
+                    } else {
+                        let output = generator.gen_inline_hash(entries, &hasher, &hasher_type, &hasher_ctor);
+                        explain(
+                            output,
+                            &[
+                                ENOUGH_ENTRIES_TO_HASH,
+                                NO_SUBSLICE,
+                                "SIMD tag probing is only used for 8 to 64 keys of at most 16 bytes",
+                            ],
+                        )
+                    }
                 }
             }
         };
@@ -402,50 +667,117 @@ impl CollectionEmitter {
 
     #[cfg(feature = "macros")]
     pub(crate) fn emit_hash_collection_expr(self, entries: Vec<CollectionEntry<NonLiteralKey>>) -> Result<TokenStream, String> {
+        if let Some(hasher) = &self.hasher {
+            return self.emit_custom_hasher_collection(entries, hasher, false).map(|(tokens, _)| tokens);
+        }
+
         let generator = self.preflight(entries.len())?;
         let output = if entries.len() < 4 {
-            generator.gen_inline_scan_vec(entries)
+            explain(generator.gen_inline_scan_vec(entries), &[FEW_ENTRIES])
         } else {
-            generator.gen_fz_hash(entries)
+            explain(generator.gen_fz_hash(entries), &[RUNTIME_ANALYSIS])
         };
 
-        Ok(self.postflight(output))
+        Ok(self.postflight(output).0)
     }
 
     #[cfg(feature = "macros")]
     pub(crate) fn emit_ordered_collection_expr(self, entries: Vec<CollectionEntry<NonLiteralKey>>) -> Result<TokenStream, String> {
         let generator = self.preflight(entries.len())?;
-        let output = if entries.len() < 4 {
-            generator.gen_inline_scan_vec(entries)
-        } else {
-            generator.gen_inline_eytzinger_search_vec(entries)
-        };
-
-        Ok(self.postflight(output))
+        let output = explain(generator.gen_inline_eytzinger_search_vec(entries), &[ORDERED_QUERIES]);
+        Ok(self.postflight(output).0)
     }
 
     #[cfg(feature = "macros")]
     pub(crate) fn emit_scalar_collection_expr(self, entries: Vec<CollectionEntry<NonLiteralKey>>) -> Result<TokenStream, String> {
         let generator = self.preflight(entries.len())?;
         let output = if entries.len() < 8 {
-            generator.gen_inline_scan_vec(entries)
+            explain(
+                generator.gen_inline_scan_vec(entries),
+                &["there are fewer than 8 entries, so scanning is faster than hashing"],
+            )
         } else {
-            generator.gen_fz_scalar(entries)
+            explain(generator.gen_fz_scalar(entries), &[RUNTIME_ANALYSIS])
         };
 
-        Ok(self.postflight(output))
+        Ok(self.postflight(output).0)
     }
 
     #[cfg(feature = "macros")]
     pub(crate) fn emit_string_collection_expr(self, entries: Vec<CollectionEntry<NonLiteralKey>>) -> Result<TokenStream, String> {
+        if let Some(hasher) = &self.hasher {
+            return self
+                .emit_custom_hasher_collection(entries, hasher, self.ignore_ascii_case)
+                .map(|(tokens, _)| tokens);
+        }
+
+        let mut generator = self.preflight(entries.len())?;
+        if self.ignore_ascii_case {
+            generator = generator.ignore_ascii_case();
+        }
+
+        let output = if self.ignore_ascii_case {
+            let entries: Vec<_> = entries.into_iter().map(CollectionEntry::ignore_ascii_case).collect();
+            if entries.len() < 4 {
+                explain(generator.gen_inline_scan_vec(entries), &[FEW_ENTRIES])
+            } else {
+                explain(generator.gen_fz_string(entries), &[RUNTIME_ANALYSIS])
+            }
+        } else if entries.len() < 4 {
+            explain(generator.gen_inline_scan_vec(entries), &[FEW_ENTRIES])
+        } else {
+            explain(generator.gen_fz_string(entries), &[RUNTIME_ANALYSIS])
+        };
+
+        Ok(self.postflight(output).0)
+    }
+
+    #[cfg(feature = "macros")]
+    pub(crate) fn emit_bytes_collection_expr(self, entries: Vec<CollectionEntry<NonLiteralKey>>) -> Result<TokenStream, String> {
         let generator = self.preflight(entries.len())?;
         let output = if entries.len() < 4 {
-            generator.gen_inline_scan_vec(entries)
+            explain(generator.gen_inline_scan_vec(entries), &[FEW_ENTRIES])
         } else {
-            generator.gen_fz_string(entries)
+            explain(generator.gen_fz_bytes(entries), &[RUNTIME_ANALYSIS])
         };
 
-        Ok(self.postflight(output))
+        Ok(self.postflight(output).0)
+    }
+
+    #[cfg(feature = "macros")]
+    pub(crate) fn emit_prefix_collection_expr(self, entries: Vec<CollectionEntry<NonLiteralKey>>) -> Result<TokenStream, String> {
+        let generator = self.preflight(entries.len())?;
+        let output = explain(generator.gen_fz_prefix(entries), &[PREFIX_QUERIES]);
+        Ok(self.postflight(output).0)
+    }
+
+    #[cfg(feature = "macros")]
+    pub(crate) fn emit_enum_collection_expr(self, entries: Vec<CollectionEntry<NonLiteralKey>>) -> Result<TokenStream, String> {
+        let generator = self.preflight(entries.len())?;
+        let output = explain(generator.gen_fz_enum(entries), &[EXHAUSTIVE_KEYS]);
+        Ok(self.postflight(output).0)
+    }
+
+    /// Emits a collection whose layout is computed at runtime, since a custom hasher can't be run ahead of time.
+    fn emit_custom_hasher_collection<K>(
+        &self,
+        entries: Vec<CollectionEntry<K>>,
+        hasher: &Type,
+        ignore_ascii_case: bool,
+    ) -> Result<(TokenStream, AnalysisReport), String> {
+        if self.seed.is_some() {
+            return Err("a seed can't be combined with a custom hasher".to_string());
+        }
+
+        let generator = self.preflight(entries.len())?;
+        let output = if ignore_ascii_case {
+            let entries = entries.into_iter().map(CollectionEntry::ignore_ascii_case).collect();
+            generator.gen_fz_case_insensitive_string_with_hasher(entries, hasher)
+        } else {
+            generator.gen_hash_with_hasher(entries, hasher)
+        };
+
+        Ok(self.postflight(explain(output, &[CUSTOM_HASHER])))
     }
 
     fn clean_values<K>(&self, entries: &mut [CollectionEntry<K>]) {
@@ -466,38 +798,51 @@ impl CollectionEmitter {
         } else if self.alias_name.is_some() && self.symbol_name.is_none() {
             Err("alias_name cannot be used without symbol_name".to_string())
         } else {
-            Ok(Generator::new(&self.key_type, self.value_type.as_ref(), len))
+            Ok(Generator::new(&self.key_type, self.value_type.as_ref(), len).seed(self.seed))
         }
     }
 
     #[expect(clippy::option_if_let_else, reason = "Reads better without the recommended sugar")]
-    fn postflight(&self, output: Output) -> TokenStream {
+    fn postflight(&self, output: Output) -> (TokenStream, AnalysisReport) {
         let type_sig = output.type_sig;
         let ctor = output.ctor;
         let visibility = &self.visibility;
 
-        if self.is_static {
+        let doc = if self.report {
+            let text = output.report.to_string();
+            let lines = text.lines().map(|line| format!(" {line}"));
+            quote!(#( #[doc = #lines] )*)
+        } else {
+            quote!()
+        };
+
+        let tokens = if self.is_static {
             let symbol_name = format_ident!("{}", self.symbol_name.as_ref().unwrap());
-            if self.const_keys && self.const_values {
+            // collections using a custom hasher are built at runtime, so they can't be const
+            if self.const_keys && self.const_values && self.hasher.is_none() {
                 if let Some(alias_name) = self.alias_name.as_ref() {
                     let alias_name = format_ident!("{}", alias_name);
                     quote!(
+                        #doc
                         #visibility type #alias_name = #type_sig;
                         #visibility static #symbol_name: #alias_name = #ctor;
                     )
                 } else {
                     quote!(
+                        #doc
                         #visibility static #symbol_name: #type_sig = #ctor;
                     )
                 }
             } else if let Some(alias_name) = self.alias_name.as_ref() {
                 let alias_name = format_ident!("{}", alias_name);
                 quote!(
+                    #doc
                     #visibility type #alias_name = #type_sig;
                     #visibility static #symbol_name: std::sync::LazyLock<#alias_name> = std::sync::LazyLock::new(|| { #ctor });
                 )
             } else {
                 quote!(
+                   #doc
                    #visibility static #symbol_name: std::sync::LazyLock<#type_sig> = std::sync::LazyLock::new(|| { #ctor });
                 )
             }
@@ -508,6 +853,7 @@ impl CollectionEmitter {
             if let Some(alias_name) = self.alias_name.as_ref() {
                 let alias_name = format_ident!("{}", alias_name);
                 quote!(
+                    #doc
                     type #alias_name = #type_sig;
                     let #mutable #symbol_name: #alias_name = #ctor;
                 )
@@ -518,10 +864,31 @@ impl CollectionEmitter {
             }
         } else {
             ctor
-        }
+        };
+
+        (tokens, output.report)
     }
 }
 
+const FEW_ENTRIES: &str = "there are fewer than 4 entries, so scanning is faster than hashing or searching";
+const ENOUGH_ENTRIES_TO_HASH: &str = "there are at least 4 entries, so hashing is faster than scanning";
+const ORDERED_QUERIES: &str = "ordered collections answer range and order-based queries, so their keys are kept sorted and searched";
+const NO_SUBSLICE: &str = "no short range of bytes tells the keys apart, so whole keys are hashed";
+const SIMD_PROBING: &str = "the collection is small enough to compare hash tags of all the entries in parallel";
+const TOO_MANY_FOR_SIMD_PROBING: &str = "SIMD tag probing is only used for 8 to 64 entries";
+const RUNTIME_ANALYSIS: &str = "some keys aren't literals, so the strategy is chosen when the collection is created at runtime";
+const CUSTOM_HASHER: &str = "a custom hasher can't run while the collection is generated, so the layout is computed at runtime";
+const EXHAUSTIVE_KEYS: &str = "every variant of the enum must have an entry, which is checked by matching on the keys";
+const PREFIX_QUERIES: &str = "prefix queries need the keys in sorted order, so they are searched rather than hashed";
+
+/// Records why the generator was picked, ahead of the reasons given by the generator itself.
+fn explain(mut output: Output, reasons: &[&str]) -> Output {
+    let mut all: Vec<String> = reasons.iter().map(ToString::to_string).collect();
+    all.append(&mut output.report.reasons);
+    output.report.reasons = all;
+    output
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -602,8 +969,151 @@ mod tests {
             .to_string();
 
         assert_eq!(
-            "let SYMBOL : :: frozen_collections :: inline_sets :: InlineScanSet :: < i32 , 0 > = :: frozen_collections :: inline_sets :: InlineScanSet :: < i32 , 0 > :: new (:: frozen_collections :: inline_maps :: InlineScanMap :: < i32 , () , 0 > :: new_raw ([])) ;",
+            "let SYMBOL : :: frozen_collections :: inline_sets :: InlineEytzingerSearchSet :: < i32 , 0 > = :: frozen_collections :: inline_sets :: InlineEytzingerSearchSet :: < i32 , 0 > :: new (:: frozen_collections :: inline_maps :: InlineEytzingerSearchMap :: < i32 , () , 0 > :: new_raw ([])) ;",
             result
         );
     }
+
+    #[test]
+    fn test_analysis_report() {
+        use crate::diagnostics::{KeyHashing, Strategy};
+
+        let entries: Vec<_> = (0..100).map(|x| CollectionEntry::set_entry(x * 3, parse_quote!(#x))).collect();
+        let (_, report) = CollectionEmitter::new(&parse_quote! { i32 })
+            .emit_scalar_collection_with_report(entries)
+            .unwrap();
+
+        let layout = report.layout.as_ref().unwrap();
+        assert_eq!("InlineBitsetScalarLookupSet", report.collection_type);
+        assert_eq!(Strategy::BitsetScalarLookup, layout.strategy);
+        assert_eq!(100, layout.len);
+        assert_eq!(320, layout.slots);
+        assert_eq!(1, report.reasons.len());
+
+        let entries: Vec<_> = (0..20).map(|x| CollectionEntry::set_entry(x * 1000, parse_quote!(#x))).collect();
+        let (_, report) = CollectionEmitter::new(&parse_quote! { i32 })
+            .emit_scalar_collection_with_report(entries)
+            .unwrap();
+
+        let layout = report.layout.as_ref().unwrap();
+        assert_eq!("InlineSimdProbeSet", report.collection_type);
+        assert_eq!(Some(KeyHashing::Scalar), layout.key_hashing);
+        assert_eq!(0, layout.heap_bytes);
+
+        let entries = vec![CollectionEntry::set_entry("a".to_string(), parse_quote!("a"))];
+        let (_, report) = CollectionEmitter::new(&parse_quote! { &str })
+            .emit_string_collection_with_report(entries)
+            .unwrap();
+
+        assert_eq!("InlineScanSet", report.collection_type);
+        assert!(report.to_string().contains("- Strategy: Scan\n"));
+    }
+
+    #[test]
+    fn test_ignore_ascii_case() {
+        use crate::diagnostics::KeyHashing;
+
+        let entries: Vec<_> = ["Apple", "APPLE", "Banana", "Cherry", "Damson", "Elder"]
+            .iter()
+            .map(|x| CollectionEntry::set_entry((*x).to_string(), parse_quote!(#x)))
+            .collect();
+
+        let (tokens, report) = CollectionEmitter::new(&parse_quote! { &str })
+            .ignore_ascii_case(true)
+            .emit_string_collection_with_report(entries.clone())
+            .unwrap();
+
+        let layout = report.layout.as_ref().unwrap();
+        assert_eq!(5, layout.len);
+        assert_eq!(Some(KeyHashing::LeftSubslice), layout.key_hashing);
+        assert!(
+            tokens
+                .to_string()
+                .contains(":: frozen_collections :: AsciiCaseInsensitive < & str >"),
+            "{tokens}"
+        );
+
+        let (_, report) = CollectionEmitter::new(&parse_quote! { &str })
+            .emit_string_collection_with_report(entries)
+            .unwrap();
+        assert_eq!(6, report.layout.unwrap().len);
+    }
+
+    #[test]
+    fn test_seed() {
+        let entries: Vec<_> = (0..10).map(|x| CollectionEntry::set_entry(x * 7919, parse_quote!(#x))).collect();
+        let emit = |emitter: CollectionEmitter| emitter.emit_hash_collection(entries.clone()).unwrap().to_string();
+
+        let default = emit(CollectionEmitter::new(&parse_quote! { i32 }));
+        let seeded = emit(CollectionEmitter::new(&parse_quote! { i32 }).seed(42));
+        assert_eq!(seeded, emit(CollectionEmitter::new(&parse_quote! { i32 }).seed(42)));
+        assert_ne!(default, seeded);
+        assert!(seeded.contains("with_seed (42u64)"), "{seeded}");
+    }
+
+    #[test]
+    fn test_hasher() {
+        let entries: Vec<_> = (0..10).map(|x| CollectionEntry::set_entry(x, parse_quote!(#x))).collect();
+
+        let (tokens, report) = CollectionEmitter::new(&parse_quote! { i32 })
+            .hasher(&parse_quote! { my::Hasher })
+            .symbol_name("SET")
+            .static_instance(true)
+            .const_keys(true)
+            .const_values(true)
+            .emit_hash_collection_with_report(entries)
+            .unwrap();
+
+        let r = tokens.to_string();
+        assert!(
+            r.contains(
+                "std :: sync :: LazyLock < :: frozen_collections :: sets :: HashSet :: < i32 , :: frozen_collections :: SmallCollection , :: frozen_collections :: hashers :: BridgeHasher < my :: Hasher > > >"
+            ),
+            "{r}"
+        );
+        assert!(r.contains("< my :: Hasher as :: core :: default :: Default > :: default ()"), "{r}");
+        assert_eq!("HashSet", report.collection_type);
+
+        let entries = vec![CollectionEntry::map_entry("a".to_string(), parse_quote!("a"), parse_quote!(1))];
+        let r = CollectionEmitter::new(&parse_quote! { &str })
+            .value_type(&parse_quote! { i32 })
+            .hasher(&parse_quote! { my::Hasher })
+            .ignore_ascii_case(true)
+            .emit_string_collection(entries.clone())
+            .unwrap()
+            .to_string();
+        assert!(
+            r.contains(":: FzStringMap :: < :: frozen_collections :: AsciiCaseInsensitive < Box < str >> , i32 , my :: Hasher >"),
+            "{r}"
+        );
+        assert!(r.contains("(:: frozen_collections :: AsciiCaseInsensitive (\"a\") , 1)"), "{r}");
+
+        let r = CollectionEmitter::new(&parse_quote! { &str })
+            .value_type(&parse_quote! { i32 })
+            .hasher(&parse_quote! { my::Hasher })
+            .seed(42)
+            .emit_string_collection(entries);
+        assert_eq!("a seed can't be combined with a custom hasher", r.unwrap_err());
+    }
+
+    #[test]
+    fn test_analysis_report_doc() {
+        let entries: Vec<_> = (0..4).map(|x| CollectionEntry::set_entry(x, parse_quote!(#x))).collect();
+
+        let r = CollectionEmitter::new(&parse_quote! { i32 })
+            .symbol_name("SYMBOL")
+            .alias_name("Alias")
+            .analysis_report(true)
+            .emit_ordered_collection(entries.clone())
+            .unwrap()
+            .to_string();
+        assert!(r.starts_with("# [doc = \" Collection type: `InlineEytzingerSearchSet`\"]"), "{r}");
+
+        let r = CollectionEmitter::new(&parse_quote! { i32 })
+            .analysis_report(true)
+            .emit_ordered_collection(entries)
+            .unwrap()
+            .to_string();
+        assert!(!r.contains("doc"), "{r}");
+    }
 }
@@ -1,5 +1,6 @@
 #![expect(clippy::redundant_pub_crate, reason = "Helps clarity")]
 
+use crate::keys::AsciiCaseInsensitive;
 use core::fmt::{Debug, Formatter};
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
@@ -11,7 +12,7 @@ pub(crate) struct NonLiteralKey;
 /// Represents an entry in a collection used to build a frozen collection.
 pub struct CollectionEntry<K> {
     pub(crate) key: K,
-    key_expr: Expr,
+    pub(crate) key_expr: Expr,
     pub(crate) value_expr: Expr,
 }
 
@@ -29,6 +30,16 @@ impl<K> CollectionEntry<K> {
             value_expr: parse_quote!(()),
         }
     }
+
+    /// Wraps the key so that it is compared and hashed without regard to ASCII case.
+    pub(crate) fn ignore_ascii_case(self) -> CollectionEntry<AsciiCaseInsensitive<K>> {
+        let key_expr = &self.key_expr;
+        CollectionEntry {
+            key: AsciiCaseInsensitive(self.key),
+            key_expr: parse_quote!(::frozen_collections::AsciiCaseInsensitive(#key_expr)),
+            value_expr: self.value_expr,
+        }
+    }
 }
 
 impl<K> ToTokens for CollectionEntry<K> {
@@ -129,4 +140,17 @@ mod tests {
 
         assert_eq!(debug_str, "CollectionEntry { key: \"key\", key_expr 'key', value_expr: 'value'}");
     }
+
+    #[test]
+    fn test_ignore_ascii_case() {
+        let key_expr: Expr = parse_quote!("Key");
+        let value_expr: Expr = parse_quote!(value);
+        let entry = CollectionEntry::map_entry("Key", key_expr, value_expr).ignore_ascii_case();
+
+        assert_eq!(entry.key, AsciiCaseInsensitive("key"));
+        assert_eq!(
+            entry.to_token_stream().to_string(),
+            "(:: frozen_collections :: AsciiCaseInsensitive (\"Key\") , value)"
+        );
+    }
 }
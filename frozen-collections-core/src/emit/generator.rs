@@ -1,16 +1,19 @@
 #![expect(clippy::needless_pass_by_value, reason = "Expected in syn-related code")]
 
+use crate::analyzers::{PERFECT_HASH_MAX_INPUT_SIZE, PERFECT_HASH_MIN_INPUT_SIZE, PerfectHashAnalysisResult, analyze_perfect_hash_codes};
+use crate::diagnostics::{Diagnostics, Strategy};
+use crate::emit::analysis_report::AnalysisReport;
 use crate::emit::collection_entry::CollectionEntry;
-use crate::hash_tables::HashTable;
+use crate::hash_tables::{HashTable, PerfectHashTable, SimdProbeTable};
 use crate::traits::{CollectionMagnitude, Hasher, LargeCollection, MediumCollection, Scalar, SmallCollection};
-use crate::utils::{DeduppedVec, SortedAndDeduppedVec};
+use crate::utils::{DeduppedVec, SortedAndDeduppedVec, scalar_bitset};
 use alloc::vec;
-use proc_macro2::{Literal, TokenStream};
+use proc_macro2::{Literal, TokenStream, TokenTree};
 use quote::{ToTokens, quote};
 use syn::{Type, parse_quote, parse_str};
 
 #[cfg(not(feature = "std"))]
-use {alloc::string::String, alloc::string::ToString, alloc::vec::Vec};
+use {alloc::format, alloc::string::String, alloc::string::ToString, alloc::vec::Vec};
 
 #[derive(Debug)]
 pub(super) struct Generator {
@@ -18,11 +21,14 @@ pub(super) struct Generator {
     value_type: Type,
     len: TokenStream,
     gen_set: bool,
+    ignore_ascii_case: bool,
+    seed: Option<u64>,
 }
 
 pub(super) struct Output {
     pub ctor: TokenStream,
     pub type_sig: TokenStream,
+    pub report: AnalysisReport,
 }
 
 impl Generator {
@@ -32,30 +38,93 @@ impl Generator {
             value_type: value_type.map_or_else(|| parse_quote!(()), Clone::clone),
             len: Self::inject_underscores(Literal::usize_unsuffixed(len).to_token_stream()),
             gen_set: value_type.is_none(),
+            ignore_ascii_case: false,
+            seed: None,
         }
     }
 
+    /// Pins the seed of the collections whose layout is computed at runtime.
+    pub(super) const fn seed(mut self, seed: Option<u64>) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Generates collections whose string keys are compared and hashed without regard to ASCII case.
+    pub(super) fn ignore_ascii_case(mut self) -> Self {
+        let key_type = &self.key_type;
+        self.key_type = parse_quote!(::frozen_collections::AsciiCaseInsensitive<#key_type>);
+        self.ignore_ascii_case = true;
+        self
+    }
+
     pub fn gen_fz_hash<K>(self, entries: Vec<CollectionEntry<K>>) -> Output {
         let key_type = &self.key_type;
         let value_type = &self.value_type;
 
         let mut ty = quote!(::frozen_collections::FzHashMap);
-        let mut generics = quote!(<#key_type, #value_type>);
+        let bh_type = self.seeded_build_hasher_type();
+        let mut generics = quote!(<#key_type, #value_type #bh_type>);
         let mut type_sig = quote!(#ty::#generics);
-        let mut ctor = quote!(#type_sig::new(vec![
+        let mut ctor = self.runtime_ctor(&type_sig, entries);
+
+        if self.gen_set {
+            ty = quote!(::frozen_collections::FzHashSet);
+            generics = quote!(<#key_type #bh_type>);
+            type_sig = quote!(#ty::#generics);
+            ctor = quote!(#type_sig::from(#ctor));
+        }
+
+        Self::output(&ty, ctor, type_sig, None)
+    }
+
+    /// Generates a hash collection whose keys are hashed with the given `BuildHasher`, which is created with `Default`.
+    pub(super) fn gen_hash_with_hasher<K>(self, entries: Vec<CollectionEntry<K>>, hasher: &Type) -> Output {
+        let key_type = &self.key_type;
+        let value_type = &self.value_type;
+        let magnitude = Self::collection_magnitude(entries.len());
+        let hasher_type = quote!(::frozen_collections::hashers::BridgeHasher<#hasher>);
+
+        let mut ty = quote!(::frozen_collections::maps::HashMap);
+        let mut generics = quote!(<#key_type, #value_type, #magnitude, #hasher_type>);
+        let mut type_sig = quote!(#ty::#generics);
+        let mut ctor = quote!(#type_sig::with_hasher(vec![
             #(
                 #entries,
             )*
-        ]));
+        ], ::frozen_collections::hashers::BridgeHasher::new(<#hasher as ::core::default::Default>::default())).unwrap());
 
         if self.gen_set {
-            ty = quote!(::frozen_collections::FzHashSet);
-            generics = quote!(<#key_type>);
+            ty = quote!(::frozen_collections::sets::HashSet);
+            generics = quote!(<#key_type, #magnitude, #hasher_type>);
+            type_sig = quote!(#ty::#generics);
+            ctor = quote!(#type_sig::new(#ctor));
+        }
+
+        Self::output(&ty, ctor, type_sig, None)
+    }
+
+    /// Generates a case-insensitive string collection whose keys are hashed with the given `BuildHasher`.
+    pub(super) fn gen_fz_case_insensitive_string_with_hasher<K>(self, entries: Vec<CollectionEntry<K>>, hasher: &Type) -> Output {
+        let key_type = quote!(::frozen_collections::AsciiCaseInsensitive<Box<str>>);
+        let value_type = &self.value_type;
+
+        let mut ty = quote!(::frozen_collections::FzStringMap);
+        let mut generics = quote!(<#key_type, #value_type, #hasher>);
+        let mut type_sig = quote!(#ty::#generics);
+        let mut ctor = quote!(#type_sig::with_hasher(vec![
+            #(
+                #entries,
+            )*
+        ], <#hasher as ::core::default::Default>::default()));
+
+        if self.gen_set {
+            ty = quote!(::frozen_collections::FzStringSet);
+            generics = quote!(<#key_type, #hasher>);
             type_sig = quote!(#ty::#generics);
             ctor = quote!(#type_sig::from(#ctor));
         }
 
-        Output { ctor, type_sig }
+        Self::output(&ty, ctor, type_sig, None)
     }
 
     #[cfg(feature = "macros")]
@@ -79,7 +148,7 @@ impl Generator {
             ctor = quote!(#type_sig::from(#ctor));
         }
 
-        Output { ctor, type_sig }
+        Self::output(&ty, ctor, type_sig, None)
     }
 
     #[cfg(feature = "macros")]
@@ -88,22 +157,137 @@ impl Generator {
         let value_type = &self.value_type;
 
         let mut ty = quote!(::frozen_collections::FzStringMap);
-        let mut generics = quote!(<#key_type, #value_type>);
+        let bh_type = self.seeded_build_hasher_type();
+        let mut generics = quote!(<#key_type, #value_type #bh_type>);
         let mut type_sig = quote!(#ty::#generics);
-        let mut ctor = quote!(#type_sig::new(vec![
+        let mut ctor = self.runtime_ctor(&type_sig, entries);
+
+        if self.gen_set {
+            ty = quote!(::frozen_collections::FzStringSet);
+            generics = quote!(<#key_type #bh_type>);
+            type_sig = quote!(#ty::#generics);
+            ctor = quote!(#type_sig::from(#ctor));
+        }
+
+        Self::output(&ty, ctor, type_sig, None)
+    }
+
+    #[cfg(feature = "macros")]
+    pub(super) fn gen_fz_bytes<K>(self, entries: Vec<CollectionEntry<K>>) -> Output {
+        let key_type = &self.key_type;
+        let value_type = &self.value_type;
+
+        let mut ty = quote!(::frozen_collections::FzBytesMap);
+        let bh_type = self.seeded_build_hasher_type();
+        let mut generics = quote!(<#key_type, #value_type #bh_type>);
+        let mut type_sig = quote!(#ty::#generics);
+        let mut ctor = self.runtime_ctor(&type_sig, entries);
+
+        if self.gen_set {
+            ty = quote!(::frozen_collections::FzBytesSet);
+            generics = quote!(<#key_type #bh_type>);
+            type_sig = quote!(#ty::#generics);
+            ctor = quote!(#type_sig::from(#ctor));
+        }
+
+        Self::output(&ty, ctor, type_sig, None)
+    }
+
+    #[cfg(feature = "macros")]
+    pub(super) fn gen_fz_prefix<K>(self, entries: Vec<CollectionEntry<K>>) -> Output {
+        let key_type = &self.key_type;
+        let value_type = &self.value_type;
+
+        let ty = quote!(::frozen_collections::FzPrefixMap);
+        let generics = quote!(<#key_type, #value_type>);
+        let type_sig = quote!(#ty::#generics);
+        let ctor = quote!(#type_sig::new(vec![
             #(
                 #entries,
             )*
         ]));
 
+        Self::output(&ty, ctor, type_sig, None)
+    }
+
+    #[cfg(feature = "macros")]
+    pub(super) fn gen_fz_enum<K>(self, entries: Vec<CollectionEntry<K>>) -> Output {
+        let key_type = &self.key_type;
+        let value_type = &self.value_type;
+        let keys = entries.iter().map(|entry| &entry.key_expr);
+        let values = entries.iter().map(|entry| &entry.value_expr);
+        let indices = (0..entries.len()).map(Literal::usize_unsuffixed);
+        let len = Literal::usize_unsuffixed(entries.len());
+
+        // the values are evaluated once, in order, so they may move captured variables, while
+        // the keys are used as the patterns of an exhaustive match, so the compiler reports any missing variants
+        let ty = quote!(::frozen_collections::FzEnumMap);
+        let generics = quote!(<#key_type, #value_type>);
+        let type_sig = quote!(#ty::#generics);
+        let ctor = quote!({
+            let mut values: [::core::option::Option<#value_type>; #len] = [
+                #(
+                    ::core::option::Option::Some(#values),
+                )*
+            ];
+
+            #type_sig::from_fn(|key| match key {
+                #(
+                    #keys => values[#indices].take().unwrap(),
+                )*
+            })
+        });
+
+        Self::output(&ty, ctor, type_sig, None)
+    }
+
+    pub(super) fn gen_inline_bitset_scalar_lookup<K>(&self, entries: SortedAndDeduppedVec<CollectionEntry<K>>) -> Output
+    where
+        K: Scalar,
+    {
+        let indices: Vec<_> = entries.iter().map(|entry| entry.key.index()).collect();
+        let (base, words, ranks) = scalar_bitset(&indices);
+
+        let entries = entries.into_vec();
+
+        let mut layout = Diagnostics::new(Strategy::BitsetScalarLookup, entries.len());
+        layout.slots = words.len() * 64;
+
+        let key_type = &self.key_type;
+        let value_type = &self.value_type;
+        let len = &self.len;
+        let magnitude = Self::collection_magnitude(entries.len());
+        let num_words = Literal::usize_unsuffixed(words.len());
+        let words = words.iter().map(|x| Literal::u64_unsuffixed(*x));
+        let ranks = ranks.iter().map(|x| Literal::u32_unsuffixed(*x));
+
+        let mut ty = quote!(::frozen_collections::inline_maps::InlineBitsetScalarLookupMap);
+        let mut generics = quote!(<#key_type, #value_type, #len, #num_words, #magnitude>);
+        let mut type_sig = quote!(#ty::#generics);
+        let mut ctor = quote!(#type_sig::new_raw([
+            #(
+                #entries,
+            )*
+        ],
+        [
+            #(
+                #words,
+            )*
+        ],
+        [
+            #(
+                #ranks,
+            )*
+        ], #base));
+
         if self.gen_set {
-            ty = quote!(::frozen_collections::FzStringSet);
-            generics = quote!(<#key_type>);
+            ty = quote!(::frozen_collections::inline_sets::InlineBitsetScalarLookupSet);
+            generics = quote!(<#key_type, #len, #num_words, #magnitude>);
             type_sig = quote!(#ty::#generics);
-            ctor = quote!(#type_sig::from(#ctor));
+            ctor = quote!(#type_sig::new(#ctor));
         }
 
-        Output { ctor, type_sig }
+        Self::output(&ty, ctor, type_sig, Some(layout))
     }
 
     pub(super) fn gen_inline_dense_scalar_lookup<K>(&self, entries: SortedAndDeduppedVec<CollectionEntry<K>>) -> Output
@@ -112,6 +296,9 @@ impl Generator {
     {
         let entries = entries.into_vec();
 
+        let mut layout = Diagnostics::new(Strategy::DenseScalarLookup, entries.len());
+        layout.slots = entries.len();
+
         let key_type = &self.key_type;
         let value_type = &self.value_type;
         let len = &self.len;
@@ -134,13 +321,13 @@ impl Generator {
             ctor = quote!(#type_sig::new(#ctor));
         }
 
-        Output { ctor, type_sig }
+        Self::output(&ty, ctor, type_sig, Some(layout))
     }
 
-    #[cfg(feature = "emit")]
     pub(super) fn gen_inline_eytzinger_search<K>(&self, entries: SortedAndDeduppedVec<CollectionEntry<K>>) -> Output {
         let mut entries = entries.into_vec();
         crate::utils::eytzinger_layout(&mut entries);
+        let layout = Self::eytzinger_layout(entries.len());
 
         let key_type = &self.key_type;
         let value_type = &self.value_type;
@@ -162,13 +349,14 @@ impl Generator {
             ctor = quote!(#type_sig::new(#ctor));
         }
 
-        Output { ctor, type_sig }
+        Self::output(&ty, ctor, type_sig, Some(layout))
     }
 
     pub(super) fn gen_inline_eytzinger_search_vec<K>(&self, entries: Vec<CollectionEntry<K>>) -> Output {
         let key_type = &self.key_type;
         let value_type = &self.value_type;
         let len = &self.len;
+        let entries_len = entries.len();
 
         let mut ty = quote!(::frozen_collections::inline_maps::InlineEytzingerSearchMap);
         let mut generics = quote!(<#key_type, #value_type, #len>);
@@ -186,7 +374,7 @@ impl Generator {
             ctor = quote!(#type_sig::new(#ctor));
         }
 
-        Output { ctor, type_sig }
+        Self::output(&ty, ctor, type_sig, Some(Self::eytzinger_layout(entries_len)))
     }
 
     pub(super) fn gen_inline_hash<K, H>(
@@ -199,10 +387,22 @@ impl Generator {
     where
         H: Hasher<K>,
     {
+        let (hasher_type, hasher_ctor) = &self.query_hasher(hasher_type, hasher_ctor);
+        let hash_codes: Vec<_> = entries.iter().map(|x| hasher.hash_one(&x.key)).collect();
+        if let Some(analysis) = analyze_perfect_hash_codes(&hash_codes) {
+            let mut output = self.gen_inline_perfect_hash(entries, analysis, hasher_type, hasher_ctor);
+            output.report.layout = output.report.layout.map(|layout| Self::hashed_layout(layout, hasher));
+            output
+                .report
+                .reasons
+                .push("a minimal perfect hash function was found, so every lookup inspects a single entry".to_string());
+            return output;
+        }
+
         let key_type = &self.key_type;
         let value_type = &self.value_type;
         let len = &self.len;
-        let (ht, magnitude, num_slots, collisions) = self.gen_inline_hash_table_components(entries, hasher);
+        let (ht, magnitude, num_slots, collisions, layout) = self.gen_inline_hash_table_components(entries, hasher);
 
         let mut ty = if collisions {
             quote!(::frozen_collections::inline_maps::InlineHashMap)
@@ -226,11 +426,125 @@ impl Generator {
             ctor = quote!(#type_sig::new(#ctor));
         }
 
-        Output { ctor, type_sig }
+        let mut output = Self::output(&ty, ctor, type_sig, Some(layout));
+        if (PERFECT_HASH_MIN_INPUT_SIZE..=PERFECT_HASH_MAX_INPUT_SIZE).contains(&hash_codes.len()) {
+            output
+                .report
+                .reasons
+                .push("a classic hash table is used since no minimal perfect hash function was found".to_string());
+        } else {
+            output.report.reasons.push(format!(
+                "a classic hash table is used since perfect hashing is only considered for {PERFECT_HASH_MIN_INPUT_SIZE} to {PERFECT_HASH_MAX_INPUT_SIZE} entries"
+            ));
+        }
+
+        if !collisions {
+            output
+                .report
+                .reasons
+                .push("no two keys share a hash slot, so each slot refers to at most one entry".to_string());
+        }
+
+        output
+    }
+
+    fn gen_inline_perfect_hash<K>(
+        &self,
+        entries: DeduppedVec<CollectionEntry<K>>,
+        analysis: PerfectHashAnalysisResult,
+        hasher_type: &TokenStream,
+        hasher_ctor: &TokenStream,
+    ) -> Output {
+        let key_type = &self.key_type;
+        let value_type = &self.value_type;
+        let len = &self.len;
+
+        let ht = PerfectHashTable::new(entries, analysis);
+        let layout = ht.diagnostics();
+        let pilots = ht.pilots.iter().map(|x| Literal::u32_unsuffixed(*x));
+        let num_buckets = Literal::usize_unsuffixed(ht.pilots.len());
+        let entries = ht.entries;
+
+        let ht = quote!(::frozen_collections::hash_tables::InlinePerfectHashTable::<(#key_type, #value_type), #len, #num_buckets>::new_raw(
+            [
+            #(
+                #pilots,
+            )*
+            ],
+            [
+            #(
+                #entries,
+            )*
+            ],
+        ));
+
+        let mut ty = quote!(::frozen_collections::inline_maps::InlinePerfectHashMap);
+        let mut generics = quote!(<#key_type, #value_type, #len, #num_buckets, #hasher_type>);
+        let mut type_sig = quote!(#ty::#generics);
+        let mut ctor = quote!(#type_sig::new_raw(#ht, #hasher_ctor));
+
+        if self.gen_set {
+            ty = quote!(::frozen_collections::inline_sets::InlinePerfectHashSet);
+            generics = quote!(<#key_type, #len, #num_buckets, #hasher_type>);
+            type_sig = quote!(#ty::#generics);
+            ctor = quote!(#type_sig::new(#ctor));
+        }
+
+        Self::output(&ty, ctor, type_sig, Some(layout))
+    }
+
+    pub(super) fn gen_inline_simd_probe<K, H>(
+        &self,
+        entries: DeduppedVec<CollectionEntry<K>>,
+        hasher: &H,
+        hasher_type: &TokenStream,
+        hasher_ctor: &TokenStream,
+    ) -> Output
+    where
+        H: Hasher<K>,
+    {
+        let key_type = &self.key_type;
+        let value_type = &self.value_type;
+        let len = &self.len;
+        let (hasher_type, hasher_ctor) = &self.query_hasher(hasher_type, hasher_ctor);
+
+        let table = SimdProbeTable::new(entries, |x| hasher.hash_one(&x.key));
+        let layout = Self::hashed_layout(table.diagnostics(), hasher);
+        let tags = table.tags.iter().map(|x| Literal::u8_unsuffixed(*x));
+        let num_tags = Literal::usize_unsuffixed(table.tags.len());
+        let entries = table.entries;
+
+        let table = quote!(::frozen_collections::hash_tables::InlineSimdProbeTable::<(#key_type, #value_type), #len, #num_tags>::new_raw(
+            [
+            #(
+                #tags,
+            )*
+            ],
+            [
+            #(
+                #entries,
+            )*
+            ],
+        ));
+
+        let mut ty = quote!(::frozen_collections::inline_maps::InlineSimdProbeMap);
+        let mut generics = quote!(<#key_type, #value_type, #len, #num_tags, #hasher_type>);
+        let mut type_sig = quote!(#ty::#generics);
+        let mut ctor = quote!(#type_sig::new_raw(#table, #hasher_ctor));
+
+        if self.gen_set {
+            ty = quote!(::frozen_collections::inline_sets::InlineSimdProbeSet);
+            generics = quote!(<#key_type, #len, #num_tags, #hasher_type>);
+            type_sig = quote!(#ty::#generics);
+            ctor = quote!(#type_sig::new(#ctor));
+        }
+
+        Self::output(&ty, ctor, type_sig, Some(layout))
     }
 
     pub(super) fn gen_inline_scan<K>(&self, entries: DeduppedVec<CollectionEntry<K>>) -> Output {
         let entries = entries.into_vec();
+        let layout = Self::scan_layout(entries.len());
 
         let key_type = &self.key_type;
         let value_type = &self.value_type;
@@ -252,13 +566,14 @@ impl Generator {
             ctor = quote!(#type_sig::new(#ctor));
         }
 
-        Output { ctor, type_sig }
+        Self::output(&ty, ctor, type_sig, Some(layout))
     }
 
     pub fn gen_inline_scan_vec<K>(&self, entries: Vec<CollectionEntry<K>>) -> Output {
         let key_type = &self.key_type;
         let value_type = &self.value_type;
         let len = &self.len;
+        let layout = Self::scan_layout(entries.len());
 
         let mut ty = quote!(::frozen_collections::inline_maps::InlineScanMap);
         let mut generics = quote!(<#key_type, #value_type, #len>);
@@ -276,7 +591,7 @@ impl Generator {
             ctor = quote!(#type_sig::new(#ctor));
         }
 
-        Output { ctor, type_sig }
+        Self::output(&ty, ctor, type_sig, Some(layout))
     }
 
     pub(super) fn gen_inline_sparse_scalar_lookup<K>(&self, entries: SortedAndDeduppedVec<CollectionEntry<K>>) -> Output
@@ -297,6 +612,9 @@ impl Generator {
 
         let entries = entries.into_vec();
 
+        let mut layout = Diagnostics::new(Strategy::SparseScalarLookup, entries.len());
+        layout.slots = count;
+
         let key_type = &self.key_type;
         let value_type = &self.value_type;
         let len = &self.len;
@@ -325,14 +643,14 @@ impl Generator {
             ctor = quote!(#type_sig::new(#ctor));
         }
 
-        Output { ctor, type_sig }
+        Self::output(&ty, ctor, type_sig, Some(layout))
     }
 
     fn gen_inline_hash_table_components<K, H>(
         &self,
         entries: DeduppedVec<CollectionEntry<K>>,
         hasher: &H,
-    ) -> (TokenStream, TokenStream, Literal, bool)
+    ) -> (TokenStream, TokenStream, Literal, bool, Diagnostics)
     where
         H: Hasher<K>,
     {
@@ -342,6 +660,7 @@ impl Generator {
 
         let ht = HashTable::<_, LargeCollection>::new(entries, |x| hasher.hash_one(&x.key)).unwrap();
         let collisions = ht.has_collisions();
+        let layout = Self::hashed_layout(ht.diagnostics(), hasher);
         let slots = ht.slots;
         let num_slots = Literal::usize_unsuffixed(slots.len());
         let entries = ht.entries;
@@ -364,6 +683,7 @@ impl Generator {
                 magnitude,
                 parse_quote!(#num_slots),
                 true,
+                layout,
             )
         } else {
             let slots = slots.iter().map(|s| {
@@ -390,10 +710,65 @@ impl Generator {
                 magnitude,
                 parse_quote!(#num_slots),
                 false,
+                layout,
             )
         }
     }
 
+    /// Wraps the hasher of a case-insensitive collection so the collection can be queried with plain strings.
+    fn query_hasher(&self, hasher_type: &TokenStream, hasher_ctor: &TokenStream) -> (TokenStream, TokenStream) {
+        if self.ignore_ascii_case {
+            (
+                quote!(::frozen_collections::hashers::AsciiCaseInsensitiveHasher<#hasher_type>),
+                quote!(::frozen_collections::hashers::AsciiCaseInsensitiveHasher::new(#hasher_ctor)),
+            )
+        } else {
+            (hasher_type.clone(), hasher_ctor.clone())
+        }
+    }
+
+    fn output(ty: &TokenStream, ctor: TokenStream, type_sig: TokenStream, layout: Option<Diagnostics>) -> Output {
+        // the last identifier of the type's path is its name
+        let collection_type = ty
+            .clone()
+            .into_iter()
+            .filter_map(|token| match token {
+                TokenTree::Ident(ident) => Some(ident.to_string()),
+                _ => None,
+            })
+            .last()
+            .unwrap_or_default();
+
+        Output {
+            ctor,
+            type_sig,
+            report: AnalysisReport::new(collection_type, layout),
+        }
+    }
+
+    const fn scan_layout(len: usize) -> Diagnostics {
+        let mut layout = Diagnostics::new(Strategy::Scan, len);
+        layout.longest_chain = len;
+        layout
+    }
+
+    const fn eytzinger_layout(len: usize) -> Diagnostics {
+        let mut layout = Diagnostics::new(Strategy::EytzingerSearch, len);
+        layout.longest_chain = (usize::BITS - len.leading_zeros()) as usize;
+        layout
+    }
+
+    fn hashed_layout<K, H>(mut layout: Diagnostics, hasher: &H) -> Diagnostics
+    where
+        H: Hasher<K>,
+    {
+        // the generated collections hold their tables inline
+        layout.heap_bytes = 0;
+        layout.key_hashing = Some(hasher.key_hashing());
+        layout.subslice_range = hasher.subslice_range();
+        layout
+    }
+
     fn collection_magnitude(len: usize) -> TokenStream {
         if len <= SmallCollection::MAX_CAPACITY {
             quote!(::frozen_collections::SmallCollection)
@@ -404,6 +779,29 @@ impl Generator {
         }
     }
 
+    /// Returns the `BuildHasher` type argument of a collection whose layout is computed at runtime, prefixed with a comma.
+    ///
+    /// Without a seed, the collection uses its default `BuildHasher`, and no type argument is needed.
+    fn seeded_build_hasher_type(&self) -> Option<TokenStream> {
+        self.seed.map(|_| quote!(, ::frozen_collections::foldhash::FixedState))
+    }
+
+    /// Creates a collection whose layout is computed at runtime, hashing its keys with the pinned seed if there is one.
+    fn runtime_ctor<K>(&self, type_sig: &TokenStream, entries: Vec<CollectionEntry<K>>) -> TokenStream {
+        let entries = quote!(vec![
+            #(
+                #entries,
+            )*
+        ]);
+
+        let Some(seed) = self.seed else {
+            return quote!(#type_sig::new(#entries));
+        };
+
+        let seed = Self::inject_underscores(seed.to_token_stream());
+        quote!(#type_sig::with_hasher(#entries, ::frozen_collections::foldhash::FixedState::with_seed(#seed)))
+    }
+
     pub fn inject_underscores(v: TokenStream) -> TokenStream {
         let mut full: Vec<char> = v.to_string().chars().collect();
         let mut suffix_index = None;
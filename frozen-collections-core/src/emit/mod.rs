@@ -1,8 +1,10 @@
 //! Implementation logic for build script emitter features.
 
+pub use analysis_report::*;
 pub use collection_emitter::*;
 pub use collection_entry::*;
 
+mod analysis_report;
 mod collection_emitter;
 mod collection_entry;
 mod generator;
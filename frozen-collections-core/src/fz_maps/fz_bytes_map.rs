@@ -0,0 +1,698 @@
+use crate::DefaultBuildHasher;
+use crate::analyzers::{SliceKeyAnalysisResult, analyze_perfect_hash_codes, analyze_simd_probing, analyze_slice_keys};
+use crate::diagnostics::Diagnostics;
+use crate::hashers::{BridgeHasher, LeftRangeHasher, LengthHasher, RightRangeHasher};
+use crate::maps::decl_macros::{
+    debug_trait_funcs, filter_funcs, index_trait_funcs, len_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs,
+};
+use crate::maps::{HashMap, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, PerfectHashMap, SimdProbeMap, Values, ValuesMut};
+use crate::traits::{Hasher, LargeCollection, Len, Map, MapExtras, MapIteration, MapQuery};
+use crate::utils::{DeduppedVec, into_ok, retain_flagged, retain_flags};
+use core::array;
+use core::fmt::{Debug, Formatter, Result};
+use core::hash::BuildHasher;
+use core::marker::PhantomData;
+use core::ops::Index;
+
+#[cfg(not(feature = "std"))]
+use {alloc::boxed::Box, alloc::vec::Vec};
+
+#[cfg(feature = "serde")]
+use {
+    crate::maps::decl_macros::serialize_trait_funcs,
+    serde::de::{MapAccess, Visitor},
+    serde::ser::SerializeMap,
+    serde::{Deserialize, Deserializer, Serialize, Serializer},
+};
+
+#[derive(Clone)]
+enum MapTypes<V, BH> {
+    LeftRange(HashMap<Box<[u8]>, V, LargeCollection, LeftRangeHasher<BH>>),
+    RightRange(HashMap<Box<[u8]>, V, LargeCollection, RightRangeHasher<BH>>),
+    Length(HashMap<Box<[u8]>, V, LargeCollection, LengthHasher>),
+    Hash(HashMap<Box<[u8]>, V, LargeCollection, BridgeHasher<BH>>),
+    PerfectHash(PerfectHashMap<Box<[u8]>, V, BridgeHasher<BH>>),
+    SimdProbe(SimdProbeMap<Box<[u8]>, V, BridgeHasher<BH>>),
+}
+
+/// A map optimized for fast read access with byte slice keys.
+///
+#[doc = include_str!("../doc_snippets/about.md")]
+#[doc = include_str!("../doc_snippets/hash_warning.md")]
+///
+/// # Alternate Choices
+///
+/// If your keys are known at compile time, consider using the various `fz_*_map` macros instead of
+/// this type as they generally perform better.
+#[derive(Clone)]
+pub struct FzBytesMap<K, V, BH = DefaultBuildHasher> {
+    map_impl: MapTypes<V, BH>,
+    _0: PhantomData<K>,
+}
+
+impl<V> FzBytesMap<Box<[u8]>, V, DefaultBuildHasher> {
+    /// Creates a frozen map.
+    #[must_use]
+    pub fn new(entries: Vec<(impl AsRef<[u8]>, V)>) -> Self {
+        Self::with_hasher(entries, DefaultBuildHasher::default())
+    }
+}
+
+impl<V, BH> FzBytesMap<Box<[u8]>, V, BH> {
+    /// Creates a frozen map which uses the given hash builder to hash keys.
+    #[must_use]
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "Guaranteed not to panic because the map is a LargeCollection"
+    )]
+    pub fn with_hasher(entries: Vec<(impl AsRef<[u8]>, V)>, bh: BH) -> Self
+    where
+        BH: BuildHasher,
+    {
+        let entries: Vec<(Box<[u8]>, V)> = entries.into_iter().map(|(k, v)| (Box::from(k.as_ref()), v)).collect();
+
+        let entries = DeduppedVec::using_cmp(entries, |x, y| x.0.as_ref().cmp(y.0.as_ref()));
+
+        Self {
+            map_impl: {
+                match analyze_slice_keys(entries.iter().map(|x| x.0.as_ref()), &bh) {
+                    SliceKeyAnalysisResult::General => Self::general_map_types(entries, BridgeHasher::new(bh)),
+
+                    SliceKeyAnalysisResult::LeftHandSubslice(range) => {
+                        let h = LeftRangeHasher::new(bh, range);
+                        MapTypes::LeftRange(HashMap::from_dedupped(entries, h).unwrap())
+                    }
+
+                    SliceKeyAnalysisResult::RightHandSubslice(range) => {
+                        let h = RightRangeHasher::new(bh, range);
+                        MapTypes::RightRange(HashMap::from_dedupped(entries, h).unwrap())
+                    }
+
+                    SliceKeyAnalysisResult::Length => MapTypes::Length(HashMap::from_dedupped(entries, LengthHasher).unwrap()),
+                }
+            },
+            _0: PhantomData,
+        }
+    }
+
+    fn general_map_types(entries: DeduppedVec<(Box<[u8]>, V)>, h: BridgeHasher<BH>) -> MapTypes<V, BH>
+    where
+        BH: BuildHasher,
+    {
+        let max_key_len = entries.iter().map(|x| x.0.len()).max().unwrap_or_default();
+        if analyze_simd_probing(entries.len(), Some(max_key_len)) {
+            MapTypes::SimdProbe(SimdProbeMap::from_dedupped(entries, h))
+        } else {
+            let hash_codes: Vec<_> = entries.iter().map(|x| h.hash_one(&x.0)).collect();
+            match analyze_perfect_hash_codes(&hash_codes) {
+                Some(analysis) => MapTypes::PerfectHash(PerfectHashMap::from_analysis(entries, analysis, h)),
+                None => MapTypes::Hash(HashMap::from_dedupped(entries, h).unwrap()),
+            }
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&Box<[u8]>, V) -> U) -> FzBytesMap<Box<[u8]>, U, BH> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(
+        self,
+        f: impl FnMut(&Box<[u8]>, V) -> core::result::Result<U, E>,
+    ) -> core::result::Result<FzBytesMap<Box<[u8]>, U, BH>, E> {
+        Ok(FzBytesMap {
+            map_impl: match self.map_impl {
+                MapTypes::LeftRange(m) => MapTypes::LeftRange(m.try_map_values(f)?),
+                MapTypes::RightRange(m) => MapTypes::RightRange(m.try_map_values(f)?),
+                MapTypes::Length(m) => MapTypes::Length(m.try_map_values(f)?),
+                MapTypes::Hash(m) => MapTypes::Hash(m.try_map_values(f)?),
+                MapTypes::PerfectHash(m) => MapTypes::PerfectHash(m.try_map_values(f)?),
+                MapTypes::SimdProbe(m) => MapTypes::SimdProbe(m.try_map_values(f)?),
+            },
+            _0: PhantomData,
+        })
+    }
+
+    #[doc = include_str!("../doc_snippets/retain_into.md")]
+    #[must_use]
+    pub fn retain_into(self, f: impl FnMut(&Box<[u8]>, &V) -> bool) -> Self
+    where
+        BH: BuildHasher,
+    {
+        Self {
+            map_impl: match self.map_impl {
+                MapTypes::LeftRange(m) => MapTypes::LeftRange(m.retain_into(f)),
+                MapTypes::RightRange(m) => MapTypes::RightRange(m.retain_into(f)),
+                MapTypes::Length(m) => MapTypes::Length(m.retain_into(f)),
+                MapTypes::Hash(m) => MapTypes::Hash(m.retain_into(f)),
+                MapTypes::SimdProbe(m) => MapTypes::SimdProbe(m.retain_into(f)),
+                MapTypes::PerfectHash(m) => {
+                    // a subset of the keys may not have a perfect hash function, so pick a new layout
+                    let Some(flags) = retain_flags(m.iter(), f) else {
+                        return Self {
+                            map_impl: MapTypes::PerfectHash(m),
+                            _0: PhantomData,
+                        };
+                    };
+
+                    let (entries, h) = m.into_parts();
+                    Self::general_map_types(DeduppedVec::from_unique(retain_flagged(entries, flags)), h)
+                }
+            },
+            _0: PhantomData,
+        }
+    }
+
+    /// Creates a map like this one holding the given entries, a subset of this map's entries in iteration order.
+    fn with_retained(&self, entries: Vec<(Box<[u8]>, V)>) -> Self
+    where
+        BH: BuildHasher + Clone,
+    {
+        Self {
+            map_impl: match &self.map_impl {
+                MapTypes::LeftRange(m) => MapTypes::LeftRange(m.with_retained(entries)),
+                MapTypes::RightRange(m) => MapTypes::RightRange(m.with_retained(entries)),
+                MapTypes::Length(m) => MapTypes::Length(m.with_retained(entries)),
+                MapTypes::Hash(m) => MapTypes::Hash(m.with_retained(entries)),
+                MapTypes::SimdProbe(m) => MapTypes::SimdProbe(m.with_retained(entries)),
+
+                // a subset of the keys may not have a perfect hash function, so pick a new layout
+                MapTypes::PerfectHash(m) => Self::general_map_types(DeduppedVec::from_unique(entries), m.hasher().clone()),
+            },
+            _0: PhantomData,
+        }
+    }
+
+    filter_funcs!(Box<[u8]>, BH: BuildHasher + Clone);
+
+    #[doc = include_str!("../doc_snippets/get.md")]
+    #[inline]
+    pub fn get(&self, key: impl AsRef<[u8]>) -> Option<&V>
+    where
+        BH: BuildHasher,
+    {
+        let key = key.as_ref();
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.get(key),
+            MapTypes::RightRange(m) => m.get(key),
+            MapTypes::Length(m) => m.get(key),
+            MapTypes::Hash(m) => m.get(key),
+            MapTypes::PerfectHash(m) => m.get(key),
+            MapTypes::SimdProbe(m) => m.get(key),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/get_mut.md")]
+    #[inline]
+    pub fn get_mut(&mut self, key: impl AsRef<[u8]>) -> Option<&mut V>
+    where
+        BH: BuildHasher,
+    {
+        let key = key.as_ref();
+        match &mut self.map_impl {
+            MapTypes::LeftRange(m) => m.get_mut(key),
+            MapTypes::RightRange(m) => m.get_mut(key),
+            MapTypes::Length(m) => m.get_mut(key),
+            MapTypes::Hash(m) => m.get_mut(key),
+            MapTypes::PerfectHash(m) => m.get_mut(key),
+            MapTypes::SimdProbe(m) => m.get_mut(key),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/get_key_value.md")]
+    #[inline]
+    #[expect(clippy::borrowed_box, reason = "By design")]
+    pub fn get_key_value(&self, key: impl AsRef<[u8]>) -> Option<(&Box<[u8]>, &V)>
+    where
+        BH: BuildHasher,
+    {
+        let key = key.as_ref();
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.get_key_value(key),
+            MapTypes::RightRange(m) => m.get_key_value(key),
+            MapTypes::Length(m) => m.get_key_value(key),
+            MapTypes::Hash(m) => m.get_key_value(key),
+            MapTypes::PerfectHash(m) => m.get_key_value(key),
+            MapTypes::SimdProbe(m) => m.get_key_value(key),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/contains_key.md")]
+    #[inline]
+    #[must_use]
+    pub fn contains_key(&self, key: impl AsRef<[u8]>) -> bool
+    where
+        BH: BuildHasher,
+    {
+        let key = key.as_ref();
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.contains_key(key),
+            MapTypes::RightRange(m) => m.contains_key(key),
+            MapTypes::Length(m) => m.contains_key(key),
+            MapTypes::Hash(m) => m.contains_key(key),
+            MapTypes::PerfectHash(m) => m.contains_key(key),
+            MapTypes::SimdProbe(m) => m.contains_key(key),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/get_disjoint_mut.md")]
+    #[expect(clippy::needless_pass_by_value, reason = "By design")]
+    pub fn get_disjoint_mut<const N: usize>(&mut self, keys: [impl AsRef<[u8]>; N]) -> [Option<&mut V>; N]
+    where
+        BH: BuildHasher,
+    {
+        let keys: [&[u8]; N] = array::from_fn(|i| keys[i].as_ref());
+        match &mut self.map_impl {
+            MapTypes::LeftRange(m) => m.get_disjoint_mut(keys),
+            MapTypes::RightRange(m) => m.get_disjoint_mut(keys),
+            MapTypes::Length(m) => m.get_disjoint_mut(keys),
+            MapTypes::Hash(m) => m.get_disjoint_mut(keys),
+            MapTypes::PerfectHash(m) => m.get_disjoint_mut(keys),
+            MapTypes::SimdProbe(m) => m.get_disjoint_mut(keys),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/get_disjoint_unchecked_mut.md")]
+    #[expect(clippy::needless_pass_by_value, reason = "By design")]
+    pub unsafe fn get_disjoint_unchecked_mut<const N: usize>(&mut self, keys: [impl AsRef<[u8]>; N]) -> [Option<&mut V>; N]
+    where
+        BH: BuildHasher,
+    {
+        let keys: [&[u8]; N] = array::from_fn(|i| keys[i].as_ref());
+        match &mut self.map_impl {
+            // SAFETY: The caller must ensure that the keys are disjoint.
+            MapTypes::LeftRange(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
+
+            // SAFETY: The caller must ensure that the keys are disjoint.
+            MapTypes::RightRange(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
+
+            // SAFETY: The caller must ensure that the keys are disjoint.
+            MapTypes::Length(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
+
+            // SAFETY: The caller must ensure that the keys are disjoint.
+            MapTypes::Hash(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
+
+            // SAFETY: The caller must ensure that the keys are disjoint.
+            MapTypes::PerfectHash(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
+
+            // SAFETY: The caller must ensure that the keys are disjoint.
+            MapTypes::SimdProbe(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/len.md")]
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.len(),
+            MapTypes::RightRange(m) => m.len(),
+            MapTypes::Length(m) => m.len(),
+            MapTypes::Hash(m) => m.len(),
+            MapTypes::PerfectHash(m) => m.len(),
+            MapTypes::SimdProbe(m) => m.len(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/is_empty.md")]
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.is_empty(),
+            MapTypes::RightRange(m) => m.is_empty(),
+            MapTypes::Length(m) => m.is_empty(),
+            MapTypes::Hash(m) => m.is_empty(),
+            MapTypes::PerfectHash(m) => m.is_empty(),
+            MapTypes::SimdProbe(m) => m.is_empty(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/diagnostics.md")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics
+    where
+        BH: BuildHasher,
+    {
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.diagnostics(),
+            MapTypes::RightRange(m) => m.diagnostics(),
+            MapTypes::Length(m) => m.diagnostics(),
+            MapTypes::Hash(m) => m.diagnostics(),
+            MapTypes::PerfectHash(m) => m.diagnostics(),
+            MapTypes::SimdProbe(m) => m.diagnostics(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/iter.md")]
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, Box<[u8]>, V> {
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.iter(),
+            MapTypes::RightRange(m) => m.iter(),
+            MapTypes::Length(m) => m.iter(),
+            MapTypes::Hash(m) => m.iter(),
+            MapTypes::PerfectHash(m) => m.iter(),
+            MapTypes::SimdProbe(m) => m.iter(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/iter_mut.md")]
+    #[must_use]
+    pub fn iter_mut(&mut self) -> IterMut<'_, Box<[u8]>, V> {
+        match &mut self.map_impl {
+            MapTypes::LeftRange(m) => m.iter_mut(),
+            MapTypes::RightRange(m) => m.iter_mut(),
+            MapTypes::Length(m) => m.iter_mut(),
+            MapTypes::Hash(m) => m.iter_mut(),
+            MapTypes::PerfectHash(m) => m.iter_mut(),
+            MapTypes::SimdProbe(m) => m.iter_mut(),
+        }
+    }
+
+    #[must_use]
+    fn into_iter(self) -> IntoIter<Box<[u8]>, V> {
+        match self.map_impl {
+            MapTypes::LeftRange(m) => m.into_iter(),
+            MapTypes::RightRange(m) => m.into_iter(),
+            MapTypes::Length(m) => m.into_iter(),
+            MapTypes::Hash(m) => m.into_iter(),
+            MapTypes::PerfectHash(m) => m.into_iter(),
+            MapTypes::SimdProbe(m) => m.into_iter(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/keys.md")]
+    #[must_use]
+    pub fn keys(&self) -> Keys<'_, Box<[u8]>, V> {
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.keys(),
+            MapTypes::RightRange(m) => m.keys(),
+            MapTypes::Length(m) => m.keys(),
+            MapTypes::Hash(m) => m.keys(),
+            MapTypes::PerfectHash(m) => m.keys(),
+            MapTypes::SimdProbe(m) => m.keys(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/into_keys.md")]
+    #[must_use]
+    pub fn into_keys(self) -> IntoKeys<Box<[u8]>, V> {
+        match self.map_impl {
+            MapTypes::LeftRange(m) => m.into_keys(),
+            MapTypes::RightRange(m) => m.into_keys(),
+            MapTypes::Length(m) => m.into_keys(),
+            MapTypes::Hash(m) => m.into_keys(),
+            MapTypes::PerfectHash(m) => m.into_keys(),
+            MapTypes::SimdProbe(m) => m.into_keys(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/values.md")]
+    #[must_use]
+    pub fn values(&self) -> Values<'_, Box<[u8]>, V> {
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.values(),
+            MapTypes::RightRange(m) => m.values(),
+            MapTypes::Length(m) => m.values(),
+            MapTypes::Hash(m) => m.values(),
+            MapTypes::PerfectHash(m) => m.values(),
+            MapTypes::SimdProbe(m) => m.values(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/values_mut.md")]
+    #[must_use]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, Box<[u8]>, V> {
+        match &mut self.map_impl {
+            MapTypes::LeftRange(m) => m.values_mut(),
+            MapTypes::RightRange(m) => m.values_mut(),
+            MapTypes::Length(m) => m.values_mut(),
+            MapTypes::Hash(m) => m.values_mut(),
+            MapTypes::PerfectHash(m) => m.values_mut(),
+            MapTypes::SimdProbe(m) => m.values_mut(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/into_values.md")]
+    #[must_use]
+    pub fn into_values(self) -> IntoValues<Box<[u8]>, V> {
+        match self.map_impl {
+            MapTypes::LeftRange(m) => m.into_values(),
+            MapTypes::RightRange(m) => m.into_values(),
+            MapTypes::Length(m) => m.into_values(),
+            MapTypes::Hash(m) => m.into_values(),
+            MapTypes::PerfectHash(m) => m.into_values(),
+            MapTypes::SimdProbe(m) => m.into_values(),
+        }
+    }
+}
+
+impl<V, BH> Default for FzBytesMap<Box<[u8]>, V, BH>
+where
+    BH: Default,
+{
+    fn default() -> Self {
+        Self {
+            map_impl: MapTypes::Hash(HashMap::default()),
+            _0: PhantomData,
+        }
+    }
+}
+
+impl<K, V, const N: usize, BH> From<[(K, V); N]> for FzBytesMap<Box<[u8]>, V, BH>
+where
+    K: AsRef<[u8]>,
+    BH: BuildHasher + Default,
+{
+    fn from(entries: [(K, V); N]) -> Self {
+        Self::with_hasher(Vec::from(entries), BH::default())
+    }
+}
+
+impl<K, V, BH> FromIterator<(K, V)> for FzBytesMap<Box<[u8]>, V, BH>
+where
+    K: AsRef<[u8]>,
+    BH: BuildHasher + Default,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self::with_hasher(iter.into_iter().collect(), BH::default())
+    }
+}
+
+impl<V, Q, BH> Map<Box<[u8]>, V, Q> for FzBytesMap<Box<[u8]>, V, BH>
+where
+    Q: AsRef<[u8]>,
+    BH: BuildHasher,
+{
+}
+
+impl<V, Q, BH> MapExtras<Box<[u8]>, V, Q> for FzBytesMap<Box<[u8]>, V, BH>
+where
+    Q: AsRef<[u8]>,
+    BH: BuildHasher,
+{
+    fn get_key_value(&self, key: &Q) -> Option<(&Box<[u8]>, &V)> {
+        self.get_key_value(key)
+    }
+
+    fn get_disjoint_mut<const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N]
+    where
+        Q: Eq,
+    {
+        self.get_disjoint_mut(keys)
+    }
+
+    unsafe fn get_disjoint_unchecked_mut<const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N] {
+        // SAFETY: The caller must ensure that the keys are disjoint.
+        unsafe { self.get_disjoint_unchecked_mut(keys) }
+    }
+}
+
+impl<V, Q, BH> MapQuery<Q, V> for FzBytesMap<Box<[u8]>, V, BH>
+where
+    Q: AsRef<[u8]>,
+    BH: BuildHasher,
+{
+    map_query_trait_funcs!();
+}
+
+impl<V, BH> MapIteration<Box<[u8]>, V> for FzBytesMap<Box<[u8]>, V, BH>
+where
+    BH: BuildHasher,
+{
+    type Iterator<'a>
+        = Iter<'a, Box<[u8]>, V>
+    where
+        V: 'a,
+        BH: 'a;
+
+    type KeyIterator<'a>
+        = Keys<'a, Box<[u8]>, V>
+    where
+        V: 'a,
+        BH: 'a;
+
+    type ValueIterator<'a>
+        = Values<'a, Box<[u8]>, V>
+    where
+        V: 'a,
+        BH: 'a;
+
+    type IntoKeyIterator = IntoKeys<Box<[u8]>, V>;
+    type IntoValueIterator = IntoValues<Box<[u8]>, V>;
+
+    type MutIterator<'a>
+        = IterMut<'a, Box<[u8]>, V>
+    where
+        V: 'a,
+        BH: 'a;
+    type ValueMutIterator<'a>
+        = ValuesMut<'a, Box<[u8]>, V>
+    where
+        V: 'a,
+        BH: 'a;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.iter()
+    }
+
+    fn iter_mut(&mut self) -> Self::MutIterator<'_> {
+        self.iter_mut()
+    }
+
+    fn keys(&self) -> Self::KeyIterator<'_> {
+        self.keys()
+    }
+
+    fn into_keys(self) -> Self::IntoKeyIterator {
+        self.into_keys()
+    }
+
+    fn values(&self) -> Self::ValueIterator<'_> {
+        self.values()
+    }
+
+    fn values_mut(&mut self) -> Self::ValueMutIterator<'_> {
+        self.values_mut()
+    }
+
+    fn into_values(self) -> Self::IntoValueIterator {
+        self.into_values()
+    }
+}
+
+impl<V, BH> Len for FzBytesMap<Box<[u8]>, V, BH> {
+    len_trait_funcs!();
+}
+
+impl<V, Q, BH> Index<&Q> for FzBytesMap<Box<[u8]>, V, BH>
+where
+    Q: AsRef<[u8]>,
+    BH: BuildHasher,
+{
+    index_trait_funcs!();
+}
+
+impl<V, BH> IntoIterator for FzBytesMap<Box<[u8]>, V, BH> {
+    type Item = (Box<[u8]>, V);
+    type IntoIter = IntoIter<Box<[u8]>, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+impl<'a, V, BH> IntoIterator for &'a FzBytesMap<Box<[u8]>, V, BH> {
+    type Item = (&'a Box<[u8]>, &'a V);
+    type IntoIter = Iter<'a, Box<[u8]>, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, V, BH> IntoIterator for &'a mut FzBytesMap<Box<[u8]>, V, BH> {
+    type Item = (&'a Box<[u8]>, &'a mut V);
+    type IntoIter = IterMut<'a, Box<[u8]>, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<V, MT, BH> PartialEq<MT> for FzBytesMap<Box<[u8]>, V, BH>
+where
+    V: PartialEq,
+    MT: MapQuery<Box<[u8]>, V>,
+    BH: BuildHasher,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<V, BH> Eq for FzBytesMap<Box<[u8]>, V, BH>
+where
+    V: Eq,
+    BH: BuildHasher,
+{
+}
+
+impl<V, BH> Debug for FzBytesMap<Box<[u8]>, V, BH>
+where
+    V: Debug,
+{
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<V, BH> Serialize for FzBytesMap<Box<[u8]>, V, BH>
+where
+    V: Serialize,
+{
+    serialize_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V> Deserialize<'de> for FzBytesMap<Box<[u8]>, V>
+where
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(BytesMapVisitor { marker: PhantomData })
+    }
+}
+
+#[cfg(feature = "serde")]
+struct BytesMapVisitor<V, BH> {
+    marker: PhantomData<(V, BH)>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V, BH> Visitor<'de> for BytesMapVisitor<V, BH>
+where
+    V: Deserialize<'de>,
+    BH: BuildHasher + Default,
+{
+    type Value = FzBytesMap<Box<[u8]>, V, BH>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> Result {
+        formatter.write_str("a map with byte slice keys")
+    }
+
+    fn visit_map<M>(self, mut map: M) -> core::result::Result<Self::Value, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let mut v: Vec<(Vec<u8>, _)> = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(x) = map.next_entry()? {
+            v.push(x);
+        }
+
+        Ok(FzBytesMap::with_hasher(v, BH::default()))
+    }
+}
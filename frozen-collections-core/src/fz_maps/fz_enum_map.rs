@@ -0,0 +1,417 @@
+use crate::diagnostics::Diagnostics;
+use crate::fz_maps::FzScalarMap;
+use crate::maps::decl_macros::{
+    debug_trait_funcs, into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs,
+    map_iteration_trait_funcs, partial_eq_trait_funcs,
+};
+use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::traits::{Len, MapIteration, MapQuery, Scalar, ScalarEnum};
+use crate::utils::into_ok;
+use alloc::format;
+use core::fmt::{Debug, Formatter, Result};
+use core::ops::{Index, IndexMut};
+
+#[cfg(not(feature = "std"))]
+use {alloc::string::String, alloc::vec::Vec};
+
+#[cfg(feature = "serde")]
+use {
+    crate::maps::decl_macros::serialize_trait_funcs,
+    core::marker::PhantomData,
+    serde::de::{Error, MapAccess, Visitor},
+    serde::ser::SerializeMap,
+    serde::{Deserialize, Deserializer, Serialize, Serializer},
+};
+
+/// A map with an entry for every variant of an enum.
+///
+/// Since every possible key is present, lookups can't fail, so [`get`](Self::get) returns a value
+/// rather than an option and indexing never panics. The map is checked for completeness when it is
+/// created, and the [`fz_enum_map!`](https://docs.rs/frozen-collections/latest/frozen_collections/macro.fz_enum_map.html)
+/// macro checks it at compile time.
+///
+/// The keys must implement [`ScalarEnum`], which is done by `#[derive(Scalar)]`.
+///
+#[doc = include_str!("../doc_snippets/about.md")]
+///
+/// # Alternate Choices
+///
+/// If some variants may not be present, you should use the [`FzScalarMap`] type instead.
+#[derive(Clone)]
+pub struct FzEnumMap<K, V> {
+    map_impl: FzScalarMap<K, V>,
+}
+
+impl<K, V> FzEnumMap<K, V> {
+    /// Creates a frozen enum map.
+    ///
+    /// # Errors
+    ///
+    /// Fails with a list of the missing variants if there isn't an entry for every variant of the enum.
+    pub fn new(entries: Vec<(K, V)>) -> core::result::Result<Self, String>
+    where
+        K: ScalarEnum + Debug,
+    {
+        let map_impl = FzScalarMap::new(entries);
+        let missing: Vec<_> = K::VARIANTS.iter().filter(|v| !map_impl.contains_key(*v)).collect();
+        if !missing.is_empty() {
+            return Err(format!("missing entries for variants {missing:?}"));
+        }
+
+        Ok(Self { map_impl })
+    }
+
+    /// Creates a frozen enum map by calling a function for every variant of the enum.
+    #[must_use]
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        K: ScalarEnum,
+        F: FnMut(K) -> V,
+    {
+        Self {
+            map_impl: FzScalarMap::new(K::VARIANTS.iter().map(|k| (*k, f(*k))).collect()),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> FzEnumMap<K, U> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(self, f: impl FnMut(&K, V) -> core::result::Result<U, E>) -> core::result::Result<FzEnumMap<K, U>, E> {
+        Ok(FzEnumMap {
+            map_impl: self.map_impl.try_map_values(f)?,
+        })
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    #[inline]
+    #[must_use]
+    #[expect(clippy::missing_panics_doc, reason = "Guaranteed to work because every variant is present")]
+    pub fn get(&self, key: &K) -> &V
+    where
+        K: Scalar,
+    {
+        self.map_impl.get(key).expect("every variant should be present")
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    #[inline]
+    #[must_use]
+    #[expect(clippy::missing_panics_doc, reason = "Guaranteed to work because every variant is present")]
+    pub fn get_mut(&mut self, key: &K) -> &mut V
+    where
+        K: Scalar,
+    {
+        self.map_impl.get_mut(key).expect("every variant should be present")
+    }
+
+    #[doc = include_str!("../doc_snippets/len.md")]
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map_impl.len()
+    }
+
+    #[doc = include_str!("../doc_snippets/is_empty.md")]
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map_impl.is_empty()
+    }
+
+    #[doc = include_str!("../doc_snippets/diagnostics.md")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics
+    where
+        K: Scalar,
+    {
+        self.map_impl.diagnostics()
+    }
+
+    #[doc = include_str!("../doc_snippets/iter.md")]
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.map_impl.iter()
+    }
+
+    #[doc = include_str!("../doc_snippets/iter_mut.md")]
+    #[must_use]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        self.map_impl.iter_mut()
+    }
+
+    #[must_use]
+    fn into_iter(self) -> IntoIter<K, V> {
+        self.map_impl.into_iter()
+    }
+
+    #[doc = include_str!("../doc_snippets/keys.md")]
+    #[must_use]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.map_impl.keys()
+    }
+
+    #[doc = include_str!("../doc_snippets/into_keys.md")]
+    #[must_use]
+    pub fn into_keys(self) -> IntoKeys<K, V> {
+        self.map_impl.into_keys()
+    }
+
+    #[doc = include_str!("../doc_snippets/values.md")]
+    #[must_use]
+    pub fn values(&self) -> Values<'_, K, V> {
+        self.map_impl.values()
+    }
+
+    #[doc = include_str!("../doc_snippets/values_mut.md")]
+    #[must_use]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        self.map_impl.values_mut()
+    }
+
+    #[doc = include_str!("../doc_snippets/into_values.md")]
+    #[must_use]
+    pub fn into_values(self) -> IntoValues<K, V> {
+        self.map_impl.into_values()
+    }
+}
+
+impl<K, V> Default for FzEnumMap<K, V>
+where
+    K: ScalarEnum,
+    V: Default,
+{
+    fn default() -> Self {
+        Self::from_fn(|_| V::default())
+    }
+}
+
+impl<K, V> MapQuery<K, V> for FzEnumMap<K, V>
+where
+    K: Scalar,
+{
+    #[inline]
+    fn get(&self, key: &K) -> Option<&V> {
+        Some(self.get(key))
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        Some(self.get_mut(key))
+    }
+
+    #[inline]
+    fn contains_key(&self, _key: &K) -> bool {
+        true
+    }
+}
+
+impl<K, V> MapIteration<K, V> for FzEnumMap<K, V> {
+    type Iterator<'a>
+        = Iter<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    type KeyIterator<'a>
+        = Keys<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    type ValueIterator<'a>
+        = Values<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    type MutIterator<'a>
+        = IterMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    type ValueMutIterator<'a>
+        = ValuesMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    map_iteration_trait_funcs!();
+}
+
+impl<K, V> Len for FzEnumMap<K, V> {
+    len_trait_funcs!();
+}
+
+impl<K, V> Index<&K> for FzEnumMap<K, V>
+where
+    K: Scalar,
+{
+    type Output = V;
+
+    #[inline]
+    fn index(&self, index: &K) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+impl<K, V> IndexMut<&K> for FzEnumMap<K, V>
+where
+    K: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: &K) -> &mut Self::Output {
+        self.get_mut(index)
+    }
+}
+
+impl<K, V> IntoIterator for FzEnumMap<K, V> {
+    into_iterator_trait_funcs!();
+}
+
+impl<'a, K, V> IntoIterator for &'a FzEnumMap<K, V> {
+    into_iterator_trait_ref_funcs!();
+}
+
+impl<'a, K, V> IntoIterator for &'a mut FzEnumMap<K, V> {
+    into_iterator_trait_mut_ref_funcs!();
+}
+
+impl<K, V, MT> PartialEq<MT> for FzEnumMap<K, V>
+where
+    K: Scalar,
+    V: PartialEq,
+    MT: MapQuery<K, V>,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<K, V> Eq for FzEnumMap<K, V>
+where
+    K: Scalar,
+    V: Eq,
+{
+}
+
+impl<K, V> Debug for FzEnumMap<K, V>
+where
+    K: Debug,
+    V: Debug,
+{
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<K, V> Serialize for FzEnumMap<K, V>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    serialize_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> Deserialize<'de> for FzEnumMap<K, V>
+where
+    K: Deserialize<'de> + ScalarEnum + Debug,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(EnumMapVisitor { marker: PhantomData })
+    }
+}
+
+#[cfg(feature = "serde")]
+struct EnumMapVisitor<K, V> {
+    marker: PhantomData<(K, V)>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> Visitor<'de> for EnumMapVisitor<K, V>
+where
+    K: Deserialize<'de> + ScalarEnum + Debug,
+    V: Deserialize<'de>,
+{
+    type Value = FzEnumMap<K, V>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> Result {
+        formatter.write_str("a map with an entry for every variant of an enum")
+    }
+
+    fn visit_map<M>(self, mut map: M) -> core::result::Result<Self::Value, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let mut v = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(x) = map.next_entry()? {
+            v.push(x);
+        }
+
+        FzEnumMap::new(v).map_err(M::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    impl Scalar for Color {
+        fn index(&self) -> usize {
+            *self as usize
+        }
+    }
+
+    impl ScalarEnum for Color {
+        const VARIANTS: &'static [Self] = &[Self::Red, Self::Green, Self::Blue];
+    }
+
+    #[test]
+    fn new_requires_every_variant() {
+        let map = FzEnumMap::new(vec![(Color::Red, 1), (Color::Green, 2), (Color::Blue, 3)]).unwrap();
+        assert_eq!(3, map.len());
+        assert_eq!(&2, map.get(&Color::Green));
+        assert_eq!(3, map[&Color::Blue]);
+
+        let r = FzEnumMap::new(vec![(Color::Green, 2)]);
+        assert_eq!("missing entries for variants [Red, Blue]", r.unwrap_err());
+    }
+
+    #[test]
+    fn from_fn_and_mutation() {
+        let mut map = FzEnumMap::from_fn(|c: Color| c.index() * 10);
+        assert_eq!(&10, map.get(&Color::Green));
+
+        map[&Color::Blue] += 1;
+        *map.get_mut(&Color::Red) = 7;
+        assert_eq!(vec![7, 10, 21], map.values().copied().collect::<Vec<_>>());
+        assert_eq!(vec![Color::Red, Color::Green, Color::Blue], map.keys().copied().collect::<Vec<_>>());
+
+        let default: FzEnumMap<Color, String> = FzEnumMap::default();
+        assert_eq!("", default[&Color::Red]);
+        assert_eq!(default, FzEnumMap::from_fn(|_: Color| String::new()));
+        assert_ne!(map, FzEnumMap::from_fn(|c: Color| c.index()));
+    }
+
+    #[test]
+    fn map_query() {
+        let map = FzEnumMap::from_fn(|c: Color| c.index().to_string());
+        assert!(MapQuery::contains_key(&map, &Color::Red));
+        assert_eq!(Some(&"2".to_string()), MapQuery::get(&map, &Color::Blue));
+        assert_eq!("{Red: \"0\", Green: \"1\", Blue: \"2\"}", format!("{map:?}"));
+    }
+}
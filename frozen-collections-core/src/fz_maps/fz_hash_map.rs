@@ -1,12 +1,16 @@
 use crate::DefaultBuildHasher;
+use crate::analyzers::analyze_perfect_hash_codes;
+use crate::columns::{Column, KeyIndex};
+use crate::diagnostics::Diagnostics;
 use crate::hashers::BridgeHasher;
 use crate::maps::decl_macros::{
-    debug_trait_funcs, index_trait_funcs, into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs,
-    len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs,
+    debug_trait_funcs, filter_funcs, index_trait_funcs, into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs,
+    into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs, map_query_trait_funcs,
+    partial_eq_trait_funcs,
 };
-use crate::maps::{HashMap, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
-use crate::traits::{LargeCollection, Len, Map, MapExtras, MapIteration, MapQuery};
-use crate::utils::DeduppedVec;
+use crate::maps::{HashMap, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, PerfectHashMap, Values, ValuesMut};
+use crate::traits::{Hasher, LargeCollection, Len, Map, MapExtras, MapIteration, MapQuery};
+use crate::utils::{DeduppedVec, into_ok, retain_flagged, retain_flags};
 use core::fmt::{Debug, Formatter, Result};
 use core::hash::{BuildHasher, Hash};
 use core::ops::Index;
@@ -24,6 +28,12 @@ use {
     serde::{Deserialize, Deserializer, Serialize, Serializer},
 };
 
+#[derive(Clone)]
+enum MapTypes<K, V, BH> {
+    Hash(HashMap<K, V, LargeCollection, BridgeHasher<BH>>),
+    PerfectHash(PerfectHashMap<K, V, BridgeHasher<BH>>),
+}
+
 /// A map optimized for fast read access with hashable keys.
 ///
 #[doc = include_str!("../doc_snippets/about.md")]
@@ -39,7 +49,7 @@ use {
 /// this type as they generally perform better.
 #[derive(Clone)]
 pub struct FzHashMap<K, V, BH = DefaultBuildHasher> {
-    map_impl: HashMap<K, V, LargeCollection, BridgeHasher<BH>>,
+    map_impl: MapTypes<K, V, BH>,
 }
 
 impl<K, V> FzHashMap<K, V, DefaultBuildHasher> {
@@ -59,21 +69,119 @@ where
 {
     /// Creates a frozen map which uses the given hash builder to hash keys.
     #[must_use]
-    #[expect(
-        clippy::missing_panics_doc,
-        reason = "Guaranteed not to panic because the map is a LargeCollection"
-    )]
     pub fn with_hasher(entries: Vec<(K, V)>, bh: BH) -> Self
     where
         K: Eq + Hash,
     {
-        let entries = DeduppedVec::using_hash(entries, |x| bh.hash_one(&x.0), |x, y| x.0 == y.0);
+        // the hash codes computed to find duplicates are reused to look for a perfect hash function
+        let (entries, hash_codes) = DeduppedVec::using_hash_with_codes(entries, |x| bh.hash_one(&x.0), |x, y| x.0 == y.0);
+
+        Self {
+            map_impl: Self::map_types_with_hash_codes(entries, &hash_codes, BridgeHasher::new(bh)),
+        }
+    }
+
+    /// Creates a frozen map from entries which are already free of duplicates.
+    #[must_use]
+    pub(crate) fn from_dedupped(entries: DeduppedVec<(K, V)>, bh: BH) -> Self
+    where
+        K: Hash,
+    {
+        Self {
+            map_impl: Self::map_types(entries, BridgeHasher::new(bh)),
+        }
+    }
+
+    fn map_types(entries: DeduppedVec<(K, V)>, h: BridgeHasher<BH>) -> MapTypes<K, V, BH>
+    where
+        K: Hash,
+    {
+        let hash_codes: Vec<_> = entries.iter().map(|x| h.hash_one(&x.0)).collect();
+        Self::map_types_with_hash_codes(entries, &hash_codes, h)
+    }
+
+    /// Picks the layout of the map, given the hash code of every entry.
+    fn map_types_with_hash_codes(entries: DeduppedVec<(K, V)>, hash_codes: &[u64], h: BridgeHasher<BH>) -> MapTypes<K, V, BH>
+    where
+        K: Hash,
+    {
+        match analyze_perfect_hash_codes(hash_codes) {
+            Some(analysis) => MapTypes::PerfectHash(PerfectHashMap::from_analysis(entries, analysis, h)),
+            None => MapTypes::Hash(HashMap::from_dedupped(entries, h).unwrap()),
+        }
+    }
+
+    pub(crate) const fn build_hasher(&self) -> &BH {
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.hasher().build_hasher(),
+            MapTypes::PerfectHash(m) => m.hasher().build_hasher(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> FzHashMap<K, U, BH> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(self, f: impl FnMut(&K, V) -> core::result::Result<U, E>) -> core::result::Result<FzHashMap<K, U, BH>, E> {
+        Ok(FzHashMap {
+            map_impl: match self.map_impl {
+                MapTypes::Hash(m) => MapTypes::Hash(m.try_map_values(f)?),
+                MapTypes::PerfectHash(m) => MapTypes::PerfectHash(m.try_map_values(f)?),
+            },
+        })
+    }
+
+    #[doc = include_str!("../doc_snippets/retain_into.md")]
+    #[must_use]
+    pub fn retain_into(self, f: impl FnMut(&K, &V) -> bool) -> Self
+    where
+        K: Hash,
+    {
+        let Some(flags) = retain_flags(self.iter(), f) else {
+            return self;
+        };
+
+        let (entries, h) = match self.map_impl {
+            MapTypes::Hash(m) => m.into_parts(),
+            MapTypes::PerfectHash(m) => m.into_parts(),
+        };
 
         Self {
-            map_impl: HashMap::from_dedupped(entries, BridgeHasher::new(bh)).unwrap(),
+            map_impl: Self::map_types(DeduppedVec::from_unique(retain_flagged(entries, flags)), h),
         }
     }
 
+    /// Creates a map like this one holding the given entries, a subset of this map's entries in iteration order.
+    fn with_retained(&self, entries: Vec<(K, V)>) -> Self
+    where
+        K: Hash,
+        BH: Clone,
+    {
+        Self::from_dedupped(DeduppedVec::from_unique(entries), self.build_hasher().clone())
+    }
+
+    filter_funcs!(K, K: Hash, BH: Clone);
+
+    /// Splits the map into a key index and a column holding its values.
+    ///
+    /// The keys keep the layout of the map, so lookups through the index are as fast as lookups in the map,
+    /// and more columns can be created from the index to associate additional values with the same keys.
+    #[must_use]
+    pub fn into_index_and_column(self) -> (KeyIndex<FzHashMap<K, usize, BH>>, Column<V>) {
+        let mut values = Vec::with_capacity(self.len());
+        let slots = self.map_values(|_, value| {
+            values.push(value);
+            values.len() - 1
+        });
+
+        let index = KeyIndex::new(slots);
+        let column = Column::new(index.id(), values);
+        (index, column)
+    }
+
     #[doc = include_str!("../doc_snippets/get.md")]
     #[inline]
     #[must_use]
@@ -81,7 +189,10 @@ where
     where
         Q: ?Sized + Hash + Equivalent<K>,
     {
-        self.map_impl.get(key)
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.get(key),
+            MapTypes::PerfectHash(m) => m.get(key),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/get_mut.md")]
@@ -91,7 +202,10 @@ where
     where
         Q: ?Sized + Hash + Equivalent<K>,
     {
-        self.map_impl.get_mut(key)
+        match &mut self.map_impl {
+            MapTypes::Hash(m) => m.get_mut(key),
+            MapTypes::PerfectHash(m) => m.get_mut(key),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/get_key_value.md")]
@@ -101,7 +215,10 @@ where
     where
         Q: ?Sized + Hash + Equivalent<K>,
     {
-        self.map_impl.get_key_value(key)
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.get_key_value(key),
+            MapTypes::PerfectHash(m) => m.get_key_value(key),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/contains_key.md")]
@@ -111,7 +228,71 @@ where
     where
         Q: ?Sized + Hash + Equivalent<K>,
     {
-        self.map_impl.contains_key(key)
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.contains_key(key),
+            MapTypes::PerfectHash(m) => m.contains_key(key),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/hash_key.md")]
+    #[inline]
+    #[must_use]
+    pub fn hash_key<Q>(&self, key: &Q) -> u64
+    where
+        Q: ?Sized + Hash,
+    {
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.hash_key(key),
+            MapTypes::PerfectHash(m) => m.hash_key(key),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/get_with_hash.md")]
+    #[inline]
+    #[must_use]
+    pub fn get_with_hash<Q>(&self, hash_code: u64, key: &Q) -> Option<&V>
+    where
+        Q: ?Sized + Equivalent<K>,
+    {
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.get_with_hash(hash_code, key),
+            MapTypes::PerfectHash(m) => m.get_with_hash(hash_code, key),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/contains_with_hash.md")]
+    #[inline]
+    #[must_use]
+    pub fn contains_with_hash<Q>(&self, hash_code: u64, key: &Q) -> bool
+    where
+        Q: ?Sized + Equivalent<K>,
+    {
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.contains_with_hash(hash_code, key),
+            MapTypes::PerfectHash(m) => m.contains_with_hash(hash_code, key),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/get_many.md")]
+    pub fn get_many<'a, Q>(&'a self, keys: &[&Q], results: &mut [Option<&'a V>])
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.get_many(keys, results),
+            MapTypes::PerfectHash(m) => m.get_many(keys, results),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/contains_many.md")]
+    pub fn contains_many<Q>(&self, keys: &[&Q], results: &mut [bool])
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.contains_many(keys, results),
+            MapTypes::PerfectHash(m) => m.contains_many(keys, results),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/get_disjoint_mut.md")]
@@ -120,7 +301,10 @@ where
     where
         Q: ?Sized + Hash + Eq + Equivalent<K>,
     {
-        self.map_impl.get_disjoint_mut(keys)
+        match &mut self.map_impl {
+            MapTypes::Hash(m) => m.get_disjoint_mut(keys),
+            MapTypes::PerfectHash(m) => m.get_disjoint_mut(keys),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/get_disjoint_unchecked_mut.md")]
@@ -129,69 +313,116 @@ where
     where
         Q: ?Sized + Hash + Equivalent<K>,
     {
-        // SAFETY: The caller must ensure that the keys are disjoint and valid for the map.
-        unsafe { self.map_impl.get_disjoint_unchecked_mut(keys) }
+        match &mut self.map_impl {
+            // SAFETY: The caller must ensure that the keys are disjoint and valid for the map.
+            MapTypes::Hash(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
+
+            // SAFETY: The caller must ensure that the keys are disjoint and valid for the map.
+            MapTypes::PerfectHash(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
+        }
     }
 
     #[doc = include_str!("../doc_snippets/len.md")]
     #[inline]
     #[must_use]
     pub fn len(&self) -> usize {
-        self.map_impl.len()
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.len(),
+            MapTypes::PerfectHash(m) => m.len(),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/is_empty.md")]
     #[inline]
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.map_impl.is_empty()
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.is_empty(),
+            MapTypes::PerfectHash(m) => m.is_empty(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/diagnostics.md")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics
+    where
+        K: Hash,
+    {
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.diagnostics(),
+            MapTypes::PerfectHash(m) => m.diagnostics(),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/iter.md")]
     #[must_use]
     pub fn iter(&self) -> Iter<'_, K, V> {
-        self.map_impl.iter()
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.iter(),
+            MapTypes::PerfectHash(m) => m.iter(),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/iter_mut.md")]
     #[must_use]
     pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
-        self.map_impl.iter_mut()
+        match &mut self.map_impl {
+            MapTypes::Hash(m) => m.iter_mut(),
+            MapTypes::PerfectHash(m) => m.iter_mut(),
+        }
     }
 
     #[must_use]
     fn into_iter(self) -> IntoIter<K, V> {
-        self.map_impl.into_iter()
+        match self.map_impl {
+            MapTypes::Hash(m) => m.into_iter(),
+            MapTypes::PerfectHash(m) => m.into_iter(),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/keys.md")]
     #[must_use]
     pub fn keys(&self) -> Keys<'_, K, V> {
-        self.map_impl.keys()
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.keys(),
+            MapTypes::PerfectHash(m) => m.keys(),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/into_keys.md")]
     #[must_use]
     pub fn into_keys(self) -> IntoKeys<K, V> {
-        self.map_impl.into_keys()
+        match self.map_impl {
+            MapTypes::Hash(m) => m.into_keys(),
+            MapTypes::PerfectHash(m) => m.into_keys(),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/values.md")]
     #[must_use]
     pub fn values(&self) -> Values<'_, K, V> {
-        self.map_impl.values()
+        match &self.map_impl {
+            MapTypes::Hash(m) => m.values(),
+            MapTypes::PerfectHash(m) => m.values(),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/values_mut.md")]
     #[must_use]
     pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
-        self.map_impl.values_mut()
+        match &mut self.map_impl {
+            MapTypes::Hash(m) => m.values_mut(),
+            MapTypes::PerfectHash(m) => m.values_mut(),
+        }
     }
 
     #[doc = include_str!("../doc_snippets/into_values.md")]
     #[must_use]
     pub fn into_values(self) -> IntoValues<K, V> {
-        self.map_impl.into_values()
+        match self.map_impl {
+            MapTypes::Hash(m) => m.into_values(),
+            MapTypes::PerfectHash(m) => m.into_values(),
+        }
     }
 }
 
@@ -201,7 +432,7 @@ where
 {
     fn default() -> Self {
         Self {
-            map_impl: HashMap::default(),
+            map_impl: MapTypes::Hash(HashMap::default()),
         }
     }
 }
@@ -1,12 +1,14 @@
+use crate::diagnostics::Diagnostics;
 use crate::maps::decl_macros::{
-    debug_trait_funcs, index_trait_funcs, into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs,
-    len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs,
+    debug_trait_funcs, filter_funcs, index_trait_funcs, into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs,
+    into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs, map_query_trait_funcs,
+    partial_eq_trait_funcs,
 };
-use crate::maps::{EytzingerSearchMap, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::maps::{EytzingerSearchMap, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Range, Values, ValuesMut};
 use crate::traits::{Len, Map, MapExtras, MapIteration, MapQuery};
-use crate::utils::SortedAndDeduppedVec;
+use crate::utils::{SortedAndDeduppedVec, into_ok};
 use core::fmt::{Debug, Formatter, Result};
-use core::ops::Index;
+use core::ops::{Index, RangeBounds};
 use equivalent::Comparable;
 
 #[cfg(not(feature = "std"))]
@@ -48,11 +50,53 @@ impl<K, V> FzOrderedMap<K, V> {
     {
         let entries = SortedAndDeduppedVec::new(entries, |x, y| x.0.cmp(&y.0));
 
+        Self::from_sorted_and_dedupped(entries)
+    }
+
+    /// Creates a frozen ordered map from entries which are already sorted and free of duplicates.
+    #[must_use]
+    pub(crate) fn from_sorted_and_dedupped(entries: SortedAndDeduppedVec<(K, V)>) -> Self {
         Self {
             map_impl: { EytzingerSearchMap::from_sorted_and_dedupped(entries) },
         }
     }
 
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> FzOrderedMap<K, U> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(self, f: impl FnMut(&K, V) -> core::result::Result<U, E>) -> core::result::Result<FzOrderedMap<K, U>, E> {
+        Ok(FzOrderedMap {
+            map_impl: self.map_impl.try_map_values(f)?,
+        })
+    }
+
+    #[doc = include_str!("../doc_snippets/retain_into.md")]
+    #[must_use]
+    pub fn retain_into(self, f: impl FnMut(&K, &V) -> bool) -> Self
+    where
+        K: Ord,
+    {
+        Self {
+            map_impl: self.map_impl.retain_into(f),
+        }
+    }
+
+    /// Creates a map like this one holding the given entries, a subset of this map's entries in iteration order.
+    fn with_retained(&self, entries: Vec<(K, V)>) -> Self
+    where
+        K: Ord,
+    {
+        Self {
+            map_impl: self.map_impl.with_retained(entries),
+        }
+    }
+
+    filter_funcs!(K, K: Ord);
+
     #[doc = include_str!("../doc_snippets/get.md")]
     #[inline]
     pub fn get<Q>(&self, key: &Q) -> Option<&V>
@@ -90,6 +134,87 @@ impl<K, V> FzOrderedMap<K, V> {
         self.map_impl.contains_key(key)
     }
 
+    #[doc = include_str!("../doc_snippets/rank.md")]
+    #[inline]
+    #[must_use]
+    pub fn rank<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: ?Sized + Comparable<K>,
+    {
+        self.map_impl.rank(key)
+    }
+
+    #[doc = include_str!("../doc_snippets/select.md")]
+    #[inline]
+    #[must_use]
+    pub fn select(&self, sorted_index: usize) -> Option<(&K, &V)> {
+        self.map_impl.select(sorted_index)
+    }
+
+    #[doc = include_str!("../doc_snippets/floor.md")]
+    #[inline]
+    pub fn floor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: ?Sized + Comparable<K>,
+    {
+        self.map_impl.floor(key)
+    }
+
+    #[doc = include_str!("../doc_snippets/ceiling.md")]
+    #[inline]
+    pub fn ceiling<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: ?Sized + Comparable<K>,
+    {
+        self.map_impl.ceiling(key)
+    }
+
+    #[doc = include_str!("../doc_snippets/predecessor.md")]
+    #[inline]
+    pub fn predecessor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: ?Sized + Comparable<K>,
+    {
+        self.map_impl.predecessor(key)
+    }
+
+    #[doc = include_str!("../doc_snippets/successor.md")]
+    #[inline]
+    pub fn successor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: ?Sized + Comparable<K>,
+    {
+        self.map_impl.successor(key)
+    }
+
+    #[doc = include_str!("../doc_snippets/range.md")]
+    #[must_use]
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, K, V>
+    where
+        Q: ?Sized + Comparable<K>,
+        R: RangeBounds<Q>,
+    {
+        self.map_impl.range(range)
+    }
+
+    #[doc = include_str!("../doc_snippets/iter_sorted.md")]
+    #[must_use]
+    pub fn iter_sorted(&self) -> Range<'_, K, V> {
+        self.map_impl.iter_sorted()
+    }
+
+    #[doc = include_str!("../doc_snippets/first_key_value.md")]
+    #[must_use]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.map_impl.first_key_value()
+    }
+
+    #[doc = include_str!("../doc_snippets/last_key_value.md")]
+    #[must_use]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.map_impl.last_key_value()
+    }
+
     #[doc = include_str!("../doc_snippets/get_disjoint_mut.md")]
     #[must_use]
     pub fn get_disjoint_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N]
@@ -123,6 +248,12 @@ impl<K, V> FzOrderedMap<K, V> {
         self.map_impl.is_empty()
     }
 
+    #[doc = include_str!("../doc_snippets/diagnostics.md")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.map_impl.diagnostics()
+    }
+
     #[doc = include_str!("../doc_snippets/iter.md")]
     #[must_use]
     pub fn iter(&self) -> Iter<'_, K, V> {
@@ -0,0 +1,583 @@
+use crate::diagnostics::Diagnostics;
+use crate::maps::decl_macros::{
+    debug_trait_funcs, filter_funcs, index_trait_funcs, into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs,
+    into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs, map_query_trait_funcs,
+    partial_eq_trait_funcs,
+};
+use crate::maps::{EytzingerSearchMap, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Range, Values, ValuesMut};
+use crate::traits::{Len, Map, MapExtras, MapIteration, MapQuery};
+use crate::utils::{SortedAndDeduppedVec, into_ok};
+use core::array;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter, Result};
+use core::ops::Index;
+use equivalent::{Comparable, Equivalent};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use {
+    crate::maps::decl_macros::serialize_trait_funcs,
+    core::marker::PhantomData,
+    serde::de::{MapAccess, Visitor},
+    serde::ser::SerializeMap,
+    serde::{Deserialize, Deserializer, Serialize, Serializer},
+};
+
+/// A map with string keys which supports prefix queries.
+///
+/// In addition to looking up keys exactly, this map can find the longest key which is a prefix of some
+/// input, as done when routing requests or dispatching commands, and can enumerate all the keys which
+/// start with a given prefix. The keys are kept in sorted order, so exact lookups are done with a binary
+/// search rather than with hashing.
+///
+#[doc = include_str!("../doc_snippets/about.md")]
+#[doc = include_str!("../doc_snippets/ord_warning.md")]
+///
+/// # Alternate Choices
+///
+/// If you never need prefix queries, you should use the [`FzStringMap`](crate::fz_maps::FzStringMap) type instead
+/// as its exact lookups are faster.
+#[derive(Clone)]
+pub struct FzPrefixMap<K, V> {
+    map_impl: EytzingerSearchMap<K, V>,
+}
+
+impl<K, V> FzPrefixMap<K, V> {
+    /// Creates a frozen prefix map.
+    #[must_use]
+    pub fn new(entries: Vec<(K, V)>) -> Self
+    where
+        K: Ord,
+    {
+        let entries = SortedAndDeduppedVec::new(entries, |x, y| x.0.cmp(&y.0));
+
+        Self {
+            map_impl: EytzingerSearchMap::from_sorted_and_dedupped(entries),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> FzPrefixMap<K, U> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(self, f: impl FnMut(&K, V) -> core::result::Result<U, E>) -> core::result::Result<FzPrefixMap<K, U>, E> {
+        Ok(FzPrefixMap {
+            map_impl: self.map_impl.try_map_values(f)?,
+        })
+    }
+
+    #[doc = include_str!("../doc_snippets/retain_into.md")]
+    #[must_use]
+    pub fn retain_into(self, f: impl FnMut(&K, &V) -> bool) -> Self
+    where
+        K: Ord,
+    {
+        Self {
+            map_impl: self.map_impl.retain_into(f),
+        }
+    }
+
+    /// Creates a map like this one holding the given entries, a subset of this map's entries in iteration order.
+    fn with_retained(&self, entries: Vec<(K, V)>) -> Self
+    where
+        K: Ord,
+    {
+        Self {
+            map_impl: self.map_impl.with_retained(entries),
+        }
+    }
+
+    filter_funcs!(K, K: Ord);
+
+    #[doc = include_str!("../doc_snippets/get.md")]
+    #[inline]
+    pub fn get(&self, key: impl AsRef<str>) -> Option<&V>
+    where
+        K: Borrow<str>,
+    {
+        self.map_impl.get(key.as_ref())
+    }
+
+    #[doc = include_str!("../doc_snippets/get_mut.md")]
+    #[inline]
+    pub fn get_mut(&mut self, key: impl AsRef<str>) -> Option<&mut V>
+    where
+        K: Borrow<str>,
+    {
+        self.map_impl.get_mut(key.as_ref())
+    }
+
+    #[doc = include_str!("../doc_snippets/get_key_value.md")]
+    #[inline]
+    pub fn get_key_value(&self, key: impl AsRef<str>) -> Option<(&K, &V)>
+    where
+        K: Borrow<str>,
+    {
+        self.map_impl.get_key_value(key.as_ref())
+    }
+
+    #[doc = include_str!("../doc_snippets/contains_key.md")]
+    #[inline]
+    #[must_use]
+    pub fn contains_key(&self, key: impl AsRef<str>) -> bool
+    where
+        K: Borrow<str>,
+    {
+        self.map_impl.contains_key(key.as_ref())
+    }
+
+    /// Finds the longest key which is a prefix of the input, and returns it along with its value.
+    ///
+    /// A key equal to the input counts as a prefix of it, as does an empty key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use frozen_collections_core::fz_maps::FzPrefixMap;
+    /// let routes = FzPrefixMap::new(vec![("/", 0), ("/api", 1), ("/api/users", 2)]);
+    ///
+    /// assert_eq!(Some((&"/api/users", &2)), routes.longest_prefix_match("/api/users/42"));
+    /// assert_eq!(Some((&"/api", &1)), routes.longest_prefix_match("/api/orders"));
+    /// assert_eq!(Some((&"/", &0)), routes.longest_prefix_match("/about"));
+    /// assert_eq!(None, routes.longest_prefix_match("about"));
+    /// ```
+    #[must_use]
+    pub fn longest_prefix_match(&self, input: impl AsRef<str>) -> Option<(&K, &V)>
+    where
+        K: Borrow<str>,
+    {
+        let mut input = input.as_ref();
+        loop {
+            let (key, value) = self.map_impl.floor(input)?;
+            let key_str: &str = key.borrow();
+            if input.starts_with(key_str) {
+                return Some((key, value));
+            }
+
+            // Any key which is a prefix of the input sorts between that key and the input, so it is
+            // also a prefix of the key found. Only the part shared by the two is left to search, which
+            // is strictly shorter than the input since the key found isn't a prefix of it.
+            // Keys are whole strings, so the shared part is cut back to the nearest character boundary.
+            let common = key_str.bytes().zip(input.bytes()).take_while(|(x, y)| x == y).count();
+            input = (0..=common).rev().find_map(|len| input.get(..len)).unwrap_or_default();
+        }
+    }
+
+    /// Returns an iterator visiting the keys which start with the given prefix, in sorted order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use frozen_collections_core::fz_maps::FzPrefixMap;
+    /// let commands = FzPrefixMap::new(vec![("commit", 0), ("config", 1), ("checkout", 2), ("push", 3)]);
+    ///
+    /// assert_eq!(vec![&"commit", &"config"], commands.keys_with_prefix("co").collect::<Vec<_>>());
+    /// assert_eq!(0, commands.keys_with_prefix("pull").count());
+    /// ```
+    pub fn keys_with_prefix(&self, prefix: impl AsRef<str>) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator
+    where
+        K: Borrow<str>,
+    {
+        self.entries_with_prefix(prefix).map(|(key, _)| key)
+    }
+
+    /// Returns an iterator visiting the entries whose keys start with the given prefix, in sorted order.
+    #[must_use]
+    pub fn entries_with_prefix(&self, prefix: impl AsRef<str>) -> Range<'_, K, V>
+    where
+        K: Borrow<str>,
+    {
+        let prefix = Prefix(prefix.as_ref());
+        self.map_impl.range(prefix..=prefix)
+    }
+
+    #[doc = include_str!("../doc_snippets/iter_sorted.md")]
+    #[must_use]
+    pub fn iter_sorted(&self) -> Range<'_, K, V> {
+        self.map_impl.iter_sorted()
+    }
+
+    #[doc = include_str!("../doc_snippets/get_disjoint_mut.md")]
+    #[must_use]
+    #[expect(clippy::needless_pass_by_value, reason = "By design")]
+    pub fn get_disjoint_mut<const N: usize>(&mut self, keys: [impl AsRef<str>; N]) -> [Option<&mut V>; N]
+    where
+        K: Borrow<str>,
+    {
+        let keys: [&str; N] = array::from_fn(|i| keys[i].as_ref());
+        self.map_impl.get_disjoint_mut(keys)
+    }
+
+    #[doc = include_str!("../doc_snippets/get_disjoint_unchecked_mut.md")]
+    #[must_use]
+    #[expect(clippy::needless_pass_by_value, reason = "By design")]
+    pub unsafe fn get_disjoint_unchecked_mut<const N: usize>(&mut self, keys: [impl AsRef<str>; N]) -> [Option<&mut V>; N]
+    where
+        K: Borrow<str>,
+    {
+        let keys: [&str; N] = array::from_fn(|i| keys[i].as_ref());
+
+        // SAFETY: The caller must ensure that the keys are disjoint and valid for the map.
+        unsafe { self.map_impl.get_disjoint_unchecked_mut(keys) }
+    }
+
+    #[doc = include_str!("../doc_snippets/len.md")]
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map_impl.len()
+    }
+
+    #[doc = include_str!("../doc_snippets/is_empty.md")]
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map_impl.is_empty()
+    }
+
+    #[doc = include_str!("../doc_snippets/diagnostics.md")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.map_impl.diagnostics()
+    }
+
+    #[doc = include_str!("../doc_snippets/iter.md")]
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.map_impl.iter()
+    }
+
+    #[doc = include_str!("../doc_snippets/iter_mut.md")]
+    #[must_use]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        self.map_impl.iter_mut()
+    }
+
+    #[must_use]
+    fn into_iter(self) -> IntoIter<K, V> {
+        self.map_impl.into_iter()
+    }
+
+    #[doc = include_str!("../doc_snippets/keys.md")]
+    #[must_use]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.map_impl.keys()
+    }
+
+    #[doc = include_str!("../doc_snippets/into_keys.md")]
+    #[must_use]
+    pub fn into_keys(self) -> IntoKeys<K, V> {
+        self.map_impl.into_keys()
+    }
+
+    #[doc = include_str!("../doc_snippets/values.md")]
+    #[must_use]
+    pub fn values(&self) -> Values<'_, K, V> {
+        self.map_impl.values()
+    }
+
+    #[doc = include_str!("../doc_snippets/values_mut.md")]
+    #[must_use]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        self.map_impl.values_mut()
+    }
+
+    #[doc = include_str!("../doc_snippets/into_values.md")]
+    #[must_use]
+    pub fn into_values(self) -> IntoValues<K, V> {
+        self.map_impl.into_values()
+    }
+}
+
+/// Compares keys by whether they start with a prefix.
+///
+/// The keys which start with the prefix compare as equal, and since they are contiguous in sorted
+/// order, searching for this range yields exactly those keys.
+#[derive(Clone, Copy)]
+struct Prefix<'a>(&'a str);
+
+impl<K> Equivalent<K> for Prefix<'_>
+where
+    K: Borrow<str>,
+{
+    fn equivalent(&self, key: &K) -> bool {
+        key.borrow().starts_with(self.0)
+    }
+}
+
+impl<K> Comparable<K> for Prefix<'_>
+where
+    K: Borrow<str>,
+{
+    fn compare(&self, key: &K) -> Ordering {
+        let key = key.borrow();
+        if key.starts_with(self.0) {
+            Ordering::Equal
+        } else {
+            self.0.cmp(key)
+        }
+    }
+}
+
+impl<K, V> Default for FzPrefixMap<K, V> {
+    fn default() -> Self {
+        Self {
+            map_impl: EytzingerSearchMap::default(),
+        }
+    }
+}
+
+impl<K, V, const N: usize> From<[(K, V); N]> for FzPrefixMap<K, V>
+where
+    K: Ord,
+{
+    fn from(entries: [(K, V); N]) -> Self {
+        Self::new(Vec::from(entries))
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for FzPrefixMap<K, V>
+where
+    K: Ord,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<K, V, Q> Map<K, V, Q> for FzPrefixMap<K, V>
+where
+    K: Borrow<str>,
+    Q: AsRef<str>,
+{
+}
+
+impl<K, V, Q> MapExtras<K, V, Q> for FzPrefixMap<K, V>
+where
+    K: Borrow<str>,
+    Q: AsRef<str>,
+{
+    map_extras_trait_funcs!();
+}
+
+impl<K, V, Q> MapQuery<Q, V> for FzPrefixMap<K, V>
+where
+    K: Borrow<str>,
+    Q: AsRef<str>,
+{
+    map_query_trait_funcs!();
+}
+
+impl<K, V> MapIteration<K, V> for FzPrefixMap<K, V> {
+    type Iterator<'a>
+        = Iter<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    type KeyIterator<'a>
+        = Keys<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    type ValueIterator<'a>
+        = Values<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    type MutIterator<'a>
+        = IterMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    type ValueMutIterator<'a>
+        = ValuesMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    map_iteration_trait_funcs!();
+}
+
+impl<K, V> Len for FzPrefixMap<K, V> {
+    len_trait_funcs!();
+}
+
+impl<K, V, Q> Index<&Q> for FzPrefixMap<K, V>
+where
+    K: Borrow<str>,
+    Q: AsRef<str>,
+{
+    index_trait_funcs!();
+}
+
+impl<K, V> IntoIterator for FzPrefixMap<K, V> {
+    into_iterator_trait_funcs!();
+}
+
+impl<'a, K, V> IntoIterator for &'a FzPrefixMap<K, V> {
+    into_iterator_trait_ref_funcs!();
+}
+
+impl<'a, K, V> IntoIterator for &'a mut FzPrefixMap<K, V> {
+    into_iterator_trait_mut_ref_funcs!();
+}
+
+impl<K, V, MT> PartialEq<MT> for FzPrefixMap<K, V>
+where
+    K: Borrow<str>,
+    V: PartialEq,
+    MT: MapQuery<K, V>,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<K, V> Eq for FzPrefixMap<K, V>
+where
+    K: Borrow<str> + AsRef<str>,
+    V: Eq,
+{
+}
+
+impl<K, V> Debug for FzPrefixMap<K, V>
+where
+    K: Debug,
+    V: Debug,
+{
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<K, V> Serialize for FzPrefixMap<K, V>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    serialize_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> Deserialize<'de> for FzPrefixMap<K, V>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(PrefixMapVisitor { marker: PhantomData })
+    }
+}
+
+#[cfg(feature = "serde")]
+struct PrefixMapVisitor<K, V> {
+    marker: PhantomData<(K, V)>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> Visitor<'de> for PrefixMapVisitor<K, V>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    type Value = FzPrefixMap<K, V>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> Result {
+        formatter.write_str("a map with string keys")
+    }
+
+    fn visit_map<M>(self, mut map: M) -> core::result::Result<Self::Value, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let mut v = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(x) = map.next_entry()? {
+            v.push(x);
+        }
+
+        Ok(FzPrefixMap::new(v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_prefix_match() {
+        let map = FzPrefixMap::new(vec![("", 0), ("a", 1), ("abc", 2), ("abd", 3), ("b", 4), ("héllo", 5)]);
+
+        assert_eq!(Some((&"abc", &2)), map.longest_prefix_match("abcdef"));
+        assert_eq!(Some((&"abd", &3)), map.longest_prefix_match("abd"));
+        assert_eq!(Some((&"a", &1)), map.longest_prefix_match("abx"));
+        assert_eq!(Some((&"a", &1)), map.longest_prefix_match("ab"));
+        assert_eq!(Some((&"b", &4)), map.longest_prefix_match("bz"));
+        assert_eq!(Some((&"", &0)), map.longest_prefix_match("c"));
+        assert_eq!(Some((&"", &0)), map.longest_prefix_match(""));
+
+        // the search must not split the multi-byte 'é' when it backs off
+        assert_eq!(Some((&"héllo", &5)), map.longest_prefix_match("héllo world"));
+        assert_eq!(Some((&"", &0)), map.longest_prefix_match("hèllo"));
+
+        let map = FzPrefixMap::new(vec![("x".to_string(), 0), ("xyz".to_string(), 1)]);
+        assert_eq!(None, map.longest_prefix_match("abc"));
+        assert_eq!(Some(&1), map.longest_prefix_match("xyzzy").map(|x| x.1));
+
+        let map = FzPrefixMap::<&str, i32>::default();
+        assert_eq!(None, map.longest_prefix_match("abc"));
+    }
+
+    #[test]
+    fn keys_with_prefix() {
+        let map = FzPrefixMap::new(vec![
+            ("apple", 0),
+            ("app", 1),
+            ("application", 2),
+            ("banana", 3),
+            ("ap", 4),
+            ("b", 5),
+        ]);
+
+        assert_eq!(
+            vec![&"app", &"apple", &"application"],
+            map.keys_with_prefix("app").collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![&"application", &"apple"],
+            map.keys_with_prefix("appl").rev().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![&"b", &"banana"], map.keys_with_prefix("b").collect::<Vec<_>>());
+        assert_eq!(6, map.keys_with_prefix("").len());
+        assert_eq!(0, map.keys_with_prefix("c").len());
+        assert_eq!(0, map.keys_with_prefix("apples").len());
+        assert_eq!(Some((&"banana", &3)), map.entries_with_prefix("ban").next());
+    }
+
+    #[test]
+    fn exact_lookups() {
+        let mut map = FzPrefixMap::new(vec![("one", 1), ("two", 2), ("three", 3), ("one", 4)]);
+
+        assert_eq!(3, map.len());
+        assert_eq!(Some(&4), map.get("one"));
+        assert_eq!(None, map.get("on"));
+        assert!(map.contains_key(String::from("two")));
+
+        if let Some(v) = map.get_mut("three") {
+            *v = 33;
+        }
+
+        assert_eq!(Some((&"three", &33)), map.get_key_value("three"));
+        assert_eq!(33, map[&"three"]);
+        assert_eq!(vec![&"one", &"three", &"two"], map.iter_sorted().map(|x| x.0).collect::<Vec<_>>());
+    }
+}
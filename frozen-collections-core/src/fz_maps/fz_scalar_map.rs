@@ -1,13 +1,17 @@
-use crate::analyzers::{ScalarKeyAnalysisResult, analyze_scalar_keys};
+use crate::analyzers::{ScalarKeyAnalysisResult, analyze_scalar_keys, analyze_simd_probing};
+use crate::diagnostics::{Diagnostics, HeapBytes};
 use crate::maps::decl_macros::{
-    debug_trait_funcs, index_trait_funcs, into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs,
-    len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs,
+    debug_trait_funcs, filter_funcs, index_trait_funcs, into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs,
+    into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs, map_query_trait_funcs,
+    partial_eq_trait_funcs,
 };
 use crate::maps::{
-    DenseScalarLookupMap, HashMap, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, SparseScalarLookupMap, Values, ValuesMut,
+    BitsetScalarLookupMap, DenseScalarLookupMap, HashMap, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, SimdProbeMap,
+    SparseScalarLookupMap, Values, ValuesMut,
 };
+use crate::sets::BitsetView;
 use crate::traits::{LargeCollection, Len, Map, MapExtras, MapIteration, MapQuery, Scalar};
-use crate::utils::SortedAndDeduppedVec;
+use crate::utils::{SortedAndDeduppedVec, SortedOrder, into_ok, retain_flagged, retain_flags};
 use core::fmt::{Debug, Formatter, Result};
 use core::ops::Index;
 use equivalent::Comparable;
@@ -27,15 +31,39 @@ use {
 
 #[derive(Clone)]
 enum MapTypes<K, V> {
-    Hash(HashMap<K, V, LargeCollection, ScalarHasher>),
+    Hash(HashMap<K, V, LargeCollection, ScalarHasher>, SortedOrder),
+    SimdProbe(SimdProbeMap<K, V, ScalarHasher>, SortedOrder),
     Dense(DenseScalarLookupMap<K, V>),
     Sparse(SparseScalarLookupMap<K, V>),
+    Bitset(BitsetScalarLookupMap<K, V>),
+}
+
+/// Performs an order-based lookup, which hashed maps answer from their recorded sorted order
+/// and lookup tables answer directly.
+macro_rules! ordered_lookup {
+    ($self:ident, $func:ident($arg:expr)) => {
+        match &$self.map_impl {
+            MapTypes::Hash(m, order) => order.$func(m.entries(), $arg),
+            MapTypes::SimdProbe(m, order) => order.$func(m.entries(), $arg),
+            MapTypes::Dense(m) => m.$func($arg),
+            MapTypes::Sparse(m) => m.$func($arg),
+            MapTypes::Bitset(m) => m.$func($arg),
+        }
+    };
 }
 
 /// A map optimized for fast read access using scalar keys.
 ///
 #[doc = include_str!("../doc_snippets/about.md")]
 ///
+/// # Order-Based Lookups
+///
+/// The [`rank`](Self::rank), [`select`](Self::select), [`floor`](Self::floor), [`ceiling`](Self::ceiling),
+/// [`predecessor`](Self::predecessor), and [`successor`](Self::successor) functions are fast when the
+/// keys are clustered closely enough to be served by a lookup table. When the keys are widely spread out and the
+/// map resorts to hashing, the map also records the sorted order of its entries when it is created, so these
+/// functions perform a binary search, at the cost of one extra `usize` per entry.
+///
 /// # Alternate Choices
 ///
 /// If your keys are known at compile time, consider using the various `fz_*_map` macros instead of
@@ -48,22 +76,92 @@ pub struct FzScalarMap<K, V> {
 impl<K, V> FzScalarMap<K, V> {
     /// Creates a frozen map.
     #[must_use]
-    #[expect(clippy::missing_panics_doc, reason = "Guaranteed to work because the map is a LargeCollection")]
     pub fn new(entries: Vec<(K, V)>) -> Self
     where
         K: Scalar,
     {
         let entries = SortedAndDeduppedVec::new(entries, |x, y| x.0.cmp(&y.0));
 
+        Self::from_sorted_and_dedupped(entries)
+    }
+
+    /// Creates a frozen map from entries which are already sorted and free of duplicates.
+    #[must_use]
+    pub(crate) fn from_sorted_and_dedupped(entries: SortedAndDeduppedVec<(K, V)>) -> Self
+    where
+        K: Scalar,
+    {
         Self {
-            map_impl: match analyze_scalar_keys(entries.iter().map(|x| x.0)) {
+            map_impl: match analyze_scalar_keys(entries.iter().map(|x| x.0), size_of::<V>() == 0) {
                 ScalarKeyAnalysisResult::DenseRange => MapTypes::Dense(DenseScalarLookupMap::from_sorted_and_dedupped(entries)),
                 ScalarKeyAnalysisResult::SparseRange => MapTypes::Sparse(SparseScalarLookupMap::from_sorted_and_dedupped(entries)),
-                ScalarKeyAnalysisResult::General => MapTypes::Hash(HashMap::from_dedupped(entries.into(), ScalarHasher {}).unwrap()),
+                ScalarKeyAnalysisResult::BitsetRange => MapTypes::Bitset(BitsetScalarLookupMap::from_sorted_and_dedupped(entries)),
+                ScalarKeyAnalysisResult::General => {
+                    if analyze_simd_probing(entries.len(), None) {
+                        let m = SimdProbeMap::from_dedupped(entries.into(), ScalarHasher {});
+                        let order = SortedOrder::new(m.entries());
+                        MapTypes::SimdProbe(m, order)
+                    } else {
+                        let m = HashMap::from_dedupped(entries.into(), ScalarHasher {}).unwrap();
+                        let order = SortedOrder::new(m.entries());
+                        MapTypes::Hash(m, order)
+                    }
+                }
             },
         }
     }
 
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> FzScalarMap<K, U> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(self, f: impl FnMut(&K, V) -> core::result::Result<U, E>) -> core::result::Result<FzScalarMap<K, U>, E> {
+        Ok(FzScalarMap {
+            map_impl: match self.map_impl {
+                // entries keep their positions, so the sorted order still applies
+                MapTypes::Hash(m, order) => MapTypes::Hash(m.try_map_values(f)?, order),
+                MapTypes::SimdProbe(m, order) => MapTypes::SimdProbe(m.try_map_values(f)?, order),
+                MapTypes::Dense(m) => MapTypes::Dense(m.try_map_values(f)?),
+                MapTypes::Sparse(m) => MapTypes::Sparse(m.try_map_values(f)?),
+                MapTypes::Bitset(m) => MapTypes::Bitset(m.try_map_values(f)?),
+            },
+        })
+    }
+
+    #[doc = include_str!("../doc_snippets/retain_into.md")]
+    #[must_use]
+    pub fn retain_into(self, f: impl FnMut(&K, &V) -> bool) -> Self
+    where
+        K: Scalar,
+    {
+        let Some(flags) = retain_flags(self.iter(), f) else {
+            return self;
+        };
+
+        // the remaining keys may fit a different layout, so analyze them again
+        let mut entries = retain_flagged(self, flags);
+        entries.sort_unstable_by(|x, y| x.0.cmp(&y.0));
+        Self::from_sorted_and_dedupped(SortedAndDeduppedVec::from_sorted_unique(entries, |x, y| x.0.cmp(&y.0)))
+    }
+
+    /// Creates a map like this one holding the given entries, a subset of this map's entries in iteration order.
+    #[allow(
+        clippy::unused_self,
+        reason = "Called through filter_funcs!, like the maps which reuse their hasher"
+    )]
+    fn with_retained(&self, mut entries: Vec<(K, V)>) -> Self
+    where
+        K: Scalar,
+    {
+        entries.sort_unstable_by(|x, y| x.0.cmp(&y.0));
+        Self::from_sorted_and_dedupped(SortedAndDeduppedVec::from_sorted_unique(entries, |x, y| x.0.cmp(&y.0)))
+    }
+
+    filter_funcs!(K, K: Scalar);
+
     #[doc = include_str!("../doc_snippets/get.md")]
     #[inline]
     pub fn get<Q>(&self, key: &Q) -> Option<&V>
@@ -71,9 +169,11 @@ impl<K, V> FzScalarMap<K, V> {
         Q: Scalar + Comparable<K>,
     {
         match &self.map_impl {
-            MapTypes::Hash(m) => m.get(key),
+            MapTypes::Hash(m, _) => m.get(key),
+            MapTypes::SimdProbe(m, _) => m.get(key),
             MapTypes::Dense(m) => m.get(key),
             MapTypes::Sparse(m) => m.get(key),
+            MapTypes::Bitset(m) => m.get(key),
         }
     }
 
@@ -84,9 +184,11 @@ impl<K, V> FzScalarMap<K, V> {
         Q: Scalar + Comparable<K>,
     {
         match &mut self.map_impl {
-            MapTypes::Hash(m) => m.get_mut(key),
+            MapTypes::Hash(m, _) => m.get_mut(key),
+            MapTypes::SimdProbe(m, _) => m.get_mut(key),
             MapTypes::Dense(m) => m.get_mut(key),
             MapTypes::Sparse(m) => m.get_mut(key),
+            MapTypes::Bitset(m) => m.get_mut(key),
         }
     }
 
@@ -97,9 +199,11 @@ impl<K, V> FzScalarMap<K, V> {
         Q: Scalar + Comparable<K>,
     {
         match &self.map_impl {
-            MapTypes::Hash(m) => m.get_key_value(key),
+            MapTypes::Hash(m, _) => m.get_key_value(key),
+            MapTypes::SimdProbe(m, _) => m.get_key_value(key),
             MapTypes::Dense(m) => m.get_key_value(key),
             MapTypes::Sparse(m) => m.get_key_value(key),
+            MapTypes::Bitset(m) => m.get_key_value(key),
         }
     }
 
@@ -111,12 +215,75 @@ impl<K, V> FzScalarMap<K, V> {
         Q: Scalar + Comparable<K>,
     {
         match &self.map_impl {
-            MapTypes::Hash(m) => m.contains_key(key),
+            MapTypes::Hash(m, _) => m.contains_key(key),
+            MapTypes::SimdProbe(m, _) => m.contains_key(key),
             MapTypes::Dense(m) => m.contains_key(key),
             MapTypes::Sparse(m) => m.contains_key(key),
+            MapTypes::Bitset(m) => m.contains_key(key),
         }
     }
 
+    #[doc = include_str!("../doc_snippets/rank.md")]
+    #[inline]
+    #[must_use]
+    pub fn rank<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Scalar,
+        Q: Scalar + Comparable<K>,
+    {
+        ordered_lookup!(self, rank(key))
+    }
+
+    #[doc = include_str!("../doc_snippets/select.md")]
+    #[inline]
+    #[must_use]
+    pub fn select(&self, sorted_index: usize) -> Option<(&K, &V)>
+    where
+        K: Scalar,
+    {
+        ordered_lookup!(self, select(sorted_index))
+    }
+
+    #[doc = include_str!("../doc_snippets/floor.md")]
+    #[inline]
+    pub fn floor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Scalar,
+        Q: Scalar + Comparable<K>,
+    {
+        ordered_lookup!(self, floor(key))
+    }
+
+    #[doc = include_str!("../doc_snippets/ceiling.md")]
+    #[inline]
+    pub fn ceiling<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Scalar,
+        Q: Scalar + Comparable<K>,
+    {
+        ordered_lookup!(self, ceiling(key))
+    }
+
+    #[doc = include_str!("../doc_snippets/predecessor.md")]
+    #[inline]
+    pub fn predecessor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Scalar,
+        Q: Scalar + Comparable<K>,
+    {
+        ordered_lookup!(self, predecessor(key))
+    }
+
+    #[doc = include_str!("../doc_snippets/successor.md")]
+    #[inline]
+    pub fn successor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Scalar,
+        Q: Scalar + Comparable<K>,
+    {
+        ordered_lookup!(self, successor(key))
+    }
+
     #[doc = include_str!("../doc_snippets/get_disjoint_mut.md")]
     #[must_use]
     pub fn get_disjoint_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N]
@@ -124,9 +291,11 @@ impl<K, V> FzScalarMap<K, V> {
         Q: Scalar + Comparable<K>,
     {
         match &mut self.map_impl {
-            MapTypes::Hash(m) => m.get_disjoint_mut(keys),
+            MapTypes::Hash(m, _) => m.get_disjoint_mut(keys),
+            MapTypes::SimdProbe(m, _) => m.get_disjoint_mut(keys),
             MapTypes::Dense(m) => m.get_disjoint_mut(keys),
             MapTypes::Sparse(m) => m.get_disjoint_mut(keys),
+            MapTypes::Bitset(m) => m.get_disjoint_mut(keys),
         }
     }
 
@@ -137,7 +306,12 @@ impl<K, V> FzScalarMap<K, V> {
         Q: Scalar + Comparable<K>,
     {
         match &mut self.map_impl {
-            MapTypes::Hash(m) => {
+            MapTypes::Hash(m, _) => {
+                // SAFETY: The caller must ensure that the keys are disjoint and valid for the map.
+                unsafe { m.get_disjoint_unchecked_mut(keys) }
+            }
+
+            MapTypes::SimdProbe(m, _) => {
                 // SAFETY: The caller must ensure that the keys are disjoint and valid for the map.
                 unsafe { m.get_disjoint_unchecked_mut(keys) }
             }
@@ -151,6 +325,11 @@ impl<K, V> FzScalarMap<K, V> {
                 // SAFETY: The caller must ensure that the keys are disjoint and valid for the map.
                 unsafe { m.get_disjoint_unchecked_mut(keys) }
             }
+
+            MapTypes::Bitset(m) => {
+                // SAFETY: The caller must ensure that the keys are disjoint and valid for the map.
+                unsafe { m.get_disjoint_unchecked_mut(keys) }
+            }
         }
     }
 
@@ -159,9 +338,11 @@ impl<K, V> FzScalarMap<K, V> {
     #[must_use]
     pub fn len(&self) -> usize {
         match &self.map_impl {
-            MapTypes::Hash(m) => m.len(),
+            MapTypes::Hash(m, _) => m.len(),
+            MapTypes::SimdProbe(m, _) => m.len(),
             MapTypes::Dense(m) => m.len(),
             MapTypes::Sparse(m) => m.len(),
+            MapTypes::Bitset(m) => m.len(),
         }
     }
 
@@ -170,9 +351,26 @@ impl<K, V> FzScalarMap<K, V> {
     #[must_use]
     pub fn is_empty(&self) -> bool {
         match &self.map_impl {
-            MapTypes::Hash(m) => m.is_empty(),
+            MapTypes::Hash(m, _) => m.is_empty(),
+            MapTypes::SimdProbe(m, _) => m.is_empty(),
             MapTypes::Dense(m) => m.is_empty(),
             MapTypes::Sparse(m) => m.is_empty(),
+            MapTypes::Bitset(m) => m.is_empty(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/diagnostics.md")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics
+    where
+        K: Scalar,
+    {
+        match &self.map_impl {
+            MapTypes::Hash(m, order) => with_order_bytes(m.diagnostics(), order),
+            MapTypes::SimdProbe(m, order) => with_order_bytes(m.diagnostics(), order),
+            MapTypes::Dense(m) => m.diagnostics(),
+            MapTypes::Sparse(m) => m.diagnostics(),
+            MapTypes::Bitset(m) => m.diagnostics(),
         }
     }
 
@@ -180,9 +378,11 @@ impl<K, V> FzScalarMap<K, V> {
     #[must_use]
     pub fn iter(&self) -> Iter<'_, K, V> {
         match &self.map_impl {
-            MapTypes::Hash(m) => m.iter(),
+            MapTypes::Hash(m, _) => m.iter(),
+            MapTypes::SimdProbe(m, _) => m.iter(),
             MapTypes::Dense(m) => m.iter(),
             MapTypes::Sparse(m) => m.iter(),
+            MapTypes::Bitset(m) => m.iter(),
         }
     }
 
@@ -190,18 +390,22 @@ impl<K, V> FzScalarMap<K, V> {
     #[must_use]
     pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
         match &mut self.map_impl {
-            MapTypes::Hash(m) => m.iter_mut(),
+            MapTypes::Hash(m, _) => m.iter_mut(),
+            MapTypes::SimdProbe(m, _) => m.iter_mut(),
             MapTypes::Dense(m) => m.iter_mut(),
             MapTypes::Sparse(m) => m.iter_mut(),
+            MapTypes::Bitset(m) => m.iter_mut(),
         }
     }
 
     #[must_use]
     fn into_iter(self) -> IntoIter<K, V> {
         match self.map_impl {
-            MapTypes::Hash(m) => m.into_iter(),
+            MapTypes::Hash(m, _) => m.into_iter(),
+            MapTypes::SimdProbe(m, _) => m.into_iter(),
             MapTypes::Dense(m) => m.into_iter(),
             MapTypes::Sparse(m) => m.into_iter(),
+            MapTypes::Bitset(m) => m.into_iter(),
         }
     }
 
@@ -209,9 +413,11 @@ impl<K, V> FzScalarMap<K, V> {
     #[must_use]
     pub fn keys(&self) -> Keys<'_, K, V> {
         match &self.map_impl {
-            MapTypes::Hash(m) => m.keys(),
+            MapTypes::Hash(m, _) => m.keys(),
+            MapTypes::SimdProbe(m, _) => m.keys(),
             MapTypes::Dense(m) => m.keys(),
             MapTypes::Sparse(m) => m.keys(),
+            MapTypes::Bitset(m) => m.keys(),
         }
     }
 
@@ -219,9 +425,11 @@ impl<K, V> FzScalarMap<K, V> {
     #[must_use]
     pub fn into_keys(self) -> IntoKeys<K, V> {
         match self.map_impl {
-            MapTypes::Hash(m) => m.into_keys(),
+            MapTypes::Hash(m, _) => m.into_keys(),
+            MapTypes::SimdProbe(m, _) => m.into_keys(),
             MapTypes::Dense(m) => m.into_keys(),
             MapTypes::Sparse(m) => m.into_keys(),
+            MapTypes::Bitset(m) => m.into_keys(),
         }
     }
 
@@ -229,9 +437,11 @@ impl<K, V> FzScalarMap<K, V> {
     #[must_use]
     pub fn values(&self) -> Values<'_, K, V> {
         match &self.map_impl {
-            MapTypes::Hash(m) => m.values(),
+            MapTypes::Hash(m, _) => m.values(),
+            MapTypes::SimdProbe(m, _) => m.values(),
             MapTypes::Dense(m) => m.values(),
             MapTypes::Sparse(m) => m.values(),
+            MapTypes::Bitset(m) => m.values(),
         }
     }
 
@@ -239,9 +449,11 @@ impl<K, V> FzScalarMap<K, V> {
     #[must_use]
     pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
         match &mut self.map_impl {
-            MapTypes::Hash(m) => m.values_mut(),
+            MapTypes::Hash(m, _) => m.values_mut(),
+            MapTypes::SimdProbe(m, _) => m.values_mut(),
             MapTypes::Dense(m) => m.values_mut(),
             MapTypes::Sparse(m) => m.values_mut(),
+            MapTypes::Bitset(m) => m.values_mut(),
         }
     }
 
@@ -249,9 +461,26 @@ impl<K, V> FzScalarMap<K, V> {
     #[must_use]
     pub fn into_values(self) -> IntoValues<K, V> {
         match self.map_impl {
-            MapTypes::Hash(m) => m.into_values(),
+            MapTypes::Hash(m, _) => m.into_values(),
+            MapTypes::SimdProbe(m, _) => m.into_values(),
             MapTypes::Dense(m) => m.into_values(),
             MapTypes::Sparse(m) => m.into_values(),
+            MapTypes::Bitset(m) => m.into_values(),
+        }
+    }
+}
+
+fn with_order_bytes(mut d: Diagnostics, order: &SortedOrder) -> Diagnostics {
+    d.heap_bytes += order.heap_bytes();
+    d
+}
+
+impl<K> FzScalarMap<K, ()> {
+    pub(crate) fn bitset(&self) -> Option<BitsetView<'_, K>> {
+        match &self.map_impl {
+            MapTypes::Dense(m) => Some(m.bitset()),
+            MapTypes::Bitset(m) => Some(m.bitset()),
+            _ => None,
         }
     }
 }
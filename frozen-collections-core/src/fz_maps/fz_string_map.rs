@@ -1,36 +1,48 @@
 use crate::DefaultBuildHasher;
-use crate::analyzers::{SliceKeyAnalysisResult, analyze_slice_keys};
-use crate::hashers::{BridgeHasher, LeftRangeHasher, RightRangeHasher};
-use crate::maps::decl_macros::{debug_trait_funcs, index_trait_funcs, len_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs};
-use crate::maps::{HashMap, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
-use crate::traits::{LargeCollection, Len, Map, MapExtras, MapIteration, MapQuery};
-use crate::utils::DeduppedVec;
+use crate::analyzers::{SliceKeyAnalysisResult, analyze_perfect_hash_codes, analyze_simd_probing, analyze_slice_keys};
+use crate::diagnostics::Diagnostics;
+use crate::hashers::{BridgeHasher, LeftRangeHasher, LengthHasher, RightRangeHasher};
+use crate::keys::AsciiCaseInsensitive;
+use crate::maps::decl_macros::{
+    debug_trait_funcs, filter_funcs, index_trait_funcs, len_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs,
+};
+use crate::maps::{HashMap, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, PerfectHashMap, SimdProbeMap, Values, ValuesMut};
+use crate::traits::{Hasher, IntoStringKey, LargeCollection, Len, Map, MapExtras, MapIteration, MapQuery, StringKey};
+use crate::utils::{DeduppedVec, into_ok, retain_flagged, retain_flags};
 use core::array;
 use core::fmt::{Debug, Formatter, Result};
 use core::hash::BuildHasher;
-use core::marker::PhantomData;
 use core::ops::Index;
 
 #[cfg(not(feature = "std"))]
-use {alloc::boxed::Box, alloc::string::ToString, alloc::vec::Vec};
+use {alloc::boxed::Box, alloc::vec::Vec};
 
 #[cfg(feature = "serde")]
 use {
     crate::maps::decl_macros::serialize_trait_funcs,
+    core::marker::PhantomData,
     serde::de::{MapAccess, Visitor},
     serde::ser::SerializeMap,
     serde::{Deserialize, Deserializer, Serialize, Serializer},
 };
 
 #[derive(Clone)]
-enum MapTypes<V, BH> {
-    LeftRange(HashMap<Box<str>, V, LargeCollection, LeftRangeHasher<BH>>),
-    RightRange(HashMap<Box<str>, V, LargeCollection, RightRangeHasher<BH>>),
-    Hash(HashMap<Box<str>, V, LargeCollection, BridgeHasher<BH>>),
+enum MapTypes<K, V, BH> {
+    LeftRange(HashMap<K, V, LargeCollection, LeftRangeHasher<BH>>),
+    RightRange(HashMap<K, V, LargeCollection, RightRangeHasher<BH>>),
+    Length(HashMap<K, V, LargeCollection, LengthHasher>, BH),
+    Hash(HashMap<K, V, LargeCollection, BridgeHasher<BH>>),
+    PerfectHash(PerfectHashMap<K, V, BridgeHasher<BH>>),
+    SimdProbe(SimdProbeMap<K, V, BridgeHasher<BH>>),
 }
 
 /// A map optimized for fast read access with string keys.
 ///
+/// The key type decides how keys are compared: `Box<str>` keys are compared as-is, while
+/// [`AsciiCaseInsensitive<Box<str>>`](AsciiCaseInsensitive) keys match regardless of the case of their
+/// ASCII letters. Either way, the map is looked up with plain strings, which are folded as they are
+/// hashed and compared, so lookups don't allocate.
+///
 #[doc = include_str!("../doc_snippets/about.md")]
 #[doc = include_str!("../doc_snippets/hash_warning.md")]
 ///
@@ -40,43 +52,56 @@ enum MapTypes<V, BH> {
 /// this type as they generally perform better.
 #[derive(Clone)]
 pub struct FzStringMap<K, V, BH = DefaultBuildHasher> {
-    map_impl: MapTypes<V, BH>,
-    _0: PhantomData<K>,
+    map_impl: MapTypes<K, V, BH>,
 }
 
-impl<V> FzStringMap<Box<str>, V, DefaultBuildHasher> {
+impl<K, V> FzStringMap<K, V, DefaultBuildHasher>
+where
+    K: StringKey,
+{
     /// Creates a frozen map.
+    ///
+    /// The map ignores the case of ASCII letters in its keys when they are given wrapped in
+    /// [`AsciiCaseInsensitive`], and compares them as-is otherwise.
     #[must_use]
-    pub fn new(entries: Vec<(impl AsRef<str>, V)>) -> Self {
+    pub fn new(entries: Vec<(impl IntoStringKey<Key = K>, V)>) -> Self {
         Self::with_hasher(entries, DefaultBuildHasher::default())
     }
 }
 
-impl<V, BH> FzStringMap<Box<str>, V, BH> {
+impl<K, V, BH> FzStringMap<K, V, BH>
+where
+    K: StringKey,
+{
     /// Creates a frozen map which uses the given hash builder to hash keys.
+    ///
+    /// The map ignores the case of ASCII letters in its keys when they are given wrapped in
+    /// [`AsciiCaseInsensitive`], and compares them as-is otherwise.
     #[must_use]
+    pub fn with_hasher(entries: Vec<(impl IntoStringKey<Key = K>, V)>, bh: BH) -> Self
+    where
+        BH: BuildHasher,
+    {
+        Self::from_keys(entries.into_iter().map(|(k, v)| (k.into_string_key(), v)).collect(), bh)
+    }
+
     #[expect(
         clippy::missing_panics_doc,
         reason = "Guaranteed not to panic because the map is a LargeCollection"
     )]
-    pub fn with_hasher(entries: Vec<(impl AsRef<str>, V)>, bh: BH) -> Self
+    pub(crate) fn from_keys(entries: Vec<(K, V)>, bh: BH) -> Self
     where
         BH: BuildHasher,
     {
-        let entries: Vec<(Box<str>, V)> = entries
-            .into_iter()
-            .map(|(k, v)| (k.as_ref().to_string().into_boxed_str(), v))
-            .collect();
+        let entries = DeduppedVec::using_cmp(entries, |x, y| x.0.cmp(&y.0));
 
-        let entries = DeduppedVec::using_cmp(entries, |x, y| x.0.as_ref().cmp(y.0.as_ref()));
+        // the analysis sees the keys the way the hashers do, which may fold their case
+        let hashed: Vec<_> = entries.iter().map(|x| x.0.hashed_bytes()).collect();
 
         Self {
             map_impl: {
-                match analyze_slice_keys(entries.iter().map(|x| x.0.as_ref().as_bytes()), &bh) {
-                    SliceKeyAnalysisResult::General | SliceKeyAnalysisResult::Length => {
-                        let h = BridgeHasher::new(bh);
-                        MapTypes::Hash(HashMap::from_dedupped(entries, h).unwrap())
-                    }
+                match analyze_slice_keys(hashed.iter().map(AsRef::as_ref), &bh) {
+                    SliceKeyAnalysisResult::General => Self::general_map_types(entries, BridgeHasher::new(bh)),
 
                     SliceKeyAnalysisResult::LeftHandSubslice(range) => {
                         let h = LeftRangeHasher::new(bh, range);
@@ -87,23 +112,126 @@ impl<V, BH> FzStringMap<Box<str>, V, BH> {
                         let h = RightRangeHasher::new(bh, range);
                         MapTypes::RightRange(HashMap::from_dedupped(entries, h).unwrap())
                     }
+
+                    SliceKeyAnalysisResult::Length => MapTypes::Length(HashMap::from_dedupped(entries, LengthHasher).unwrap(), bh),
+                }
+            },
+        }
+    }
+
+    fn general_map_types(entries: DeduppedVec<(K, V)>, h: BridgeHasher<BH>) -> MapTypes<K, V, BH>
+    where
+        BH: BuildHasher,
+    {
+        let max_key_len = entries.iter().map(|x| x.0.len()).max().unwrap_or_default();
+        if analyze_simd_probing(entries.len(), Some(max_key_len)) {
+            MapTypes::SimdProbe(SimdProbeMap::from_dedupped(entries, h))
+        } else {
+            let hash_codes: Vec<_> = entries.iter().map(|x| h.hash_one(&x.0)).collect();
+            match analyze_perfect_hash_codes(&hash_codes) {
+                Some(analysis) => MapTypes::PerfectHash(PerfectHashMap::from_analysis(entries, analysis, h)),
+                None => MapTypes::Hash(HashMap::from_dedupped(entries, h).unwrap()),
+            }
+        }
+    }
+
+    pub(crate) const fn build_hasher(&self) -> &BH {
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.hasher().build_hasher(),
+            MapTypes::RightRange(m) => m.hasher().build_hasher(),
+            MapTypes::Length(_, bh) => bh,
+            MapTypes::Hash(m) => m.hasher().build_hasher(),
+            MapTypes::PerfectHash(m) => m.hasher().build_hasher(),
+            MapTypes::SimdProbe(m) => m.hasher().build_hasher(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> FzStringMap<K, U, BH> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(
+        self,
+        f: impl FnMut(&K, V) -> core::result::Result<U, E>,
+    ) -> core::result::Result<FzStringMap<K, U, BH>, E> {
+        Ok(FzStringMap {
+            map_impl: match self.map_impl {
+                MapTypes::LeftRange(m) => MapTypes::LeftRange(m.try_map_values(f)?),
+                MapTypes::RightRange(m) => MapTypes::RightRange(m.try_map_values(f)?),
+                MapTypes::Length(m, bh) => MapTypes::Length(m.try_map_values(f)?, bh),
+                MapTypes::Hash(m) => MapTypes::Hash(m.try_map_values(f)?),
+                MapTypes::PerfectHash(m) => MapTypes::PerfectHash(m.try_map_values(f)?),
+                MapTypes::SimdProbe(m) => MapTypes::SimdProbe(m.try_map_values(f)?),
+            },
+        })
+    }
+
+    #[doc = include_str!("../doc_snippets/retain_into.md")]
+    #[must_use]
+    pub fn retain_into(self, f: impl FnMut(&K, &V) -> bool) -> Self
+    where
+        BH: BuildHasher,
+    {
+        Self {
+            map_impl: match self.map_impl {
+                MapTypes::LeftRange(m) => MapTypes::LeftRange(m.retain_into(f)),
+                MapTypes::RightRange(m) => MapTypes::RightRange(m.retain_into(f)),
+                MapTypes::Length(m, bh) => MapTypes::Length(m.retain_into(f), bh),
+                MapTypes::Hash(m) => MapTypes::Hash(m.retain_into(f)),
+                MapTypes::SimdProbe(m) => MapTypes::SimdProbe(m.retain_into(f)),
+                MapTypes::PerfectHash(m) => {
+                    // a subset of the keys may not have a perfect hash function, so pick a new layout
+                    let Some(flags) = retain_flags(m.iter(), f) else {
+                        return Self {
+                            map_impl: MapTypes::PerfectHash(m),
+                        };
+                    };
+
+                    let (entries, h) = m.into_parts();
+                    Self::general_map_types(DeduppedVec::from_unique(retain_flagged(entries, flags)), h)
                 }
             },
-            _0: PhantomData,
         }
     }
 
+    /// Creates a map like this one holding the given entries, a subset of this map's entries in iteration order.
+    fn with_retained(&self, entries: Vec<(K, V)>) -> Self
+    where
+        BH: BuildHasher + Clone,
+    {
+        Self {
+            map_impl: match &self.map_impl {
+                MapTypes::LeftRange(m) => MapTypes::LeftRange(m.with_retained(entries)),
+                MapTypes::RightRange(m) => MapTypes::RightRange(m.with_retained(entries)),
+                MapTypes::Length(m, bh) => MapTypes::Length(m.with_retained(entries), bh.clone()),
+                MapTypes::Hash(m) => MapTypes::Hash(m.with_retained(entries)),
+                MapTypes::SimdProbe(m) => MapTypes::SimdProbe(m.with_retained(entries)),
+
+                // a subset of the keys may not have a perfect hash function, so pick a new layout
+                MapTypes::PerfectHash(m) => Self::general_map_types(DeduppedVec::from_unique(entries), m.hasher().clone()),
+            },
+        }
+    }
+
+    filter_funcs!(K, BH: BuildHasher + Clone);
+
     #[doc = include_str!("../doc_snippets/get.md")]
     #[inline]
     pub fn get(&self, key: impl AsRef<str>) -> Option<&V>
     where
         BH: BuildHasher,
     {
-        let key = key.as_ref();
+        let key = K::as_query(key.as_ref());
         match &self.map_impl {
             MapTypes::LeftRange(m) => m.get(key),
             MapTypes::RightRange(m) => m.get(key),
+            MapTypes::Length(m, _) => m.get(key),
             MapTypes::Hash(m) => m.get(key),
+            MapTypes::PerfectHash(m) => m.get(key),
+            MapTypes::SimdProbe(m) => m.get(key),
         }
     }
 
@@ -113,26 +241,31 @@ impl<V, BH> FzStringMap<Box<str>, V, BH> {
     where
         BH: BuildHasher,
     {
-        let key = key.as_ref();
+        let key = K::as_query(key.as_ref());
         match &mut self.map_impl {
             MapTypes::LeftRange(m) => m.get_mut(key),
             MapTypes::RightRange(m) => m.get_mut(key),
+            MapTypes::Length(m, _) => m.get_mut(key),
             MapTypes::Hash(m) => m.get_mut(key),
+            MapTypes::PerfectHash(m) => m.get_mut(key),
+            MapTypes::SimdProbe(m) => m.get_mut(key),
         }
     }
 
     #[doc = include_str!("../doc_snippets/get_key_value.md")]
     #[inline]
-    #[expect(clippy::borrowed_box, reason = "By design")]
-    pub fn get_key_value(&self, key: impl AsRef<str>) -> Option<(&Box<str>, &V)>
+    pub fn get_key_value(&self, key: impl AsRef<str>) -> Option<(&K, &V)>
     where
         BH: BuildHasher,
     {
-        let key = key.as_ref();
+        let key = K::as_query(key.as_ref());
         match &self.map_impl {
             MapTypes::LeftRange(m) => m.get_key_value(key),
             MapTypes::RightRange(m) => m.get_key_value(key),
+            MapTypes::Length(m, _) => m.get_key_value(key),
             MapTypes::Hash(m) => m.get_key_value(key),
+            MapTypes::PerfectHash(m) => m.get_key_value(key),
+            MapTypes::SimdProbe(m) => m.get_key_value(key),
         }
     }
 
@@ -143,11 +276,92 @@ impl<V, BH> FzStringMap<Box<str>, V, BH> {
     where
         BH: BuildHasher,
     {
-        let key = key.as_ref();
+        let key = K::as_query(key.as_ref());
         match &self.map_impl {
             MapTypes::LeftRange(m) => m.contains_key(key),
             MapTypes::RightRange(m) => m.contains_key(key),
+            MapTypes::Length(m, _) => m.contains_key(key),
             MapTypes::Hash(m) => m.contains_key(key),
+            MapTypes::PerfectHash(m) => m.contains_key(key),
+            MapTypes::SimdProbe(m) => m.contains_key(key),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/hash_key.md")]
+    ///
+    /// The hash code only depends on the hash builder, and not on the keys the map holds, so it can be shared between
+    /// string maps and sets whose hash builders hash keys the same way.
+    #[inline]
+    #[must_use]
+    pub fn hash_key(&self, key: impl AsRef<str>) -> u64
+    where
+        BH: BuildHasher,
+    {
+        self.build_hasher().hash_one(K::as_query(key.as_ref()))
+    }
+
+    #[doc = include_str!("../doc_snippets/get_with_hash.md")]
+    ///
+    /// When the map hashes only part of its keys, or their lengths, the hash code isn't used and the key is
+    /// looked up as with [`get`](Self::get).
+    #[inline]
+    #[must_use]
+    pub fn get_with_hash(&self, hash_code: u64, key: impl AsRef<str>) -> Option<&V>
+    where
+        BH: BuildHasher,
+    {
+        let key = K::as_query(key.as_ref());
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.get(key),
+            MapTypes::RightRange(m) => m.get(key),
+            MapTypes::Length(m, _) => m.get(key),
+            MapTypes::Hash(m) => m.get_with_hash(hash_code, key),
+            MapTypes::PerfectHash(m) => m.get_with_hash(hash_code, key),
+            MapTypes::SimdProbe(m) => m.get_with_hash(hash_code, key),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/contains_with_hash.md")]
+    #[inline]
+    #[must_use]
+    pub fn contains_with_hash(&self, hash_code: u64, key: impl AsRef<str>) -> bool
+    where
+        BH: BuildHasher,
+    {
+        self.get_with_hash(hash_code, key).is_some()
+    }
+
+    #[doc = include_str!("../doc_snippets/get_many.md")]
+    pub fn get_many<'a>(&'a self, keys: &[impl AsRef<str>], results: &mut [Option<&'a V>])
+    where
+        BH: BuildHasher,
+    {
+        assert_eq!(keys.len(), results.len(), "keys and results must have the same length");
+        let found = |i: usize, entry: Option<&'a (K, V)>| results[i] = entry.map(|(_, v)| v);
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.find_many(keys.len(), |i| K::as_query(keys[i].as_ref()), found),
+            MapTypes::RightRange(m) => m.find_many(keys.len(), |i| K::as_query(keys[i].as_ref()), found),
+            MapTypes::Length(m, _) => m.find_many(keys.len(), |i| K::as_query(keys[i].as_ref()), found),
+            MapTypes::Hash(m) => m.find_many(keys.len(), |i| K::as_query(keys[i].as_ref()), found),
+            MapTypes::PerfectHash(m) => m.find_many(keys.len(), |i| K::as_query(keys[i].as_ref()), found),
+            MapTypes::SimdProbe(m) => m.find_many(keys.len(), |i| K::as_query(keys[i].as_ref()), found),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/contains_many.md")]
+    pub fn contains_many(&self, keys: &[impl AsRef<str>], results: &mut [bool])
+    where
+        BH: BuildHasher,
+    {
+        assert_eq!(keys.len(), results.len(), "keys and results must have the same length");
+        let found = |i: usize, entry: Option<&(K, V)>| results[i] = entry.is_some();
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.find_many(keys.len(), |i| K::as_query(keys[i].as_ref()), found),
+            MapTypes::RightRange(m) => m.find_many(keys.len(), |i| K::as_query(keys[i].as_ref()), found),
+            MapTypes::Length(m, _) => m.find_many(keys.len(), |i| K::as_query(keys[i].as_ref()), found),
+            MapTypes::Hash(m) => m.find_many(keys.len(), |i| K::as_query(keys[i].as_ref()), found),
+            MapTypes::PerfectHash(m) => m.find_many(keys.len(), |i| K::as_query(keys[i].as_ref()), found),
+            MapTypes::SimdProbe(m) => m.find_many(keys.len(), |i| K::as_query(keys[i].as_ref()), found),
         }
     }
 
@@ -157,11 +371,14 @@ impl<V, BH> FzStringMap<Box<str>, V, BH> {
     where
         BH: BuildHasher,
     {
-        let keys: [&str; N] = array::from_fn(|i| keys[i].as_ref());
+        let keys: [&K::Query; N] = array::from_fn(|i| K::as_query(keys[i].as_ref()));
         match &mut self.map_impl {
             MapTypes::LeftRange(m) => m.get_disjoint_mut(keys),
             MapTypes::RightRange(m) => m.get_disjoint_mut(keys),
+            MapTypes::Length(m, _) => m.get_disjoint_mut(keys),
             MapTypes::Hash(m) => m.get_disjoint_mut(keys),
+            MapTypes::PerfectHash(m) => m.get_disjoint_mut(keys),
+            MapTypes::SimdProbe(m) => m.get_disjoint_mut(keys),
         }
     }
 
@@ -171,7 +388,7 @@ impl<V, BH> FzStringMap<Box<str>, V, BH> {
     where
         BH: BuildHasher,
     {
-        let keys: [&str; N] = array::from_fn(|i| keys[i].as_ref());
+        let keys: [&K::Query; N] = array::from_fn(|i| K::as_query(keys[i].as_ref()));
         match &mut self.map_impl {
             // SAFETY: The caller must ensure that the keys are disjoint.
             MapTypes::LeftRange(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
@@ -179,8 +396,17 @@ impl<V, BH> FzStringMap<Box<str>, V, BH> {
             // SAFETY: The caller must ensure that the keys are disjoint.
             MapTypes::RightRange(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
 
+            // SAFETY: The caller must ensure that the keys are disjoint.
+            MapTypes::Length(m, _) => unsafe { m.get_disjoint_unchecked_mut(keys) },
+
             // SAFETY: The caller must ensure that the keys are disjoint.
             MapTypes::Hash(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
+
+            // SAFETY: The caller must ensure that the keys are disjoint.
+            MapTypes::PerfectHash(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
+
+            // SAFETY: The caller must ensure that the keys are disjoint.
+            MapTypes::SimdProbe(m) => unsafe { m.get_disjoint_unchecked_mut(keys) },
         }
     }
 
@@ -191,7 +417,10 @@ impl<V, BH> FzStringMap<Box<str>, V, BH> {
         match &self.map_impl {
             MapTypes::LeftRange(m) => m.len(),
             MapTypes::RightRange(m) => m.len(),
+            MapTypes::Length(m, _) => m.len(),
             MapTypes::Hash(m) => m.len(),
+            MapTypes::PerfectHash(m) => m.len(),
+            MapTypes::SimdProbe(m) => m.len(),
         }
     }
 
@@ -202,135 +431,181 @@ impl<V, BH> FzStringMap<Box<str>, V, BH> {
         match &self.map_impl {
             MapTypes::LeftRange(m) => m.is_empty(),
             MapTypes::RightRange(m) => m.is_empty(),
+            MapTypes::Length(m, _) => m.is_empty(),
             MapTypes::Hash(m) => m.is_empty(),
+            MapTypes::PerfectHash(m) => m.is_empty(),
+            MapTypes::SimdProbe(m) => m.is_empty(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/diagnostics.md")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics
+    where
+        BH: BuildHasher,
+    {
+        match &self.map_impl {
+            MapTypes::LeftRange(m) => m.diagnostics(),
+            MapTypes::RightRange(m) => m.diagnostics(),
+            MapTypes::Length(m, _) => m.diagnostics(),
+            MapTypes::Hash(m) => m.diagnostics(),
+            MapTypes::PerfectHash(m) => m.diagnostics(),
+            MapTypes::SimdProbe(m) => m.diagnostics(),
         }
     }
 
     #[doc = include_str!("../doc_snippets/iter.md")]
     #[must_use]
-    pub fn iter(&self) -> Iter<'_, Box<str>, V> {
+    pub fn iter(&self) -> Iter<'_, K, V> {
         match &self.map_impl {
             MapTypes::LeftRange(m) => m.iter(),
             MapTypes::RightRange(m) => m.iter(),
+            MapTypes::Length(m, _) => m.iter(),
             MapTypes::Hash(m) => m.iter(),
+            MapTypes::PerfectHash(m) => m.iter(),
+            MapTypes::SimdProbe(m) => m.iter(),
         }
     }
 
     #[doc = include_str!("../doc_snippets/iter_mut.md")]
     #[must_use]
-    pub fn iter_mut(&mut self) -> IterMut<'_, Box<str>, V> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
         match &mut self.map_impl {
             MapTypes::LeftRange(m) => m.iter_mut(),
             MapTypes::RightRange(m) => m.iter_mut(),
+            MapTypes::Length(m, _) => m.iter_mut(),
             MapTypes::Hash(m) => m.iter_mut(),
+            MapTypes::PerfectHash(m) => m.iter_mut(),
+            MapTypes::SimdProbe(m) => m.iter_mut(),
         }
     }
 
     #[must_use]
-    fn into_iter(self) -> IntoIter<Box<str>, V> {
+    fn into_iter(self) -> IntoIter<K, V> {
         match self.map_impl {
             MapTypes::LeftRange(m) => m.into_iter(),
             MapTypes::RightRange(m) => m.into_iter(),
+            MapTypes::Length(m, _) => m.into_iter(),
             MapTypes::Hash(m) => m.into_iter(),
+            MapTypes::PerfectHash(m) => m.into_iter(),
+            MapTypes::SimdProbe(m) => m.into_iter(),
         }
     }
 
     #[doc = include_str!("../doc_snippets/keys.md")]
     #[must_use]
-    pub fn keys(&self) -> Keys<'_, Box<str>, V> {
+    pub fn keys(&self) -> Keys<'_, K, V> {
         match &self.map_impl {
             MapTypes::LeftRange(m) => m.keys(),
             MapTypes::RightRange(m) => m.keys(),
+            MapTypes::Length(m, _) => m.keys(),
             MapTypes::Hash(m) => m.keys(),
+            MapTypes::PerfectHash(m) => m.keys(),
+            MapTypes::SimdProbe(m) => m.keys(),
         }
     }
 
     #[doc = include_str!("../doc_snippets/into_keys.md")]
     #[must_use]
-    pub fn into_keys(self) -> IntoKeys<Box<str>, V> {
+    pub fn into_keys(self) -> IntoKeys<K, V> {
         match self.map_impl {
             MapTypes::LeftRange(m) => m.into_keys(),
             MapTypes::RightRange(m) => m.into_keys(),
+            MapTypes::Length(m, _) => m.into_keys(),
             MapTypes::Hash(m) => m.into_keys(),
+            MapTypes::PerfectHash(m) => m.into_keys(),
+            MapTypes::SimdProbe(m) => m.into_keys(),
         }
     }
 
     #[doc = include_str!("../doc_snippets/values.md")]
     #[must_use]
-    pub fn values(&self) -> Values<'_, Box<str>, V> {
+    pub fn values(&self) -> Values<'_, K, V> {
         match &self.map_impl {
             MapTypes::LeftRange(m) => m.values(),
             MapTypes::RightRange(m) => m.values(),
+            MapTypes::Length(m, _) => m.values(),
             MapTypes::Hash(m) => m.values(),
+            MapTypes::PerfectHash(m) => m.values(),
+            MapTypes::SimdProbe(m) => m.values(),
         }
     }
 
     #[doc = include_str!("../doc_snippets/values_mut.md")]
     #[must_use]
-    pub fn values_mut(&mut self) -> ValuesMut<'_, Box<str>, V> {
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
         match &mut self.map_impl {
             MapTypes::LeftRange(m) => m.values_mut(),
             MapTypes::RightRange(m) => m.values_mut(),
+            MapTypes::Length(m, _) => m.values_mut(),
             MapTypes::Hash(m) => m.values_mut(),
+            MapTypes::PerfectHash(m) => m.values_mut(),
+            MapTypes::SimdProbe(m) => m.values_mut(),
         }
     }
 
     #[doc = include_str!("../doc_snippets/into_values.md")]
     #[must_use]
-    pub fn into_values(self) -> IntoValues<Box<str>, V> {
+    pub fn into_values(self) -> IntoValues<K, V> {
         match self.map_impl {
             MapTypes::LeftRange(m) => m.into_values(),
             MapTypes::RightRange(m) => m.into_values(),
+            MapTypes::Length(m, _) => m.into_values(),
             MapTypes::Hash(m) => m.into_values(),
+            MapTypes::PerfectHash(m) => m.into_values(),
+            MapTypes::SimdProbe(m) => m.into_values(),
         }
     }
 }
 
-impl<V, BH> Default for FzStringMap<Box<str>, V, BH>
+impl<K, V, BH> Default for FzStringMap<K, V, BH>
 where
     BH: Default,
 {
     fn default() -> Self {
         Self {
             map_impl: MapTypes::Hash(HashMap::default()),
-            _0: PhantomData,
         }
     }
 }
 
-impl<K, V, const N: usize, BH> From<[(K, V); N]> for FzStringMap<Box<str>, V, BH>
+impl<K, V, Q, const N: usize, BH> From<[(Q, V); N]> for FzStringMap<K, V, BH>
 where
-    K: AsRef<str>,
+    K: StringKey,
+    Q: IntoStringKey<Key = K>,
     BH: BuildHasher + Default,
 {
-    fn from(entries: [(K, V); N]) -> Self {
+    fn from(entries: [(Q, V); N]) -> Self {
         Self::with_hasher(Vec::from(entries), BH::default())
     }
 }
 
-impl<K, V, BH> FromIterator<(K, V)> for FzStringMap<Box<str>, V, BH>
+impl<K, V, Q, BH> FromIterator<(Q, V)> for FzStringMap<K, V, BH>
 where
-    K: AsRef<str>,
+    K: StringKey,
+    Q: IntoStringKey<Key = K>,
     BH: BuildHasher + Default,
 {
-    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+    fn from_iter<T: IntoIterator<Item = (Q, V)>>(iter: T) -> Self {
         Self::with_hasher(iter.into_iter().collect(), BH::default())
     }
 }
 
-impl<V, Q, BH> Map<Box<str>, V, Q> for FzStringMap<Box<str>, V, BH>
+impl<K, V, Q, BH> Map<K, V, Q> for FzStringMap<K, V, BH>
 where
+    K: StringKey,
     Q: AsRef<str>,
     BH: BuildHasher,
 {
 }
 
-impl<V, Q, BH> MapExtras<Box<str>, V, Q> for FzStringMap<Box<str>, V, BH>
+impl<K, V, Q, BH> MapExtras<K, V, Q> for FzStringMap<K, V, BH>
 where
+    K: StringKey,
     Q: AsRef<str>,
     BH: BuildHasher,
 {
-    fn get_key_value(&self, key: &Q) -> Option<(&Box<str>, &V)> {
+    fn get_key_value(&self, key: &Q) -> Option<(&K, &V)> {
         self.get_key_value(key)
     }
 
@@ -347,47 +622,101 @@ where
     }
 }
 
-impl<V, Q, BH> MapQuery<Q, V> for FzStringMap<Box<str>, V, BH>
+impl<K, V, Q, BH> MapQuery<Q, V> for FzStringMap<K, V, BH>
 where
+    K: StringKey,
     Q: AsRef<str>,
     BH: BuildHasher,
 {
     map_query_trait_funcs!();
 }
 
-impl<V, BH> MapIteration<Box<str>, V> for FzStringMap<Box<str>, V, BH>
+impl<V, S, BH> Map<AsciiCaseInsensitive<Box<str>>, V, AsciiCaseInsensitive<S>> for FzStringMap<AsciiCaseInsensitive<Box<str>>, V, BH>
 where
+    S: AsRef<str>,
+    BH: BuildHasher,
+{
+}
+
+impl<V, S, BH> MapExtras<AsciiCaseInsensitive<Box<str>>, V, AsciiCaseInsensitive<S>> for FzStringMap<AsciiCaseInsensitive<Box<str>>, V, BH>
+where
+    S: AsRef<str>,
+    BH: BuildHasher,
+{
+    fn get_key_value(&self, key: &AsciiCaseInsensitive<S>) -> Option<(&AsciiCaseInsensitive<Box<str>>, &V)> {
+        self.get_key_value(key.as_str())
+    }
+
+    fn get_disjoint_mut<const N: usize>(&mut self, keys: [&AsciiCaseInsensitive<S>; N]) -> [Option<&mut V>; N] {
+        self.get_disjoint_mut(keys.map(AsciiCaseInsensitive::as_str))
+    }
+
+    unsafe fn get_disjoint_unchecked_mut<const N: usize>(&mut self, keys: [&AsciiCaseInsensitive<S>; N]) -> [Option<&mut V>; N] {
+        // SAFETY: The caller must ensure that the keys are disjoint.
+        unsafe { self.get_disjoint_unchecked_mut(keys.map(AsciiCaseInsensitive::as_str)) }
+    }
+}
+
+impl<V, S, BH> MapQuery<AsciiCaseInsensitive<S>, V> for FzStringMap<AsciiCaseInsensitive<Box<str>>, V, BH>
+where
+    S: AsRef<str>,
+    BH: BuildHasher,
+{
+    #[inline]
+    fn get(&self, key: &AsciiCaseInsensitive<S>) -> Option<&V> {
+        self.get(key.as_str())
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &AsciiCaseInsensitive<S>) -> Option<&mut V> {
+        self.get_mut(key.as_str())
+    }
+
+    #[inline]
+    fn contains_key(&self, key: &AsciiCaseInsensitive<S>) -> bool {
+        self.contains_key(key.as_str())
+    }
+}
+
+impl<K, V, BH> MapIteration<K, V> for FzStringMap<K, V, BH>
+where
+    K: StringKey,
     BH: BuildHasher,
 {
     type Iterator<'a>
-        = Iter<'a, Box<str>, V>
+        = Iter<'a, K, V>
     where
+        K: 'a,
         V: 'a,
         BH: 'a;
 
     type KeyIterator<'a>
-        = Keys<'a, Box<str>, V>
+        = Keys<'a, K, V>
     where
+        K: 'a,
         V: 'a,
         BH: 'a;
 
     type ValueIterator<'a>
-        = Values<'a, Box<str>, V>
+        = Values<'a, K, V>
     where
+        K: 'a,
         V: 'a,
         BH: 'a;
 
-    type IntoKeyIterator = IntoKeys<Box<str>, V>;
-    type IntoValueIterator = IntoValues<Box<str>, V>;
+    type IntoKeyIterator = IntoKeys<K, V>;
+    type IntoValueIterator = IntoValues<K, V>;
 
     type MutIterator<'a>
-        = IterMut<'a, Box<str>, V>
+        = IterMut<'a, K, V>
     where
+        K: 'a,
         V: 'a,
         BH: 'a;
     type ValueMutIterator<'a>
-        = ValuesMut<'a, Box<str>, V>
+        = ValuesMut<'a, K, V>
     where
+        K: 'a,
         V: 'a,
         BH: 'a;
 
@@ -420,79 +749,98 @@ where
     }
 }
 
-impl<V, BH> Len for FzStringMap<Box<str>, V, BH> {
+impl<K, V, BH> Len for FzStringMap<K, V, BH>
+where
+    K: StringKey,
+{
     len_trait_funcs!();
 }
 
-impl<V, Q, BH> Index<&Q> for FzStringMap<Box<str>, V, BH>
+impl<K, V, Q, BH> Index<&Q> for FzStringMap<K, V, BH>
 where
+    K: StringKey,
     Q: AsRef<str>,
     BH: BuildHasher,
 {
     index_trait_funcs!();
 }
 
-impl<V, BH> IntoIterator for FzStringMap<Box<str>, V, BH> {
-    type Item = (Box<str>, V);
-    type IntoIter = IntoIter<Box<str>, V>;
+impl<K, V, BH> IntoIterator for FzStringMap<K, V, BH>
+where
+    K: StringKey,
+{
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.into_iter()
     }
 }
 
-impl<'a, V, BH> IntoIterator for &'a FzStringMap<Box<str>, V, BH> {
-    type Item = (&'a Box<str>, &'a V);
-    type IntoIter = Iter<'a, Box<str>, V>;
+impl<'a, K, V, BH> IntoIterator for &'a FzStringMap<K, V, BH>
+where
+    K: StringKey,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-impl<'a, V, BH> IntoIterator for &'a mut FzStringMap<Box<str>, V, BH> {
-    type Item = (&'a Box<str>, &'a mut V);
-    type IntoIter = IterMut<'a, Box<str>, V>;
+impl<'a, K, V, BH> IntoIterator for &'a mut FzStringMap<K, V, BH>
+where
+    K: StringKey,
+{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter_mut()
     }
 }
 
-impl<V, MT, BH> PartialEq<MT> for FzStringMap<Box<str>, V, BH>
+impl<K, V, MT, BH> PartialEq<MT> for FzStringMap<K, V, BH>
 where
+    K: StringKey,
     V: PartialEq,
-    MT: MapQuery<Box<str>, V>,
+    MT: MapQuery<K, V>,
     BH: BuildHasher,
 {
     partial_eq_trait_funcs!();
 }
 
-impl<V, BH> Eq for FzStringMap<Box<str>, V, BH>
+impl<K, V, BH> Eq for FzStringMap<K, V, BH>
 where
+    K: StringKey,
     V: Eq,
     BH: BuildHasher,
+    Self: MapQuery<K, V>,
 {
 }
 
-impl<V, BH> Debug for FzStringMap<Box<str>, V, BH>
+impl<K, V, BH> Debug for FzStringMap<K, V, BH>
 where
+    K: StringKey + Debug,
     V: Debug,
 {
     debug_trait_funcs!();
 }
 
 #[cfg(feature = "serde")]
-impl<V, BH> Serialize for FzStringMap<Box<str>, V, BH>
+impl<K, V, BH> Serialize for FzStringMap<K, V, BH>
 where
+    K: StringKey + Serialize,
     V: Serialize,
 {
     serialize_trait_funcs!();
 }
 
 #[cfg(feature = "serde")]
-impl<'de, V> Deserialize<'de> for FzStringMap<Box<str>, V>
+impl<'de, K, V> Deserialize<'de> for FzStringMap<K, V>
 where
+    K: StringKey,
     V: Deserialize<'de>,
 {
     fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
@@ -504,17 +852,18 @@ where
 }
 
 #[cfg(feature = "serde")]
-struct StrMapVisitor<V, BH> {
-    marker: PhantomData<(V, BH)>,
+struct StrMapVisitor<K, V, BH> {
+    marker: PhantomData<(K, V, BH)>,
 }
 
 #[cfg(feature = "serde")]
-impl<'de, V, BH> Visitor<'de> for StrMapVisitor<V, BH>
+impl<'de, K, V, BH> Visitor<'de> for StrMapVisitor<K, V, BH>
 where
+    K: StringKey,
     V: Deserialize<'de>,
     BH: BuildHasher + Default,
 {
-    type Value = FzStringMap<Box<str>, V, BH>;
+    type Value = FzStringMap<K, V, BH>;
 
     fn expecting(&self, formatter: &mut Formatter) -> Result {
         formatter.write_str("a map with string keys")
@@ -524,11 +873,11 @@ where
     where
         M: MapAccess<'de>,
     {
-        let mut v: Vec<(&'de str, _)> = Vec::with_capacity(map.size_hint().unwrap_or(0));
-        while let Some(x) = map.next_entry()? {
-            v.push(x);
+        let mut v = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some((key, value)) = map.next_entry::<&'de str, V>()? {
+            v.push((K::from_str(key), value));
         }
 
-        Ok(FzStringMap::with_hasher(v, BH::default()))
+        Ok(FzStringMap::from_keys(v, BH::default()))
     }
 }
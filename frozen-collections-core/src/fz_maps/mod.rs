@@ -1,11 +1,17 @@
 //! Wrappers around other map types allowing runtime selection of implementation types based on input.
 
+pub use fz_bytes_map::FzBytesMap;
+pub use fz_enum_map::FzEnumMap;
 pub use fz_hash_map::FzHashMap;
 pub use fz_ordered_map::FzOrderedMap;
+pub use fz_prefix_map::FzPrefixMap;
 pub use fz_scalar_map::FzScalarMap;
 pub use fz_string_map::FzStringMap;
 
+mod fz_bytes_map;
+mod fz_enum_map;
 mod fz_hash_map;
 mod fz_ordered_map;
+mod fz_prefix_map;
 mod fz_scalar_map;
 mod fz_string_map;
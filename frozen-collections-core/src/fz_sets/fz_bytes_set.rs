@@ -0,0 +1,327 @@
+use crate::DefaultBuildHasher;
+use crate::columns::KeyIndex;
+use crate::diagnostics::Diagnostics;
+use crate::fz_maps::FzBytesMap;
+use crate::maps::decl_macros::len_trait_funcs;
+use crate::sets::decl_macros::{debug_trait_funcs, partial_eq_trait_funcs};
+use crate::sets::{IntoIter, Iter};
+use crate::traits::{Len, Set, SetExtras, SetIteration, SetOps, SetQuery};
+use crate::utils::assign_slots;
+use core::fmt::Debug;
+use core::hash::BuildHasher;
+use core::ops::{BitAnd, BitOr, BitXor, Sub};
+
+#[cfg(not(feature = "std"))]
+use {alloc::boxed::Box, alloc::vec::Vec};
+
+#[cfg(feature = "serde")]
+use {
+    crate::sets::decl_macros::serialize_trait_funcs,
+    core::fmt::Formatter,
+    core::marker::PhantomData,
+    serde::de::{SeqAccess, Visitor},
+    serde::ser::SerializeSeq,
+    serde::{Deserialize, Deserializer, Serialize, Serializer},
+};
+
+/// A set optimized for fast read access with byte slice values.
+///
+#[doc = include_str!("../doc_snippets/about.md")]
+#[doc = include_str!("../doc_snippets/hash_warning.md")]
+///
+/// # Alternate Choices
+///
+/// If your values are known at compile time, consider using the various `fz_*_set` macros instead of
+/// this type as they generally perform better.
+#[derive(Clone)]
+pub struct FzBytesSet<K, BH = DefaultBuildHasher> {
+    map: FzBytesMap<K, (), BH>,
+}
+
+impl FzBytesSet<Box<[u8]>, DefaultBuildHasher> {
+    /// Creates a new frozen set.
+    #[must_use]
+    pub fn new(entries: Vec<impl AsRef<[u8]>>) -> Self {
+        Self::with_hasher(entries, DefaultBuildHasher::default())
+    }
+}
+
+impl<BH> FzBytesSet<Box<[u8]>, BH> {
+    /// Creates a new frozen set which uses the given hash builder to hash values.
+    #[must_use]
+    pub fn with_hasher(entries: Vec<impl AsRef<[u8]>>, bh: BH) -> Self
+    where
+        BH: BuildHasher,
+    {
+        Self {
+            map: FzBytesMap::with_hasher(entries.into_iter().map(|x| (x, ())).collect(), bh),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/get_from_set.md")]
+    #[inline]
+    #[expect(clippy::borrowed_box, reason = "By design")]
+    pub fn get(&self, value: impl AsRef<[u8]>) -> Option<&Box<[u8]>>
+    where
+        BH: BuildHasher,
+    {
+        Some(self.map.get_key_value(value)?.0)
+    }
+
+    #[doc = include_str!("../doc_snippets/contains.md")]
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, value: impl AsRef<[u8]>) -> bool
+    where
+        BH: BuildHasher,
+    {
+        self.map.contains_key(value)
+    }
+
+    #[doc = include_str!("../doc_snippets/len.md")]
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[doc = include_str!("../doc_snippets/is_empty.md")]
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    #[doc = include_str!("../doc_snippets/diagnostics.md")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics
+    where
+        BH: BuildHasher,
+    {
+        self.map.diagnostics()
+    }
+
+    #[doc = include_str!("../doc_snippets/iter.md")]
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, Box<[u8]>> {
+        Iter::new(self.map.iter())
+    }
+
+    fn into_iter(self) -> IntoIter<Box<[u8]>> {
+        IntoIter::new(self.map.into_iter())
+    }
+}
+
+impl<BH> Default for FzBytesSet<Box<[u8]>, BH>
+where
+    BH: Default,
+{
+    fn default() -> Self {
+        Self {
+            map: FzBytesMap::default(),
+        }
+    }
+}
+
+impl<BH> From<FzBytesMap<Box<[u8]>, (), BH>> for FzBytesSet<Box<[u8]>, BH> {
+    fn from(map: FzBytesMap<Box<[u8]>, (), BH>) -> Self {
+        Self { map }
+    }
+}
+
+impl<BH> From<FzBytesSet<Box<[u8]>, BH>> for KeyIndex<FzBytesMap<Box<[u8]>, usize, BH>> {
+    fn from(set: FzBytesSet<Box<[u8]>, BH>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
+}
+
+impl<T, const N: usize, BH> From<[T; N]> for FzBytesSet<Box<[u8]>, BH>
+where
+    T: AsRef<[u8]>,
+    BH: BuildHasher + Default,
+{
+    fn from(entries: [T; N]) -> Self {
+        Self::from(FzBytesMap::from_iter(entries.into_iter().map(|x| (x, ()))))
+    }
+}
+
+impl<T, BH> FromIterator<T> for FzBytesSet<Box<[u8]>, BH>
+where
+    T: AsRef<[u8]>,
+    BH: BuildHasher + Default,
+{
+    fn from_iter<IT: IntoIterator<Item = T>>(iter: IT) -> Self {
+        Self::from(FzBytesMap::from_iter(iter.into_iter().map(|x| (x, ()))))
+    }
+}
+
+impl<Q, BH> Set<Box<[u8]>, Q> for FzBytesSet<Box<[u8]>, BH>
+where
+    Q: AsRef<[u8]>,
+    BH: BuildHasher,
+{
+}
+
+impl<Q, BH> SetExtras<Box<[u8]>, Q> for FzBytesSet<Box<[u8]>, BH>
+where
+    Q: AsRef<[u8]>,
+    BH: BuildHasher,
+{
+    #[inline]
+    fn get(&self, value: &Q) -> Option<&Box<[u8]>> {
+        self.get(value)
+    }
+}
+
+impl<Q, BH> SetQuery<Q> for FzBytesSet<Box<[u8]>, BH>
+where
+    Q: AsRef<[u8]>,
+    BH: BuildHasher,
+{
+    #[inline]
+    fn contains(&self, value: &Q) -> bool {
+        self.contains(value)
+    }
+}
+
+impl<BH> SetIteration<Box<[u8]>> for FzBytesSet<Box<[u8]>, BH> {
+    type Iterator<'a>
+        = Iter<'a, Box<[u8]>>
+    where
+        BH: 'a;
+
+    fn iter(&self) -> Iter<'_, Box<[u8]>> {
+        self.iter()
+    }
+}
+
+impl<BH> Len for FzBytesSet<Box<[u8]>, BH> {
+    len_trait_funcs!();
+}
+
+impl<ST, BH> BitOr<&ST> for &FzBytesSet<Box<[u8]>, BH>
+where
+    ST: Set<Box<[u8]>>,
+    BH: BuildHasher + Default,
+{
+    type Output = hashbrown::HashSet<Box<[u8]>>;
+
+    fn bitor(self, rhs: &ST) -> Self::Output {
+        Self::Output::from_iter(self.union(rhs).cloned())
+    }
+}
+
+impl<ST, BH> BitAnd<&ST> for &FzBytesSet<Box<[u8]>, BH>
+where
+    ST: Set<Box<[u8]>>,
+    BH: BuildHasher + Default,
+{
+    type Output = hashbrown::HashSet<Box<[u8]>>;
+
+    fn bitand(self, rhs: &ST) -> Self::Output {
+        Self::Output::from_iter(self.intersection(rhs).cloned())
+    }
+}
+
+impl<ST, BH> BitXor<&ST> for &FzBytesSet<Box<[u8]>, BH>
+where
+    ST: Set<Box<[u8]>>,
+    BH: BuildHasher + Default,
+{
+    type Output = hashbrown::HashSet<Box<[u8]>>;
+
+    fn bitxor(self, rhs: &ST) -> Self::Output {
+        self.symmetric_difference(rhs).cloned().collect()
+    }
+}
+
+impl<ST, BH> Sub<&ST> for &FzBytesSet<Box<[u8]>, BH>
+where
+    ST: Set<Box<[u8]>>,
+    BH: BuildHasher + Default,
+{
+    type Output = hashbrown::HashSet<Box<[u8]>>;
+
+    fn sub(self, rhs: &ST) -> Self::Output {
+        self.difference(rhs).cloned().collect()
+    }
+}
+
+impl<BH> IntoIterator for FzBytesSet<Box<[u8]>, BH> {
+    type Item = Box<[u8]>;
+    type IntoIter = IntoIter<Box<[u8]>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+impl<'a, BH> IntoIterator for &'a FzBytesSet<Box<[u8]>, BH> {
+    type Item = &'a Box<[u8]>;
+    type IntoIter = Iter<'a, Box<[u8]>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<ST, BH> PartialEq<ST> for FzBytesSet<Box<[u8]>, BH>
+where
+    ST: SetQuery<Box<[u8]>>,
+    BH: BuildHasher + Default,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<BH> Eq for FzBytesSet<Box<[u8]>, BH> where BH: BuildHasher + Default {}
+
+impl<BH> Debug for FzBytesSet<Box<[u8]>, BH> {
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for FzBytesSet<Box<[u8]>> {
+    serialize_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<'de, BH> Deserialize<'de> for FzBytesSet<Box<[u8]>, BH>
+where
+    BH: BuildHasher + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(BytesSetVisitor { marker: PhantomData })
+    }
+}
+
+#[cfg(feature = "serde")]
+struct BytesSetVisitor<BH> {
+    marker: PhantomData<BH>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, BH> Visitor<'de> for BytesSetVisitor<BH>
+where
+    BH: BuildHasher + Default,
+{
+    type Value = FzBytesSet<Box<[u8]>, BH>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("a set with byte slice values")
+    }
+
+    fn visit_seq<M>(self, mut seq: M) -> Result<Self::Value, M::Error>
+    where
+        M: SeqAccess<'de>,
+    {
+        let mut v: Vec<(Vec<u8>, ())> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(x) = seq.next_element()? {
+            v.push((x, ()));
+        }
+
+        Ok(FzBytesSet::from(FzBytesMap::with_hasher(v, BH::default())))
+    }
+}
@@ -1,12 +1,15 @@
 use crate::DefaultBuildHasher;
+use crate::columns::KeyIndex;
+use crate::diagnostics::Diagnostics;
 use crate::fz_maps::FzHashMap;
 use crate::maps::decl_macros::len_trait_funcs;
 use crate::sets::decl_macros::{
     bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, debug_trait_funcs, into_iterator_ref_trait_funcs, into_iterator_trait_funcs,
-    partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs, set_query_trait_funcs, sub_trait_funcs,
+    materialize_funcs, partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs, set_query_trait_funcs, sub_trait_funcs,
 };
 use crate::sets::{IntoIter, Iter};
 use crate::traits::{Len, Set, SetExtras, SetIteration, SetOps, SetQuery};
+use crate::utils::{DeduppedVec, SetOp, assign_slots};
 use core::fmt::Debug;
 use core::hash::BuildHasher;
 use core::hash::Hash;
@@ -89,6 +92,34 @@ where
         self.map.contains_key(value)
     }
 
+    #[doc = include_str!("../doc_snippets/contains_many_from_set.md")]
+    pub fn contains_many<Q>(&self, values: &[&Q], results: &mut [bool])
+    where
+        Q: ?Sized + Hash + Equivalent<T>,
+    {
+        self.map.contains_many(values, results);
+    }
+
+    #[doc = include_str!("../doc_snippets/hash_key.md")]
+    #[inline]
+    #[must_use]
+    pub fn hash_key<Q>(&self, value: &Q) -> u64
+    where
+        Q: ?Sized + Hash,
+    {
+        self.map.hash_key(value)
+    }
+
+    #[doc = include_str!("../doc_snippets/contains_with_hash_from_set.md")]
+    #[inline]
+    #[must_use]
+    pub fn contains_with_hash<Q>(&self, hash_code: u64, value: &Q) -> bool
+    where
+        Q: ?Sized + Equivalent<T>,
+    {
+        self.map.contains_with_hash(hash_code, value)
+    }
+
     #[doc = include_str!("../doc_snippets/len.md")]
     #[inline]
     #[must_use]
@@ -103,6 +134,15 @@ where
         self.map.is_empty()
     }
 
+    #[doc = include_str!("../doc_snippets/diagnostics.md")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics
+    where
+        T: Hash,
+    {
+        self.map.diagnostics()
+    }
+
     #[doc = include_str!("../doc_snippets/iter.md")]
     #[must_use]
     pub fn iter(&self) -> Iter<'_, T> {
@@ -115,6 +155,27 @@ where
     }
 }
 
+impl<T, BH> FzHashSet<T, BH>
+where
+    T: Hash + Eq + Clone,
+    BH: BuildHasher + Clone,
+{
+    materialize_funcs!();
+
+    fn combine(&self, other: &Self, op: SetOp) -> Self {
+        let entries: Vec<_> = match op {
+            SetOp::Union => self.union(other).map(|x| (x.clone(), ())).collect(),
+            SetOp::Intersection => self.intersection(other).map(|x| (x.clone(), ())).collect(),
+            SetOp::Difference => self.difference(other).map(|x| (x.clone(), ())).collect(),
+            SetOp::SymmetricDifference => self.symmetric_difference(other).map(|x| (x.clone(), ())).collect(),
+        };
+
+        Self {
+            map: FzHashMap::from_dedupped(DeduppedVec::from_unique(entries), self.map.build_hasher().clone()),
+        }
+    }
+}
+
 impl<T, BH> Default for FzHashSet<T, BH>
 where
     BH: Default,
@@ -134,6 +195,15 @@ where
     }
 }
 
+impl<T, BH> From<FzHashSet<T, BH>> for KeyIndex<FzHashMap<T, usize, BH>>
+where
+    BH: BuildHasher,
+{
+    fn from(set: FzHashSet<T, BH>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
+}
+
 impl<T, const N: usize, BH> From<[T; N]> for FzHashSet<T, BH>
 where
     T: Hash + Eq,
@@ -1,14 +1,18 @@
+use crate::columns::KeyIndex;
+use crate::diagnostics::Diagnostics;
 use crate::fz_maps::FzOrderedMap;
 use crate::maps::decl_macros::len_trait_funcs;
 use crate::sets::decl_macros::{
     bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, debug_trait_funcs, into_iterator_ref_trait_funcs, into_iterator_trait_funcs,
-    partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs, set_query_trait_funcs, sub_trait_funcs,
+    materialize_funcs, ordered_primary_funcs, partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs,
+    set_query_trait_funcs, sorted_query_funcs, sub_trait_funcs,
 };
-use crate::sets::{IntoIter, Iter};
+use crate::sets::{IntoIter, Iter, Range};
 use crate::traits::{Len, Set, SetExtras, SetIteration, SetOps, SetQuery};
+use crate::utils::{SetOp, SortedAndDeduppedVec, assign_slots, merge_sorted};
 use core::fmt::Debug;
 use core::hash::Hash;
-use core::ops::{BitAnd, BitOr, BitXor, Sub};
+use core::ops::{BitAnd, BitOr, BitXor, RangeBounds, Sub};
 use equivalent::Comparable;
 
 #[cfg(not(feature = "std"))]
@@ -87,6 +91,12 @@ impl<T> FzOrderedSet<T> {
         self.map.is_empty()
     }
 
+    #[doc = include_str!("../doc_snippets/diagnostics.md")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.map.diagnostics()
+    }
+
     #[doc = include_str!("../doc_snippets/iter.md")]
     #[must_use]
     pub fn iter(&self) -> Iter<'_, T> {
@@ -97,6 +107,27 @@ impl<T> FzOrderedSet<T> {
     fn into_iter(self) -> IntoIter<T> {
         IntoIter::new(self.map.into_iter())
     }
+
+    ordered_primary_funcs!();
+    sorted_query_funcs!("Ord");
+}
+
+impl<T> FzOrderedSet<T>
+where
+    T: Ord + Clone,
+{
+    materialize_funcs!();
+
+    fn combine(&self, other: &Self, op: SetOp) -> Self {
+        let entries = merge_sorted(self.iter_sorted(), other.iter_sorted(), op)
+            .into_iter()
+            .map(|x| (x, ()))
+            .collect();
+
+        Self {
+            map: FzOrderedMap::from_sorted_and_dedupped(SortedAndDeduppedVec::from_sorted_unique(entries, |x, y| x.0.cmp(&y.0))),
+        }
+    }
 }
 
 impl<T> Default for FzOrderedSet<T> {
@@ -116,6 +147,12 @@ where
     }
 }
 
+impl<T> From<FzOrderedSet<T>> for KeyIndex<FzOrderedMap<T, usize>> {
+    fn from(set: FzOrderedSet<T>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
+}
+
 impl<T, const N: usize> From<[T; N]> for FzOrderedSet<T>
 where
     T: Ord,
@@ -1,11 +1,15 @@
+use crate::columns::KeyIndex;
+use crate::diagnostics::Diagnostics;
 use crate::fz_maps::FzScalarMap;
 use crate::maps::decl_macros::len_trait_funcs;
 use crate::sets::decl_macros::{
     bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, debug_trait_funcs, into_iterator_ref_trait_funcs, into_iterator_trait_funcs,
-    partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs, set_query_trait_funcs, sub_trait_funcs,
+    materialize_funcs, partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs, set_query_trait_funcs,
+    sorted_query_funcs, sub_trait_funcs,
 };
-use crate::sets::{IntoIter, Iter};
+use crate::sets::{BitsetView, IntoIter, Iter};
 use crate::traits::{Len, Scalar, Set, SetExtras, SetIteration, SetOps, SetQuery};
+use crate::utils::{SetOp, SortedAndDeduppedVec, assign_slots, merge_sorted};
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::ops::{BitAnd, BitOr, BitXor, Sub};
@@ -28,6 +32,20 @@ use {
 ///
 #[doc = include_str!("../doc_snippets/about.md")]
 ///
+/// # Bitsets
+///
+/// When the values are clustered closely enough, they are tracked in a bitset. Set operations such as
+/// [`union`](SetOps::union) and [`intersection`](SetOps::intersection) between two sets stored this way,
+/// or whose values form a continuous range, are computed a word at a time.
+///
+/// # Order-Based Lookups
+///
+/// The [`rank`](Self::rank), [`select`](Self::select), [`floor`](Self::floor), [`ceiling`](Self::ceiling),
+/// [`predecessor`](Self::predecessor), and [`successor`](Self::successor) functions are fast when the
+/// values are clustered closely enough to be served by a lookup table. When the values are widely spread out and
+/// the set resorts to hashing, the set also records the sorted order of its values when it is created, so these
+/// functions perform a binary search, at the cost of one extra `usize` per value.
+///
 /// # Alternate Choices
 ///
 /// If your values are known at compile time, consider using the various `fz_*_set` macros instead of
@@ -82,6 +100,15 @@ impl<T> FzScalarSet<T> {
         self.map.is_empty()
     }
 
+    #[doc = include_str!("../doc_snippets/diagnostics.md")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics
+    where
+        T: Scalar,
+    {
+        self.map.diagnostics()
+    }
+
     #[doc = include_str!("../doc_snippets/iter.md")]
     #[must_use]
     pub fn iter(&self) -> Iter<'_, T> {
@@ -91,6 +118,31 @@ impl<T> FzScalarSet<T> {
     fn into_iter(self) -> IntoIter<T> {
         IntoIter::new(self.map.into_iter())
     }
+
+    sorted_query_funcs!("Scalar");
+}
+
+impl<T> FzScalarSet<T>
+where
+    T: Scalar,
+{
+    materialize_funcs!();
+
+    fn combine(&self, other: &Self, op: SetOp) -> Self {
+        let entries = merge_sorted(self.iter_sorted(), other.iter_sorted(), op)
+            .into_iter()
+            .map(|x| (x, ()))
+            .collect();
+
+        Self {
+            map: FzScalarMap::from_sorted_and_dedupped(SortedAndDeduppedVec::from_sorted_unique(entries, |x, y| x.0.cmp(&y.0))),
+        }
+    }
+
+    fn iter_sorted(&self) -> impl Iterator<Item = &T> {
+        // hashed values are visited through the sorted order recorded when the set was created
+        (0..self.len()).filter_map(|index| self.select(index))
+    }
 }
 
 impl<T> Default for FzScalarSet<T> {
@@ -110,6 +162,12 @@ where
     }
 }
 
+impl<T> From<FzScalarSet<T>> for KeyIndex<FzScalarMap<T, usize>> {
+    fn from(set: FzScalarSet<T>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
+}
+
 impl<T, const N: usize> From<[T; N]> for FzScalarSet<T>
 where
     T: Scalar,
@@ -151,6 +209,10 @@ impl<T> SetIteration<T> for FzScalarSet<T> {
         T: 'a;
 
     set_iteration_trait_funcs!();
+
+    fn bitset(&self) -> Option<BitsetView<'_, T>> {
+        self.map.bitset()
+    }
 }
 
 impl<T> Len for FzScalarSet<T> {
@@ -1,9 +1,13 @@
 use crate::DefaultBuildHasher;
+use crate::columns::KeyIndex;
+use crate::diagnostics::Diagnostics;
 use crate::fz_maps::FzStringMap;
+use crate::keys::AsciiCaseInsensitive;
 use crate::maps::decl_macros::len_trait_funcs;
-use crate::sets::decl_macros::{debug_trait_funcs, partial_eq_trait_funcs};
+use crate::sets::decl_macros::{debug_trait_funcs, materialize_funcs, partial_eq_trait_funcs};
 use crate::sets::{IntoIter, Iter};
-use crate::traits::{Len, Set, SetExtras, SetIteration, SetOps, SetQuery};
+use crate::traits::{IntoStringKey, Len, Set, SetExtras, SetIteration, SetOps, SetQuery, StringKey};
+use crate::utils::{SetOp, assign_slots};
 use core::fmt::Debug;
 use core::hash::BuildHasher;
 use core::ops::{BitAnd, BitOr, BitXor, Sub};
@@ -23,6 +27,11 @@ use {
 
 /// A set optimized for fast read access with string values.
 ///
+/// The value type decides how values are compared. A set of `Box<str>` values compares them as-is,
+/// while a set of [`AsciiCaseInsensitive<Box<str>>`](AsciiCaseInsensitive) values ignores the case of
+/// ASCII letters. The value type is inferred from the strings the set is created with, and either
+/// kind of set is queried with plain strings.
+///
 #[doc = include_str!("../doc_snippets/about.md")]
 #[doc = include_str!("../doc_snippets/hash_warning.md")]
 ///
@@ -35,18 +44,24 @@ pub struct FzStringSet<K, BH = DefaultBuildHasher> {
     map: FzStringMap<K, (), BH>,
 }
 
-impl FzStringSet<Box<str>, DefaultBuildHasher> {
+impl<K> FzStringSet<K, DefaultBuildHasher>
+where
+    K: StringKey,
+{
     /// Creates a new frozen set.
     #[must_use]
-    pub fn new(entries: Vec<impl AsRef<str>>) -> Self {
+    pub fn new(entries: Vec<impl IntoStringKey<Key = K>>) -> Self {
         Self::with_hasher(entries, DefaultBuildHasher::default())
     }
 }
 
-impl<BH> FzStringSet<Box<str>, BH> {
+impl<K, BH> FzStringSet<K, BH>
+where
+    K: StringKey,
+{
     /// Creates a new frozen set which uses the given hash builder to hash values.
     #[must_use]
-    pub fn with_hasher(entries: Vec<impl AsRef<str>>, bh: BH) -> Self
+    pub fn with_hasher(entries: Vec<impl IntoStringKey<Key = K>>, bh: BH) -> Self
     where
         BH: BuildHasher,
     {
@@ -57,8 +72,7 @@ impl<BH> FzStringSet<Box<str>, BH> {
 
     #[doc = include_str!("../doc_snippets/get_from_set.md")]
     #[inline]
-    #[expect(clippy::borrowed_box, reason = "By design")]
-    pub fn get(&self, value: impl AsRef<str>) -> Option<&Box<str>>
+    pub fn get(&self, value: impl AsRef<str>) -> Option<&K>
     where
         BH: BuildHasher,
     {
@@ -75,6 +89,37 @@ impl<BH> FzStringSet<Box<str>, BH> {
         self.map.contains_key(value)
     }
 
+    #[doc = include_str!("../doc_snippets/contains_many_from_set.md")]
+    pub fn contains_many(&self, values: &[impl AsRef<str>], results: &mut [bool])
+    where
+        BH: BuildHasher,
+    {
+        self.map.contains_many(values, results);
+    }
+
+    #[doc = include_str!("../doc_snippets/hash_key.md")]
+    ///
+    /// The hash code only depends on the hash builder, and not on the values the set holds, so it can be shared between
+    /// string maps and sets whose hash builders hash values the same way.
+    #[inline]
+    #[must_use]
+    pub fn hash_key(&self, value: impl AsRef<str>) -> u64
+    where
+        BH: BuildHasher,
+    {
+        self.map.hash_key(value)
+    }
+
+    #[doc = include_str!("../doc_snippets/contains_with_hash_from_set.md")]
+    #[inline]
+    #[must_use]
+    pub fn contains_with_hash(&self, hash_code: u64, value: impl AsRef<str>) -> bool
+    where
+        BH: BuildHasher,
+    {
+        self.map.contains_with_hash(hash_code, value)
+    }
+
     #[doc = include_str!("../doc_snippets/len.md")]
     #[inline]
     #[must_use]
@@ -89,18 +134,47 @@ impl<BH> FzStringSet<Box<str>, BH> {
         self.map.is_empty()
     }
 
+    #[doc = include_str!("../doc_snippets/diagnostics.md")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics
+    where
+        BH: BuildHasher,
+    {
+        self.map.diagnostics()
+    }
+
     #[doc = include_str!("../doc_snippets/iter.md")]
     #[must_use]
-    pub fn iter(&self) -> Iter<'_, Box<str>> {
+    pub fn iter(&self) -> Iter<'_, K> {
         Iter::new(self.map.iter())
     }
 
-    fn into_iter(self) -> IntoIter<Box<str>> {
+    fn into_iter(self) -> IntoIter<K> {
         IntoIter::new(self.map.into_iter())
     }
 }
 
-impl<BH> Default for FzStringSet<Box<str>, BH>
+impl<K, BH> FzStringSet<K, BH>
+where
+    K: StringKey,
+    BH: BuildHasher + Clone,
+    Self: Set<K>,
+{
+    materialize_funcs!();
+
+    fn combine(&self, other: &Self, op: SetOp) -> Self {
+        let values: Vec<_> = match op {
+            SetOp::Union => self.union(other).map(|x| (x.clone(), ())).collect(),
+            SetOp::Intersection => self.intersection(other).map(|x| (x.clone(), ())).collect(),
+            SetOp::Difference => self.difference(other).map(|x| (x.clone(), ())).collect(),
+            SetOp::SymmetricDifference => self.symmetric_difference(other).map(|x| (x.clone(), ())).collect(),
+        };
+
+        Self::from(FzStringMap::from_keys(values, self.map.build_hasher().clone()))
+    }
+}
+
+impl<K, BH> Default for FzStringSet<K, BH>
 where
     BH: Default,
 {
@@ -111,15 +185,25 @@ where
     }
 }
 
-impl<BH> From<FzStringMap<Box<str>, (), BH>> for FzStringSet<Box<str>, BH> {
-    fn from(map: FzStringMap<Box<str>, (), BH>) -> Self {
+impl<K, BH> From<FzStringMap<K, (), BH>> for FzStringSet<K, BH> {
+    fn from(map: FzStringMap<K, (), BH>) -> Self {
         Self { map }
     }
 }
 
-impl<T, const N: usize, BH> From<[T; N]> for FzStringSet<Box<str>, BH>
+impl<K, BH> From<FzStringSet<K, BH>> for KeyIndex<FzStringMap<K, usize, BH>>
 where
-    T: AsRef<str>,
+    K: StringKey,
+{
+    fn from(set: FzStringSet<K, BH>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
+}
+
+impl<K, T, const N: usize, BH> From<[T; N]> for FzStringSet<K, BH>
+where
+    K: StringKey,
+    T: IntoStringKey<Key = K>,
     BH: BuildHasher + Default,
 {
     fn from(entries: [T; N]) -> Self {
@@ -127,9 +211,10 @@ where
     }
 }
 
-impl<T, BH> FromIterator<T> for FzStringSet<Box<str>, BH>
+impl<K, T, BH> FromIterator<T> for FzStringSet<K, BH>
 where
-    T: AsRef<str>,
+    K: StringKey,
+    T: IntoStringKey<Key = K>,
     BH: BuildHasher + Default,
 {
     fn from_iter<IT: IntoIterator<Item = T>>(iter: IT) -> Self {
@@ -137,26 +222,29 @@ where
     }
 }
 
-impl<Q, BH> Set<Box<str>, Q> for FzStringSet<Box<str>, BH>
+impl<K, Q, BH> Set<K, Q> for FzStringSet<K, BH>
 where
+    K: StringKey,
     Q: AsRef<str>,
     BH: BuildHasher,
 {
 }
 
-impl<Q, BH> SetExtras<Box<str>, Q> for FzStringSet<Box<str>, BH>
+impl<K, Q, BH> SetExtras<K, Q> for FzStringSet<K, BH>
 where
+    K: StringKey,
     Q: AsRef<str>,
     BH: BuildHasher,
 {
     #[inline]
-    fn get(&self, value: &Q) -> Option<&Box<str>> {
+    fn get(&self, value: &Q) -> Option<&K> {
         self.get(value)
     }
 }
 
-impl<Q, BH> SetQuery<Q> for FzStringSet<Box<str>, BH>
+impl<K, Q, BH> SetQuery<Q> for FzStringSet<K, BH>
 where
+    K: StringKey,
     Q: AsRef<str>,
     BH: BuildHasher,
 {
@@ -166,109 +254,173 @@ where
     }
 }
 
-impl<BH> SetIteration<Box<str>> for FzStringSet<Box<str>, BH> {
+impl<S, BH> Set<AsciiCaseInsensitive<Box<str>>, AsciiCaseInsensitive<S>> for FzStringSet<AsciiCaseInsensitive<Box<str>>, BH>
+where
+    S: AsRef<str>,
+    BH: BuildHasher,
+{
+}
+
+impl<S, BH> SetExtras<AsciiCaseInsensitive<Box<str>>, AsciiCaseInsensitive<S>> for FzStringSet<AsciiCaseInsensitive<Box<str>>, BH>
+where
+    S: AsRef<str>,
+    BH: BuildHasher,
+{
+    #[inline]
+    fn get(&self, value: &AsciiCaseInsensitive<S>) -> Option<&AsciiCaseInsensitive<Box<str>>> {
+        self.get(value.as_str())
+    }
+}
+
+impl<S, BH> SetQuery<AsciiCaseInsensitive<S>> for FzStringSet<AsciiCaseInsensitive<Box<str>>, BH>
+where
+    S: AsRef<str>,
+    BH: BuildHasher,
+{
+    #[inline]
+    fn contains(&self, value: &AsciiCaseInsensitive<S>) -> bool {
+        self.contains(value.as_str())
+    }
+}
+
+impl<K, BH> SetIteration<K> for FzStringSet<K, BH>
+where
+    K: StringKey,
+{
     type Iterator<'a>
-        = Iter<'a, Box<str>>
+        = Iter<'a, K>
     where
+        K: 'a,
         BH: 'a;
 
-    fn iter(&self) -> Iter<'_, Box<str>> {
+    fn iter(&self) -> Iter<'_, K> {
         self.iter()
     }
 }
 
-impl<BH> Len for FzStringSet<Box<str>, BH> {
+impl<K, BH> Len for FzStringSet<K, BH>
+where
+    K: StringKey,
+{
     len_trait_funcs!();
 }
 
-impl<ST, BH> BitOr<&ST> for &FzStringSet<Box<str>, BH>
+impl<K, ST, BH> BitOr<&ST> for &FzStringSet<K, BH>
 where
-    ST: Set<Box<str>>,
+    K: StringKey,
+    ST: Set<K>,
     BH: BuildHasher + Default,
+    FzStringSet<K, BH>: Set<K>,
 {
-    type Output = hashbrown::HashSet<Box<str>>;
+    type Output = hashbrown::HashSet<K>;
 
     fn bitor(self, rhs: &ST) -> Self::Output {
         Self::Output::from_iter(self.union(rhs).cloned())
     }
 }
 
-impl<ST, BH> BitAnd<&ST> for &FzStringSet<Box<str>, BH>
+impl<K, ST, BH> BitAnd<&ST> for &FzStringSet<K, BH>
 where
-    ST: Set<Box<str>>,
+    K: StringKey,
+    ST: Set<K>,
     BH: BuildHasher + Default,
+    FzStringSet<K, BH>: Set<K>,
 {
-    type Output = hashbrown::HashSet<Box<str>>;
+    type Output = hashbrown::HashSet<K>;
 
     fn bitand(self, rhs: &ST) -> Self::Output {
         Self::Output::from_iter(self.intersection(rhs).cloned())
     }
 }
 
-impl<ST, BH> BitXor<&ST> for &FzStringSet<Box<str>, BH>
+impl<K, ST, BH> BitXor<&ST> for &FzStringSet<K, BH>
 where
-    ST: Set<Box<str>>,
+    K: StringKey,
+    ST: Set<K>,
     BH: BuildHasher + Default,
+    FzStringSet<K, BH>: Set<K>,
 {
-    type Output = hashbrown::HashSet<Box<str>>;
+    type Output = hashbrown::HashSet<K>;
 
     fn bitxor(self, rhs: &ST) -> Self::Output {
         self.symmetric_difference(rhs).cloned().collect()
     }
 }
 
-impl<ST, BH> Sub<&ST> for &FzStringSet<Box<str>, BH>
+impl<K, ST, BH> Sub<&ST> for &FzStringSet<K, BH>
 where
-    ST: Set<Box<str>>,
+    K: StringKey,
+    ST: Set<K>,
     BH: BuildHasher + Default,
+    FzStringSet<K, BH>: Set<K>,
 {
-    type Output = hashbrown::HashSet<Box<str>>;
+    type Output = hashbrown::HashSet<K>;
 
     fn sub(self, rhs: &ST) -> Self::Output {
         self.difference(rhs).cloned().collect()
     }
 }
 
-impl<BH> IntoIterator for FzStringSet<Box<str>, BH> {
-    type Item = Box<str>;
-    type IntoIter = IntoIter<Box<str>>;
+impl<K, BH> IntoIterator for FzStringSet<K, BH>
+where
+    K: StringKey,
+{
+    type Item = K;
+    type IntoIter = IntoIter<K>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.into_iter()
     }
 }
 
-impl<'a, BH> IntoIterator for &'a FzStringSet<Box<str>, BH> {
-    type Item = &'a Box<str>;
-    type IntoIter = Iter<'a, Box<str>>;
+impl<'a, K, BH> IntoIterator for &'a FzStringSet<K, BH>
+where
+    K: StringKey,
+{
+    type Item = &'a K;
+    type IntoIter = Iter<'a, K>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-impl<ST, BH> PartialEq<ST> for FzStringSet<Box<str>, BH>
+impl<K, ST, BH> PartialEq<ST> for FzStringSet<K, BH>
 where
-    ST: SetQuery<Box<str>>,
+    K: StringKey,
+    ST: SetQuery<K>,
     BH: BuildHasher + Default,
 {
     partial_eq_trait_funcs!();
 }
 
-impl<BH> Eq for FzStringSet<Box<str>, BH> where BH: BuildHasher + Default {}
+impl<K, BH> Eq for FzStringSet<K, BH>
+where
+    K: StringKey,
+    BH: BuildHasher + Default,
+    Self: SetQuery<K>,
+{
+}
 
-impl<BH> Debug for FzStringSet<Box<str>, BH> {
+impl<K, BH> Debug for FzStringSet<K, BH>
+where
+    K: StringKey + Debug,
+{
     debug_trait_funcs!();
 }
 
 #[cfg(feature = "serde")]
-impl Serialize for FzStringSet<Box<str>> {
+impl<K> Serialize for FzStringSet<K>
+where
+    K: StringKey + Serialize,
+{
     serialize_trait_funcs!();
 }
 
 #[cfg(feature = "serde")]
-impl<'de, BH> Deserialize<'de> for FzStringSet<Box<str>, BH>
+impl<'de, K, BH> Deserialize<'de> for FzStringSet<K, BH>
 where
+    K: StringKey,
     BH: BuildHasher + Default,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -280,16 +432,17 @@ where
 }
 
 #[cfg(feature = "serde")]
-struct StrSetVisitor<BH> {
-    marker: PhantomData<BH>,
+struct StrSetVisitor<K, BH> {
+    marker: PhantomData<(K, BH)>,
 }
 
 #[cfg(feature = "serde")]
-impl<'de, BH> Visitor<'de> for StrSetVisitor<BH>
+impl<'de, K, BH> Visitor<'de> for StrSetVisitor<K, BH>
 where
+    K: StringKey,
     BH: BuildHasher + Default,
 {
-    type Value = FzStringSet<Box<str>, BH>;
+    type Value = FzStringSet<K, BH>;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("a set with string values")
@@ -299,11 +452,11 @@ where
     where
         M: SeqAccess<'de>,
     {
-        let mut v: Vec<(&str, ())> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
-        while let Some(x) = seq.next_element()? {
-            v.push((x, ()));
+        let mut v = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(x) = seq.next_element::<&'de str>()? {
+            v.push((K::from_str(x), ()));
         }
 
-        Ok(FzStringSet::from(FzStringMap::with_hasher(v, BH::default())))
+        Ok(FzStringSet::from(FzStringMap::from_keys(v, BH::default())))
     }
 }
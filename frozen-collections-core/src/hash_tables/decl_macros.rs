@@ -57,7 +57,248 @@ macro_rules! hash_table_funcs {
 
             None
         }
+
+        /// Looks up a batch of probes, prefetching every probe's hash slot and then the entries it refers
+        /// to before resolving any of them.
+        pub(crate) fn find_many<'a>(
+            &'a self,
+            count: usize,
+            hash_code: impl Fn(usize) -> u64,
+            eq: impl Fn(usize, &T) -> bool,
+            mut found: impl FnMut(usize, Option<&'a T>),
+        ) {
+            crate::utils::for_each_probe_batch(count, hash_code, |start, hash_codes| {
+                for hash_code in hash_codes {
+                    #[expect(clippy::cast_possible_truncation, reason = "Truncation ok on 32 bit systems")]
+                    let hash_slot_index = (hash_code & self.mask) as usize;
+
+                    // SAFETY: The hash slot index is guaranteed to be within bounds because of the masking above
+                    crate::utils::prefetch_for_read(unsafe { self.slots.as_ptr().add(hash_slot_index) });
+                }
+
+                for hash_code in hash_codes {
+                    #[expect(clippy::cast_possible_truncation, reason = "Truncation ok on 32 bit systems")]
+                    let hash_slot_index = (hash_code & self.mask) as usize;
+
+                    // SAFETY: The hash slot index is guaranteed to be within bounds because of the masking above
+                    let min: usize = unsafe { self.slots.get_unchecked(hash_slot_index) }.min_index.into();
+
+                    // SAFETY: The index is at most the number of entries by construction
+                    crate::utils::prefetch_for_read(unsafe { self.entries.as_ptr().add(min) });
+                }
+
+                for (i, hash_code) in hash_codes.iter().enumerate() {
+                    found(start + i, self.find(*hash_code, |entry| eq(start + i, entry)));
+                }
+            });
+        }
+
+        pub(crate) fn diagnostics(&self) -> crate::diagnostics::Diagnostics {
+            use crate::diagnostics::{Diagnostics, HeapBytes, Strategy};
+
+            let chains = self.slots.iter().map(|slot| {
+                let min: usize = slot.min_index.into();
+                let max: usize = slot.max_index.into();
+                max - min
+            });
+
+            let mut d = Diagnostics::new(Strategy::Hash, self.entries.len()).with_chains(chains);
+            d.slots = self.slots.len();
+            d.heap_bytes = self.slots.heap_bytes() + self.entries.heap_bytes();
+            d
+        }
+    };
+}
+
+macro_rules! perfect_hash_table_funcs {
+    () => {
+        #[inline]
+        pub(crate) fn find(&self, hash_code: u64, eq: impl Fn(&T) -> bool) -> Option<&T> {
+            if self.entries.is_empty() {
+                return None;
+            }
+
+            let bucket = crate::hash_tables::perfect_hash_bucket(hash_code, self.pilots.len());
+
+            // SAFETY: The bucket index is guaranteed to be less than the number of buckets
+            let pilot = unsafe { *self.pilots.get_unchecked(bucket) };
+            let slot = crate::hash_tables::perfect_hash_slot(hash_code, pilot, self.entries.len());
+
+            // SAFETY: The slot index is guaranteed to be less than the number of entries
+            let entry = unsafe { self.entries.get_unchecked(slot) };
+            if eq(entry) { Some(entry) } else { None }
+        }
+
+        #[inline]
+        pub(crate) fn find_mut(&mut self, hash_code: u64, eq: impl Fn(&T) -> bool) -> Option<&mut T> {
+            if self.entries.is_empty() {
+                return None;
+            }
+
+            let bucket = crate::hash_tables::perfect_hash_bucket(hash_code, self.pilots.len());
+
+            // SAFETY: The bucket index is guaranteed to be less than the number of buckets
+            let pilot = unsafe { *self.pilots.get_unchecked(bucket) };
+            let slot = crate::hash_tables::perfect_hash_slot(hash_code, pilot, self.entries.len());
+
+            // SAFETY: The slot index is guaranteed to be less than the number of entries
+            let entry = unsafe { self.entries.get_unchecked_mut(slot) };
+            if eq(entry) { Some(entry) } else { None }
+        }
+
+        /// Looks up a batch of probes, prefetching every probe's pilot and then the entry it refers
+        /// to before resolving any of them.
+        pub(crate) fn find_many<'a>(
+            &'a self,
+            count: usize,
+            hash_code: impl Fn(usize) -> u64,
+            eq: impl Fn(usize, &T) -> bool,
+            mut found: impl FnMut(usize, Option<&'a T>),
+        ) {
+            crate::utils::for_each_probe_batch(count, hash_code, |start, hash_codes| {
+                if !self.entries.is_empty() {
+                    for hash_code in hash_codes {
+                        let bucket = crate::hash_tables::perfect_hash_bucket(*hash_code, self.pilots.len());
+
+                        // SAFETY: The bucket index is guaranteed to be less than the number of buckets
+                        crate::utils::prefetch_for_read(unsafe { self.pilots.as_ptr().add(bucket) });
+                    }
+
+                    for hash_code in hash_codes {
+                        let bucket = crate::hash_tables::perfect_hash_bucket(*hash_code, self.pilots.len());
+
+                        // SAFETY: The bucket index is guaranteed to be less than the number of buckets
+                        let pilot = unsafe { *self.pilots.get_unchecked(bucket) };
+                        let slot = crate::hash_tables::perfect_hash_slot(*hash_code, pilot, self.entries.len());
+
+                        // SAFETY: The slot index is guaranteed to be less than the number of entries
+                        crate::utils::prefetch_for_read(unsafe { self.entries.as_ptr().add(slot) });
+                    }
+                }
+
+                for (i, hash_code) in hash_codes.iter().enumerate() {
+                    found(start + i, self.find(*hash_code, |entry| eq(start + i, entry)));
+                }
+            });
+        }
+
+        pub(crate) fn diagnostics(&self) -> crate::diagnostics::Diagnostics {
+            use crate::diagnostics::{Diagnostics, HeapBytes, Strategy};
+
+            let mut d = Diagnostics::new(Strategy::PerfectHash, self.entries.len());
+            d.slots = self.entries.len();
+            d.heap_bytes = self.pilots.heap_bytes() + self.entries.heap_bytes();
+            d
+        }
+    };
+}
+
+macro_rules! simd_probe_table_funcs {
+    () => {
+        #[inline]
+        pub(crate) fn find(&self, hash_code: u64, eq: impl Fn(&T) -> bool) -> Option<&T> {
+            let tag = crate::utils::hash_tag(hash_code);
+            let len = self.entries.len();
+
+            for group_start in (0..self.tags.len()).step_by(crate::utils::TAG_GROUP_WIDTH) {
+                // SAFETY: The number of tags is always a multiple of the group width
+                let group = unsafe {
+                    &*self
+                        .tags
+                        .as_ptr()
+                        .add(group_start)
+                        .cast::<[u8; crate::utils::TAG_GROUP_WIDTH]>()
+                };
+
+                let mut matches = crate::utils::match_tag_group(group, tag);
+                while matches != 0 {
+                    let index = group_start + matches.trailing_zeros() as usize;
+                    if index >= len {
+                        // the padding at the end of the tags never refers to real entries
+                        return None;
+                    }
+
+                    // SAFETY: The index was checked against the number of entries above
+                    let entry = unsafe { self.entries.get_unchecked(index) };
+                    if eq(entry) {
+                        return Some(entry);
+                    }
+
+                    matches &= matches - 1;
+                }
+            }
+
+            None
+        }
+
+        #[inline]
+        pub(crate) fn find_mut(&mut self, hash_code: u64, eq: impl Fn(&T) -> bool) -> Option<&mut T> {
+            let tag = crate::utils::hash_tag(hash_code);
+            let len = self.entries.len();
+
+            for group_start in (0..self.tags.len()).step_by(crate::utils::TAG_GROUP_WIDTH) {
+                // SAFETY: The number of tags is always a multiple of the group width
+                let group = unsafe {
+                    &*self
+                        .tags
+                        .as_ptr()
+                        .add(group_start)
+                        .cast::<[u8; crate::utils::TAG_GROUP_WIDTH]>()
+                };
+
+                let mut matches = crate::utils::match_tag_group(group, tag);
+                while matches != 0 {
+                    let index = group_start + matches.trailing_zeros() as usize;
+                    if index >= len {
+                        // the padding at the end of the tags never refers to real entries
+                        return None;
+                    }
+
+                    // SAFETY: The index was checked against the number of entries above
+                    if eq(unsafe { self.entries.get_unchecked(index) }) {
+                        // SAFETY: The index was checked against the number of entries above
+                        return Some(unsafe { self.entries.get_unchecked_mut(index) });
+                    }
+
+                    matches &= matches - 1;
+                }
+            }
+
+            None
+        }
+
+        /// Looks up a batch of probes.
+        ///
+        /// Tag probing is only used for small tables which stay in cache, so nothing is prefetched.
+        pub(crate) fn find_many<'a>(
+            &'a self,
+            count: usize,
+            hash_code: impl Fn(usize) -> u64,
+            eq: impl Fn(usize, &T) -> bool,
+            mut found: impl FnMut(usize, Option<&'a T>),
+        ) {
+            for i in 0..count {
+                found(i, self.find(hash_code(i), |entry| eq(i, entry)));
+            }
+        }
+
+        pub(crate) fn diagnostics(&self) -> crate::diagnostics::Diagnostics {
+            use crate::diagnostics::{Diagnostics, HeapBytes, Strategy};
+
+            // entries with the same tag as another entry need an extra key comparison
+            let mut tag_counts = [0_usize; 256];
+            for tag in &self.tags[..self.entries.len()] {
+                tag_counts[usize::from(*tag)] += 1;
+            }
+
+            let mut d = Diagnostics::new(Strategy::SimdProbe, self.entries.len()).with_chains(tag_counts.into_iter());
+            d.slots = self.tags.len();
+            d.heap_bytes = self.tags.heap_bytes() + self.entries.heap_bytes();
+            d
+        }
     };
 }
 
 pub(crate) use hash_table_funcs;
+pub(crate) use perfect_hash_table_funcs;
+pub(crate) use simd_probe_table_funcs;
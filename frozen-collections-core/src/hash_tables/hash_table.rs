@@ -100,6 +100,17 @@ where
         })
     }
 
+    /// Transforms the entries in place, stopping at the first error.
+    ///
+    /// This keeps the table valid as long as the hash codes of the entries don't change.
+    pub(crate) fn try_map_entries<U, E>(self, f: impl FnMut(T) -> Result<U, E>) -> Result<HashTable<U, CM>, E> {
+        Ok(HashTable {
+            slots: self.slots,
+            entries: self.entries.into_iter().map(f).collect::<Result<_, _>>()?,
+            mask: self.mask,
+        })
+    }
+
     hash_table_funcs!();
 
     #[inline]
@@ -33,6 +33,17 @@ impl<T, const SZ: usize, const NHS: usize, CM> InlineHashTable<T, SZ, NHS, CM> {
             entries: processed_entries,
         }
     }
+
+    /// Transforms the entries in place.
+    ///
+    /// This keeps the table valid as long as the hash codes of the entries don't change.
+    pub(crate) fn map_entries<U>(self, f: impl FnMut(T) -> U) -> InlineHashTable<U, SZ, NHS, CM> {
+        InlineHashTable {
+            slots: self.slots,
+            mask: self.mask,
+            entries: self.entries.map(f),
+        }
+    }
 }
 
 impl<T, const SZ: usize, const NHS: usize, CM> InlineHashTable<T, SZ, NHS, CM>
@@ -1,3 +1,4 @@
+use crate::diagnostics::{Diagnostics, Strategy};
 use crate::traits::{CollectionMagnitude, SmallCollection};
 
 /// A specialized hash table that stores its entries inline and doesn't tolerate hash collisions.
@@ -31,6 +32,17 @@ impl<T, const SZ: usize, const NHS: usize, CM> InlineHashTableNoCollisions<T, SZ
             entries: processed_entries,
         }
     }
+
+    /// Transforms the entries in place.
+    ///
+    /// This keeps the table valid as long as the hash codes of the entries don't change.
+    pub(crate) fn map_entries<U>(self, f: impl FnMut(T) -> U) -> InlineHashTableNoCollisions<U, SZ, NHS, CM> {
+        InlineHashTableNoCollisions {
+            slots: self.slots,
+            mask: self.mask,
+            entries: self.entries.map(f),
+        }
+    }
 }
 
 impl<T, const SZ: usize, const NHS: usize, CM> InlineHashTableNoCollisions<T, SZ, NHS, CM>
@@ -57,6 +69,14 @@ where
         None
     }
 
+    pub(crate) fn diagnostics(&self) -> Diagnostics {
+        let chains = self.slots.iter().map(|slot| usize::from(Into::<usize>::into(*slot) > 0));
+
+        let mut d = Diagnostics::new(Strategy::Hash, self.entries.len()).with_chains(chains);
+        d.slots = self.slots.len();
+        d
+    }
+
     #[inline]
     pub(crate) fn find_mut(&mut self, hash_code: u64, eq: impl Fn(&T) -> bool) -> Option<&mut T> {
         #[expect(clippy::cast_possible_truncation, reason = "Truncation on 32 bit systems is fine")]
@@ -77,6 +97,21 @@ where
         None
     }
 
+    /// Looks up a batch of probes.
+    ///
+    /// Inline tables are embedded in the program image and are generally small, so nothing is prefetched.
+    pub(crate) fn find_many<'a>(
+        &'a self,
+        count: usize,
+        hash_code: impl Fn(usize) -> u64,
+        eq: impl Fn(usize, &T) -> bool,
+        mut found: impl FnMut(usize, Option<&'a T>),
+    ) {
+        for i in 0..count {
+            found(i, self.find(hash_code(i), |entry| eq(i, entry)));
+        }
+    }
+
     #[inline]
     pub(crate) const fn len(&self) -> usize {
         self.entries.len()
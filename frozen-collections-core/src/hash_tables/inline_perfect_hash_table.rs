@@ -0,0 +1,48 @@
+use crate::hash_tables::decl_macros::perfect_hash_table_funcs;
+
+/// A hash table that stores its entries inline and uses a minimal perfect hash function to locate them.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+///
+/// # Type Parameters
+///
+/// - `T`: The data held in the hash table.
+/// - `SZ`: The number of entries in the hash table.
+/// - `NB`: The number of buckets, each of which has a pilot value.
+///
+/// Since the table has exactly one slot per entry, a lookup always inspects a
+/// single entry and never has to deal with collisions.
+#[derive(Clone, Debug)]
+pub struct InlinePerfectHashTable<T, const SZ: usize, const NB: usize> {
+    pilots: [u32; NB],
+    pub(crate) entries: [T; SZ],
+}
+
+impl<T, const SZ: usize, const NB: usize> InlinePerfectHashTable<T, SZ, NB> {
+    /// Creates a new hash table.
+    ///
+    /// This function assumes that the pilots and processed entries are in proper order.
+    pub const fn new_raw(pilots: [u32; NB], processed_entries: [T; SZ]) -> Self {
+        Self {
+            pilots,
+            entries: processed_entries,
+        }
+    }
+
+    /// Transforms the entries in place.
+    ///
+    /// This keeps the table valid as long as the hash codes of the entries don't change.
+    pub(crate) fn map_entries<U>(self, f: impl FnMut(T) -> U) -> InlinePerfectHashTable<U, SZ, NB> {
+        InlinePerfectHashTable {
+            pilots: self.pilots,
+            entries: self.entries.map(f),
+        }
+    }
+
+    perfect_hash_table_funcs!();
+
+    #[inline]
+    pub(crate) const fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
@@ -0,0 +1,45 @@
+use crate::hash_tables::decl_macros::simd_probe_table_funcs;
+
+/// A table that stores its entries inline and locates them by comparing one-byte hash tags in parallel.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+///
+/// # Type Parameters
+///
+/// - `T`: The data held in the table.
+/// - `SZ`: The number of entries in the table.
+/// - `NT`: The number of tags. This must be `SZ` rounded up to a multiple of 16.
+#[derive(Clone, Debug)]
+pub struct InlineSimdProbeTable<T, const SZ: usize, const NT: usize> {
+    tags: [u8; NT],
+    pub(crate) entries: [T; SZ],
+}
+
+impl<T, const SZ: usize, const NT: usize> InlineSimdProbeTable<T, SZ, NT> {
+    /// Creates a new table.
+    ///
+    /// This function assumes that the tags and processed entries are in proper order.
+    pub const fn new_raw(tags: [u8; NT], processed_entries: [T; SZ]) -> Self {
+        Self {
+            tags,
+            entries: processed_entries,
+        }
+    }
+
+    /// Transforms the entries in place.
+    ///
+    /// This keeps the table valid as long as the hash codes of the entries don't change.
+    pub(crate) fn map_entries<U>(self, f: impl FnMut(T) -> U) -> InlineSimdProbeTable<U, SZ, NT> {
+        InlineSimdProbeTable {
+            tags: self.tags,
+            entries: self.entries.map(f),
+        }
+    }
+
+    simd_probe_table_funcs!();
+
+    #[inline]
+    pub(crate) const fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
@@ -4,9 +4,17 @@ pub(crate) use crate::hash_tables::hash_table::HashTable;
 pub use crate::hash_tables::hash_table_slot::HashTableSlot;
 pub use crate::hash_tables::inline_hash_table::InlineHashTable;
 pub use crate::hash_tables::inline_hash_table_no_collisions::InlineHashTableNoCollisions;
+pub use crate::hash_tables::inline_perfect_hash_table::InlinePerfectHashTable;
+pub use crate::hash_tables::inline_simd_probe_table::InlineSimdProbeTable;
+pub(crate) use crate::hash_tables::perfect_hash_table::{PerfectHashTable, perfect_hash_bucket, perfect_hash_slot};
+pub(crate) use crate::hash_tables::simd_probe_table::SimdProbeTable;
 
 mod decl_macros;
 mod hash_table;
 mod hash_table_slot;
 mod inline_hash_table;
 mod inline_hash_table_no_collisions;
+mod inline_perfect_hash_table;
+mod inline_simd_probe_table;
+mod perfect_hash_table;
+mod simd_probe_table;
@@ -0,0 +1,86 @@
+use crate::analyzers::PerfectHashAnalysisResult;
+use crate::hash_tables::decl_macros::perfect_hash_table_funcs;
+use crate::utils::DeduppedVec;
+
+#[cfg(not(feature = "std"))]
+use {alloc::boxed::Box, alloc::vec::Vec};
+
+/// A hash table that uses a minimal perfect hash function to locate its entries.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+///
+/// Entries are grouped into buckets based on their hash codes, and every bucket holds a pilot
+/// value which displaces the bucket's entries into distinct slots. Since the table has exactly
+/// one slot per entry, a lookup always inspects a single entry and never has to deal with collisions.
+#[derive(Clone)]
+pub struct PerfectHashTable<T> {
+    pub(crate) pilots: Box<[u32]>,
+    pub(crate) entries: Box<[T]>,
+}
+
+impl<T> PerfectHashTable<T> {
+    /// Creates a new hash table.
+    ///
+    /// This function assumes that there are no duplicates in the input vector, and that the
+    /// analysis was performed on the hash codes of the entries, in the order they appear in the vector.
+    pub(crate) fn new(entries: DeduppedVec<T>, analysis: PerfectHashAnalysisResult) -> Self {
+        let mut prep_items: Vec<_> = analysis.slots.into_iter().zip(entries.into_vec()).collect();
+        prep_items.sort_unstable_by_key(|x| x.0);
+
+        Self {
+            pilots: analysis.pilots.into_boxed_slice(),
+            entries: prep_items.into_iter().map(|x| x.1).collect(),
+        }
+    }
+
+    /// Transforms the entries in place, stopping at the first error.
+    ///
+    /// This keeps the table valid as long as the hash codes of the entries don't change.
+    pub(crate) fn try_map_entries<U, E>(self, f: impl FnMut(T) -> Result<U, E>) -> Result<PerfectHashTable<U>, E> {
+        Ok(PerfectHashTable {
+            pilots: self.pilots,
+            entries: self.entries.into_iter().map(f).collect::<Result<_, _>>()?,
+        })
+    }
+
+    perfect_hash_table_funcs!();
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+impl<T> Default for PerfectHashTable<T> {
+    fn default() -> Self {
+        Self {
+            pilots: Box::new([]),
+            entries: Box::new([]),
+        }
+    }
+}
+
+/// Returns the bucket a hash code belongs to.
+#[inline]
+#[expect(clippy::cast_possible_truncation, reason = "The result is always less than `num_buckets`")]
+pub const fn perfect_hash_bucket(hash_code: u64, num_buckets: usize) -> usize {
+    ((mix(hash_code) as u128 * num_buckets as u128) >> 64) as usize
+}
+
+/// Returns the slot a hash code lands in when displaced by a given pilot value.
+#[inline]
+#[expect(clippy::cast_possible_truncation, reason = "The result is always less than `num_slots`")]
+pub const fn perfect_hash_slot(hash_code: u64, pilot: u32, num_slots: usize) -> usize {
+    const PILOT_MULTIPLIER: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    let h = mix(hash_code ^ (pilot as u64).wrapping_mul(PILOT_MULTIPLIER));
+    ((h as u128 * num_slots as u128) >> 64) as usize
+}
+
+/// The splitmix64 finalizer, which spreads the entropy of a hash code over all of its bits.
+#[inline]
+const fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
@@ -0,0 +1,59 @@
+use crate::hash_tables::decl_macros::simd_probe_table_funcs;
+use crate::utils::{DeduppedVec, hash_tag, tag_slots};
+
+#[cfg(not(feature = "std"))]
+use {alloc::boxed::Box, alloc::vec::Vec};
+
+/// A table that locates its entries by comparing one-byte hash tags in parallel.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+///
+/// Every entry has a tag derived from its hash code. A lookup compares the tag of the
+/// key against groups of tags at once, and only compares keys for entries whose tags match.
+/// This works best for small collections, where the whole tag array fits in a few SIMD registers.
+#[derive(Clone)]
+pub struct SimdProbeTable<T> {
+    pub(crate) tags: Box<[u8]>,
+    pub(crate) entries: Box<[T]>,
+}
+
+impl<T> SimdProbeTable<T> {
+    /// Creates a new table.
+    ///
+    /// This function assumes that there are no duplicates in the input vector.
+    pub(crate) fn new(entries: DeduppedVec<T>, hash: impl Fn(&T) -> u64) -> Self {
+        let mut tags: Vec<u8> = entries.iter().map(|x| hash_tag(hash(x))).collect();
+        tags.resize(tag_slots(entries.len()), 0);
+
+        Self {
+            tags: tags.into_boxed_slice(),
+            entries: entries.into_boxed_slice(),
+        }
+    }
+
+    /// Transforms the entries in place, stopping at the first error.
+    ///
+    /// This keeps the table valid as long as the hash codes of the entries don't change.
+    pub(crate) fn try_map_entries<U, E>(self, f: impl FnMut(T) -> Result<U, E>) -> Result<SimdProbeTable<U>, E> {
+        Ok(SimdProbeTable {
+            tags: self.tags,
+            entries: self.entries.into_iter().map(f).collect::<Result<_, _>>()?,
+        })
+    }
+
+    simd_probe_table_funcs!();
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+impl<T> Default for SimdProbeTable<T> {
+    fn default() -> Self {
+        Self {
+            tags: Box::new([]),
+            entries: Box::new([]),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::diagnostics::KeyHashing;
+use crate::keys::AsciiCaseInsensitive;
+use crate::traits::Hasher;
+use core::ops::Range;
+
+/// Lets collections of [`AsciiCaseInsensitive`] keys be queried with plain strings.
+///
+/// Strings are hashed by the wrapped hasher as if they were wrapped in [`AsciiCaseInsensitive`],
+/// so they hash the same as the keys they match.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+#[derive(Clone, Debug, Default)]
+pub struct AsciiCaseInsensitiveHasher<H> {
+    hasher: H,
+}
+
+impl<H> AsciiCaseInsensitiveHasher<H> {
+    /// Creates a new `AsciiCaseInsensitiveHasher` wrapping the given hasher.
+    #[must_use]
+    pub const fn new(hasher: H) -> Self {
+        Self { hasher }
+    }
+}
+
+impl<Q, H> Hasher<Q> for AsciiCaseInsensitiveHasher<H>
+where
+    Q: ?Sized + AsRef<str>,
+    H: Hasher<AsciiCaseInsensitive<str>>,
+{
+    #[inline]
+    fn hash_one(&self, value: &Q) -> u64 {
+        self.hasher.hash_one(AsciiCaseInsensitive::from_ref(value.as_ref()))
+    }
+
+    fn key_hashing(&self) -> KeyHashing {
+        self.hasher.key_hashing()
+    }
+
+    fn subslice_range(&self) -> Option<Range<usize>> {
+        self.hasher.subslice_range()
+    }
+}
+
+impl<S, H> Hasher<AsciiCaseInsensitive<S>> for AsciiCaseInsensitiveHasher<H>
+where
+    S: ?Sized,
+    H: Hasher<AsciiCaseInsensitive<S>>,
+{
+    #[inline]
+    fn hash_one(&self, value: &AsciiCaseInsensitive<S>) -> u64 {
+        self.hasher.hash_one(value)
+    }
+
+    fn key_hashing(&self) -> KeyHashing {
+        <H as Hasher<AsciiCaseInsensitive<S>>>::key_hashing(&self.hasher)
+    }
+
+    fn subslice_range(&self) -> Option<Range<usize>> {
+        <H as Hasher<AsciiCaseInsensitive<S>>>::subslice_range(&self.hasher)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashers::{BridgeHasher, InlineLeftRangeHasher};
+    use foldhash::fast::FixedState;
+
+    #[test]
+    fn test_strings_hash_like_keys() {
+        let hasher = AsciiCaseInsensitiveHasher::new(BridgeHasher::new(FixedState::with_seed(42)));
+        assert_eq!(hasher.hash_one("Hello"), hasher.hash_one(&AsciiCaseInsensitive("hELLO")));
+        assert_eq!(hasher.hash_one("Hello"), hasher.hash_one(&String::from("HELLO")));
+        assert_ne!(hasher.hash_one("Hello"), hasher.hash_one("World"));
+    }
+
+    #[test]
+    fn test_delegates_diagnostics() {
+        let hasher = AsciiCaseInsensitiveHasher::new(InlineLeftRangeHasher::<1, 3>::new(FixedState::default()));
+        assert_eq!(KeyHashing::LeftSubslice, Hasher::<str>::key_hashing(&hasher));
+        assert_eq!(Some(1..3), Hasher::<str>::subslice_range(&hasher));
+        assert_eq!(hasher.hash_one("xAB"), hasher.hash_one(&AsciiCaseInsensitive("yab")));
+    }
+}
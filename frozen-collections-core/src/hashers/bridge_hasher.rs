@@ -16,6 +16,10 @@ impl<BH> BridgeHasher<BH> {
     pub const fn new(bh: BH) -> Self {
         Self { bh }
     }
+
+    pub(crate) const fn build_hasher(&self) -> &BH {
+        &self.bh
+    }
 }
 
 impl<T, BH> Hasher<T> for BridgeHasher<BH>
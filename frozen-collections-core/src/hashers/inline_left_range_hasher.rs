@@ -1,6 +1,9 @@
+use crate::diagnostics::KeyHashing;
+use crate::hashers::SliceKey;
 use crate::traits::Hasher;
 use crate::utils::cold;
 use core::hash::{BuildHasher, Hash};
+use core::ops::Range;
 use foldhash::fast::FixedState;
 
 /// Hashes a portion of a left-aligned slice.
@@ -33,44 +36,45 @@ where
 
         self.bh.hash_one(&value[RANGE_START..RANGE_END])
     }
-}
 
-impl<const RANGE_START: usize, const RANGE_END: usize, BH> Hasher<str> for InlineLeftRangeHasher<RANGE_START, RANGE_END, BH>
-where
-    BH: BuildHasher,
-{
-    #[inline]
-    fn hash_one(&self, value: &str) -> u64 {
-        let b = value.as_bytes();
-        if b.len() < RANGE_END {
-            cold();
-            return 0;
-        }
+    fn key_hashing(&self) -> KeyHashing {
+        KeyHashing::LeftSubslice
+    }
 
-        self.bh.hash_one(&b[RANGE_START..RANGE_END])
+    fn subslice_range(&self) -> Option<Range<usize>> {
+        Some(RANGE_START..RANGE_END)
     }
 }
 
-impl<AR, const RANGE_START: usize, const RANGE_END: usize, BH> Hasher<AR> for InlineLeftRangeHasher<RANGE_START, RANGE_END, BH>
+impl<K, const RANGE_START: usize, const RANGE_END: usize, BH> Hasher<K> for InlineLeftRangeHasher<RANGE_START, RANGE_END, BH>
 where
-    AR: AsRef<str>,
+    K: ?Sized + SliceKey,
     BH: BuildHasher,
 {
     #[inline]
-    fn hash_one(&self, value: &AR) -> u64 {
-        let b = value.as_ref().as_bytes();
-        if b.len() < RANGE_END {
+    fn hash_one(&self, value: &K) -> u64 {
+        let slice = value.as_slice();
+        if slice.len() < RANGE_END {
             cold();
             return 0;
         }
 
-        self.bh.hash_one(&b[RANGE_START..RANGE_END])
+        K::hash_slice(&self.bh, &slice[RANGE_START..RANGE_END])
+    }
+
+    fn key_hashing(&self) -> KeyHashing {
+        KeyHashing::LeftSubslice
+    }
+
+    fn subslice_range(&self) -> Option<Range<usize>> {
+        Some(RANGE_START..RANGE_END)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::keys::{AsciiCaseInsensitive, hash_ascii_lowercase};
     use alloc::vec;
 
     #[test]
@@ -103,4 +107,27 @@ mod tests {
         assert_eq!(hasher.hash_one("abcd"), hasher.bh.hash_one(b"abc"));
         assert_eq!(hasher.hash_one("ab"), 0);
     }
+
+    #[test]
+    fn test_left_range_hasher_hash_byte_keys() {
+        let hasher = InlineLeftRangeHasher::<0, 3>::new(FixedState::default());
+        assert_eq!(hasher.hash_one(&b"abcd".to_vec()), hasher.bh.hash_one(b"abc"));
+        assert_eq!(hasher.hash_one(&Box::<[u8]>::from(&b"abcd"[..])), hasher.hash_one("abcd"));
+        assert_eq!(hasher.hash_one(&&b"abcd"[..]), hasher.hash_one(&"abcd"));
+        assert_eq!(hasher.hash_one(&b"a".to_vec()), 0);
+    }
+
+    #[test]
+    fn test_left_range_hasher_hash_ascii_case_insensitive() {
+        let hasher = InlineLeftRangeHasher::<0, 3>::new(FixedState::default());
+        assert_eq!(
+            hasher.hash_one(&AsciiCaseInsensitive("ABcd")),
+            hasher.hash_one(AsciiCaseInsensitive::from_ref("abCD"))
+        );
+        assert_eq!(
+            hasher.hash_one(&AsciiCaseInsensitive("ABcd")),
+            hash_ascii_lowercase(&hasher.bh, b"abc")
+        );
+        assert_eq!(hasher.hash_one(&AsciiCaseInsensitive("AB")), 0);
+    }
 }
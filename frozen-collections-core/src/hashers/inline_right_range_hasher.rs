@@ -1,6 +1,9 @@
+use crate::diagnostics::KeyHashing;
+use crate::hashers::SliceKey;
 use crate::traits::Hasher;
 use crate::utils::cold;
 use core::hash::{BuildHasher, Hash};
+use core::ops::Range;
 use foldhash::fast::FixedState;
 
 /// Hashes a portion of a right-aligned slice.
@@ -34,46 +37,46 @@ where
         let effective_range = value.len() - RANGE_END..value.len() - RANGE_START;
         self.bh.hash_one(&value[effective_range])
     }
-}
 
-impl<const RANGE_START: usize, const RANGE_END: usize, BH> Hasher<str> for InlineRightRangeHasher<RANGE_START, RANGE_END, BH>
-where
-    BH: BuildHasher,
-{
-    #[inline]
-    fn hash_one(&self, value: &str) -> u64 {
-        let b = value.as_bytes();
-        if b.len() < RANGE_END {
-            cold();
-            return 0;
-        }
+    fn key_hashing(&self) -> KeyHashing {
+        KeyHashing::RightSubslice
+    }
 
-        let effective_range = value.len() - RANGE_END..value.len() - RANGE_START;
-        self.bh.hash_one(&b[effective_range])
+    fn subslice_range(&self) -> Option<Range<usize>> {
+        Some(RANGE_START..RANGE_END)
     }
 }
 
-impl<AR, const RANGE_START: usize, const RANGE_END: usize, BH> Hasher<AR> for InlineRightRangeHasher<RANGE_START, RANGE_END, BH>
+impl<K, const RANGE_START: usize, const RANGE_END: usize, BH> Hasher<K> for InlineRightRangeHasher<RANGE_START, RANGE_END, BH>
 where
-    AR: AsRef<str>,
+    K: ?Sized + SliceKey,
     BH: BuildHasher,
 {
     #[inline]
-    fn hash_one(&self, value: &AR) -> u64 {
-        let b = value.as_ref().as_bytes();
-        if b.len() < RANGE_END {
+    fn hash_one(&self, value: &K) -> u64 {
+        let slice = value.as_slice();
+        if slice.len() < RANGE_END {
             cold();
             return 0;
         }
 
-        let effective_range = value.as_ref().len() - RANGE_END..value.as_ref().len() - RANGE_START;
-        self.bh.hash_one(&b[effective_range])
+        let effective_range = slice.len() - RANGE_END..slice.len() - RANGE_START;
+        K::hash_slice(&self.bh, &slice[effective_range])
+    }
+
+    fn key_hashing(&self) -> KeyHashing {
+        KeyHashing::RightSubslice
+    }
+
+    fn subslice_range(&self) -> Option<Range<usize>> {
+        Some(RANGE_START..RANGE_END)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::keys::{AsciiCaseInsensitive, hash_ascii_lowercase};
     use alloc::vec;
 
     #[test]
@@ -113,4 +116,27 @@ mod tests {
         assert_eq!(hasher.hash_one("abcdefghijklmn"), hasher.bh.hash_one(b"lm"));
         assert_eq!(hasher.hash_one("a"), 0);
     }
+
+    #[test]
+    fn test_right_range_hasher_hash_byte_keys() {
+        let hasher = InlineRightRangeHasher::<1, 3>::new(FixedState::default());
+        assert_eq!(hasher.hash_one(&b"abcd".to_vec()), hasher.bh.hash_one(b"bc"));
+        assert_eq!(hasher.hash_one(&Box::<[u8]>::from(&b"abcd"[..])), hasher.hash_one("abcd"));
+        assert_eq!(hasher.hash_one(&&b"abcd"[..]), hasher.hash_one(&"abcd"));
+        assert_eq!(hasher.hash_one(&b"a".to_vec()), 0);
+    }
+
+    #[test]
+    fn test_right_range_hasher_hash_ascii_case_insensitive() {
+        let hasher = InlineRightRangeHasher::<1, 3>::new(FixedState::default());
+        assert_eq!(
+            hasher.hash_one(&AsciiCaseInsensitive("ABcd")),
+            hasher.hash_one(AsciiCaseInsensitive::from_ref("abCD"))
+        );
+        assert_eq!(
+            hasher.hash_one(&AsciiCaseInsensitive("ABcd")),
+            hash_ascii_lowercase(&hasher.bh, b"bc")
+        );
+        assert_eq!(hasher.hash_one(&AsciiCaseInsensitive("A")), 0);
+    }
 }
@@ -1,4 +1,6 @@
 use crate::DefaultBuildHasher;
+use crate::diagnostics::KeyHashing;
+use crate::hashers::SliceKey;
 use crate::traits::Hasher;
 use crate::utils::cold;
 use core::hash::{BuildHasher, Hash};
@@ -19,6 +21,10 @@ impl<BH> LeftRangeHasher<BH> {
     pub const fn new(bh: BH, range: Range<usize>) -> Self {
         Self { bh, range }
     }
+
+    pub(crate) const fn build_hasher(&self) -> &BH {
+        &self.bh
+    }
 }
 
 impl<T, BH> Hasher<[T]> for LeftRangeHasher<BH>
@@ -34,38 +40,38 @@ where
 
         self.bh.hash_one(&value[self.range.clone()])
     }
-}
 
-impl<BH> Hasher<str> for LeftRangeHasher<BH>
-where
-    BH: BuildHasher,
-{
-    #[inline]
-    fn hash_one(&self, value: &str) -> u64 {
-        let b = value.as_bytes();
-        if b.len() < self.range.end {
-            cold();
-            return 0;
-        }
+    fn key_hashing(&self) -> KeyHashing {
+        KeyHashing::LeftSubslice
+    }
 
-        self.bh.hash_one(&b[self.range.clone()])
+    fn subslice_range(&self) -> Option<Range<usize>> {
+        Some(self.range.clone())
     }
 }
 
-impl<AR, BH> Hasher<AR> for LeftRangeHasher<BH>
+impl<K, BH> Hasher<K> for LeftRangeHasher<BH>
 where
-    AR: AsRef<str>,
+    K: ?Sized + SliceKey,
     BH: BuildHasher,
 {
     #[inline]
-    fn hash_one(&self, value: &AR) -> u64 {
-        let b = value.as_ref().as_bytes();
-        if b.len() < self.range.end {
+    fn hash_one(&self, value: &K) -> u64 {
+        let slice = value.as_slice();
+        if slice.len() < self.range.end {
             cold();
             return 0;
         }
 
-        self.bh.hash_one(&b[self.range.clone()])
+        K::hash_slice(&self.bh, &slice[self.range.clone()])
+    }
+
+    fn key_hashing(&self) -> KeyHashing {
+        KeyHashing::LeftSubslice
+    }
+
+    fn subslice_range(&self) -> Option<Range<usize>> {
+        Some(self.range.clone())
     }
 }
 
@@ -81,6 +87,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::keys::{AsciiCaseInsensitive, hash_ascii_lowercase};
     use alloc::vec;
     use foldhash::fast::RandomState;
 
@@ -115,6 +122,29 @@ mod tests {
         assert_eq!(hasher.hash_one("ab"), 0);
     }
 
+    #[test]
+    fn test_left_range_hasher_hash_byte_keys() {
+        let hasher = LeftRangeHasher::new(RandomState::default(), 0..3);
+        assert_eq!(hasher.hash_one(&b"abcd".to_vec()), hasher.bh.hash_one(b"abc"));
+        assert_eq!(hasher.hash_one(&Box::<[u8]>::from(&b"abcd"[..])), hasher.hash_one("abcd"));
+        assert_eq!(hasher.hash_one(&&b"abcd"[..]), hasher.hash_one(&"abcd"));
+        assert_eq!(hasher.hash_one(&b"a".to_vec()), 0);
+    }
+
+    #[test]
+    fn test_left_range_hasher_hash_ascii_case_insensitive() {
+        let hasher = LeftRangeHasher::new(RandomState::default(), 0..3);
+        assert_eq!(
+            hasher.hash_one(&AsciiCaseInsensitive("ABcd")),
+            hasher.hash_one(AsciiCaseInsensitive::from_ref("abCD"))
+        );
+        assert_eq!(
+            hasher.hash_one(&AsciiCaseInsensitive("ABcd")),
+            hash_ascii_lowercase(&hasher.bh, b"abc")
+        );
+        assert_eq!(hasher.hash_one(&AsciiCaseInsensitive("AB")), 0);
+    }
+
     #[test]
     fn test_left_range_hasher_default() {
         let hasher: LeftRangeHasher = LeftRangeHasher::default();
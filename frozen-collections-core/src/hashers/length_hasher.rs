@@ -1,3 +1,4 @@
+use crate::diagnostics::KeyHashing;
 use crate::traits::{Hasher, Len};
 
 /// Returns the value's length as the hash.
@@ -14,6 +15,10 @@ where
     fn hash_one(&self, value: &T) -> u64 {
         value.len() as u64
     }
+
+    fn key_hashing(&self) -> KeyHashing {
+        KeyHashing::Length
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 //! Hasher implementations for various situations.
 
+pub use crate::hashers::ascii_case_insensitive_hasher::AsciiCaseInsensitiveHasher;
 pub use crate::hashers::bridge_hasher::BridgeHasher;
 pub use crate::hashers::inline_left_range_hasher::InlineLeftRangeHasher;
 pub use crate::hashers::inline_right_range_hasher::InlineRightRangeHasher;
@@ -7,7 +8,9 @@ pub use crate::hashers::left_range_hasher::LeftRangeHasher;
 pub use crate::hashers::length_hasher::LengthHasher;
 pub use crate::hashers::right_range_hasher::RightRangeHasher;
 pub use crate::hashers::scalar_hasher::ScalarHasher;
+pub use crate::hashers::slice_key::SliceKey;
 
+mod ascii_case_insensitive_hasher;
 mod bridge_hasher;
 mod inline_left_range_hasher;
 mod inline_right_range_hasher;
@@ -15,3 +18,4 @@ mod left_range_hasher;
 mod length_hasher;
 mod right_range_hasher;
 mod scalar_hasher;
+mod slice_key;
@@ -1,4 +1,6 @@
 use crate::DefaultBuildHasher;
+use crate::diagnostics::KeyHashing;
+use crate::hashers::SliceKey;
 use crate::traits::Hasher;
 use crate::utils::cold;
 use core::hash::{BuildHasher, Hash};
@@ -19,6 +21,10 @@ impl<BH> RightRangeHasher<BH> {
     pub const fn new(bh: BH, range: Range<usize>) -> Self {
         Self { bh, range }
     }
+
+    pub(crate) const fn build_hasher(&self) -> &BH {
+        &self.bh
+    }
 }
 
 impl<T, BH> Hasher<[T]> for RightRangeHasher<BH>
@@ -36,41 +42,39 @@ where
         let effective_range = value.len() - self.range.end..value.len() - self.range.start;
         self.bh.hash_one(&value[effective_range])
     }
-}
 
-impl<BH> Hasher<str> for RightRangeHasher<BH>
-where
-    BH: BuildHasher,
-{
-    #[inline]
-    fn hash_one(&self, value: &str) -> u64 {
-        let b = value.as_bytes();
-        if b.len() < self.range.end {
-            cold();
-            return 0;
-        }
+    fn key_hashing(&self) -> KeyHashing {
+        KeyHashing::RightSubslice
+    }
 
-        let effective_range = value.len() - self.range.end..value.len() - self.range.start;
-        self.bh.hash_one(&b[effective_range])
+    fn subslice_range(&self) -> Option<Range<usize>> {
+        Some(self.range.clone())
     }
 }
 
-impl<AR, BH> Hasher<AR> for RightRangeHasher<BH>
+impl<K, BH> Hasher<K> for RightRangeHasher<BH>
 where
-    AR: AsRef<str>,
+    K: ?Sized + SliceKey,
     BH: BuildHasher,
 {
     #[inline]
-    fn hash_one(&self, value: &AR) -> u64 {
-        let value = value.as_ref();
-        let b = value.as_bytes();
-        if b.len() < self.range.end {
+    fn hash_one(&self, value: &K) -> u64 {
+        let slice = value.as_slice();
+        if slice.len() < self.range.end {
             cold();
             return 0;
         }
 
-        let effective_range = value.len() - self.range.end..value.len() - self.range.start;
-        self.bh.hash_one(&b[effective_range])
+        let effective_range = slice.len() - self.range.end..slice.len() - self.range.start;
+        K::hash_slice(&self.bh, &slice[effective_range])
+    }
+
+    fn key_hashing(&self) -> KeyHashing {
+        KeyHashing::RightSubslice
+    }
+
+    fn subslice_range(&self) -> Option<Range<usize>> {
+        Some(self.range.clone())
     }
 }
 
@@ -86,6 +90,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::keys::{AsciiCaseInsensitive, hash_ascii_lowercase};
     use alloc::vec;
     use foldhash::fast::RandomState;
 
@@ -126,6 +131,29 @@ mod tests {
         assert_eq!(hasher.hash_one("a"), 0);
     }
 
+    #[test]
+    fn test_right_range_hasher_hash_byte_keys() {
+        let hasher = RightRangeHasher::new(RandomState::default(), 1..3);
+        assert_eq!(hasher.hash_one(&b"abcd".to_vec()), hasher.bh.hash_one(b"bc"));
+        assert_eq!(hasher.hash_one(&Box::<[u8]>::from(&b"abcd"[..])), hasher.hash_one("abcd"));
+        assert_eq!(hasher.hash_one(&&b"abcd"[..]), hasher.hash_one(&"abcd"));
+        assert_eq!(hasher.hash_one(&b"a".to_vec()), 0);
+    }
+
+    #[test]
+    fn test_right_range_hasher_hash_ascii_case_insensitive() {
+        let hasher = RightRangeHasher::new(RandomState::default(), 1..3);
+        assert_eq!(
+            hasher.hash_one(&AsciiCaseInsensitive("ABcd")),
+            hasher.hash_one(AsciiCaseInsensitive::from_ref("abCD"))
+        );
+        assert_eq!(
+            hasher.hash_one(&AsciiCaseInsensitive("ABcd")),
+            hash_ascii_lowercase(&hasher.bh, b"bc")
+        );
+        assert_eq!(hasher.hash_one(&AsciiCaseInsensitive("A")), 0);
+    }
+
     #[test]
     fn test_right_range_hasher_default() {
         let hasher: RightRangeHasher = RightRangeHasher::default();
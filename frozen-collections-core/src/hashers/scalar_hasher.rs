@@ -1,3 +1,4 @@
+use crate::diagnostics::KeyHashing;
 use crate::traits::{Hasher, Scalar};
 
 /// Returns the value itself as the hash.
@@ -12,7 +13,11 @@ where
 {
     #[inline]
     fn hash_one(&self, value: &S) -> u64 {
-        value.index() as u64
+        value.hash_code()
+    }
+
+    fn key_hashing(&self) -> KeyHashing {
+        KeyHashing::Scalar
     }
 }
 
@@ -27,5 +32,7 @@ mod tests {
 
         assert_eq!(hasher.hash_one(&42u64), 42u64);
         assert_eq!(hasher.hash_one(&0u64), 0u64);
+        assert_eq!(hasher.hash_one(&42u128), 42u64);
+        assert_ne!(hasher.hash_one(&(1u128 << 100)), hasher.hash_one(&(1u128 << 101)));
     }
 }
@@ -0,0 +1,125 @@
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::hash::{BuildHasher, Hash};
+
+#[cfg(not(feature = "std"))]
+use {alloc::boxed::Box, alloc::string::String, alloc::vec::Vec};
+
+/// Keys which the range hashers view as slices.
+///
+/// Strings are viewed as their UTF-8 bytes, so a string key and a byte key with the
+/// same content produce the same hash code.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+pub trait SliceKey {
+    /// The type of the elements of the slice.
+    type Item: Hash;
+
+    /// Returns the key as a slice.
+    fn as_slice(&self) -> &[Self::Item];
+
+    /// Hashes a portion of the slice returned by [`as_slice`](Self::as_slice).
+    ///
+    /// Keys which compare equal in spite of having different slices override this to hash the
+    /// slices the same way.
+    #[inline]
+    fn hash_slice<BH: BuildHasher>(bh: &BH, slice: &[Self::Item]) -> u64 {
+        bh.hash_one(slice)
+    }
+}
+
+impl SliceKey for str {
+    type Item = u8;
+
+    fn as_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl SliceKey for String {
+    type Item = u8;
+
+    fn as_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl SliceKey for &str {
+    type Item = u8;
+
+    fn as_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl SliceKey for Box<str> {
+    type Item = u8;
+
+    fn as_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl SliceKey for Rc<str> {
+    type Item = u8;
+
+    fn as_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl SliceKey for Arc<str> {
+    type Item = u8;
+
+    fn as_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T: Hash> SliceKey for Vec<T> {
+    type Item = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Hash> SliceKey for &[T] {
+    type Item = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Hash> SliceKey for Box<[T]> {
+    type Item = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Hash> SliceKey for Rc<[T]> {
+    type Item = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Hash> SliceKey for Arc<[T]> {
+    type Item = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Hash, const N: usize> SliceKey for [T; N] {
+    type Item = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
@@ -0,0 +1,214 @@
+use crate::maps::decl_macros::{
+    bitset_scalar_lookup_primary_funcs, common_primary_funcs, debug_trait_funcs, get_disjoint_mut_funcs, index_trait_funcs,
+    into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs,
+    map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs, sorted_query_funcs,
+};
+use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::sets::BitsetView;
+use crate::traits::{CollectionMagnitude, Len, Map, MapExtras, MapIteration, MapQuery, Scalar, SmallCollection};
+use core::fmt::{Debug, Formatter, Result};
+use core::ops::Index;
+use equivalent::Comparable;
+
+#[cfg(feature = "serde")]
+use {
+    crate::maps::decl_macros::serialize_trait_funcs,
+    serde::ser::SerializeMap,
+    serde::{Serialize, Serializer},
+};
+
+/// A map whose keys are a sparse range of integers, located with a bitset.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+#[doc = include_str!("../doc_snippets/about.md")]
+///
+/// # Type Parameters
+///
+/// - `K`: The key type.
+/// - `V`: The value type.
+/// - `CM`: The magnitude of the map, one of [`SmallCollection`](SmallCollection), [`MediumCollection`](crate::traits::MediumCollection), or [`LargeCollection`](crate::traits::LargeCollection).
+/// - `SZ`: The number of entries in the map.
+/// - `WSZ`: The number of words in the bitset.
+#[derive(Clone)]
+pub struct InlineBitsetScalarLookupMap<K, V, const SZ: usize, const WSZ: usize, CM = SmallCollection> {
+    base: usize,
+    words: [u64; WSZ],
+    ranks: [CM; WSZ],
+    entries: [(K, V); SZ],
+}
+
+impl<K, V, const SZ: usize, const WSZ: usize, CM> InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+{
+    /// Creates a frozen map.
+    #[must_use]
+    pub const fn new_raw(sorted_and_dedupped_entries: [(K, V); SZ], words: [u64; WSZ], ranks: [CM; WSZ], base: usize) -> Self {
+        Self {
+            base,
+            words,
+            ranks,
+            entries: sorted_and_dedupped_entries,
+        }
+    }
+
+    /// Transforms the values of the map, keeping its layout.
+    pub(crate) fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> InlineBitsetScalarLookupMap<K, U, SZ, WSZ, CM> {
+        InlineBitsetScalarLookupMap {
+            base: self.base,
+            words: self.words,
+            ranks: self.ranks,
+            entries: self.entries.map(|(k, v)| {
+                let v = f(&k, v);
+                (k, v)
+            }),
+        }
+    }
+
+    bitset_scalar_lookup_primary_funcs!(Into::into);
+    common_primary_funcs!(const_len, entries);
+}
+
+impl<K, const SZ: usize, const WSZ: usize, CM> InlineBitsetScalarLookupMap<K, (), SZ, WSZ, CM> {
+    pub(crate) fn bitset(&self) -> BitsetView<'_, K> {
+        BitsetView::new(self.base, &self.words, &self.entries)
+    }
+}
+
+impl<K, V, Q, const SZ: usize, const WSZ: usize, CM> Map<K, V, Q> for InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+    Q: Scalar + Comparable<K>,
+{
+}
+
+impl<K, V, Q, const SZ: usize, const WSZ: usize, CM> MapExtras<K, V, Q> for InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+    Q: Scalar + Comparable<K>,
+{
+    map_extras_trait_funcs!();
+}
+
+impl<K, V, Q, const SZ: usize, const WSZ: usize, CM> MapQuery<Q, V> for InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+    Q: Scalar + Comparable<K>,
+{
+    map_query_trait_funcs!();
+}
+
+impl<K, V, const SZ: usize, const WSZ: usize, CM> MapIteration<K, V> for InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+{
+    type Iterator<'a>
+        = Iter<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        CM: 'a;
+
+    type KeyIterator<'a>
+        = Keys<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        CM: 'a;
+
+    type ValueIterator<'a>
+        = Values<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        CM: 'a;
+
+    type MutIterator<'a>
+        = IterMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        CM: 'a;
+
+    type ValueMutIterator<'a>
+        = ValuesMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        CM: 'a;
+
+    map_iteration_trait_funcs!();
+}
+
+impl<K, V, const SZ: usize, const WSZ: usize, CM> Len for InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+{
+    len_trait_funcs!();
+}
+
+impl<K, V, Q, const SZ: usize, const WSZ: usize, CM> Index<&Q> for InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    Q: Comparable<K> + Scalar,
+    CM: CollectionMagnitude,
+{
+    index_trait_funcs!();
+}
+
+impl<K, V, const SZ: usize, const WSZ: usize, CM> IntoIterator for InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+{
+    into_iterator_trait_funcs!();
+}
+
+impl<'a, K, V, const SZ: usize, const WSZ: usize, CM> IntoIterator for &'a InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+{
+    into_iterator_trait_ref_funcs!();
+}
+
+impl<'a, K, V, const SZ: usize, const WSZ: usize, CM> IntoIterator for &'a mut InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+{
+    into_iterator_trait_mut_ref_funcs!();
+}
+
+impl<K, V, MT, const SZ: usize, const WSZ: usize, CM> PartialEq<MT> for InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    K: Scalar,
+    V: PartialEq,
+    MT: MapQuery<K, V>,
+    CM: CollectionMagnitude,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<K, V, const SZ: usize, const WSZ: usize, CM> Eq for InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    K: Scalar,
+    V: Eq,
+    CM: CollectionMagnitude,
+{
+}
+
+impl<K, V, const SZ: usize, const WSZ: usize, CM> Debug for InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    K: Debug,
+    V: Debug,
+    CM: CollectionMagnitude,
+{
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<K, V, const SZ: usize, const WSZ: usize, CM> Serialize for InlineBitsetScalarLookupMap<K, V, SZ, WSZ, CM>
+where
+    K: Serialize,
+    V: Serialize,
+    CM: CollectionMagnitude,
+{
+    serialize_trait_funcs!();
+}
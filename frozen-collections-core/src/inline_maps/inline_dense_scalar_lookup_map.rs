@@ -1,9 +1,10 @@
 use crate::maps::decl_macros::{
     common_primary_funcs, debug_trait_funcs, dense_scalar_lookup_primary_funcs, get_disjoint_mut_funcs, index_trait_funcs,
     into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs,
-    map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs,
+    map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs, sorted_query_funcs,
 };
 use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::sets::BitsetView;
 use crate::traits::{Len, Map, MapExtras, MapIteration, MapQuery, Scalar};
 use core::fmt::{Debug, Formatter, Result};
 use core::ops::Index;
@@ -47,10 +48,28 @@ impl<K, V, const SZ: usize> InlineDenseScalarLookupMap<K, V, SZ> {
         }
     }
 
+    /// Transforms the values of the map, keeping its layout.
+    pub(crate) fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> InlineDenseScalarLookupMap<K, U, SZ> {
+        InlineDenseScalarLookupMap {
+            min: self.min,
+            max: self.max,
+            entries: self.entries.map(|(k, v)| {
+                let v = f(&k, v);
+                (k, v)
+            }),
+        }
+    }
+
     dense_scalar_lookup_primary_funcs!();
     common_primary_funcs!(const_len, entries);
 }
 
+impl<K, const SZ: usize> InlineDenseScalarLookupMap<K, (), SZ> {
+    pub(crate) const fn bitset(&self) -> BitsetView<'_, K> {
+        BitsetView::dense(self.min, &self.entries)
+    }
+}
+
 impl<K, V, Q, const SZ: usize> Map<K, V, Q> for InlineDenseScalarLookupMap<K, V, SZ> where Q: Scalar + Comparable<K> {}
 
 impl<K, V, Q, const SZ: usize> MapExtras<K, V, Q> for InlineDenseScalarLookupMap<K, V, SZ>
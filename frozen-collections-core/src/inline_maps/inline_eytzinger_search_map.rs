@@ -1,13 +1,15 @@
 use crate::maps::decl_macros::{
     common_primary_funcs, debug_trait_funcs, eytzinger_search_primary_funcs, get_disjoint_mut_funcs, index_trait_funcs,
     into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs,
-    map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs,
+    map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs, sorted_query_funcs,
 };
-use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Range, Values, ValuesMut};
 use crate::traits::{Len, Map, MapExtras, MapIteration, MapQuery};
-use crate::utils::{SortedAndDeduppedVec, eytzinger_layout, eytzinger_search_by};
+use crate::utils::{
+    SortedAndDeduppedVec, eytzinger_layout, eytzinger_range_by, eytzinger_search_by, eytzinger_to_sorted_index, sorted_to_eytzinger_index,
+};
 use core::fmt::{Debug, Formatter, Result};
-use core::ops::Index;
+use core::ops::{Index, RangeBounds};
 use equivalent::Comparable;
 
 #[cfg(not(feature = "std"))]
@@ -66,6 +68,16 @@ impl<K, V, const SZ: usize> InlineEytzingerSearchMap<K, V, SZ> {
         }
     }
 
+    /// Transforms the values of the map, keeping its layout.
+    pub(crate) fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> InlineEytzingerSearchMap<K, U, SZ> {
+        InlineEytzingerSearchMap {
+            entries: self.entries.map(|(k, v)| {
+                let v = f(&k, v);
+                (k, v)
+            }),
+        }
+    }
+
     eytzinger_search_primary_funcs!();
     common_primary_funcs!(const_len, entries);
 }
@@ -48,6 +48,17 @@ where
         Self { entries: table, hasher }
     }
 
+    /// Transforms the values of the map, keeping its layout.
+    pub(crate) fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> InlineHashMapNoCollisions<K, U, SZ, NHS, CM, H> {
+        InlineHashMapNoCollisions {
+            hasher: self.hasher,
+            entries: self.entries.map_entries(|(k, v)| {
+                let v = f(&k, v);
+                (k, v)
+            }),
+        }
+    }
+
     hash_primary_funcs!();
     common_primary_funcs!(const_len, entries entries);
 }
@@ -0,0 +1,181 @@
+use crate::hash_tables::InlinePerfectHashTable;
+use crate::hashers::BridgeHasher;
+use crate::maps::decl_macros::{
+    common_primary_funcs, debug_trait_funcs, get_disjoint_mut_funcs, hash_primary_funcs, index_trait_funcs, into_iterator_trait_funcs,
+    into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs,
+    map_query_trait_funcs, partial_eq_trait_funcs,
+};
+use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::traits::{Hasher, Len, Map, MapExtras, MapIteration, MapQuery};
+use core::fmt::{Debug, Formatter, Result};
+use core::ops::Index;
+use equivalent::Equivalent;
+
+#[cfg(feature = "serde")]
+use {
+    crate::maps::decl_macros::serialize_trait_funcs,
+    serde::ser::SerializeMap,
+    serde::{Serialize, Serializer},
+};
+
+/// A general-purpose map implemented using a minimal perfect hash table.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+#[doc = include_str!("../doc_snippets/about.md")]
+#[doc = include_str!("../doc_snippets/hash_warning.md")]
+///
+/// # Type Parameters
+///
+/// - `K`: The key type.
+/// - `V`: The value type.
+/// - `SZ`: The number of entries in the map.
+/// - `NB`: The number of buckets in the hash table.
+/// - `H`: The hasher to generate hash codes.
+#[derive(Clone)]
+pub struct InlinePerfectHashMap<K, V, const SZ: usize, const NB: usize, H = BridgeHasher> {
+    entries: InlinePerfectHashTable<(K, V), SZ, NB>,
+    hasher: H,
+}
+
+impl<K, V, const SZ: usize, const NB: usize, H> InlinePerfectHashMap<K, V, SZ, NB, H> {
+    /// Creates a frozen map.
+    #[must_use]
+    pub const fn new_raw(table: InlinePerfectHashTable<(K, V), SZ, NB>, hasher: H) -> Self {
+        Self { entries: table, hasher }
+    }
+
+    /// Transforms the values of the map, keeping its layout.
+    pub(crate) fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> InlinePerfectHashMap<K, U, SZ, NB, H> {
+        InlinePerfectHashMap {
+            hasher: self.hasher,
+            entries: self.entries.map_entries(|(k, v)| {
+                let v = f(&k, v);
+                (k, v)
+            }),
+        }
+    }
+
+    hash_primary_funcs!();
+    common_primary_funcs!(const_len, entries entries);
+}
+
+impl<K, V, Q, const SZ: usize, const NB: usize, H> Map<K, V, Q> for InlinePerfectHashMap<K, V, SZ, NB, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+}
+
+impl<K, V, Q, const SZ: usize, const NB: usize, H> MapExtras<K, V, Q> for InlinePerfectHashMap<K, V, SZ, NB, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+    map_extras_trait_funcs!();
+}
+
+impl<K, V, Q, const SZ: usize, const NB: usize, H> MapQuery<Q, V> for InlinePerfectHashMap<K, V, SZ, NB, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+    map_query_trait_funcs!();
+}
+
+impl<K, V, const SZ: usize, const NB: usize, H> MapIteration<K, V> for InlinePerfectHashMap<K, V, SZ, NB, H> {
+    type Iterator<'a>
+        = Iter<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type KeyIterator<'a>
+        = Keys<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type ValueIterator<'a>
+        = Values<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type MutIterator<'a>
+        = IterMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type ValueMutIterator<'a>
+        = ValuesMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    map_iteration_trait_funcs!();
+}
+
+impl<K, V, const SZ: usize, const NB: usize, H> Len for InlinePerfectHashMap<K, V, SZ, NB, H> {
+    len_trait_funcs!();
+}
+
+impl<Q, K, V, const SZ: usize, const NB: usize, H> Index<&Q> for InlinePerfectHashMap<K, V, SZ, NB, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+    index_trait_funcs!();
+}
+
+impl<K, V, const SZ: usize, const NB: usize, H> IntoIterator for InlinePerfectHashMap<K, V, SZ, NB, H> {
+    into_iterator_trait_funcs!();
+}
+
+impl<'a, K, V, const SZ: usize, const NB: usize, H> IntoIterator for &'a InlinePerfectHashMap<K, V, SZ, NB, H> {
+    into_iterator_trait_ref_funcs!();
+}
+
+impl<'a, K, V, const SZ: usize, const NB: usize, H> IntoIterator for &'a mut InlinePerfectHashMap<K, V, SZ, NB, H> {
+    into_iterator_trait_mut_ref_funcs!();
+}
+
+impl<K, V, MT, const SZ: usize, const NB: usize, H> PartialEq<MT> for InlinePerfectHashMap<K, V, SZ, NB, H>
+where
+    K: PartialEq,
+    V: PartialEq,
+    MT: MapQuery<K, V>,
+    H: Hasher<K>,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<K, V, const SZ: usize, const NB: usize, H> Eq for InlinePerfectHashMap<K, V, SZ, NB, H>
+where
+    K: Eq,
+    V: Eq,
+    H: Hasher<K>,
+{
+}
+
+impl<K, V, const SZ: usize, const NB: usize, H> Debug for InlinePerfectHashMap<K, V, SZ, NB, H>
+where
+    K: Debug,
+    V: Debug,
+{
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<K, V, const SZ: usize, const NB: usize, H> Serialize for InlinePerfectHashMap<K, V, SZ, NB, H>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    serialize_trait_funcs!();
+}
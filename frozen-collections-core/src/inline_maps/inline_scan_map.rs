@@ -55,6 +55,16 @@ impl<K, V, const SZ: usize> InlineScanMap<K, V, SZ> {
         Self { entries: dedupped_entries }
     }
 
+    /// Transforms the values of the map, keeping its layout.
+    pub(crate) fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> InlineScanMap<K, U, SZ> {
+        InlineScanMap {
+            entries: self.entries.map(|(k, v)| {
+                let v = f(&k, v);
+                (k, v)
+            }),
+        }
+    }
+
     scan_primary_funcs!();
     common_primary_funcs!(const_len, entries);
 }
@@ -0,0 +1,181 @@
+use crate::hash_tables::InlineSimdProbeTable;
+use crate::hashers::BridgeHasher;
+use crate::maps::decl_macros::{
+    common_primary_funcs, debug_trait_funcs, get_disjoint_mut_funcs, hash_primary_funcs, index_trait_funcs, into_iterator_trait_funcs,
+    into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs,
+    map_query_trait_funcs, partial_eq_trait_funcs,
+};
+use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::traits::{Hasher, Len, Map, MapExtras, MapIteration, MapQuery};
+use core::fmt::{Debug, Formatter, Result};
+use core::ops::Index;
+use equivalent::Equivalent;
+
+#[cfg(feature = "serde")]
+use {
+    crate::maps::decl_macros::serialize_trait_funcs,
+    serde::ser::SerializeMap,
+    serde::{Serialize, Serializer},
+};
+
+/// A map for small collections implemented by comparing hash tags in parallel.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+#[doc = include_str!("../doc_snippets/about.md")]
+#[doc = include_str!("../doc_snippets/hash_warning.md")]
+///
+/// # Type Parameters
+///
+/// - `K`: The key type.
+/// - `V`: The value type.
+/// - `SZ`: The number of entries in the map.
+/// - `NT`: The number of hash tags, which is `SZ` rounded up to a multiple of 16.
+/// - `H`: The hasher to generate hash codes.
+#[derive(Clone)]
+pub struct InlineSimdProbeMap<K, V, const SZ: usize, const NT: usize, H = BridgeHasher> {
+    entries: InlineSimdProbeTable<(K, V), SZ, NT>,
+    hasher: H,
+}
+
+impl<K, V, const SZ: usize, const NT: usize, H> InlineSimdProbeMap<K, V, SZ, NT, H> {
+    /// Creates a frozen map.
+    #[must_use]
+    pub const fn new_raw(table: InlineSimdProbeTable<(K, V), SZ, NT>, hasher: H) -> Self {
+        Self { entries: table, hasher }
+    }
+
+    /// Transforms the values of the map, keeping its layout.
+    pub(crate) fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> InlineSimdProbeMap<K, U, SZ, NT, H> {
+        InlineSimdProbeMap {
+            hasher: self.hasher,
+            entries: self.entries.map_entries(|(k, v)| {
+                let v = f(&k, v);
+                (k, v)
+            }),
+        }
+    }
+
+    hash_primary_funcs!();
+    common_primary_funcs!(const_len, entries entries);
+}
+
+impl<K, V, Q, const SZ: usize, const NT: usize, H> Map<K, V, Q> for InlineSimdProbeMap<K, V, SZ, NT, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+}
+
+impl<K, V, Q, const SZ: usize, const NT: usize, H> MapExtras<K, V, Q> for InlineSimdProbeMap<K, V, SZ, NT, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+    map_extras_trait_funcs!();
+}
+
+impl<K, V, Q, const SZ: usize, const NT: usize, H> MapQuery<Q, V> for InlineSimdProbeMap<K, V, SZ, NT, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+    map_query_trait_funcs!();
+}
+
+impl<K, V, const SZ: usize, const NT: usize, H> MapIteration<K, V> for InlineSimdProbeMap<K, V, SZ, NT, H> {
+    type Iterator<'a>
+        = Iter<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type KeyIterator<'a>
+        = Keys<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type ValueIterator<'a>
+        = Values<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type MutIterator<'a>
+        = IterMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type ValueMutIterator<'a>
+        = ValuesMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    map_iteration_trait_funcs!();
+}
+
+impl<K, V, const SZ: usize, const NT: usize, H> Len for InlineSimdProbeMap<K, V, SZ, NT, H> {
+    len_trait_funcs!();
+}
+
+impl<Q, K, V, const SZ: usize, const NT: usize, H> Index<&Q> for InlineSimdProbeMap<K, V, SZ, NT, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+    index_trait_funcs!();
+}
+
+impl<K, V, const SZ: usize, const NT: usize, H> IntoIterator for InlineSimdProbeMap<K, V, SZ, NT, H> {
+    into_iterator_trait_funcs!();
+}
+
+impl<'a, K, V, const SZ: usize, const NT: usize, H> IntoIterator for &'a InlineSimdProbeMap<K, V, SZ, NT, H> {
+    into_iterator_trait_ref_funcs!();
+}
+
+impl<'a, K, V, const SZ: usize, const NT: usize, H> IntoIterator for &'a mut InlineSimdProbeMap<K, V, SZ, NT, H> {
+    into_iterator_trait_mut_ref_funcs!();
+}
+
+impl<K, V, MT, const SZ: usize, const NT: usize, H> PartialEq<MT> for InlineSimdProbeMap<K, V, SZ, NT, H>
+where
+    K: PartialEq,
+    V: PartialEq,
+    MT: MapQuery<K, V>,
+    H: Hasher<K>,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<K, V, const SZ: usize, const NT: usize, H> Eq for InlineSimdProbeMap<K, V, SZ, NT, H>
+where
+    K: Eq,
+    V: Eq,
+    H: Hasher<K>,
+{
+}
+
+impl<K, V, const SZ: usize, const NT: usize, H> Debug for InlineSimdProbeMap<K, V, SZ, NT, H>
+where
+    K: Debug,
+    V: Debug,
+{
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<K, V, const SZ: usize, const NT: usize, H> Serialize for InlineSimdProbeMap<K, V, SZ, NT, H>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    serialize_trait_funcs!();
+}
@@ -1,7 +1,7 @@
 use crate::maps::decl_macros::{
     common_primary_funcs, debug_trait_funcs, get_disjoint_mut_funcs, index_trait_funcs, into_iterator_trait_funcs,
     into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs,
-    map_query_trait_funcs, partial_eq_trait_funcs, sparse_scalar_lookup_primary_funcs,
+    map_query_trait_funcs, partial_eq_trait_funcs, sorted_query_funcs, sparse_scalar_lookup_primary_funcs,
 };
 use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
 use crate::traits::{CollectionMagnitude, Len, Map, MapExtras, MapIteration, MapQuery, Scalar, SmallCollection};
@@ -51,6 +51,19 @@ where
         }
     }
 
+    /// Transforms the values of the map, keeping its layout.
+    pub(crate) fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> InlineSparseScalarLookupMap<K, U, SZ, LTSZ, CM> {
+        InlineSparseScalarLookupMap {
+            min: self.min,
+            max: self.max,
+            lookup: self.lookup,
+            entries: self.entries.map(|(k, v)| {
+                let v = f(&k, v);
+                (k, v)
+            }),
+        }
+    }
+
     sparse_scalar_lookup_primary_funcs!();
     common_primary_funcs!(const_len, entries);
 }
@@ -0,0 +1,212 @@
+use crate::columns::KeyIndex;
+use crate::inline_maps::InlineBitsetScalarLookupMap;
+use crate::sets::decl_macros::{
+    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, diagnostics_funcs,
+    into_iterator_ref_trait_funcs, into_iterator_trait_funcs, partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs,
+    set_query_trait_funcs, sorted_query_funcs, sub_trait_funcs,
+};
+use crate::sets::{BitsetView, IntoIter, Iter};
+use crate::traits::{CollectionMagnitude, Len, Scalar, Set, SetExtras, SetIteration, SetOps, SetQuery, SmallCollection};
+use crate::utils::assign_slots;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::ops::{BitAnd, BitOr, BitXor, Sub};
+use equivalent::Comparable;
+
+use crate::maps::decl_macros::len_trait_funcs;
+#[cfg(feature = "serde")]
+use {
+    crate::sets::decl_macros::serialize_trait_funcs,
+    serde::ser::SerializeSeq,
+    serde::{Serialize, Serializer},
+};
+
+/// A set whose values are scalars, stored in a bitset.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+#[doc = include_str!("../doc_snippets/about.md")]
+///
+/// # Type Parameters
+///
+/// - `T`: The value type.
+/// - `CM`: The magnitude of the set, one of [`SmallCollection`](SmallCollection), [`MediumCollection`](crate::traits::MediumCollection), or [`LargeCollection`](crate::traits::LargeCollection).
+/// - `SZ`: The number of entries in the set.
+/// - `WSZ`: The number of words in the bitset.
+#[derive(Clone)]
+pub struct InlineBitsetScalarLookupSet<T, const SZ: usize, const WSZ: usize, CM = SmallCollection> {
+    map: InlineBitsetScalarLookupMap<T, (), SZ, WSZ, CM>,
+}
+
+impl<T, const SZ: usize, const WSZ: usize, CM> InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+{
+    /// Creates a frozen set.
+    #[must_use]
+    pub const fn new(map: InlineBitsetScalarLookupMap<T, (), SZ, WSZ, CM>) -> Self {
+        Self { map }
+    }
+
+    #[doc = include_str!("../doc_snippets/get_from_set.md")]
+    #[inline]
+    #[must_use]
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        Q: Comparable<T> + Scalar,
+    {
+        Some(self.map.get_key_value(value)?.0)
+    }
+
+    #[doc = include_str!("../doc_snippets/contains.md")]
+    #[inline]
+    #[must_use]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        Q: Comparable<T> + Scalar,
+    {
+        self.get(value).is_some()
+    }
+
+    sorted_query_funcs!("Scalar");
+    common_primary_funcs!(const_len);
+    diagnostics_funcs!();
+}
+
+impl<T, const SZ: usize, const WSZ: usize, CM> From<InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>>
+    for KeyIndex<InlineBitsetScalarLookupMap<T, usize, SZ, WSZ, CM>>
+where
+    CM: CollectionMagnitude,
+{
+    fn from(set: InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
+}
+
+impl<T, Q, const SZ: usize, const WSZ: usize, CM> Set<T, Q> for InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+    Q: Comparable<T> + Scalar,
+{
+}
+
+impl<T, Q, const SZ: usize, const WSZ: usize, CM> SetExtras<T, Q> for InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+    Q: Scalar + Comparable<T>,
+{
+    set_extras_trait_funcs!();
+}
+
+impl<T, Q, const SZ: usize, const WSZ: usize, CM> SetQuery<Q> for InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+    Q: Scalar + Comparable<T>,
+{
+    set_query_trait_funcs!();
+}
+
+impl<T, const SZ: usize, const WSZ: usize, CM> SetIteration<T> for InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+{
+    type Iterator<'a>
+        = Iter<'a, T>
+    where
+        T: 'a,
+        CM: 'a;
+
+    set_iteration_trait_funcs!();
+
+    fn bitset(&self) -> Option<BitsetView<'_, T>> {
+        Some(self.map.bitset())
+    }
+}
+
+impl<T, const SZ: usize, const WSZ: usize, CM> Len for InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+{
+    len_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const WSZ: usize, CM> BitOr<&ST> for &InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    T: Scalar + Hash,
+    ST: Set<T>,
+    CM: CollectionMagnitude,
+{
+    bitor_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const WSZ: usize, CM> BitAnd<&ST> for &InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    T: Scalar + Hash,
+    ST: Set<T>,
+    CM: CollectionMagnitude,
+{
+    bitand_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const WSZ: usize, CM> BitXor<&ST> for &InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    T: Scalar + Hash,
+    ST: Set<T>,
+    CM: CollectionMagnitude,
+{
+    bitxor_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const WSZ: usize, CM> Sub<&ST> for &InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    T: Scalar + Hash,
+    ST: Set<T>,
+    CM: CollectionMagnitude,
+{
+    sub_trait_funcs!();
+}
+
+impl<T, const SZ: usize, const WSZ: usize, CM> IntoIterator for InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+{
+    into_iterator_trait_funcs!();
+}
+
+impl<'a, T, const SZ: usize, const WSZ: usize, CM> IntoIterator for &'a InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    CM: CollectionMagnitude,
+{
+    into_iterator_ref_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const WSZ: usize, CM> PartialEq<ST> for InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    ST: SetQuery<T>,
+    CM: CollectionMagnitude,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<T, const SZ: usize, const WSZ: usize, CM> Eq for InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    T: Scalar,
+    CM: CollectionMagnitude,
+{
+}
+
+impl<T, const SZ: usize, const WSZ: usize, CM> Debug for InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    T: Debug,
+    CM: CollectionMagnitude,
+{
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<T, const SZ: usize, const WSZ: usize, CM> Serialize for InlineBitsetScalarLookupSet<T, SZ, WSZ, CM>
+where
+    T: Serialize,
+    CM: CollectionMagnitude,
+{
+    serialize_trait_funcs!();
+}
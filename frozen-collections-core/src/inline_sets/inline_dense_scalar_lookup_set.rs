@@ -3,14 +3,16 @@ use core::hash::Hash;
 use core::ops::{BitAnd, BitOr, BitXor, Sub};
 use equivalent::Comparable;
 
+use crate::columns::KeyIndex;
 use crate::inline_maps::InlineDenseScalarLookupMap;
 use crate::sets::decl_macros::{
-    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, into_iterator_ref_trait_funcs,
-    into_iterator_trait_funcs, partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs, set_query_trait_funcs,
-    sub_trait_funcs,
+    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, diagnostics_funcs,
+    into_iterator_ref_trait_funcs, into_iterator_trait_funcs, partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs,
+    set_query_trait_funcs, sorted_query_funcs, sub_trait_funcs,
 };
-use crate::sets::{IntoIter, Iter};
+use crate::sets::{BitsetView, IntoIter, Iter};
 use crate::traits::{Len, Scalar, Set, SetExtras, SetIteration, SetOps, SetQuery};
+use crate::utils::assign_slots;
 
 use crate::maps::decl_macros::len_trait_funcs;
 #[cfg(feature = "serde")]
@@ -61,7 +63,15 @@ impl<T, const SZ: usize> InlineDenseScalarLookupSet<T, SZ> {
         self.get(value).is_some()
     }
 
+    sorted_query_funcs!("Scalar");
     common_primary_funcs!(const_len);
+    diagnostics_funcs!();
+}
+
+impl<T, const SZ: usize> From<InlineDenseScalarLookupSet<T, SZ>> for KeyIndex<InlineDenseScalarLookupMap<T, usize, SZ>> {
+    fn from(set: InlineDenseScalarLookupSet<T, SZ>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
 }
 
 impl<T, Q, const SZ: usize> Set<T, Q> for InlineDenseScalarLookupSet<T, SZ> where Q: Comparable<T> + Scalar {}
@@ -87,6 +97,10 @@ impl<T, const SZ: usize> SetIteration<T> for InlineDenseScalarLookupSet<T, SZ> {
         T: 'a;
 
     set_iteration_trait_funcs!();
+
+    fn bitset(&self) -> Option<BitsetView<'_, T>> {
+        Some(self.map.bitset())
+    }
 }
 
 impl<T, const SZ: usize> Len for InlineDenseScalarLookupSet<T, SZ> {
@@ -1,14 +1,16 @@
+use crate::columns::KeyIndex;
 use crate::inline_maps::InlineEytzingerSearchMap;
 use crate::sets::decl_macros::{
-    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, into_iterator_ref_trait_funcs,
-    into_iterator_trait_funcs, partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs, set_query_trait_funcs,
-    sub_trait_funcs,
+    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, diagnostics_funcs,
+    into_iterator_ref_trait_funcs, into_iterator_trait_funcs, ordered_primary_funcs, partial_eq_trait_funcs, set_extras_trait_funcs,
+    set_iteration_trait_funcs, set_query_trait_funcs, sorted_query_funcs, sub_trait_funcs,
 };
-use crate::sets::{IntoIter, Iter};
+use crate::sets::{IntoIter, Iter, Range};
 use crate::traits::{Len, Set, SetExtras, SetIteration, SetOps, SetQuery};
+use crate::utils::assign_slots;
 use core::fmt::Debug;
 use core::hash::Hash;
-use core::ops::{BitAnd, BitOr, BitXor, Sub};
+use core::ops::{BitAnd, BitOr, BitXor, RangeBounds, Sub};
 use equivalent::Comparable;
 
 use crate::maps::decl_macros::len_trait_funcs;
@@ -61,7 +63,16 @@ impl<T, const SZ: usize> InlineEytzingerSearchSet<T, SZ> {
         self.get(value).is_some()
     }
 
+    ordered_primary_funcs!();
+    sorted_query_funcs!("Ord");
     common_primary_funcs!(const_len);
+    diagnostics_funcs!();
+}
+
+impl<T, const SZ: usize> From<InlineEytzingerSearchSet<T, SZ>> for KeyIndex<InlineEytzingerSearchMap<T, usize, SZ>> {
+    fn from(set: InlineEytzingerSearchSet<T, SZ>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
 }
 
 impl<T, Q, const SZ: usize> Set<T, Q> for InlineEytzingerSearchSet<T, SZ> where Q: ?Sized + Comparable<T> {}
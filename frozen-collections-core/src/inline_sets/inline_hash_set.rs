@@ -1,12 +1,14 @@
+use crate::columns::KeyIndex;
 use crate::hashers::BridgeHasher;
 use crate::inline_maps::InlineHashMap;
 use crate::sets::decl_macros::{
-    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, hash_primary_funcs,
-    into_iterator_ref_trait_funcs, into_iterator_trait_funcs, partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs,
-    set_query_trait_funcs, sub_trait_funcs,
+    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, diagnostics_funcs,
+    hash_primary_funcs, into_iterator_ref_trait_funcs, into_iterator_trait_funcs, partial_eq_trait_funcs, set_extras_trait_funcs,
+    set_iteration_trait_funcs, set_query_trait_funcs, sub_trait_funcs,
 };
 use crate::sets::{IntoIter, Iter};
 use crate::traits::{CollectionMagnitude, Hasher, Len, Set, SetExtras, SetIteration, SetOps, SetQuery, SmallCollection};
+use crate::utils::assign_slots;
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::ops::{BitAnd, BitOr, BitXor, Sub};
@@ -52,6 +54,16 @@ where
     common_primary_funcs!(const_len);
 }
 
+impl<T, const SZ: usize, const NHS: usize, CM, H> From<InlineHashSet<T, SZ, NHS, CM, H>>
+    for KeyIndex<InlineHashMap<T, usize, SZ, NHS, CM, H>>
+where
+    CM: CollectionMagnitude,
+{
+    fn from(set: InlineHashSet<T, SZ, NHS, CM, H>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
+}
+
 impl<T, Q, const SZ: usize, const NHS: usize, CM, H> Set<T, Q> for InlineHashSet<T, SZ, NHS, CM, H>
 where
     Q: ?Sized + Equivalent<T>,
@@ -0,0 +1,172 @@
+use crate::columns::KeyIndex;
+use crate::hashers::BridgeHasher;
+use crate::inline_maps::InlinePerfectHashMap;
+use crate::sets::decl_macros::{
+    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, diagnostics_funcs,
+    hash_primary_funcs, into_iterator_ref_trait_funcs, into_iterator_trait_funcs, partial_eq_trait_funcs, set_extras_trait_funcs,
+    set_iteration_trait_funcs, set_query_trait_funcs, sub_trait_funcs,
+};
+use crate::sets::{IntoIter, Iter};
+use crate::traits::{Hasher, Len, Set, SetExtras, SetIteration, SetOps, SetQuery};
+use crate::utils::assign_slots;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::ops::{BitAnd, BitOr, BitXor, Sub};
+use equivalent::Equivalent;
+
+use crate::maps::decl_macros::len_trait_funcs;
+#[cfg(feature = "serde")]
+use {
+    crate::sets::decl_macros::serialize_trait_funcs,
+    serde::ser::SerializeSeq,
+    serde::{Serialize, Serializer},
+};
+
+/// A general-purpose set implemented using a minimal perfect hash table.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+#[doc = include_str!("../doc_snippets/about.md")]
+#[doc = include_str!("../doc_snippets/hash_warning.md")]
+///
+/// # Type Parameters
+///
+/// - `T`: The value type.
+/// - `SZ`: The number of entries in the set.
+/// - `NB`: The number of buckets in the hash table.
+/// - `H`: The hasher to generate hash codes.
+#[derive(Clone)]
+pub struct InlinePerfectHashSet<T, const SZ: usize, const NB: usize, H = BridgeHasher> {
+    map: InlinePerfectHashMap<T, (), SZ, NB, H>,
+}
+
+impl<T, const SZ: usize, const NB: usize, H> InlinePerfectHashSet<T, SZ, NB, H> {
+    /// Creates a frozen set.
+    #[must_use]
+    pub const fn new(map: InlinePerfectHashMap<T, (), SZ, NB, H>) -> Self {
+        Self { map }
+    }
+
+    hash_primary_funcs!();
+    common_primary_funcs!(const_len);
+}
+
+impl<T, const SZ: usize, const NB: usize, H> From<InlinePerfectHashSet<T, SZ, NB, H>>
+    for KeyIndex<InlinePerfectHashMap<T, usize, SZ, NB, H>>
+{
+    fn from(set: InlinePerfectHashSet<T, SZ, NB, H>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
+}
+
+impl<T, Q, const SZ: usize, const NB: usize, H> Set<T, Q> for InlinePerfectHashSet<T, SZ, NB, H>
+where
+    Q: ?Sized + Equivalent<T>,
+    H: Hasher<Q>,
+{
+}
+
+impl<T, Q, const SZ: usize, const NB: usize, H> SetExtras<T, Q> for InlinePerfectHashSet<T, SZ, NB, H>
+where
+    Q: ?Sized + Equivalent<T>,
+    H: Hasher<Q>,
+{
+    set_extras_trait_funcs!();
+}
+
+impl<T, Q, const SZ: usize, const NB: usize, H> SetQuery<Q> for InlinePerfectHashSet<T, SZ, NB, H>
+where
+    Q: ?Sized + Equivalent<T>,
+    H: Hasher<Q>,
+{
+    set_query_trait_funcs!();
+}
+
+impl<T, const SZ: usize, const NB: usize, H> SetIteration<T> for InlinePerfectHashSet<T, SZ, NB, H> {
+    type Iterator<'a>
+        = Iter<'a, T>
+    where
+        T: 'a,
+        H: 'a;
+
+    set_iteration_trait_funcs!();
+}
+
+impl<T, const SZ: usize, const NB: usize, H> Len for InlinePerfectHashSet<T, SZ, NB, H> {
+    len_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const NB: usize, H> BitOr<&ST> for &InlinePerfectHashSet<T, SZ, NB, H>
+where
+    T: Hash + Eq + Clone,
+    ST: Set<T>,
+    H: Hasher<T>,
+{
+    bitor_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const NB: usize, H> BitAnd<&ST> for &InlinePerfectHashSet<T, SZ, NB, H>
+where
+    T: Hash + Eq + Clone,
+    ST: Set<T>,
+    H: Hasher<T>,
+{
+    bitand_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const NB: usize, H> BitXor<&ST> for &InlinePerfectHashSet<T, SZ, NB, H>
+where
+    T: Hash + Eq + Clone,
+    ST: Set<T>,
+    H: Hasher<T>,
+{
+    bitxor_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const NB: usize, H> Sub<&ST> for &InlinePerfectHashSet<T, SZ, NB, H>
+where
+    T: Hash + Eq + Clone,
+    ST: Set<T>,
+    H: Hasher<T>,
+{
+    sub_trait_funcs!();
+}
+
+impl<T, const SZ: usize, const NB: usize, H> IntoIterator for InlinePerfectHashSet<T, SZ, NB, H> {
+    into_iterator_trait_funcs!();
+}
+
+impl<'a, T, const SZ: usize, const NB: usize, H> IntoIterator for &'a InlinePerfectHashSet<T, SZ, NB, H> {
+    into_iterator_ref_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const NB: usize, H> PartialEq<ST> for InlinePerfectHashSet<T, SZ, NB, H>
+where
+    T: PartialEq,
+    ST: SetQuery<T>,
+    H: Hasher<T>,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<T, const SZ: usize, const NB: usize, H> Eq for InlinePerfectHashSet<T, SZ, NB, H>
+where
+    T: Eq,
+    H: Hasher<T>,
+{
+}
+
+impl<T, const SZ: usize, const NB: usize, H> Debug for InlinePerfectHashSet<T, SZ, NB, H>
+where
+    T: Debug,
+    H: Hasher<T>,
+{
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<T, const SZ: usize, const NB: usize, H> Serialize for InlinePerfectHashSet<T, SZ, NB, H>
+where
+    T: Serialize,
+{
+    serialize_trait_funcs!();
+}
@@ -1,11 +1,13 @@
+use crate::columns::KeyIndex;
 use crate::inline_maps::InlineScanMap;
 use crate::sets::decl_macros::{
-    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, into_iterator_ref_trait_funcs,
-    into_iterator_trait_funcs, partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs, set_query_trait_funcs,
-    sub_trait_funcs,
+    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, diagnostics_funcs,
+    into_iterator_ref_trait_funcs, into_iterator_trait_funcs, partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs,
+    set_query_trait_funcs, sub_trait_funcs,
 };
 use crate::sets::{IntoIter, Iter};
 use crate::traits::{Len, Set, SetExtras, SetIteration, SetOps, SetQuery};
+use crate::utils::assign_slots;
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::ops::{BitAnd, BitOr, BitXor, Sub};
@@ -60,6 +62,13 @@ impl<T, const SZ: usize> InlineScanSet<T, SZ> {
     }
 
     common_primary_funcs!(const_len);
+    diagnostics_funcs!();
+}
+
+impl<T, const SZ: usize> From<InlineScanSet<T, SZ>> for KeyIndex<InlineScanMap<T, usize, SZ>> {
+    fn from(set: InlineScanSet<T, SZ>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
 }
 
 impl<T, Q, const SZ: usize> Set<T, Q> for InlineScanSet<T, SZ> where Q: ?Sized + Equivalent<T> {}
@@ -0,0 +1,170 @@
+use crate::columns::KeyIndex;
+use crate::hashers::BridgeHasher;
+use crate::inline_maps::InlineSimdProbeMap;
+use crate::sets::decl_macros::{
+    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, diagnostics_funcs,
+    hash_primary_funcs, into_iterator_ref_trait_funcs, into_iterator_trait_funcs, partial_eq_trait_funcs, set_extras_trait_funcs,
+    set_iteration_trait_funcs, set_query_trait_funcs, sub_trait_funcs,
+};
+use crate::sets::{IntoIter, Iter};
+use crate::traits::{Hasher, Len, Set, SetExtras, SetIteration, SetOps, SetQuery};
+use crate::utils::assign_slots;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::ops::{BitAnd, BitOr, BitXor, Sub};
+use equivalent::Equivalent;
+
+use crate::maps::decl_macros::len_trait_funcs;
+#[cfg(feature = "serde")]
+use {
+    crate::sets::decl_macros::serialize_trait_funcs,
+    serde::ser::SerializeSeq,
+    serde::{Serialize, Serializer},
+};
+
+/// A set for small collections implemented by comparing hash tags in parallel.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+#[doc = include_str!("../doc_snippets/about.md")]
+#[doc = include_str!("../doc_snippets/hash_warning.md")]
+///
+/// # Type Parameters
+///
+/// - `T`: The value type.
+/// - `SZ`: The number of entries in the set.
+/// - `NT`: The number of hash tags, which is `SZ` rounded up to a multiple of 16.
+/// - `H`: The hasher to generate hash codes.
+#[derive(Clone)]
+pub struct InlineSimdProbeSet<T, const SZ: usize, const NT: usize, H = BridgeHasher> {
+    map: InlineSimdProbeMap<T, (), SZ, NT, H>,
+}
+
+impl<T, const SZ: usize, const NT: usize, H> InlineSimdProbeSet<T, SZ, NT, H> {
+    /// Creates a frozen set.
+    #[must_use]
+    pub const fn new(map: InlineSimdProbeMap<T, (), SZ, NT, H>) -> Self {
+        Self { map }
+    }
+
+    hash_primary_funcs!();
+    common_primary_funcs!(const_len);
+}
+
+impl<T, const SZ: usize, const NT: usize, H> From<InlineSimdProbeSet<T, SZ, NT, H>> for KeyIndex<InlineSimdProbeMap<T, usize, SZ, NT, H>> {
+    fn from(set: InlineSimdProbeSet<T, SZ, NT, H>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
+}
+
+impl<T, Q, const SZ: usize, const NT: usize, H> Set<T, Q> for InlineSimdProbeSet<T, SZ, NT, H>
+where
+    Q: ?Sized + Equivalent<T>,
+    H: Hasher<Q>,
+{
+}
+
+impl<T, Q, const SZ: usize, const NT: usize, H> SetExtras<T, Q> for InlineSimdProbeSet<T, SZ, NT, H>
+where
+    Q: ?Sized + Equivalent<T>,
+    H: Hasher<Q>,
+{
+    set_extras_trait_funcs!();
+}
+
+impl<T, Q, const SZ: usize, const NT: usize, H> SetQuery<Q> for InlineSimdProbeSet<T, SZ, NT, H>
+where
+    Q: ?Sized + Equivalent<T>,
+    H: Hasher<Q>,
+{
+    set_query_trait_funcs!();
+}
+
+impl<T, const SZ: usize, const NT: usize, H> SetIteration<T> for InlineSimdProbeSet<T, SZ, NT, H> {
+    type Iterator<'a>
+        = Iter<'a, T>
+    where
+        T: 'a,
+        H: 'a;
+
+    set_iteration_trait_funcs!();
+}
+
+impl<T, const SZ: usize, const NT: usize, H> Len for InlineSimdProbeSet<T, SZ, NT, H> {
+    len_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const NT: usize, H> BitOr<&ST> for &InlineSimdProbeSet<T, SZ, NT, H>
+where
+    T: Hash + Eq + Clone,
+    ST: Set<T>,
+    H: Hasher<T>,
+{
+    bitor_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const NT: usize, H> BitAnd<&ST> for &InlineSimdProbeSet<T, SZ, NT, H>
+where
+    T: Hash + Eq + Clone,
+    ST: Set<T>,
+    H: Hasher<T>,
+{
+    bitand_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const NT: usize, H> BitXor<&ST> for &InlineSimdProbeSet<T, SZ, NT, H>
+where
+    T: Hash + Eq + Clone,
+    ST: Set<T>,
+    H: Hasher<T>,
+{
+    bitxor_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const NT: usize, H> Sub<&ST> for &InlineSimdProbeSet<T, SZ, NT, H>
+where
+    T: Hash + Eq + Clone,
+    ST: Set<T>,
+    H: Hasher<T>,
+{
+    sub_trait_funcs!();
+}
+
+impl<T, const SZ: usize, const NT: usize, H> IntoIterator for InlineSimdProbeSet<T, SZ, NT, H> {
+    into_iterator_trait_funcs!();
+}
+
+impl<'a, T, const SZ: usize, const NT: usize, H> IntoIterator for &'a InlineSimdProbeSet<T, SZ, NT, H> {
+    into_iterator_ref_trait_funcs!();
+}
+
+impl<T, ST, const SZ: usize, const NT: usize, H> PartialEq<ST> for InlineSimdProbeSet<T, SZ, NT, H>
+where
+    T: PartialEq,
+    ST: SetQuery<T>,
+    H: Hasher<T>,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<T, const SZ: usize, const NT: usize, H> Eq for InlineSimdProbeSet<T, SZ, NT, H>
+where
+    T: Eq,
+    H: Hasher<T>,
+{
+}
+
+impl<T, const SZ: usize, const NT: usize, H> Debug for InlineSimdProbeSet<T, SZ, NT, H>
+where
+    T: Debug,
+    H: Hasher<T>,
+{
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<T, const SZ: usize, const NT: usize, H> Serialize for InlineSimdProbeSet<T, SZ, NT, H>
+where
+    T: Serialize,
+{
+    serialize_trait_funcs!();
+}
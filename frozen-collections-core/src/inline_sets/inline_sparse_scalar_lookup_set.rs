@@ -1,11 +1,13 @@
+use crate::columns::KeyIndex;
 use crate::inline_maps::InlineSparseScalarLookupMap;
 use crate::sets::decl_macros::{
-    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, into_iterator_ref_trait_funcs,
-    into_iterator_trait_funcs, partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs, set_query_trait_funcs,
-    sub_trait_funcs,
+    bitand_trait_funcs, bitor_trait_funcs, bitxor_trait_funcs, common_primary_funcs, debug_trait_funcs, diagnostics_funcs,
+    into_iterator_ref_trait_funcs, into_iterator_trait_funcs, partial_eq_trait_funcs, set_extras_trait_funcs, set_iteration_trait_funcs,
+    set_query_trait_funcs, sorted_query_funcs, sub_trait_funcs,
 };
 use crate::sets::{IntoIter, Iter};
 use crate::traits::{CollectionMagnitude, Len, Scalar, Set, SetExtras, SetIteration, SetOps, SetQuery, SmallCollection};
+use crate::utils::assign_slots;
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::ops::{BitAnd, BitOr, BitXor, Sub};
@@ -65,7 +67,19 @@ where
         self.get(value).is_some()
     }
 
+    sorted_query_funcs!("Scalar");
     common_primary_funcs!(const_len);
+    diagnostics_funcs!();
+}
+
+impl<T, const SZ: usize, const LTSZ: usize, CM> From<InlineSparseScalarLookupSet<T, SZ, LTSZ, CM>>
+    for KeyIndex<InlineSparseScalarLookupMap<T, usize, SZ, LTSZ, CM>>
+where
+    CM: CollectionMagnitude,
+{
+    fn from(set: InlineSparseScalarLookupSet<T, SZ, LTSZ, CM>) -> Self {
+        Self::new(set.map.map_values(assign_slots()))
+    }
 }
 
 impl<T, Q, const SZ: usize, const LTSZ: usize, CM> Set<T, Q> for InlineSparseScalarLookupSet<T, SZ, LTSZ, CM>
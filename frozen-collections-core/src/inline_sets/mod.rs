@@ -1,15 +1,21 @@
 //! Specialized static-friendly read-only set types.
 
+pub use inline_bitset_scalar_lookup_set::InlineBitsetScalarLookupSet;
 pub use inline_dense_scalar_lookup_set::InlineDenseScalarLookupSet;
 pub use inline_eytzinger_search_set::InlineEytzingerSearchSet;
 pub use inline_hash_set::InlineHashSet;
 pub use inline_hash_set_no_collisions::InlineHashSetNoCollisions;
+pub use inline_perfect_hash_set::InlinePerfectHashSet;
 pub use inline_scan_set::InlineScanSet;
+pub use inline_simd_probe_set::InlineSimdProbeSet;
 pub use inline_sparse_scalar_lookup_set::InlineSparseScalarLookupSet;
 
+mod inline_bitset_scalar_lookup_set;
 mod inline_dense_scalar_lookup_set;
 mod inline_eytzinger_search_set;
 mod inline_hash_set;
 mod inline_hash_set_no_collisions;
+mod inline_perfect_hash_set;
 mod inline_scan_set;
+mod inline_simd_probe_set;
 mod inline_sparse_scalar_lookup_set;
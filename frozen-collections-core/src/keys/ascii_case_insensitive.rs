@@ -0,0 +1,277 @@
+use crate::hashers::SliceKey;
+use crate::traits::Len;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter, Result};
+use core::hash::{BuildHasher, Hash, Hasher};
+use equivalent::Equivalent;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A string key which is compared and hashed without regard to ASCII case.
+///
+/// Wrapping the keys of a collection in this type makes lookups ignore the case of ASCII
+/// letters, so `"Content-Type"` and `"content-type"` are the same key. Characters outside of
+/// the ASCII range are compared as-is. Keys are folded on the fly as they are hashed and
+/// compared, so lookups never allocate.
+///
+/// The string can be owned, as in `AsciiCaseInsensitive<Box<str>>`, or borrowed, as in
+/// `AsciiCaseInsensitive<&str>`. The string collections of this crate whose keys are wrapped in
+/// this type are looked up with plain strings, which they fold the same way. Elsewhere, use
+/// [`from_ref`](AsciiCaseInsensitive::from_ref) to wrap a string slice without copying it.
+///
+/// # Example
+///
+/// ```
+/// # use frozen_collections_core::keys::AsciiCaseInsensitive;
+/// assert_eq!(AsciiCaseInsensitive("Content-Type"), AsciiCaseInsensitive("CONTENT-TYPE"));
+/// assert_ne!(AsciiCaseInsensitive("Straße"), AsciiCaseInsensitive("STRASSE"));
+/// assert_eq!(AsciiCaseInsensitive("Accept"), *AsciiCaseInsensitive::from_ref("accept"));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct AsciiCaseInsensitive<S: ?Sized>(pub S);
+
+impl AsciiCaseInsensitive<str> {
+    /// Wraps a string slice without copying it.
+    #[must_use]
+    pub const fn from_ref(s: &str) -> &Self {
+        // SAFETY: `AsciiCaseInsensitive` is a transparent wrapper, so it has the same layout as `str`
+        unsafe { &*(core::ptr::from_ref(s) as *const Self) }
+    }
+}
+
+impl<S> AsciiCaseInsensitive<S>
+where
+    S: ?Sized + AsRef<str>,
+{
+    /// Returns the wrapped string, in its original case.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+}
+
+impl<S, T> PartialEq<AsciiCaseInsensitive<T>> for AsciiCaseInsensitive<S>
+where
+    S: ?Sized + AsRef<str>,
+    T: ?Sized + AsRef<str>,
+{
+    #[inline]
+    fn eq(&self, other: &AsciiCaseInsensitive<T>) -> bool {
+        self.as_str().eq_ignore_ascii_case(other.as_str())
+    }
+}
+
+impl<S> Eq for AsciiCaseInsensitive<S> where S: ?Sized + AsRef<str> {}
+
+impl<S> PartialOrd for AsciiCaseInsensitive<S>
+where
+    S: ?Sized + AsRef<str>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for AsciiCaseInsensitive<S>
+where
+    S: ?Sized + AsRef<str>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        let x = self.as_str().bytes().map(|b| b.to_ascii_lowercase());
+        let y = other.as_str().bytes().map(|b| b.to_ascii_lowercase());
+        x.cmp(y)
+    }
+}
+
+impl<S> Hash for AsciiCaseInsensitive<S>
+where
+    S: ?Sized + AsRef<str>,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        write_ascii_lowercase(state, self.as_str().as_bytes());
+
+        // same terminator as `str` uses, so that tuples of keys hash differently when split differently
+        state.write_u8(0xff);
+    }
+}
+
+impl<S> Borrow<AsciiCaseInsensitive<str>> for AsciiCaseInsensitive<S>
+where
+    S: AsRef<str>,
+{
+    fn borrow(&self) -> &AsciiCaseInsensitive<str> {
+        AsciiCaseInsensitive::from_ref(self.as_str())
+    }
+}
+
+impl<S> Len for AsciiCaseInsensitive<S>
+where
+    S: ?Sized + AsRef<str>,
+{
+    fn len(&self) -> usize {
+        self.as_str().len()
+    }
+}
+
+impl<S> SliceKey for AsciiCaseInsensitive<S>
+where
+    S: ?Sized + AsRef<str>,
+{
+    type Item = u8;
+
+    fn as_slice(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    #[inline]
+    fn hash_slice<BH: BuildHasher>(bh: &BH, slice: &[u8]) -> u64 {
+        hash_ascii_lowercase(bh, slice)
+    }
+}
+
+impl<S> Equivalent<AsciiCaseInsensitive<S>> for str
+where
+    S: ?Sized + AsRef<Self>,
+{
+    #[inline]
+    fn equivalent(&self, key: &AsciiCaseInsensitive<S>) -> bool {
+        self.eq_ignore_ascii_case(key.as_str())
+    }
+}
+
+impl<S> Display for AsciiCaseInsensitive<S>
+where
+    S: ?Sized + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S> Serialize for AsciiCaseInsensitive<S>
+where
+    S: ?Sized + Serialize,
+{
+    fn serialize<SR>(&self, serializer: SR) -> core::result::Result<SR::Ok, SR::Error>
+    where
+        SR: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S> Deserialize<'de> for AsciiCaseInsensitive<S>
+where
+    S: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        S::deserialize(deserializer).map(Self)
+    }
+}
+
+/// Hashes bytes as if their ASCII letters were all lowercase.
+#[inline]
+pub fn hash_ascii_lowercase<BH>(bh: &BH, bytes: &[u8]) -> u64
+where
+    BH: BuildHasher,
+{
+    let mut state = bh.build_hasher();
+    write_ascii_lowercase(&mut state, bytes);
+    state.finish()
+}
+
+/// Feeds bytes to a hasher with their ASCII letters lowercased, folding them through a small buffer.
+#[inline]
+fn write_ascii_lowercase<H: Hasher>(state: &mut H, bytes: &[u8]) {
+    const CHUNK_SIZE: usize = 32;
+
+    let mut buffer = [0; CHUNK_SIZE];
+    for chunk in bytes.chunks(CHUNK_SIZE) {
+        let folded = &mut buffer[..chunk.len()];
+        folded.copy_from_slice(chunk);
+        folded.make_ascii_lowercase();
+        state.write(folded);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DefaultBuildHasher;
+
+    #[test]
+    fn eq_ignores_ascii_case() {
+        assert_eq!(AsciiCaseInsensitive("Hello"), AsciiCaseInsensitive("hELLO"));
+        assert_eq!(AsciiCaseInsensitive("Hello"), AsciiCaseInsensitive("HELLO".to_string()));
+        assert_ne!(AsciiCaseInsensitive("Hello"), AsciiCaseInsensitive("Hello!"));
+        assert_ne!(AsciiCaseInsensitive("Ä"), AsciiCaseInsensitive("ä"));
+    }
+
+    #[test]
+    fn hash_ignores_ascii_case() {
+        let bh = DefaultBuildHasher::default();
+        let long = "A Key Which Is Longer Than A Single Chunk Of Folded Bytes";
+
+        assert_eq!(
+            bh.hash_one(AsciiCaseInsensitive("Hello")),
+            bh.hash_one(AsciiCaseInsensitive("hELLO".to_string()))
+        );
+        assert_eq!(
+            bh.hash_one(AsciiCaseInsensitive(long)),
+            bh.hash_one(AsciiCaseInsensitive::from_ref(&long.to_ascii_uppercase()))
+        );
+        assert_ne!(
+            bh.hash_one(AsciiCaseInsensitive("Hello")),
+            bh.hash_one(AsciiCaseInsensitive("World"))
+        );
+
+        assert_eq!(hash_ascii_lowercase(&bh, b"ABC"), hash_ascii_lowercase(&bh, b"abc"));
+        assert_ne!(hash_ascii_lowercase(&bh, b"ABC"), hash_ascii_lowercase(&bh, b"abd"));
+    }
+
+    #[test]
+    fn cmp_ignores_ascii_case() {
+        assert_eq!(Ordering::Equal, AsciiCaseInsensitive("abc").cmp(&AsciiCaseInsensitive("ABC")));
+        assert_eq!(Ordering::Less, AsciiCaseInsensitive("ABC").cmp(&AsciiCaseInsensitive("abd")));
+        assert_eq!(Ordering::Greater, AsciiCaseInsensitive("b").cmp(&AsciiCaseInsensitive("AB")));
+    }
+
+    #[test]
+    fn borrow_and_len() {
+        let key = AsciiCaseInsensitive(Box::<str>::from("Hello"));
+        let borrowed: &AsciiCaseInsensitive<str> = key.borrow();
+
+        assert_eq!("Hello", borrowed.as_str());
+        assert_eq!(5, key.len());
+        assert_eq!("Hello", AsciiCaseInsensitive(String::from("Hello")).to_string());
+    }
+
+    #[test]
+    fn str_query() {
+        let key = AsciiCaseInsensitive("Hello");
+
+        assert!("HELLO".equivalent(&key));
+        assert!(!"Hello!".equivalent(&key));
+    }
+
+    #[test]
+    fn slice_key_hashes_folded_bytes() {
+        let bh = DefaultBuildHasher::default();
+        let key = AsciiCaseInsensitive("Hello");
+
+        assert_eq!(b"Hello", key.as_slice());
+        assert_eq!(
+            <AsciiCaseInsensitive<&str> as SliceKey>::hash_slice(&bh, &key.as_slice()[1..3]),
+            hash_ascii_lowercase(&bh, b"EL")
+        );
+    }
+}
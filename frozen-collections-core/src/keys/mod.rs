@@ -0,0 +1,8 @@
+//! Key types which change how the keys of a collection are compared and hashed.
+
+pub use crate::keys::ascii_case_insensitive::AsciiCaseInsensitive;
+
+#[cfg(test)]
+pub(crate) use crate::keys::ascii_case_insensitive::hash_ascii_lowercase;
+
+mod ascii_case_insensitive;
@@ -12,12 +12,16 @@
 extern crate alloc;
 
 mod analyzers;
+pub mod columns;
+pub mod diagnostics;
 pub mod fz_maps;
 pub mod fz_sets;
 pub mod hash_tables;
 pub mod hashers;
 pub mod inline_maps;
 pub mod inline_sets;
+pub mod keys;
+pub mod mapped;
 pub mod maps;
 pub mod sets;
 pub mod traits;
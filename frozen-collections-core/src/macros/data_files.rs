@@ -0,0 +1,327 @@
+//! Reading the entries of a collection from a data file while a macro is being expanded.
+
+use crate::macros::parsing::data_file::{DataFile, DataFormat};
+use crate::macros::parsing::entry::Entry;
+use crate::macros::processor::MacroKind;
+use core::fmt::{Display, Formatter};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use syn::{Expr, ExprLit, Lit, LitByteStr, LitStr, Type, parse_str};
+
+/// A key or value read from a data file.
+enum Datum {
+    Str(String),
+    Bytes(Vec<u8>),
+    Int(String),
+    Float(f64),
+    Bool(bool),
+}
+
+impl Display for Datum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Str(s) => write!(f, "{s:?}"),
+            Self::Bytes(b) => write!(f, "b{:?}", String::from_utf8_lossy(b)),
+            Self::Int(i) => write!(f, "{i}"),
+            Self::Float(x) => write!(f, "{x:?}"),
+            Self::Bool(b) => write!(f, "{b}"),
+        }
+    }
+}
+
+/// The top level of a JSON data file.
+///
+/// Unlike `serde_json::Value`, this keeps every member of an object, so duplicate keys can be reported.
+enum JsonDocument {
+    Object(Vec<(String, serde_json::Value)>),
+    Array(Vec<serde_json::Value>),
+}
+
+impl<'de> Deserialize<'de> for JsonDocument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(JsonDocumentVisitor)
+    }
+}
+
+struct JsonDocumentVisitor;
+
+impl<'de> Visitor<'de> for JsonDocumentVisitor {
+    type Value = JsonDocument;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("a JSON object or array")
+    }
+
+    fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let mut members = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(member) = map.next_entry()? {
+            members.push(member);
+        }
+
+        Ok(JsonDocument::Object(members))
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+
+        Ok(JsonDocument::Array(elements))
+    }
+}
+
+impl DataFile {
+    /// Returns the absolute path of the file, which is given relative to the crate being compiled.
+    pub fn resolve(&self) -> syn::Result<PathBuf> {
+        let dir = std::env::var_os("CARGO_MANIFEST_DIR")
+            .ok_or_else(|| self.error("unable to locate the data file, CARGO_MANIFEST_DIR is not set"))?;
+
+        Ok(PathBuf::from(dir).join(self.path.value()))
+    }
+
+    /// Reads the file and turns its contents into collection entries.
+    pub fn load(&self, path: &Path, macro_kind: MacroKind, key_type: &Type, value_type: Option<&Type>) -> syn::Result<Vec<Entry>> {
+        let text = std::fs::read_to_string(path).map_err(|e| self.error(&format!("unable to read '{}': {e}", path.display())))?;
+
+        let data = match self.format {
+            DataFormat::Json => self.parse_json(&text)?,
+            DataFormat::Toml => self.parse_toml(&text)?,
+            DataFormat::Csv => self.parse_csv(&text)?,
+        };
+
+        // integer keys in a file can't be given a suffix, so they take it from the declared key type instead
+        let suffix = integer_suffix(key_type);
+
+        // integer values are written as floats when the declared value type is a float, or when the file holds
+        // other values which are floats, since `1` isn't an `f64`
+        let float_values = value_type.is_some_and(is_float_type) || data.iter().any(|(_, value)| matches!(value, Some(Datum::Float(_))));
+
+        // the same key given twice in a file is most likely a mistake, so rather than letting the last one win, it's an error
+        let mut seen = HashSet::new();
+        let span = self.path.span();
+        data.into_iter()
+            .map(|(key, value)| {
+                // keys of objects, tables, and CSV records are always strings, so they are turned back into integers,
+                // and the bytes macros take the UTF-8 bytes of string keys
+                let key = match (macro_kind, key) {
+                    (MacroKind::Scalar, Datum::Str(s)) => integer(&s).map_or(Datum::Str(s), Datum::Int),
+                    (MacroKind::Bytes, Datum::Str(s)) => Datum::Bytes(s.into_bytes()),
+                    (_, key) => key,
+                };
+
+                if !seen.insert(key.to_string()) {
+                    return Err(self.error(&format!("duplicate key {key} in '{}'", path.display())));
+                }
+
+                let key = match key {
+                    Datum::Int(i) => Datum::Int(format!("{i}{suffix}")),
+                    key => key,
+                };
+
+                let value = match value {
+                    Some(Datum::Int(i)) if float_values => Some(Datum::Float(i.parse().unwrap_or_default())),
+                    value => value,
+                };
+
+                Ok(Entry {
+                    key: literal(key, span)?,
+                    value: value.map(|v| literal(v, span)).transpose()?,
+                })
+            })
+            .collect()
+    }
+
+    fn parse_json(&self, text: &str) -> syn::Result<Vec<(Datum, Option<Datum>)>> {
+        let json = serde_json::from_str::<JsonDocument>(text).map_err(|e| self.error(&format!("invalid JSON: {e}")))?;
+
+        match json {
+            JsonDocument::Object(object) => object
+                .into_iter()
+                .map(|(k, v)| Ok((Datum::Str(k), self.value(|| self.json_datum(v))?)))
+                .collect(),
+
+            JsonDocument::Array(array) => array
+                .into_iter()
+                .map(|item| {
+                    if !self.is_map {
+                        return Ok((self.json_datum(item)?, None));
+                    }
+
+                    // a map given as an array of [key, value] pairs
+                    match item {
+                        serde_json::Value::Array(pair) if pair.len() == 2 => {
+                            let mut pair = pair.into_iter();
+                            let k = pair.next().unwrap_or_default();
+                            let v = pair.next().unwrap_or_default();
+                            Ok((self.json_datum(k)?, Some(self.json_datum(v)?)))
+                        }
+                        _ => Err(self.error("expecting each array element to be a [key, value] pair")),
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    fn parse_toml(&self, text: &str) -> syn::Result<Vec<(Datum, Option<Datum>)>> {
+        let table = toml::from_str::<toml::Table>(text).map_err(|e| self.error(&format!("invalid TOML: {e}")))?;
+
+        table
+            .into_iter()
+            .map(|(k, v)| Ok((Datum::Str(k), self.value(|| self.toml_datum(v))?)))
+            .collect()
+    }
+
+    fn parse_csv(&self, text: &str) -> syn::Result<Vec<(Datum, Option<Datum>)>> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .comment(Some(b'#'))
+            .trim(csv::Trim::All)
+            .from_reader(text.as_bytes());
+
+        reader
+            .records()
+            .map(|record| {
+                // sets use the first field of each record, so the same file can be used for maps and sets
+                let record = record.map_err(|e| self.error(&format!("invalid CSV: {e}")))?;
+                if (self.is_map && record.len() != 2) || record.is_empty() {
+                    let line = record.position().map_or(0, csv::Position::line);
+                    return Err(self.error(&format!("expecting a key and a value on line {line}")));
+                }
+
+                let key = Datum::Str(record[0].to_string());
+                let value = self.is_map.then(|| csv_datum(&record[1]));
+                Ok((key, value))
+            })
+            .collect()
+    }
+
+    /// Reads a value for a map entry, or ignores it for a set.
+    fn value(&self, f: impl FnOnce() -> syn::Result<Datum>) -> syn::Result<Option<Datum>> {
+        if self.is_map { f().map(Some) } else { Ok(None) }
+    }
+
+    fn json_datum(&self, value: serde_json::Value) -> syn::Result<Datum> {
+        match value {
+            serde_json::Value::String(s) => Ok(Datum::Str(s)),
+            serde_json::Value::Bool(b) => Ok(Datum::Bool(b)),
+            serde_json::Value::Number(n) if n.is_i64() || n.is_u64() => Ok(Datum::Int(n.to_string())),
+            serde_json::Value::Number(n) => Ok(Datum::Float(n.as_f64().unwrap_or_default())),
+            _ => Err(self.error("unsupported JSON value, expecting a string, number, or boolean")),
+        }
+    }
+
+    fn toml_datum(&self, value: toml::Value) -> syn::Result<Datum> {
+        match value {
+            toml::Value::String(s) => Ok(Datum::Str(s)),
+            toml::Value::Boolean(b) => Ok(Datum::Bool(b)),
+            toml::Value::Integer(i) => Ok(Datum::Int(i.to_string())),
+            toml::Value::Float(f) => Ok(Datum::Float(f)),
+            _ => Err(self.error("unsupported TOML value, expecting a string, number, or boolean")),
+        }
+    }
+
+    fn error(&self, message: &str) -> syn::Error {
+        syn::Error::new_spanned(&self.path, message)
+    }
+}
+
+/// Makes the generated code depend on the data file, so the crate is rebuilt whenever the file changes.
+pub fn track_data_file(path: &Path, tokens: &TokenStream, is_expression: bool) -> TokenStream {
+    let path = path.to_string_lossy();
+    let dependency = quote!(
+        const _: &[u8] = ::core::include_bytes!(#path);
+    );
+
+    if is_expression {
+        quote!({
+            #dependency
+            #tokens
+        })
+    } else {
+        quote!(
+            #dependency
+            #tokens
+        )
+    }
+}
+
+/// CSV fields are untyped, so values which look like numbers or booleans are treated as such.
+fn csv_datum(field: &str) -> Datum {
+    if let Some(i) = integer(field) {
+        return Datum::Int(i);
+    }
+
+    if let Ok(b) = field.parse::<bool>() {
+        return Datum::Bool(b);
+    }
+
+    if field.bytes().all(|b| b.is_ascii_digit() || b"+-.eE".contains(&b)) {
+        if let Ok(f) = field.parse::<f64>() {
+            return Datum::Float(f);
+        }
+    }
+
+    Datum::Str(field.to_string())
+}
+
+/// Returns the literal suffix matching an integer key type, or an empty string for other types.
+fn integer_suffix(key_type: &Type) -> String {
+    const INTEGER_TYPES: [&str; 10] = ["i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize"];
+
+    match key_type {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .get_ident()
+            .map(ToString::to_string)
+            .filter(|ident| INTEGER_TYPES.contains(&ident.as_str()))
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+/// Returns whether a type is one of the primitive floating-point types.
+fn is_float_type(value_type: &Type) -> bool {
+    match value_type {
+        Type::Path(path) if path.qself.is_none() => path.path.is_ident("f32") || path.path.is_ident("f64"),
+        _ => false,
+    }
+}
+
+/// Returns the canonical form of a string holding an integer.
+fn integer(s: &str) -> Option<String> {
+    s.parse::<i128>()
+        .map(|i| i.to_string())
+        .or_else(|_| s.parse::<u128>().map(|u| u.to_string()))
+        .ok()
+}
+
+fn literal(datum: Datum, span: Span) -> syn::Result<Expr> {
+    match datum {
+        Datum::Str(s) => Ok(Expr::Lit(ExprLit {
+            attrs: Vec::new(),
+            lit: Lit::Str(LitStr::new(&s, span)),
+        })),
+        Datum::Bytes(b) => Ok(Expr::Lit(ExprLit {
+            attrs: Vec::new(),
+            lit: Lit::ByteStr(LitByteStr::new(&b, span)),
+        })),
+        Datum::Int(s) => parse_str(&s),
+        Datum::Float(f) if f.is_finite() => parse_str(&format!("{f:?}")),
+        Datum::Float(f) => Err(syn::Error::new(span, format!("unsupported floating-point value {f}"))),
+        Datum::Bool(b) => parse_str(if b { "true" } else { "false" }),
+    }
+}
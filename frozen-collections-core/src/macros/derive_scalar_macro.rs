@@ -1,10 +1,15 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Error, Fields};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Data, DataEnum, DeriveInput, Error, Fields, Ident, Meta, Token, Type, parse_quote};
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
+const INTEGER_REPRS: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
 /// Implementation logic for the `Scalar` derive macro.
 ///
 /// # Errors
@@ -12,13 +17,27 @@ use alloc::vec::Vec;
 /// Bad things happen to bad input
 pub fn derive_scalar_macro(args: TokenStream) -> syn::Result<TokenStream> {
     let input: DeriveInput = syn::parse2(args)?;
+
+    match &input.data {
+        Data::Enum(variants) => derive_enum(&input, variants),
+        Data::Struct(s) => match &s.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(derive_newtype(&input, &fields.unnamed[0].ty)),
+            _ => Err(Error::new_spanned(
+                &input.ident,
+                "Scalar can only be used with enums and single-field tuple structs",
+            )),
+        },
+        Data::Union(_) => Err(Error::new_spanned(
+            &input.ident,
+            "Scalar can only be used with enums and single-field tuple structs",
+        )),
+    }
+}
+
+fn derive_enum(input: &DeriveInput, variants: &DataEnum) -> syn::Result<TokenStream> {
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-    let Data::Enum(variants) = &input.data else {
-        return Err(Error::new_spanned(name, "Scalar can only be used with enums"));
-    };
-
     if variants.variants.is_empty() {
         return Err(Error::new_spanned(name, "Scalar can only be used with non-empty enums"));
     }
@@ -30,33 +49,103 @@ pub fn derive_scalar_macro(args: TokenStream) -> syn::Result<TokenStream> {
                 "Scalar can only be used with enums that only contain unit variants",
             ));
         }
+    }
 
-        if v.discriminant.is_some() {
-            return Err(Error::new_spanned(
-                name,
-                "Scalar can only be used with enums that do not have explicit discriminants",
-            ));
+    let index_funcs = if variants.variants.iter().all(|v| v.discriminant.is_none()) {
+        let mut matches = Vec::new();
+        for variant in &variants.variants {
+            let ident = &variant.ident;
+
+            let index = matches.len();
+            matches.push(quote! { #name::#ident => #index});
         }
-    }
 
-    let mut matches = Vec::new();
-    for variant in &variants.variants {
-        let ident = &variant.ident;
+        quote! {
+            fn index(&self) -> usize {
+                match self {
+                    #(#matches),*
+                }
+            }
+        }
+    } else {
+        // With explicit discriminants, the variants are indexed by their discriminant values so the
+        // spread of the keys is visible to the collections. The values are converted through the
+        // enum's representation, which keeps the indices in the same order as the variants.
+        let repr = integer_repr(&input.attrs)?.unwrap_or_else(|| parse_quote!(isize));
 
-        let index = matches.len();
-        matches.push(quote! { #name::#ident => #index});
-    }
+        quote! {
+            fn index(&self) -> usize {
+                ::frozen_collections::Scalar::index(&(*self as #repr))
+            }
+
+            fn index_is_shared(&self) -> bool {
+                ::frozen_collections::Scalar::index_is_shared(&(*self as #repr))
+            }
+
+            fn hash_code(&self) -> u64 {
+                ::frozen_collections::Scalar::hash_code(&(*self as #repr))
+            }
+        }
+    };
+
+    let idents = variants.variants.iter().map(|v| &v.ident);
 
     Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::frozen_collections::Scalar for #name #ty_generics #where_clause {
+            #index_funcs
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::frozen_collections::ScalarEnum for #name #ty_generics #where_clause {
+            const VARIANTS: &'static [Self] = &[#(#name::#idents),*];
+        }
+    })
+}
+
+fn derive_newtype(input: &DeriveInput, inner: &Type) -> TokenStream {
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#inner: ::frozen_collections::Scalar));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
         #[automatically_derived]
         impl #impl_generics ::frozen_collections::Scalar for #name #ty_generics #where_clause {
             fn index(&self) -> usize {
-                match self {
-                    #(#matches),*
+                ::frozen_collections::Scalar::index(&self.0)
+            }
+
+            fn index_is_shared(&self) -> bool {
+                ::frozen_collections::Scalar::index_is_shared(&self.0)
+            }
+
+            fn hash_code(&self) -> u64 {
+                ::frozen_collections::Scalar::hash_code(&self.0)
+            }
+        }
+    }
+}
+
+/// Finds the integer type given in an enum's `#[repr]` attribute, if any.
+fn integer_repr(attrs: &[Attribute]) -> syn::Result<Option<Ident>> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in metas {
+            if let Meta::Path(path) = meta {
+                if let Some(ident) = path.get_ident() {
+                    if INTEGER_REPRS.iter().any(|repr| ident == repr) {
+                        return Ok(Some(ident.clone()));
+                    }
                 }
             }
         }
-    })
+    }
+
+    Ok(None)
 }
 
 #[cfg(test)]
@@ -65,20 +154,21 @@ mod tests {
 
     #[test]
     fn basic() {
-        assert!(
-            derive_scalar_macro(quote!(
-                enum Color {
-                    Red,
-                    Green,
-                    Blue,
-                }
-            ))
-            .is_ok()
-        );
+        let r = derive_scalar_macro(quote!(
+            enum Color {
+                Red,
+                Green,
+                Blue,
+            }
+        ))
+        .unwrap()
+        .to_string();
+
+        assert!(r.contains("const VARIANTS : & 'static [Self] = & [Color :: Red , Color :: Green , Color :: Blue]"));
     }
 
     #[test]
-    fn only_with_enums() {
+    fn only_with_enums_and_newtypes() {
         let r = derive_scalar_macro(quote!(
             struct Color {
                 red: i32,
@@ -87,7 +177,37 @@ mod tests {
             }
         ));
 
-        assert_eq!("Scalar can only be used with enums", r.unwrap_err().to_string());
+        assert_eq!(
+            "Scalar can only be used with enums and single-field tuple structs",
+            r.unwrap_err().to_string()
+        );
+
+        let r = derive_scalar_macro(quote!(
+            struct Pair(u32, u32);
+        ));
+
+        assert_eq!(
+            "Scalar can only be used with enums and single-field tuple structs",
+            r.unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn newtypes() {
+        let r = derive_scalar_macro(quote!(
+            struct UserId(u32);
+        ))
+        .unwrap()
+        .to_string();
+        assert!(r.contains("Scalar :: index (& self . 0)"));
+        assert!(!r.contains("ScalarEnum"));
+
+        let r = derive_scalar_macro(quote!(
+            struct Id<T>(T);
+        ))
+        .unwrap()
+        .to_string();
+        assert!(r.contains("where T : :: frozen_collections :: Scalar"));
     }
 
     #[test]
@@ -116,18 +236,40 @@ mod tests {
     }
 
     #[test]
-    fn no_explicit_discriminants() {
+    fn explicit_discriminants() {
+        let r = derive_scalar_macro(quote!(
+            #[repr(u16)]
+            enum Opcode {
+                A = 0x10,
+                B = 0x20,
+            }
+        ))
+        .unwrap()
+        .to_string();
+        assert!(r.contains("* self as u16"));
+
+        let r = derive_scalar_macro(quote!(
+            #[derive(Clone, Copy)]
+            #[repr(C, align(8), i8)]
+            enum Color {
+                Red = -1,
+                Green,
+                Blue,
+            }
+        ))
+        .unwrap()
+        .to_string();
+        assert!(r.contains("* self as i8"));
+
         let r = derive_scalar_macro(quote!(
             enum Color {
                 Red,
                 Green = 2,
                 Blue,
             }
-        ));
-
-        assert_eq!(
-            "Scalar can only be used with enums that do not have explicit discriminants",
-            r.unwrap_err().to_string()
-        );
+        ))
+        .unwrap()
+        .to_string();
+        assert!(r.contains("* self as isize"));
     }
 }
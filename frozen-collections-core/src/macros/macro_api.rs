@@ -3,7 +3,7 @@ use crate::macros::parsing::map::Map;
 use crate::macros::parsing::set::Set;
 use crate::macros::processor::{MacroKind, process};
 use proc_macro2::TokenStream;
-use syn::parse2;
+use syn::{Attribute, Type, parse2};
 
 #[cfg(not(feature = "std"))]
 use alloc::string::ToString;
@@ -44,6 +44,33 @@ pub fn fz_scalar_map_macro(args: TokenStream) -> syn::Result<TokenStream> {
     fz_map_macro(args, MacroKind::Scalar)
 }
 
+/// Implementation logic for the `fz_bytes_map!` macro.
+///
+/// # Errors
+///
+/// Bad things happen to bad input
+pub fn fz_bytes_map_macro(args: TokenStream) -> syn::Result<TokenStream> {
+    fz_map_macro(args, MacroKind::Bytes)
+}
+
+/// Implementation logic for the `fz_prefix_map!` macro.
+///
+/// # Errors
+///
+/// Bad things happen to bad input
+pub fn fz_prefix_map_macro(args: TokenStream) -> syn::Result<TokenStream> {
+    fz_map_macro(args, MacroKind::Prefix)
+}
+
+/// Implementation logic for the `fz_enum_map!` macro.
+///
+/// # Errors
+///
+/// Bad things happen to bad input
+pub fn fz_enum_map_macro(args: TokenStream) -> syn::Result<TokenStream> {
+    fz_map_macro(args, MacroKind::Enum)
+}
+
 /// Implementation logic for the `fz_hash_set!` macro.
 ///
 /// # Errors
@@ -80,13 +107,23 @@ pub fn fz_scalar_set_macro(args: TokenStream) -> syn::Result<TokenStream> {
     fz_set_macro(args, MacroKind::Scalar)
 }
 
+/// Implementation logic for the `fz_bytes_set!` macro.
+///
+/// # Errors
+///
+/// Bad things happen to bad input
+pub fn fz_bytes_set_macro(args: TokenStream) -> syn::Result<TokenStream> {
+    fz_set_macro(args, MacroKind::Bytes)
+}
+
 fn fz_map_macro(args: TokenStream, macro_kind: MacroKind) -> syn::Result<TokenStream> {
     let input = parse2::<Map>(args)?;
 
     match input {
         Map::Short(map) => {
-            let emitter = CollectionEmitter::new_with_inferred_types();
-            process(map.payload, emitter, macro_kind)
+            let emitter =
+                CollectionEmitter::new_with_inferred_types().ignore_ascii_case(check_ignore_ascii_case(map.ignore_ascii_case, macro_kind)?);
+            process(map.payload, apply_seed(emitter, map.seed)?, macro_kind)
         }
         Map::Long(map) => {
             let emitter = CollectionEmitter::new(&map.key_type)
@@ -95,9 +132,12 @@ fn fz_map_macro(args: TokenStream, macro_kind: MacroKind) -> syn::Result<TokenSt
                 .symbol_name(map.var_name.to_string().as_str())
                 .mutable(map.is_mutable)
                 .static_instance(map.is_static)
-                .visibility(map.visibility);
+                .visibility(map.visibility)
+                .analysis_report(map.analysis_report)
+                .ignore_ascii_case(check_ignore_ascii_case(map.ignore_ascii_case, macro_kind)?);
 
-            process(map.payload, emitter, macro_kind)
+            let emitter = apply_hasher(emitter, map.hasher, macro_kind)?;
+            process(map.payload, apply_seed(emitter, map.seed)?, macro_kind)
         }
     }
 }
@@ -107,8 +147,9 @@ fn fz_set_macro(args: TokenStream, macro_kind: MacroKind) -> syn::Result<TokenSt
 
     match input {
         Set::Short(set) => {
-            let emitter = CollectionEmitter::new_with_inferred_key_type();
-            process(set.payload, emitter, macro_kind)
+            let emitter = CollectionEmitter::new_with_inferred_key_type()
+                .ignore_ascii_case(check_ignore_ascii_case(set.ignore_ascii_case, macro_kind)?);
+            process(set.payload, apply_seed(emitter, set.seed)?, macro_kind)
         }
         Set::Long(set) => {
             let emitter = CollectionEmitter::new(&set.value_type)
@@ -116,13 +157,86 @@ fn fz_set_macro(args: TokenStream, macro_kind: MacroKind) -> syn::Result<TokenSt
                 .symbol_name(set.var_name.to_string().as_str())
                 .mutable(set.is_mutable)
                 .static_instance(set.is_static)
-                .visibility(set.visibility);
+                .visibility(set.visibility)
+                .analysis_report(set.analysis_report)
+                .ignore_ascii_case(check_ignore_ascii_case(set.ignore_ascii_case, macro_kind)?);
 
-            process(set.payload, emitter, macro_kind)
+            let emitter = apply_hasher(emitter, set.hasher, macro_kind)?;
+            process(set.payload, apply_seed(emitter, set.seed)?, macro_kind)
         }
     }
 }
 
+/// Ensures `#[ignore_ascii_case]` is only given to the string macros.
+fn check_ignore_ascii_case(attr: Option<Attribute>, macro_kind: MacroKind) -> syn::Result<bool> {
+    match attr {
+        Some(attr) if macro_kind != MacroKind::String => Err(syn::Error::new_spanned(
+            attr,
+            "`#[ignore_ascii_case]` is only supported by `fz_string_map!` and `fz_string_set!`",
+        )),
+        attr => Ok(attr.is_some()),
+    }
+}
+
+/// Applies the `BuildHasher` given by `#[hasher = H]`, which is only supported by the hash and string macros.
+fn apply_hasher(emitter: CollectionEmitter, hasher: Option<(Attribute, Type)>, macro_kind: MacroKind) -> syn::Result<CollectionEmitter> {
+    match hasher {
+        Some((attr, _)) if macro_kind != MacroKind::Hashed && macro_kind != MacroKind::String => Err(syn::Error::new_spanned(
+            attr,
+            "`#[hasher]` is only supported by `fz_hash_map!`, `fz_hash_set!`, `fz_string_map!`, and `fz_string_set!`",
+        )),
+        Some((_, hasher)) => Ok(emitter.hasher(&hasher)),
+        None => Ok(emitter),
+    }
+}
+
+/// The environment variable which pins the seed of the collections generated by the macros.
+#[cfg(feature = "std")]
+const SEED_ENV_VAR: &str = "FROZEN_COLLECTIONS_SEED";
+
+/// Pins the seed given by `#[seed = N]` or, failing that, by the `FROZEN_COLLECTIONS_SEED` environment variable.
+///
+/// The environment variable doesn't apply to collections using a custom hasher, which have no seed.
+fn apply_seed(emitter: CollectionEmitter, seed: Option<u64>) -> syn::Result<CollectionEmitter> {
+    let seed = match seed {
+        Some(seed) => Some(seed),
+        None if emitter.hasher.is_some() => None,
+        None => seed_from_env()?,
+    };
+
+    Ok(match seed {
+        Some(seed) => emitter.seed(seed),
+        None => emitter,
+    })
+}
+
+#[cfg(feature = "std")]
+fn seed_from_env() -> syn::Result<Option<u64>> {
+    std::env::var(SEED_ENV_VAR).ok().map(|value| parse_seed(&value)).transpose()
+}
+
+#[cfg(not(feature = "std"))]
+#[expect(clippy::unnecessary_wraps, reason = "Matches the signature used with the standard library")]
+const fn seed_from_env() -> syn::Result<Option<u64>> {
+    Ok(None)
+}
+
+/// Parses a seed given in decimal, or in hexadecimal with a `0x` prefix.
+#[cfg(feature = "std")]
+fn parse_seed(value: &str) -> syn::Result<u64> {
+    let value = value.trim();
+    let parsed = value
+        .strip_prefix("0x")
+        .map_or_else(|| value.parse(), |hex| u64::from_str_radix(hex, 16));
+
+    parsed.map_err(|e| {
+        syn::Error::new(
+            proc_macro2::Span::call_site(),
+            format!("`{SEED_ENV_VAR}` must be an unsigned 64-bit integer, found `{value}`: {e}"),
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,13 +278,29 @@ mod tests {
         assert_eq!("incompatible scalar literal type", r.unwrap_err().to_string());
     }
 
+    #[test]
+    fn incompatible_char_and_int_literals() {
+        let r = fz_scalar_set_macro(quote!(
+            { 'a', 2 }
+        ));
+        assert_eq!("incompatible scalar literal type", r.unwrap_err().to_string());
+
+        let r = fz_scalar_set_macro(quote!(
+            { 1, true }
+        ));
+        assert_eq!("incompatible scalar literal type", r.unwrap_err().to_string());
+    }
+
     #[test]
     fn invalid_literal() {
         let r = fz_scalar_set_macro(quote!(
             { 123.0, 234.0 }
         ));
 
-        assert_eq!("invalid literal, expecting a scalar or string value", r.unwrap_err().to_string());
+        assert_eq!(
+            "invalid literal, expecting a scalar, string, or byte string value",
+            r.unwrap_err().to_string()
+        );
 
         let r = fz_string_set_macro(quote!(
             { 1, 2 }
@@ -183,6 +313,24 @@ mod tests {
         ));
 
         assert_eq!("scalar macro cannot contain string keys", r.unwrap_err().to_string());
+
+        let r = fz_string_set_macro(quote!(
+            { b"1", b"2" }
+        ));
+
+        assert_eq!("string macro cannot contain byte string keys", r.unwrap_err().to_string());
+
+        let r = fz_bytes_set_macro(quote!(
+            { b"1", "2" }
+        ));
+
+        assert_eq!("bytes macro cannot contain string keys", r.unwrap_err().to_string());
+
+        let r = fz_bytes_set_macro(quote!(
+            { 1, 2 }
+        ));
+
+        assert_eq!("bytes macro cannot contain scalar keys", r.unwrap_err().to_string());
     }
 
     #[test]
@@ -196,20 +344,28 @@ mod tests {
 
     #[test]
     fn magnitude() {
-        test_magnitude(255, "SmallCollection");
-        test_magnitude(256, "MediumCollection");
-        test_magnitude(65535, "MediumCollection");
-        test_magnitude(65536, "LargeCollection");
+        // every other value, so the values are tracked in a bitset sized by the number of values
+        test_magnitude((0..255).map(|i| i * 2), "SmallCollection");
+        test_magnitude((0..256).map(|i| i * 2), "MediumCollection");
+        test_magnitude((0..65535).map(|i| i * 2), "MediumCollection");
+        test_magnitude((0..65536).map(|i| i * 2), "LargeCollection");
     }
 
-    fn test_magnitude(count: i32, expected: &str) {
+    #[test]
+    fn perfect_hash() {
+        // large inputs use perfect hashing, which doesn't depend on the collection magnitude
+        test_magnitude((1..999).chain([12_322_225]), "MediumCollection");
+        test_magnitude((1..1000).chain([12_322_225]), "InlinePerfectHashSet");
+        test_magnitude((1..65536).chain([12_322_225]), "InlinePerfectHashSet");
+    }
+
+    fn test_magnitude(values: impl Iterator<Item = i32>, expected: &str) {
         let mut s = TokenStream::new();
 
-        for i in 1..count {
+        for i in values {
             s.append_all(quote!(#i,));
         }
 
-        s.append_all(quote!(12322225));
         let s = TokenTree::Group(Group::new(Brace, s)).to_token_stream();
 
         let r = fz_scalar_set_macro(s).unwrap();
@@ -227,6 +383,7 @@ mod tests {
         check_impl(":: InlineHashSet", quote!({ "1", "2", "3", "4" }));
 
         check_impl(":: BridgeHasher", quote!({ "1", "2", "3", "4", "5", "6", "7" }));
+        check_impl(":: InlineSimdProbeSet", quote!({ "1", "2", "3", "4", "5", "6", "7", "8" }));
 
         check_impl(
             ":: LengthHasher",
@@ -244,6 +401,91 @@ mod tests {
         check_impl(":: FzStringSet", quote!({ x, y, z, a, b, c, d }));
     }
 
+    #[test]
+    fn test_selected_bytes_set_implementation_types() {
+        fn check_impl(expected: &str, ts: TokenStream) {
+            let r = fz_bytes_set_macro(ts).unwrap().to_string();
+            assert!(r.contains(expected), "{r} doesn't contain {expected}");
+        }
+
+        check_impl(":: InlineScanSet", quote!({ b"1", b"2", b"3", }));
+        check_impl(":: InlineHashSet", quote!({ b"1", b"2", b"3", b"4" }));
+        check_impl(
+            ":: LengthHasher",
+            quote!({ b"1", b"22", b"333", b"4444", b"55555", b"666666", b"7777777" }),
+        );
+
+        check_impl(":: InlineScanSet", quote!({ x, b"2", b"3", }));
+        check_impl("b\"2\" as & [u8]", quote!({ x, b"2", b"3", }));
+        check_impl(":: FzBytesSet", quote!({ x, b"2", b"3", b"4" }));
+        check_impl(":: FzBytesSet", quote!({ x, y, z, a, b, c, d }));
+    }
+
+    #[test]
+    fn prefix_map() {
+        let r = fz_prefix_map_macro(quote!({ "a": 1, "ab": 2 })).unwrap().to_string();
+        assert!(r.contains(":: frozen_collections :: FzPrefixMap :: < _ , _ > :: new"), "{r}");
+
+        let r = fz_prefix_map_macro(quote!(static M: Routes<&'static str, i32>, { "/": 1, x: 2 }))
+            .unwrap()
+            .to_string();
+        assert!(r.contains("LazyLock < Routes >"), "{r}");
+
+        let r = fz_prefix_map_macro(quote!({ 1: 1, 2: 2 }));
+        assert_eq!("prefix macro cannot contain scalar keys", r.unwrap_err().to_string());
+
+        let r = fz_prefix_map_macro(quote!({ b"1": 1 }));
+        assert_eq!("prefix macro cannot contain byte string keys", r.unwrap_err().to_string());
+
+        let r = fz_prefix_map_macro(quote!(#[ignore_ascii_case] { "a": 1 }));
+        assert_eq!(
+            "`#[ignore_ascii_case]` is only supported by `fz_string_map!` and `fz_string_set!`",
+            r.unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn enum_map() {
+        let r = fz_enum_map_macro(quote!({ Color::Red: 1, Color::Green: 2, Color::Red: 3 }))
+            .unwrap()
+            .to_string();
+        assert!(r.contains(":: frozen_collections :: FzEnumMap :: < _ , _ > :: from_fn"), "{r}");
+        assert!(
+            r.contains("[:: core :: option :: Option :: Some (2) , :: core :: option :: Option :: Some (3) ,]"),
+            "{r}"
+        );
+        assert!(
+            r.contains("Color :: Green => values [0] . take () . unwrap () , Color :: Red => values [1]"),
+            "{r}"
+        );
+
+        let r = fz_enum_map_macro(quote!(static M: Colors<Color, i32>, { Color::Red: 1 }))
+            .unwrap()
+            .to_string();
+        assert!(r.contains("LazyLock < Colors >"), "{r}");
+
+        let r = fz_enum_map_macro(quote!({ 1: 1, 2: 2 }));
+        assert_eq!("enum macro cannot contain scalar keys", r.unwrap_err().to_string());
+
+        let r = fz_enum_map_macro(quote!({ Color::Red: 1, colors[0]: 2 }));
+        assert_eq!("enum map keys must be enum variants", r.unwrap_err().to_string());
+
+        let r = fz_enum_map_macro(quote!({ Color::Red: 1, x: 2 }));
+        assert_eq!(
+            "enum map keys must be enum variants, a lowercase name would match every variant",
+            r.unwrap_err().to_string()
+        );
+
+        let r = fz_enum_map_macro(quote!({ Red: 1, Green: 2 })).unwrap().to_string();
+        assert!(
+            r.contains("match key { Red => values [0] . take () . unwrap () , Green => values [1]"),
+            "{r}"
+        );
+
+        let r = fz_enum_map_macro(quote!(let m: Colors<Color, i32>, {}));
+        assert_eq!("no collection entries supplied", r.unwrap_err().to_string());
+    }
+
     #[test]
     fn test_selected_scalar_set_implementation_types() {
         fn check_impl(expected: &str, ts: TokenStream) {
@@ -252,12 +494,23 @@ mod tests {
         }
 
         check_impl(":: InlineDenseScalarLookupSet", quote!({ 1, 2, 3, }));
-        check_impl(":: InlineSparseScalarLookupSet", quote!({ 1, 2, 3, 4, 6 }));
+        check_impl(":: InlineBitsetScalarLookupSet", quote!({ 1, 2, 3, 4, 6 }));
+        check_impl(":: InlineBitsetScalarLookupSet", quote!({ 1, 50, 100, 150, 250 }));
         check_impl(":: InlineScanSet", quote!({ 1, 2, 3, 4, 5, 60000, 70000 }));
-        check_impl(":: InlineHashSet", quote!({ 1, 2, 3, 4, 5, 60000, 70000, 80000 }));
+        check_impl(":: InlineSimdProbeSet", quote!({ 1, 2, 3, 4, 5, 60000, 70000, 80000 }));
 
         check_impl(":: InlineScanSet", quote!({ x, 2, 3, 4, 5, 6, 7 }));
         check_impl(":: FzScalarSet", quote!({ x, 2, 3, 4, 5, 6, 7, 8 }));
+
+        check_impl(":: InlineDenseScalarLookupSet", quote!({ 'a', 'b', 'c' }));
+        check_impl(":: InlineBitsetScalarLookupSet", quote!({ 'a', 'e', 'i', 'o', 'u' }));
+        check_impl(":: InlineDenseScalarLookupSet", quote!({ false, true }));
+        check_impl(":: InlineDenseScalarLookupSet", quote!({ 1u128, 2u128, 3u128 }));
+        check_impl(":: InlineDenseScalarLookupSet", quote!({ 5i128, 6i128, 7i128 }));
+        check_impl(
+            ":: InlineSimdProbeSet",
+            quote!({ 1u128, 2u128, 3u128, 4u128, 5u128, 6u128, 7u128, 8u128, 340282366920938463463374607431768211455u128 }),
+        );
     }
 
     #[test]
@@ -267,22 +520,23 @@ mod tests {
             assert!(r.contains(expected), "{r} doesn't contain {expected}");
         }
 
-        check_impl(":: InlineDenseScalarLookupSet", quote!({ 1, 2, 3, }));
-        check_impl(":: InlineSparseScalarLookupSet", quote!({ 1, 2, 3, 4, 6 }));
-        check_impl(":: InlineScanSet", quote!({ 1, 2, 3, 4, 5, 60000, 70000 }));
-        check_impl(":: InlineHashSet", quote!({ 1, 2, 3, 4, 5, 60000, 70000, 80000 }));
+        // ordered collections are always searched, so they support range and order-based queries
+        check_impl(":: InlineEytzingerSearchSet", quote!({ 1, 2, 3, }));
+        check_impl(":: InlineEytzingerSearchSet", quote!({ 1, 2, 3, 4, 6 }));
+        check_impl(":: InlineEytzingerSearchSet", quote!({ 1, 2, 3, 4, 5, 60000, 70000, 80000 }));
 
-        check_impl(":: InlineScanSet", quote!({ "1", "2", "3", }));
-        check_impl(":: InlineHashSet", quote!({ "1", "2", "3", "4" }));
+        check_impl(":: InlineEytzingerSearchSet", quote!({ "1", "2", "3", }));
+        check_impl(":: InlineEytzingerSearchSet", quote!({ "1", "2", "3", "4" }));
+        check_impl(":: InlineEytzingerSearchSet", quote!({ b"1", b"2" }));
 
-        check_impl(":: InlineScanSet", quote!({ Foo(1), Foo(2), Foo(3),}));
+        check_impl(":: InlineEytzingerSearchSet", quote!({ Foo(1), Foo(2), Foo(3),}));
         check_impl(":: InlineEytzingerSearchSet", quote!({ Foo(1), Foo(2), Foo(3), Foo(4) }));
 
-        check_impl(":: InlineScanSet", quote!({ x, 2, 3, 4, 5, 6, 7 }));
-        check_impl(":: FzScalarSet", quote!({ x, 2, 3, 4, 5, 6, 7, 8 }));
+        check_impl(":: InlineEytzingerSearchSet", quote!({ x, 2, 3 }));
+        check_impl(":: InlineEytzingerSearchSet", quote!({ x, 2, 3, 4, 5, 6, 7, 8 }));
 
-        check_impl(":: InlineScanSet", quote!({ x, "2", "3", }));
-        check_impl(":: FzStringSet", quote!({ x, "2", "3", "4" }));
+        check_impl(":: InlineEytzingerSearchSet", quote!({ x, "2", "3", }));
+        check_impl(":: InlineEytzingerSearchSet", quote!({ x, "2", "3", "4" }));
     }
 
     #[test]
@@ -293,9 +547,9 @@ mod tests {
         }
 
         check_impl(":: InlineDenseScalarLookupSet", quote!({ 1, 2, 3, }));
-        check_impl(":: InlineSparseScalarLookupSet", quote!({ 1, 2, 3, 4, 6 }));
+        check_impl(":: InlineBitsetScalarLookupSet", quote!({ 1, 2, 3, 4, 6 }));
         check_impl(":: InlineScanSet", quote!({ 1, 2, 3, 4, 5, 60000, 70000 }));
-        check_impl(":: InlineHashSet", quote!({ 1, 2, 3, 4, 5, 60000, 70000, 80000 }));
+        check_impl(":: InlineSimdProbeSet", quote!({ 1, 2, 3, 4, 5, 60000, 70000, 80000 }));
 
         check_impl(":: InlineScanSet", quote!({ "1", "2", "3", }));
         check_impl(":: InlineHashSet", quote!({ "1", "2", "3", "4" }));
@@ -310,6 +564,129 @@ mod tests {
         check_impl(":: FzStringSet", quote!({ x, "2", "3", "4" }));
     }
 
+    #[test]
+    #[cfg(feature = "include")]
+    fn data_files() {
+        let r = fz_string_map_macro(quote!(include "data.yaml"));
+        assert_eq!(
+            "unable to determine the format of 'data.yaml', use `as json`, `as toml`, or `as csv`",
+            r.unwrap_err().to_string()
+        );
+
+        let r = fz_string_map_macro(quote!(include "data.yaml" as yaml));
+        assert_eq!("unknown data file format, expecting json, toml, or csv", r.unwrap_err().to_string());
+
+        let r = fz_string_map_macro(quote!(include "missing.json")).unwrap_err().to_string();
+        assert!(r.starts_with("unable to read"), "{r}");
+
+        let r = fz_string_map_macro(quote!(include "../frozen-collections/tests/data/countries.json"))
+            .unwrap()
+            .to_string();
+        assert!(r.contains("include_bytes"), "{r}");
+        assert!(r.contains("\"Canada\""), "{r}");
+
+        let r = fz_string_set_macro(quote!(include "../frozen-collections/tests/data/primes.json"));
+        assert_eq!("string macro cannot contain scalar keys", r.unwrap_err().to_string());
+
+        let r = fz_scalar_set_macro(quote!(include "../frozen-collections/tests/data/countries.json"));
+        assert_eq!("scalar macro cannot contain string keys", r.unwrap_err().to_string());
+
+        let r = fz_scalar_map_macro(quote!(include "../frozen-collections/tests/data/primes.json"));
+        assert_eq!("expecting each array element to be a [key, value] pair", r.unwrap_err().to_string());
+
+        let r = fz_scalar_map_macro(quote!(static M: Foo<u16, i32>, include "../frozen-collections/tests/data/http_status.toml"))
+            .unwrap()
+            .to_string();
+        assert!(r.contains("404u16"), "{r}");
+
+        let r = fz_bytes_map_macro(quote!(include "../frozen-collections/tests/data/countries.json"))
+            .unwrap()
+            .to_string();
+        assert!(r.contains("(b\"CA\" as & [u8] , \"Canada\")"), "{r}");
+
+        let r = fz_string_map_macro(quote!(include "../frozen-collections/tests/data/http_status.toml" as csv));
+        assert_eq!("expecting a key and a value on line 1", r.unwrap_err().to_string());
+
+        let r = fz_string_map_macro(quote!(include "../frozen-collections/tests/data/duplicates.json"));
+        let r = r.unwrap_err().to_string();
+        assert!(r.starts_with("duplicate key \"CA\" in '"), "{r}");
+
+        let r = fz_scalar_map_macro(quote!(include "../frozen-collections/tests/data/duplicates.csv"));
+        let r = r.unwrap_err().to_string();
+        assert!(r.starts_with("duplicate key 80 in '"), "{r}");
+
+        // integers are written as floats when other values in the file are floats
+        let r = fz_string_map_macro(quote!(include "../frozen-collections/tests/data/rates.csv"))
+            .unwrap()
+            .to_string();
+        assert!(r.contains("(\"usd\" , 1.0)"), "{r}");
+        assert!(r.contains("(\"eur\" , 0.92)"), "{r}");
+    }
+
+    #[test]
+    fn analysis_report() {
+        let r = fz_string_map_macro(quote!(
+            #[analysis_report]
+            static M: Foo<&str, i32>, { "1111": 1, "1112": 2, "1113": 3, "1114": 4, "1115": 5, "1116": 6, "1117": 7 }
+        ))
+        .unwrap()
+        .to_string();
+        assert!(r.contains("# [doc = \" Collection type: `InlineHashMapNoCollisions`\"]"), "{r}");
+        assert!(r.contains("Key hashing: LeftSubslice over bytes 3..4"), "{r}");
+
+        let r = fz_scalar_set_macro(quote!(static S: Bar<i32>, { 1, 2, 3 })).unwrap().to_string();
+        assert!(!r.contains("doc"), "{r}");
+
+        let r = fz_scalar_set_macro(quote!(#[analysis_report] { 1, 2, 3 }));
+        assert_eq!(
+            "`#[analysis_report]` requires the long form of the macro",
+            r.unwrap_err().to_string()
+        );
+
+        let r = fz_scalar_set_macro(quote!(#[inline] static S: Bar<i32>, { 1, 2, 3 }));
+        assert_eq!(
+            "unsupported attribute, expecting `#[analysis_report]`, `#[ignore_ascii_case]`, `#[seed = N]`, or `#[hasher = H]`",
+            r.unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn ignore_ascii_case() {
+        let r = fz_string_set_macro(quote!(#[ignore_ascii_case] { "a", "B", "A" }))
+            .unwrap()
+            .to_string();
+        assert!(
+            r.contains(":: InlineScanSet :: < :: frozen_collections :: AsciiCaseInsensitive < _ > , 2 >"),
+            "{r}"
+        );
+        assert!(r.contains(":: frozen_collections :: AsciiCaseInsensitive (\"B\")"), "{r}");
+
+        let r = fz_string_map_macro(quote!(
+            #[ignore_ascii_case]
+            static M: Foo<&str, i32>, { "Alpha": 1, "Beta": 2, "Gamma": 3, "Delta": 4, "Epsilon": 5 }
+        ))
+        .unwrap()
+        .to_string();
+        assert!(r.contains("type Foo = :: frozen_collections :: inline_maps ::"), "{r}");
+        assert!(r.contains(":: frozen_collections :: AsciiCaseInsensitive < & str >"), "{r}");
+        assert!(r.contains(":: hashers :: AsciiCaseInsensitiveHasher <"), "{r}");
+
+        let r = fz_string_set_macro(quote!(#[ignore_ascii_case] { x, "b", "c", "d" }))
+            .unwrap()
+            .to_string();
+        assert!(
+            r.contains(":: FzStringSet :: < :: frozen_collections :: AsciiCaseInsensitive < _ > >"),
+            "{r}"
+        );
+        assert!(r.contains("(:: frozen_collections :: AsciiCaseInsensitive (x) , ())"), "{r}");
+
+        let r = fz_scalar_set_macro(quote!(#[ignore_ascii_case] { 1, 2, 3 }));
+        assert_eq!(
+            "`#[ignore_ascii_case]` is only supported by `fz_string_map!` and `fz_string_set!`",
+            r.unwrap_err().to_string()
+        );
+    }
+
     #[test]
     fn test_scalar_suffixes() {
         let r = fz_scalar_set_macro(quote!({ 1i8, 2, 3, 4, 5, 6 })).unwrap().to_string();
@@ -319,4 +696,101 @@ mod tests {
         assert!(!r.contains("1i8"));
         assert!(r.contains("1i32"));
     }
+
+    #[test]
+    fn test_wide_and_non_integer_scalars() {
+        let r = fz_scalar_map_macro(quote!({ 1u128: 10, 2u128: 20 })).unwrap().to_string();
+        assert!(r.contains("1u128"));
+
+        let r = fz_scalar_map_macro(quote!({ 'x': 1, 'y': 2 })).unwrap().to_string();
+        assert!(r.contains("'x'"));
+
+        let r = fz_scalar_set_macro(quote!({ 0i128, 340282366920938463463374607431768211455i128 }));
+        assert_eq!("number too large to fit in target type", r.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn seed() {
+        let entries = quote!({ "apple", "banana", "cherry", "damson", "elder", "fig", "grape", "honeydew", "kiwi", "lemon" });
+
+        let r1 = fz_string_set_macro(quote!(#[seed = 42] #entries)).unwrap().to_string();
+        let r2 = fz_string_set_macro(quote!(#[seed = 42] #entries)).unwrap().to_string();
+        assert_eq!(r1, r2);
+        assert!(r1.contains("with_seed (42u64)"), "{r1}");
+
+        let r = fz_hash_set_macro(quote!(#[seed = 0x10] static S: Foo<&str>, #entries));
+        assert!(r.unwrap().to_string().contains("with_seed (16u64)"));
+
+        // collections of non-literal keys are created at runtime, with a hasher using the seed
+        let r = fz_hash_set_macro(quote!(#[seed = 42] { a, b, c, d })).unwrap().to_string();
+        assert!(
+            r.contains(":: FzHashSet :: < _ , :: frozen_collections :: foldhash :: FixedState >"),
+            "{r}"
+        );
+        assert!(r.contains("with_hasher"), "{r}");
+        assert!(r.contains("FixedState :: with_seed (42u64)"), "{r}");
+
+        let r = fz_string_map_macro(quote!(#[seed = 42] { x: 1, y: 2, z: 3, w: 4 }))
+            .unwrap()
+            .to_string();
+        assert!(r.contains("FixedState :: with_seed (42u64)"), "{r}");
+
+        let r = fz_string_set_macro(quote!({ a, b, c, d })).unwrap().to_string();
+        assert!(!r.contains("with_seed"), "{r}");
+
+        let r = fz_hash_set_macro(quote!(#[seed = "x"] { 1, 2, 3 }));
+        assert_eq!("expected an integer seed", r.unwrap_err().to_string());
+
+        let r = fz_hash_set_macro(quote!(#[seed = -1] { 1, 2, 3 }));
+        assert!(r.is_err());
+    }
+
+    #[test]
+    fn hasher() {
+        let r = fz_hash_map_macro(quote!(#[hasher = my::Hasher] static M: Foo<i32, i32>, { 1: 10, 2: 20 }))
+            .unwrap()
+            .to_string();
+        assert!(
+            r.contains(
+                "type Foo = :: frozen_collections :: maps :: HashMap :: < i32 , i32 , :: frozen_collections :: SmallCollection , :: frozen_collections :: hashers :: BridgeHasher < my :: Hasher > >"
+            ),
+            "{r}"
+        );
+        assert!(r.contains("< my :: Hasher as :: core :: default :: Default > :: default ()"), "{r}");
+        assert!(r.contains("LazyLock"), "{r}");
+
+        let r = fz_string_set_macro(quote!(#[hasher = my::Hasher] #[ignore_ascii_case] let s: Foo<&str>, { "a", x }))
+            .unwrap()
+            .to_string();
+        assert!(
+            r.contains(":: FzStringSet :: < :: frozen_collections :: AsciiCaseInsensitive < Box < str >> , my :: Hasher >"),
+            "{r}"
+        );
+
+        let r = fz_hash_set_macro(quote!(#[hasher = my::Hasher] #[seed = 42] static S: Foo<i32>, { 1, 2, 3 }));
+        assert_eq!("a seed can't be combined with a custom hasher", r.unwrap_err().to_string());
+
+        let r = fz_hash_set_macro(quote!(#[hasher = my::Hasher] { 1, 2, 3 }));
+        assert_eq!("`#[hasher]` requires the long form of the macro", r.unwrap_err().to_string());
+
+        let r = fz_ordered_set_macro(quote!(#[hasher = my::Hasher] static S: Foo<i32>, { 1, 2, 3 }));
+        assert_eq!(
+            "`#[hasher]` is only supported by `fz_hash_map!`, `fz_hash_set!`, `fz_string_map!`, and `fz_string_set!`",
+            r.unwrap_err().to_string()
+        );
+
+        let r = fz_hash_set_macro(quote!(#[hasher = 42] static S: Foo<i32>, { 1, 2, 3 }));
+        assert_eq!("expected the path of a `BuildHasher` type", r.unwrap_err().to_string());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parse_seed_values() {
+        assert_eq!(42, parse_seed("42").unwrap());
+        assert_eq!(255, parse_seed(" 0xff ").unwrap());
+        assert_eq!(
+            "`FROZEN_COLLECTIONS_SEED` must be an unsigned 64-bit integer, found `abc`: invalid digit found in string",
+            parse_seed("abc").unwrap_err().to_string()
+        );
+    }
 }
@@ -3,6 +3,8 @@
 pub use derive_scalar_macro::derive_scalar_macro;
 pub use macro_api::*;
 
+#[cfg(feature = "include")]
+mod data_files;
 mod derive_scalar_macro;
 mod macro_api;
 mod parsing;
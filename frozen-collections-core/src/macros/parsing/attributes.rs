@@ -0,0 +1,49 @@
+use quote::ToTokens;
+use syn::parse::ParseStream;
+use syn::{Attribute, Expr, ExprLit, Lit, Meta, Type, parse2};
+
+/// The attributes which may precede a collection macro.
+#[derive(Default)]
+pub struct Attributes {
+    /// `#[analysis_report]`, which is only supported by the long form of the macros.
+    pub analysis_report: Option<Attribute>,
+
+    /// `#[ignore_ascii_case]`, which is only supported by the string macros.
+    pub ignore_ascii_case: Option<Attribute>,
+
+    /// `#[seed = N]`, which pins the seed used to hash keys.
+    pub seed: Option<u64>,
+
+    /// `#[hasher = path::To::BuildHasher]`, which is only supported by the long form of the hash and string macros.
+    pub hasher: Option<(Attribute, Type)>,
+}
+
+/// Parses the attributes which may precede a collection macro.
+pub fn parse_attributes(input: ParseStream) -> syn::Result<Attributes> {
+    let mut attributes = Attributes::default();
+    for attr in input.call(Attribute::parse_outer)? {
+        match &attr.meta {
+            Meta::Path(path) if path.is_ident("analysis_report") => attributes.analysis_report = Some(attr),
+            Meta::Path(path) if path.is_ident("ignore_ascii_case") => attributes.ignore_ascii_case = Some(attr),
+            Meta::NameValue(nv) if nv.path.is_ident("seed") => match &nv.value {
+                Expr::Lit(ExprLit { lit: Lit::Int(lit), .. }) => attributes.seed = Some(lit.base10_parse()?),
+                _ => return Err(syn::Error::new_spanned(&nv.value, "expected an integer seed")),
+            },
+            Meta::NameValue(nv) if nv.path.is_ident("hasher") => match &nv.value {
+                Expr::Path(path) => {
+                    let hasher = parse2::<Type>(path.to_token_stream())?;
+                    attributes.hasher = Some((attr, hasher));
+                }
+                _ => return Err(syn::Error::new_spanned(&nv.value, "expected the path of a `BuildHasher` type")),
+            },
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "unsupported attribute, expecting `#[analysis_report]`, `#[ignore_ascii_case]`, `#[seed = N]`, or `#[hasher = H]`",
+                ));
+            }
+        }
+    }
+
+    Ok(attributes)
+}
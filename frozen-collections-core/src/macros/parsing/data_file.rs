@@ -0,0 +1,66 @@
+use syn::parse::ParseStream;
+use syn::{Ident, LitStr, Token};
+
+#[cfg(not(feature = "std"))]
+use {alloc::format, alloc::string::ToString};
+
+syn::custom_keyword!(include);
+
+/// The formats supported for data files.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum DataFormat {
+    Json,
+    Toml,
+    Csv,
+}
+
+/// A file holding the entries of a collection, read when the macro is expanded.
+#[cfg_attr(not(feature = "include"), expect(dead_code, reason = "Only used when files can be read"))]
+pub struct DataFile {
+    pub path: LitStr,
+    pub format: DataFormat,
+    pub is_map: bool,
+}
+
+impl DataFile {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(include)
+    }
+
+    pub fn parse(input: ParseStream, is_map: bool) -> syn::Result<Self> {
+        // include "path" [as format]
+        _ = input.parse::<include>()?;
+        let path = input.parse::<LitStr>()?;
+
+        let format = if input.peek(Token![as]) {
+            _ = input.parse::<Token![as]>()?;
+            let format = input.parse::<Ident>()?;
+            match format.to_string().as_str() {
+                "json" => DataFormat::Json,
+                "toml" => DataFormat::Toml,
+                "csv" => DataFormat::Csv,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        format,
+                        "unknown data file format, expecting json, toml, or csv",
+                    ));
+                }
+            }
+        } else {
+            let value = path.value();
+            match value.rsplit_once('.').map(|(_, ext)| ext.to_ascii_lowercase()).as_deref() {
+                Some("json") => DataFormat::Json,
+                Some("toml") => DataFormat::Toml,
+                Some("csv") => DataFormat::Csv,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        format!("unable to determine the format of '{value}', use `as json`, `as toml`, or `as csv`"),
+                    ));
+                }
+            }
+        };
+
+        Ok(Self { path, format, is_map })
+    }
+}
@@ -1,7 +1,7 @@
 use crate::macros::parsing::payload::{Payload, parse_map_payload};
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
-use syn::{Token, Type, Visibility};
+use syn::{Attribute, Token, Type, Visibility};
 
 pub struct LongFormMap {
     pub var_name: Ident,
@@ -14,6 +14,10 @@ pub struct LongFormMap {
     pub visibility: Visibility,
     pub is_static: bool,
     pub is_mutable: bool,
+    pub analysis_report: bool,
+    pub ignore_ascii_case: Option<Attribute>,
+    pub seed: Option<u64>,
+    pub hasher: Option<(Attribute, Type)>,
 }
 
 impl Parse for LongFormMap {
@@ -42,6 +46,10 @@ impl Parse for LongFormMap {
             visibility: Visibility::Inherited,
             is_static: false,
             is_mutable: false,
+            analysis_report: false,
+            ignore_ascii_case: None,
+            seed: None,
+            hasher: None,
         })
     }
 }
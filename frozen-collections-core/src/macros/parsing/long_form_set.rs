@@ -1,7 +1,7 @@
 use crate::macros::parsing::payload::{Payload, parse_set_payload};
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
-use syn::{Expr, Token, Type, Visibility};
+use syn::{Attribute, Expr, Token, Type, Visibility};
 
 pub struct LongFormSet {
     pub var_name: Ident,
@@ -12,6 +12,10 @@ pub struct LongFormSet {
     pub visibility: Visibility,
     pub is_static: bool,
     pub is_mutable: bool,
+    pub analysis_report: bool,
+    pub ignore_ascii_case: Option<Attribute>,
+    pub seed: Option<u64>,
+    pub hasher: Option<(Attribute, Type)>,
 }
 
 impl Parse for LongFormSet {
@@ -37,6 +41,10 @@ impl Parse for LongFormSet {
             visibility: Visibility::Inherited,
             is_static: false,
             is_mutable: false,
+            analysis_report: false,
+            ignore_ascii_case: None,
+            seed: None,
+            hasher: None,
         })
     }
 }
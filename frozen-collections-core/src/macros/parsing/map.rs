@@ -1,3 +1,4 @@
+use crate::macros::parsing::attributes::parse_attributes;
 use crate::macros::parsing::long_form_map::LongFormMap;
 use crate::macros::parsing::short_form_map::ShortFormMap;
 use syn::parse::Parse;
@@ -11,6 +12,7 @@ pub enum Map {
 
 impl Parse for Map {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let attributes = parse_attributes(input)?;
         let visibility = input.parse::<Visibility>()?;
         if visibility != Visibility::Inherited && !input.peek(Token![static]) {
             return Err(input.error("expected `static`"));
@@ -21,6 +23,10 @@ impl Parse for Map {
             let mut m = input.parse::<LongFormMap>()?;
             m.visibility = visibility;
             m.is_static = true;
+            m.analysis_report = attributes.analysis_report.is_some();
+            m.ignore_ascii_case = attributes.ignore_ascii_case;
+            m.seed = attributes.seed;
+            m.hasher = attributes.hasher;
             Ok(Self::Long(m))
         } else if input.peek(Token![let]) {
             _ = input.parse::<Token![let]>()?;
@@ -36,9 +42,23 @@ impl Parse for Map {
             m.visibility = visibility;
             m.is_static = false;
             m.is_mutable = is_mutable;
+            m.analysis_report = attributes.analysis_report.is_some();
+            m.ignore_ascii_case = attributes.ignore_ascii_case;
+            m.seed = attributes.seed;
+            m.hasher = attributes.hasher;
             Ok(Self::Long(m))
+        } else if let Some(attr) = attributes.analysis_report {
+            Err(syn::Error::new_spanned(
+                attr,
+                "`#[analysis_report]` requires the long form of the macro",
+            ))
+        } else if let Some((attr, _)) = attributes.hasher {
+            Err(syn::Error::new_spanned(attr, "`#[hasher]` requires the long form of the macro"))
         } else {
-            Ok(Self::Short(input.parse()?))
+            let mut m: ShortFormMap = input.parse()?;
+            m.ignore_ascii_case = attributes.ignore_ascii_case;
+            m.seed = attributes.seed;
+            Ok(Self::Short(m))
         }
     }
 }
@@ -1,3 +1,5 @@
+pub(super) mod attributes;
+pub(super) mod data_file;
 pub(super) mod entry;
 pub(super) mod long_form_map;
 pub(super) mod long_form_set;
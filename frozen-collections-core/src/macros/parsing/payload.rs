@@ -1,3 +1,4 @@
+use crate::macros::parsing::data_file::DataFile;
 use crate::macros::parsing::entry::Entry;
 use crate::macros::parsing::long_form_set::SetEntry;
 use syn::parse::Parse;
@@ -9,9 +10,18 @@ use alloc::vec::Vec;
 /// Data associated with a frozen collection macro.
 pub struct Payload {
     pub entries: Vec<Entry>,
+    pub data_file: Option<DataFile>,
 }
 
 pub fn parse_set_payload(input: syn::parse::ParseStream) -> syn::Result<Payload> {
+    if DataFile::peek(input) {
+        // include "path" [as format]
+        return Ok(Payload {
+            entries: Vec::new(),
+            data_file: Some(DataFile::parse(input, false)?),
+        });
+    }
+
     // { value, value, ... };
     let content;
     _ = braced!(content in input);
@@ -22,15 +32,25 @@ pub fn parse_set_payload(input: syn::parse::ParseStream) -> syn::Result<Payload>
             .into_iter()
             .map(|x| Entry { key: x.value, value: None })
             .collect(),
+        data_file: None,
     })
 }
 
 pub fn parse_map_payload(input: syn::parse::ParseStream) -> syn::Result<Payload> {
+    if DataFile::peek(input) {
+        // include "path" [as format]
+        return Ok(Payload {
+            entries: Vec::new(),
+            data_file: Some(DataFile::parse(input, true)?),
+        });
+    }
+
     // { key: value, key: value, ... };
     let content;
     _ = braced!(content in input);
 
     Ok(Payload {
         entries: content.parse_terminated(Entry::parse, Token![,])?.into_iter().collect(),
+        data_file: None,
     })
 }
@@ -1,3 +1,4 @@
+use crate::macros::parsing::attributes::parse_attributes;
 use crate::macros::parsing::long_form_set::LongFormSet;
 use crate::macros::parsing::short_form_set::ShortFormSet;
 use syn::parse::Parse;
@@ -11,6 +12,7 @@ pub enum Set {
 
 impl Parse for Set {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let attributes = parse_attributes(input)?;
         let visibility = input.parse::<Visibility>()?;
         if visibility != Visibility::Inherited && !input.peek(Token![static]) {
             return Err(input.error("expected `static`"));
@@ -21,6 +23,10 @@ impl Parse for Set {
             let mut s = input.parse::<LongFormSet>()?;
             s.visibility = visibility;
             s.is_static = true;
+            s.analysis_report = attributes.analysis_report.is_some();
+            s.ignore_ascii_case = attributes.ignore_ascii_case;
+            s.seed = attributes.seed;
+            s.hasher = attributes.hasher;
             Ok(Self::Long(s))
         } else if input.peek(Token![let]) {
             _ = input.parse::<Token![let]>()?;
@@ -36,9 +42,23 @@ impl Parse for Set {
             s.visibility = visibility;
             s.is_static = false;
             s.is_mutable = is_mutable;
+            s.analysis_report = attributes.analysis_report.is_some();
+            s.ignore_ascii_case = attributes.ignore_ascii_case;
+            s.seed = attributes.seed;
+            s.hasher = attributes.hasher;
             Ok(Self::Long(s))
+        } else if let Some(attr) = attributes.analysis_report {
+            Err(syn::Error::new_spanned(
+                attr,
+                "`#[analysis_report]` requires the long form of the macro",
+            ))
+        } else if let Some((attr, _)) = attributes.hasher {
+            Err(syn::Error::new_spanned(attr, "`#[hasher]` requires the long form of the macro"))
         } else {
-            Ok(Self::Short(input.parse()?))
+            let mut s: ShortFormSet = input.parse()?;
+            s.ignore_ascii_case = attributes.ignore_ascii_case;
+            s.seed = attributes.seed;
+            Ok(Self::Short(s))
         }
     }
 }
@@ -1,14 +1,19 @@
 use crate::macros::parsing::payload::{Payload, parse_map_payload};
+use syn::Attribute;
 use syn::parse::{Parse, ParseStream};
 
 pub struct ShortFormMap {
     pub payload: Payload,
+    pub ignore_ascii_case: Option<Attribute>,
+    pub seed: Option<u64>,
 }
 
 impl Parse for ShortFormMap {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(Self {
             payload: parse_map_payload(input)?,
+            ignore_ascii_case: None,
+            seed: None,
         })
     }
 }
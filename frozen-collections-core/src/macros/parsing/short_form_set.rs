@@ -1,14 +1,19 @@
 use crate::macros::parsing::payload::{Payload, parse_set_payload};
+use syn::Attribute;
 use syn::parse::{Parse, ParseStream};
 
 pub struct ShortFormSet {
     pub payload: Payload,
+    pub ignore_ascii_case: Option<Attribute>,
+    pub seed: Option<u64>,
 }
 
 impl Parse for ShortFormSet {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(Self {
             payload: parse_set_payload(input)?,
+            ignore_ascii_case: None,
+            seed: None,
         })
     }
 }
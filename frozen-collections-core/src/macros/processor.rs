@@ -1,4 +1,6 @@
 use crate::emit::{CollectionEmitter, CollectionEntry, NonLiteralKey};
+#[cfg(feature = "include")]
+use crate::macros::data_files::track_data_file;
 use crate::macros::parsing::entry::Entry;
 use crate::macros::parsing::payload::Payload;
 use crate::traits::Scalar;
@@ -7,31 +9,52 @@ use core::fmt::Display;
 use core::str::FromStr;
 use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
-use syn::{Expr, ExprLit, Lit, LitInt, LitStr, parse_str, parse2};
+use syn::{Expr, ExprLit, ExprPath, Lit, LitBool, LitByteStr, LitChar, LitInt, LitStr, parse_quote, parse_str, parse2};
 
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use {alloc::string::String, alloc::string::ToString, alloc::vec::Vec};
 
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub(super) enum MacroKind {
     Scalar,
     String,
+    Bytes,
+    Prefix,
+    Enum,
     Hashed,
     Ordered,
 }
 
+impl MacroKind {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Scalar => "scalar",
+            Self::String => "string",
+            Self::Bytes => "bytes",
+            Self::Prefix => "prefix",
+            Self::Enum => "enum",
+            Self::Hashed => "hash",
+            Self::Ordered => "ordered",
+        }
+    }
+}
+
 #[derive(Eq, PartialEq)]
 enum ScalarType {
     I8,
     I16,
     I32,
     I64,
+    I128,
     ISize,
     U8,
     U16,
     U32,
     U64,
+    U128,
     USize,
+    Char,
+    Bool,
     Undecided,
 }
 
@@ -39,6 +62,7 @@ enum ScalarType {
 enum DiscoveredKeyKind {
     LiteralScalar(ScalarType),
     LiteralString,
+    LiteralBytes,
     Expression,
 }
 
@@ -47,39 +71,105 @@ enum EffectiveKeyKind {
     LiteralAndExpressionScalars,
     AllLiteralStrings,
     LiteralAndExpressionStrings,
+    AllLiteralBytes,
+    LiteralAndExpressionBytes,
+    Prefix,
+    Enum,
     Hashed,
     Ordered,
 }
 
 pub(super) fn process(payload: Payload, emitter: CollectionEmitter, macro_kind: MacroKind) -> syn::Result<TokenStream> {
-    let entries = payload.entries;
+    let Some(data_file) = payload.data_file else {
+        return process_entries(payload.entries, emitter, macro_kind);
+    };
+
+    #[cfg(feature = "include")]
+    {
+        let path = data_file.resolve()?;
+        let entries = data_file.load(&path, macro_kind, &emitter.key_type, emitter.value_type.as_ref())?;
+
+        // the short forms expand to an expression, the long forms to statements
+        let is_expression = emitter.inferred_key_type;
+        let tokens = process_entries(entries, emitter, macro_kind)?;
+        Ok(track_data_file(&path, &tokens, is_expression))
+    }
+
+    #[cfg(not(feature = "include"))]
+    {
+        _ = emitter;
+        Err(syn::Error::new_spanned(
+            data_file.path,
+            "reading collection entries from a file requires the `include` feature",
+        ))
+    }
+}
+
+fn process_entries(entries: Vec<Entry>, emitter: CollectionEmitter, macro_kind: MacroKind) -> syn::Result<TokenStream> {
     if entries.is_empty() {
-        return if emitter.inferred_key_type {
+        return if emitter.inferred_key_type || macro_kind == MacroKind::Enum {
             Err(syn::Error::new(Span::call_site(), "no collection entries supplied"))
         } else {
-            emitter
-                .const_keys(true)
-                .const_values(true)
-                .emit_hash_collection(Vec::<CollectionEntry<i32>>::new())
-                .map_err(|e| syn::Error::new(Span::call_site(), e.as_str()))
+            emit_literal_keys(emitter, Vec::<CollectionEntry<i32>>::new(), macro_kind, |emitter, entries| {
+                emitter.emit_hash_collection(entries)
+            })
         };
     }
 
+    // a custom hasher can't be run ahead of time, so there's nothing to gain from analyzing the keys
+    if emitter.hasher.is_some() && macro_kind == MacroKind::Hashed {
+        return handle_hashed_keys(emitter, entries);
+    }
+
     match assess_keys(&entries, macro_kind)? {
-        EffectiveKeyKind::AllLiteralScalars(ScalarType::I8) => handle_literal_scalar_keys::<i8>(emitter, entries, ""),
-        EffectiveKeyKind::AllLiteralScalars(ScalarType::I16) => handle_literal_scalar_keys::<i16>(emitter, entries, ""),
-        EffectiveKeyKind::AllLiteralScalars(ScalarType::I32) => handle_literal_scalar_keys::<i32>(emitter, entries, ""),
-        EffectiveKeyKind::AllLiteralScalars(ScalarType::I64) => handle_literal_scalar_keys::<i64>(emitter, entries, ""),
-        EffectiveKeyKind::AllLiteralScalars(ScalarType::ISize) => handle_literal_scalar_keys::<isize>(emitter, entries, ""),
-        EffectiveKeyKind::AllLiteralScalars(ScalarType::U8) => handle_literal_scalar_keys::<u8>(emitter, entries, ""),
-        EffectiveKeyKind::AllLiteralScalars(ScalarType::U16) => handle_literal_scalar_keys::<u16>(emitter, entries, ""),
-        EffectiveKeyKind::AllLiteralScalars(ScalarType::U32) => handle_literal_scalar_keys::<u32>(emitter, entries, ""),
-        EffectiveKeyKind::AllLiteralScalars(ScalarType::U64) => handle_literal_scalar_keys::<u64>(emitter, entries, ""),
-        EffectiveKeyKind::AllLiteralScalars(ScalarType::USize) => handle_literal_scalar_keys::<usize>(emitter, entries, ""),
-        EffectiveKeyKind::AllLiteralScalars(ScalarType::Undecided) => handle_literal_scalar_keys::<i32>(emitter, entries, "i32"),
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::I8) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<i8>(key, ""))
+        }
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::I16) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<i16>(key, ""))
+        }
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::I32) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<i32>(key, ""))
+        }
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::I64) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<i64>(key, ""))
+        }
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::I128) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<i128>(key, ""))
+        }
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::ISize) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<isize>(key, ""))
+        }
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::U8) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<u8>(key, ""))
+        }
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::U16) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<u16>(key, ""))
+        }
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::U32) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<u32>(key, ""))
+        }
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::U64) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<u64>(key, ""))
+        }
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::U128) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<u128>(key, ""))
+        }
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::USize) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<usize>(key, ""))
+        }
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::Char) => handle_literal_scalar_keys(emitter, entries, macro_kind, parse_char_key),
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::Bool) => handle_literal_scalar_keys(emitter, entries, macro_kind, parse_bool_key),
+        EffectiveKeyKind::AllLiteralScalars(ScalarType::Undecided) => {
+            handle_literal_scalar_keys(emitter, entries, macro_kind, |key| parse_int_key::<i32>(key, "i32"))
+        }
         EffectiveKeyKind::LiteralAndExpressionScalars => handle_non_literal_scalar_keys(emitter, entries),
-        EffectiveKeyKind::AllLiteralStrings => handle_literal_string_keys(emitter, entries),
+        EffectiveKeyKind::AllLiteralStrings => handle_literal_string_keys(emitter, entries, macro_kind),
         EffectiveKeyKind::LiteralAndExpressionStrings => handle_non_literal_string_keys(emitter, entries),
+        EffectiveKeyKind::AllLiteralBytes => handle_literal_bytes_keys(emitter, entries, macro_kind),
+        EffectiveKeyKind::LiteralAndExpressionBytes => handle_non_literal_bytes_keys(emitter, entries),
+        EffectiveKeyKind::Prefix => handle_prefix_keys(emitter, entries),
+        EffectiveKeyKind::Enum => handle_enum_keys(emitter, entries),
         EffectiveKeyKind::Hashed => handle_hashed_keys(emitter, entries),
         EffectiveKeyKind::Ordered => handle_ordered_keys(emitter, entries),
     }
@@ -87,7 +177,9 @@ pub(super) fn process(payload: Payload, emitter: CollectionEmitter, macro_kind:
 
 fn assess_keys(entries: &[Entry], macro_kind: MacroKind) -> syn::Result<EffectiveKeyKind> {
     let mut num_strings = 0;
+    let mut num_bytes = 0;
     let mut num_scalars = 0;
+    let mut num_unsuffixed_ints = 0;
     let mut scalar_type: ScalarType = ScalarType::Undecided;
 
     for entry in entries {
@@ -100,17 +192,29 @@ fn assess_keys(entries: &[Entry], macro_kind: MacroKind) -> syn::Result<Effectiv
             _ => DiscoveredKeyKind::Expression,
         };
 
-        if macro_kind == MacroKind::Scalar && discovered_key_kind == DiscoveredKeyKind::LiteralString {
-            return Err(syn::Error::new(Span::call_site(), "scalar macro cannot contain string keys"));
-        } else if macro_kind == MacroKind::String
-            && discovered_key_kind != DiscoveredKeyKind::LiteralString
-            && discovered_key_kind != DiscoveredKeyKind::Expression
-        {
-            return Err(syn::Error::new(Span::call_site(), "string macro cannot contain scalar keys"));
+        let conflicting_key_kind = match (macro_kind, &discovered_key_kind) {
+            (MacroKind::Scalar | MacroKind::Bytes | MacroKind::Enum, DiscoveredKeyKind::LiteralString) => Some("string"),
+            (MacroKind::Scalar | MacroKind::String | MacroKind::Prefix | MacroKind::Enum, DiscoveredKeyKind::LiteralBytes) => {
+                Some("byte string")
+            }
+            (MacroKind::String | MacroKind::Bytes | MacroKind::Prefix | MacroKind::Enum, DiscoveredKeyKind::LiteralScalar(_)) => {
+                Some("scalar")
+            }
+            _ => None,
+        };
+
+        if let Some(key_kind) = conflicting_key_kind {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!("{} macro cannot contain {key_kind} keys", macro_kind.name()),
+            ));
         }
 
         match discovered_key_kind {
-            DiscoveredKeyKind::LiteralScalar(ScalarType::Undecided) => num_scalars += 1,
+            DiscoveredKeyKind::LiteralScalar(ScalarType::Undecided) => {
+                num_scalars += 1;
+                num_unsuffixed_ints += 1;
+            }
             DiscoveredKeyKind::LiteralScalar(discovered_scalar_type) => {
                 num_scalars += 1;
                 if scalar_type == ScalarType::Undecided {
@@ -121,22 +225,42 @@ fn assess_keys(entries: &[Entry], macro_kind: MacroKind) -> syn::Result<Effectiv
             }
 
             DiscoveredKeyKind::LiteralString => num_strings += 1,
+            DiscoveredKeyKind::LiteralBytes => num_bytes += 1,
             DiscoveredKeyKind::Expression => {}
         }
     }
 
-    Ok(if num_scalars == entries.len() {
+    // unsuffixed integers can take on any integer type, but they can't be mixed with chars or bools
+    if num_unsuffixed_ints > 0 && matches!(scalar_type, ScalarType::Char | ScalarType::Bool) {
+        return Err(syn::Error::new(Span::call_site(), "incompatible scalar literal type"));
+    }
+
+    Ok(if macro_kind == MacroKind::Prefix {
+        EffectiveKeyKind::Prefix
+    } else if macro_kind == MacroKind::Enum {
+        EffectiveKeyKind::Enum
+    } else if num_scalars == entries.len() {
         EffectiveKeyKind::AllLiteralScalars(scalar_type)
-    } else if num_scalars > 0 && num_strings == 0 {
-        EffectiveKeyKind::LiteralAndExpressionScalars
     } else if num_strings == entries.len() {
         EffectiveKeyKind::AllLiteralStrings
-    } else if num_strings > 0 {
+    } else if num_bytes == entries.len() {
+        EffectiveKeyKind::AllLiteralBytes
+    } else if macro_kind == MacroKind::Ordered {
+        // ordered collections are always searched, so keys which aren't all literals are sorted at runtime
+        EffectiveKeyKind::Ordered
+    } else if num_scalars > 0 && num_strings == 0 && num_bytes == 0 {
+        EffectiveKeyKind::LiteralAndExpressionScalars
+    } else if num_strings > 0 && num_bytes == 0 {
         EffectiveKeyKind::LiteralAndExpressionStrings
+    } else if num_bytes > 0 && num_strings == 0 {
+        EffectiveKeyKind::LiteralAndExpressionBytes
     } else {
         match macro_kind {
             MacroKind::Scalar => EffectiveKeyKind::LiteralAndExpressionScalars,
             MacroKind::String => EffectiveKeyKind::LiteralAndExpressionStrings,
+            MacroKind::Bytes => EffectiveKeyKind::LiteralAndExpressionBytes,
+            MacroKind::Prefix => EffectiveKeyKind::Prefix,
+            MacroKind::Enum => EffectiveKeyKind::Enum,
             MacroKind::Hashed => EffectiveKeyKind::Hashed,
             MacroKind::Ordered => EffectiveKeyKind::Ordered,
         }
@@ -146,16 +270,19 @@ fn assess_keys(entries: &[Entry], macro_kind: MacroKind) -> syn::Result<Effectiv
 fn eval_literal_expr(expr: &ExprLit) -> syn::Result<DiscoveredKeyKind> {
     let kind = match &expr.lit {
         Lit::Str(_) => DiscoveredKeyKind::LiteralString,
+        Lit::ByteStr(_) => DiscoveredKeyKind::LiteralBytes,
         Lit::Int(expr) => match expr.suffix() {
             "i8" => DiscoveredKeyKind::LiteralScalar(ScalarType::I8),
             "i16" => DiscoveredKeyKind::LiteralScalar(ScalarType::I16),
             "i32" => DiscoveredKeyKind::LiteralScalar(ScalarType::I32),
             "i64" => DiscoveredKeyKind::LiteralScalar(ScalarType::I64),
+            "i128" => DiscoveredKeyKind::LiteralScalar(ScalarType::I128),
             "isize" => DiscoveredKeyKind::LiteralScalar(ScalarType::ISize),
             "u8" => DiscoveredKeyKind::LiteralScalar(ScalarType::U8),
             "u16" => DiscoveredKeyKind::LiteralScalar(ScalarType::U16),
             "u32" => DiscoveredKeyKind::LiteralScalar(ScalarType::U32),
             "u64" => DiscoveredKeyKind::LiteralScalar(ScalarType::U64),
+            "u128" => DiscoveredKeyKind::LiteralScalar(ScalarType::U128),
             "usize" => DiscoveredKeyKind::LiteralScalar(ScalarType::USize),
             "" => DiscoveredKeyKind::LiteralScalar(ScalarType::Undecided),
             _ => {
@@ -165,29 +292,32 @@ fn eval_literal_expr(expr: &ExprLit) -> syn::Result<DiscoveredKeyKind> {
                 ));
             }
         },
+        Lit::Char(_) => DiscoveredKeyKind::LiteralScalar(ScalarType::Char),
+        Lit::Bool(_) => DiscoveredKeyKind::LiteralScalar(ScalarType::Bool),
         _ => {
-            return Err(syn::Error::new_spanned(expr, "invalid literal, expecting a scalar or string value"));
+            return Err(syn::Error::new_spanned(
+                expr,
+                "invalid literal, expecting a scalar, string, or byte string value",
+            ));
         }
     };
 
     Ok(kind)
 }
 
-fn handle_literal_scalar_keys<K>(emitter: CollectionEmitter, entries: Vec<Entry>, suffix: &str) -> syn::Result<TokenStream>
+fn handle_literal_scalar_keys<K, F>(
+    emitter: CollectionEmitter,
+    entries: Vec<Entry>,
+    macro_kind: MacroKind,
+    parse_key: F,
+) -> syn::Result<TokenStream>
 where
-    K: Scalar + Ord + FromStr,
-    K::Err: Display,
+    K: Scalar + Ord,
+    F: Fn(Expr) -> syn::Result<(K, Expr)>,
 {
     let mut coll_entries = Vec::with_capacity(entries.len());
     for entry in entries {
-        let lit = parse2::<LitInt>(entry.key.to_token_stream())?;
-        let k = lit.base10_parse::<K>()?;
-
-        let key = if suffix.is_empty() {
-            entry.key
-        } else {
-            parse_str::<Expr>(&format!("{lit}{suffix}"))?
-        };
+        let (k, key) = parse_key(entry.key)?;
 
         if entry.value.is_some() {
             coll_entries.push(CollectionEntry::map_entry(k, key, entry.value.unwrap()));
@@ -196,14 +326,60 @@ where
         }
     }
 
-    emitter
-        .const_keys(true)
-        .const_values(true)
-        .emit_scalar_collection(coll_entries)
-        .map_err(|e| syn::Error::new(Span::call_site(), e.as_str()))
+    emit_literal_keys(emitter, coll_entries, macro_kind, |emitter, entries| {
+        emitter.emit_scalar_collection(entries)
+    })
+}
+
+/// Emits a collection whose keys are all literals, which the ordered macros keep sorted rather than laying out
+/// according to the kind of the keys.
+fn emit_literal_keys<K>(
+    emitter: CollectionEmitter,
+    entries: Vec<CollectionEntry<K>>,
+    macro_kind: MacroKind,
+    emit: impl FnOnce(CollectionEmitter, Vec<CollectionEntry<K>>) -> Result<TokenStream, String>,
+) -> syn::Result<TokenStream>
+where
+    K: Ord,
+{
+    let emitter = emitter.const_keys(true).const_values(true);
+    let result = if macro_kind == MacroKind::Ordered {
+        emitter.emit_ordered_collection(entries)
+    } else {
+        emit(emitter, entries)
+    };
+
+    result.map_err(|e| syn::Error::new(Span::call_site(), e.as_str()))
+}
+
+fn parse_int_key<K>(key: Expr, suffix: &str) -> syn::Result<(K, Expr)>
+where
+    K: FromStr,
+    K::Err: Display,
+{
+    let lit = parse2::<LitInt>(key.to_token_stream())?;
+    let k = lit.base10_parse::<K>()?;
+
+    let key = if suffix.is_empty() {
+        key
+    } else {
+        parse_str::<Expr>(&format!("{lit}{suffix}"))?
+    };
+
+    Ok((k, key))
+}
+
+fn parse_char_key(key: Expr) -> syn::Result<(char, Expr)> {
+    let lit = parse2::<LitChar>(key.to_token_stream())?;
+    Ok((lit.value(), key))
+}
+
+fn parse_bool_key(key: Expr) -> syn::Result<(bool, Expr)> {
+    let lit = parse2::<LitBool>(key.to_token_stream())?;
+    Ok((lit.value, key))
 }
 
-fn handle_literal_string_keys(emitter: CollectionEmitter, entries: Vec<Entry>) -> syn::Result<TokenStream> {
+fn handle_literal_string_keys(emitter: CollectionEmitter, entries: Vec<Entry>, macro_kind: MacroKind) -> syn::Result<TokenStream> {
     let mut coll_entries = Vec::with_capacity(entries.len());
     for entry in entries {
         let ls = parse2::<LitStr>(entry.key.to_token_stream())?;
@@ -215,11 +391,25 @@ fn handle_literal_string_keys(emitter: CollectionEmitter, entries: Vec<Entry>) -
         }
     }
 
-    emitter
-        .const_keys(true)
-        .const_values(true)
-        .emit_string_collection(coll_entries)
-        .map_err(|e| syn::Error::new(Span::call_site(), e.as_str()))
+    emit_literal_keys(emitter, coll_entries, macro_kind, CollectionEmitter::emit_string_collection)
+}
+
+fn handle_literal_bytes_keys(emitter: CollectionEmitter, entries: Vec<Entry>, macro_kind: MacroKind) -> syn::Result<TokenStream> {
+    let mut coll_entries = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let lbs = parse2::<LitByteStr>(entry.key.to_token_stream())?;
+
+        // byte string literals are references to arrays, so they're turned into slices to share a single type
+        let key = parse_quote!(#lbs as &[u8]);
+
+        if entry.value.is_some() {
+            coll_entries.push(CollectionEntry::map_entry(lbs.value(), key, entry.value.unwrap()));
+        } else {
+            coll_entries.push(CollectionEntry::set_entry(lbs.value(), key));
+        }
+    }
+
+    emit_literal_keys(emitter, coll_entries, macro_kind, CollectionEmitter::emit_bytes_collection)
 }
 
 fn handle_non_literal_scalar_keys(emitter: CollectionEmitter, entries: Vec<Entry>) -> syn::Result<TokenStream> {
@@ -256,6 +446,101 @@ fn handle_non_literal_string_keys(emitter: CollectionEmitter, entries: Vec<Entry
         .map_err(|e| syn::Error::new(Span::call_site(), e.as_str()))
 }
 
+fn handle_non_literal_bytes_keys(emitter: CollectionEmitter, entries: Vec<Entry>) -> syn::Result<TokenStream> {
+    let mut coll_entries = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let key = as_byte_slice(entry.key);
+        if entry.value.is_some() {
+            coll_entries.push(CollectionEntry::map_entry(NonLiteralKey {}, key, entry.value.unwrap()));
+        } else {
+            coll_entries.push(CollectionEntry::set_entry(NonLiteralKey {}, key));
+        }
+    }
+
+    emitter
+        .const_keys(false)
+        .const_values(false)
+        .emit_bytes_collection_expr(coll_entries)
+        .map_err(|e| syn::Error::new(Span::call_site(), e.as_str()))
+}
+
+fn handle_prefix_keys(emitter: CollectionEmitter, entries: Vec<Entry>) -> syn::Result<TokenStream> {
+    let mut coll_entries = Vec::with_capacity(entries.len());
+    for entry in entries {
+        if entry.value.is_some() {
+            coll_entries.push(CollectionEntry::map_entry(NonLiteralKey {}, entry.key, entry.value.unwrap()));
+        } else {
+            coll_entries.push(CollectionEntry::set_entry(NonLiteralKey {}, entry.key));
+        }
+    }
+
+    emitter
+        .const_keys(false)
+        .const_values(false)
+        .emit_prefix_collection_expr(coll_entries)
+        .map_err(|e| syn::Error::new(Span::call_site(), e.as_str()))
+}
+
+fn handle_enum_keys(emitter: CollectionEmitter, entries: Vec<Entry>) -> syn::Result<TokenStream> {
+    let mut coll_entries: Vec<CollectionEntry<NonLiteralKey>> = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let path = match &entry.key {
+            Expr::Path(path) => Some(path),
+            Expr::Group(group) => match &*group.expr {
+                Expr::Path(path) => Some(path),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let Some(path) = path else {
+            return Err(syn::Error::new_spanned(entry.key, "enum map keys must be enum variants"));
+        };
+
+        // the keys become match patterns, where a lone lowercase name would bind every variant instead of naming one
+        if is_binding(path) {
+            return Err(syn::Error::new_spanned(
+                entry.key,
+                "enum map keys must be enum variants, a lowercase name would match every variant",
+            ));
+        }
+
+        let Some(value) = entry.value else {
+            return Err(syn::Error::new_spanned(entry.key, "enum map entries must have values"));
+        };
+
+        // the keys become match patterns, so duplicates are removed here to let the last one win like in the other maps
+        coll_entries.retain(|e| e.key_expr != entry.key);
+        coll_entries.push(CollectionEntry::map_entry(NonLiteralKey {}, entry.key, value));
+    }
+
+    emitter
+        .const_keys(false)
+        .const_values(false)
+        .emit_enum_collection_expr(coll_entries)
+        .map_err(|e| syn::Error::new(Span::call_site(), e.as_str()))
+}
+
+/// Returns whether a path would be taken as a new binding rather than a variant when used as a match pattern.
+fn is_binding(path: &ExprPath) -> bool {
+    path.qself.is_none()
+        && path
+            .path
+            .get_ident()
+            .is_some_and(|ident| ident.to_string().starts_with(|c: char| c.is_lowercase() || c == '_'))
+}
+
+/// Turns byte string literals into slices, so they have the same type as the other keys.
+fn as_byte_slice(key: Expr) -> Expr {
+    match &key {
+        Expr::Lit(ExprLit { lit: Lit::ByteStr(_), .. }) => parse_quote!(#key as &[u8]),
+        Expr::Group(group) if matches!(&*group.expr, Expr::Lit(ExprLit { lit: Lit::ByteStr(_), .. })) => {
+            parse_quote!(#key as &[u8])
+        }
+        _ => key,
+    }
+}
+
 fn handle_hashed_keys(emitter: CollectionEmitter, entries: Vec<Entry>) -> syn::Result<TokenStream> {
     let mut coll_entries = Vec::with_capacity(entries.len());
     for entry in entries {
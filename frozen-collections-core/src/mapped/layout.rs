@@ -0,0 +1,204 @@
+//! The byte layout shared by the writer and the reader of mapped maps.
+//!
+//! Every integer is stored as a little-endian `u64`, and nothing in the layout depends on
+//! alignment, so a buffer can be used wherever it happens to be placed in memory.
+//!
+//! ```text
+//! header   magic, version, hasher kind, seed, range start, range end,
+//!          number of slots, number of entries, data length, hash check
+//! slots    (min index, max index) for every hash slot
+//! entries  (key offset, key length, value length) for every entry, the value following its key
+//! data     the bytes of every key and value
+//! ```
+
+use core::hash::{BuildHasher, Hasher};
+use core::ops::Range;
+use foldhash::fast::FixedState;
+
+#[cfg(not(feature = "std"))]
+use {alloc::format, alloc::string::String, alloc::string::ToString};
+
+pub const MAGIC: [u8; 8] = *b"FZMAPPED";
+pub const VERSION: u64 = 1;
+
+pub const HEADER_LEN: usize = 80;
+pub const SLOT_LEN: usize = 16;
+pub const ENTRY_LEN: usize = 24;
+
+pub const VERSION_OFFSET: usize = 8;
+pub const KIND_OFFSET: usize = 16;
+pub const SEED_OFFSET: usize = 24;
+pub const RANGE_START_OFFSET: usize = 32;
+pub const RANGE_END_OFFSET: usize = 40;
+pub const NUM_SLOTS_OFFSET: usize = 48;
+pub const NUM_ENTRIES_OFFSET: usize = 56;
+pub const DATA_LEN_OFFSET: usize = 64;
+pub const HASH_CHECK_OFFSET: usize = 72;
+
+/// A key hashed when writing and again when opening, to detect a hash function which has changed in between.
+const HASH_CHECK_KEY: &[u8] = b"frozen-collections";
+
+/// How keys are turned into hash codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HasherKind {
+    General = 0,
+    LeftRange = 1,
+    RightRange = 2,
+    Length = 3,
+}
+
+/// Hashes the bytes of keys in a way that only depends on what's recorded in the header.
+///
+/// Unlike the regular hashers, this feeds the bytes directly to the hasher rather than going through
+/// the `Hash` implementation of slices, which mixes in a platform-dependent `usize` length.
+#[derive(Clone, Debug)]
+pub struct MappedHasher {
+    bh: FixedState,
+    kind: HasherKind,
+    range: Range<usize>,
+}
+
+impl MappedHasher {
+    pub const fn new(seed: u64, kind: HasherKind, range: Range<usize>) -> Self {
+        Self {
+            bh: FixedState::with_seed(seed),
+            kind,
+            range,
+        }
+    }
+
+    #[inline]
+    pub fn hash(&self, key: &[u8]) -> u64 {
+        match self.kind {
+            HasherKind::General => self.hash_bytes(key),
+            HasherKind::LeftRange => key.get(self.range.clone()).map_or(0, |b| self.hash_bytes(b)),
+            HasherKind::RightRange => {
+                if key.len() < self.range.end {
+                    return 0;
+                }
+
+                self.hash_bytes(&key[key.len() - self.range.end..key.len() - self.range.start])
+            }
+            HasherKind::Length => key.len() as u64,
+        }
+    }
+
+    pub const fn kind(&self) -> HasherKind {
+        self.kind
+    }
+
+    pub const fn range(&self) -> &Range<usize> {
+        &self.range
+    }
+
+    pub fn hash_check(&self) -> u64 {
+        self.hash_bytes(HASH_CHECK_KEY)
+    }
+
+    #[inline]
+    fn hash_bytes(&self, bytes: &[u8]) -> u64 {
+        let mut h = self.bh.build_hasher();
+        h.write(bytes);
+        h.finish()
+    }
+}
+
+/// The validated contents of a header.
+pub struct Header {
+    pub hasher: MappedHasher,
+    pub num_slots: usize,
+    pub num_entries: usize,
+    pub data_len: usize,
+}
+
+impl Header {
+    /// Parses a header, checking that it was written by a compatible writer.
+    pub fn parse(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < HEADER_LEN {
+            return Err("the buffer is too small to hold a mapped map header".to_string());
+        } else if bytes[..MAGIC.len()] != MAGIC {
+            return Err("the buffer doesn't contain a mapped map".to_string());
+        }
+
+        let version = read_u64(bytes, VERSION_OFFSET).unwrap_or_default();
+        if version != VERSION {
+            return Err(format!("unsupported mapped map version {version}, expected {VERSION}"));
+        }
+
+        let kind = match read_u64(bytes, KIND_OFFSET).unwrap_or_default() {
+            0 => HasherKind::General,
+            1 => HasherKind::LeftRange,
+            2 => HasherKind::RightRange,
+            3 => HasherKind::Length,
+            kind => return Err(format!("unknown hasher kind {kind}")),
+        };
+
+        let seed = read_u64(bytes, SEED_OFFSET).unwrap_or_default();
+        let range_start = read_usize(bytes, RANGE_START_OFFSET)?;
+        let range_end = read_usize(bytes, RANGE_END_OFFSET)?;
+        if range_start > range_end {
+            return Err("invalid hash range".to_string());
+        }
+
+        let hasher = MappedHasher::new(seed, kind, range_start..range_end);
+        if read_u64(bytes, HASH_CHECK_OFFSET).unwrap_or_default() != hasher.hash_check() {
+            return Err("the mapped map was written with an incompatible hash function".to_string());
+        }
+
+        let num_slots = read_usize(bytes, NUM_SLOTS_OFFSET)?;
+        if !num_slots.is_power_of_two() {
+            return Err("the number of hash slots must be a power of two".to_string());
+        }
+
+        Ok(Self {
+            hasher,
+            num_slots,
+            num_entries: read_usize(bytes, NUM_ENTRIES_OFFSET)?,
+            data_len: read_usize(bytes, DATA_LEN_OFFSET)?,
+        })
+    }
+}
+
+/// Reads a little-endian `u64` at the given offset.
+#[inline]
+pub fn read_u64(bytes: &[u8], offset: usize) -> Option<u64> {
+    let b = bytes.get(offset..offset.checked_add(8)?)?;
+    Some(u64::from_le_bytes(b.try_into().ok()?))
+}
+
+/// Reads a little-endian `u64` at the given offset and converts it to a `usize`.
+#[inline]
+pub fn read_index(bytes: &[u8], offset: usize) -> Option<usize> {
+    usize::try_from(read_u64(bytes, offset)?).ok()
+}
+
+/// Reads a header field which must fit in a `usize`.
+fn read_usize(bytes: &[u8], offset: usize) -> Result<usize, String> {
+    read_index(bytes, offset).ok_or_else(|| "a header field is out of range for this platform".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_ranges() {
+        let left = MappedHasher::new(42, HasherKind::LeftRange, 1..3);
+        assert_eq!(left.hash(b"abcd"), left.hash(b"xbcx"));
+        assert_eq!(0, left.hash(b"ab"));
+
+        let right = MappedHasher::new(42, HasherKind::RightRange, 1..3);
+        assert_eq!(right.hash(b"abcd"), right.hash(b"xbcx"));
+        assert_eq!(0, right.hash(b"ab"));
+
+        let length = MappedHasher::new(42, HasherKind::Length, 0..0);
+        assert_eq!(4, length.hash(b"abcd"));
+    }
+
+    #[test]
+    fn read_u64_out_of_bounds() {
+        assert_eq!(Some(1), read_u64(&[1, 0, 0, 0, 0, 0, 0, 0], 0));
+        assert_eq!(None, read_u64(&[1, 0, 0, 0, 0, 0, 0, 0], 1));
+        assert_eq!(None, read_u64(&[], usize::MAX));
+    }
+}
@@ -0,0 +1,404 @@
+use crate::analyzers::{SliceKeyAnalysisResult, analyze_slice_keys};
+use crate::hash_tables::HashTable;
+use crate::mapped::layout::{
+    DATA_LEN_OFFSET, ENTRY_LEN, HASH_CHECK_OFFSET, HEADER_LEN, HasherKind, Header, KIND_OFFSET, MAGIC, MappedHasher, NUM_ENTRIES_OFFSET,
+    NUM_SLOTS_OFFSET, RANGE_END_OFFSET, RANGE_START_OFFSET, SEED_OFFSET, SLOT_LEN, VERSION, VERSION_OFFSET, read_index,
+};
+use crate::traits::{LargeCollection, Len};
+use crate::utils::DeduppedVec;
+use core::fmt::{Debug, Formatter, Result};
+use core::iter::FusedIterator;
+use foldhash::fast::FixedState;
+
+#[cfg(not(feature = "std"))]
+use {alloc::string::String, alloc::string::ToString, alloc::vec, alloc::vec::Vec};
+
+/// A read-only map with byte keys and values, viewed directly over a serialized buffer.
+///
+/// Building a frozen map involves analyzing the keys, hashing them, and allocating the
+/// resulting hash table. For very large maps that are loaded at every process start, this work can
+/// dominate startup time. A `MappedMap` instead does this work once with [`MappedMap::encode`], which produces
+/// a self-contained buffer holding the hash table's slots, its entries, and the hasher's seed and key range.
+///
+/// The buffer can then be written to a file and later opened with [`MappedMap::open`], typically over a
+/// memory-mapped file. Opening validates the header and the overall size of the buffer but otherwise
+/// performs no deserialization or allocation, and lookups read straight from the buffer.
+///
+/// The buffer layout has no alignment requirements. A buffer which is corrupted past its header doesn't cause
+/// undefined behavior or panics, lookups touching the corrupted parts simply fail to find anything.
+///
+/// The hash function used by the layout is checked when opening, so a buffer written by an incompatible
+/// version of this crate is rejected rather than silently failing lookups.
+#[derive(Clone)]
+pub struct MappedMap<'a> {
+    hasher: MappedHasher,
+    mask: u64,
+    len: usize,
+    slots: &'a [u8],
+    entries: &'a [u8],
+    data: &'a [u8],
+}
+
+impl<'a> MappedMap<'a> {
+    /// Serializes entries into a buffer which can later be opened with [`MappedMap::open`].
+    ///
+    /// If there are duplicate keys, the last one wins. The output only depends on the entries
+    /// and the seed, so the same input always produces the same buffer.
+    #[must_use]
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "Guaranteed not to panic because the hash table is a LargeCollection"
+    )]
+    pub fn encode<K, V>(entries: Vec<(K, V)>, seed: u64) -> Vec<u8>
+    where
+        K: AsRef<[u8]>,
+        V: AsRef<[u8]>,
+    {
+        let bh = FixedState::with_seed(seed);
+        let probe = MappedHasher::new(seed, HasherKind::General, 0..0);
+        let entries = DeduppedVec::using_hash(entries, |x| probe.hash(x.0.as_ref()), |x, y| x.0.as_ref() == y.0.as_ref());
+
+        let hasher = match analyze_slice_keys(entries.iter().map(|x| x.0.as_ref()), &bh) {
+            SliceKeyAnalysisResult::General => probe,
+            SliceKeyAnalysisResult::LeftHandSubslice(range) => MappedHasher::new(seed, HasherKind::LeftRange, range),
+            SliceKeyAnalysisResult::RightHandSubslice(range) => MappedHasher::new(seed, HasherKind::RightRange, range),
+            SliceKeyAnalysisResult::Length => MappedHasher::new(seed, HasherKind::Length, 0..0),
+        };
+
+        let table = HashTable::<_, LargeCollection>::new(entries, |x| hasher.hash(x.0.as_ref())).unwrap();
+        let data_len: usize = table.entries.iter().map(|x| x.0.as_ref().len() + x.1.as_ref().len()).sum();
+
+        let mut header = vec![0; HEADER_LEN];
+        header[..MAGIC.len()].copy_from_slice(&MAGIC);
+        write_u64(&mut header, VERSION_OFFSET, VERSION);
+        write_u64(&mut header, KIND_OFFSET, hasher.kind() as u64);
+        write_u64(&mut header, SEED_OFFSET, seed);
+        write_u64(&mut header, RANGE_START_OFFSET, hasher.range().start as u64);
+        write_u64(&mut header, RANGE_END_OFFSET, hasher.range().end as u64);
+        write_u64(&mut header, NUM_SLOTS_OFFSET, table.slots.len() as u64);
+        write_u64(&mut header, NUM_ENTRIES_OFFSET, table.entries.len() as u64);
+        write_u64(&mut header, DATA_LEN_OFFSET, data_len as u64);
+        write_u64(&mut header, HASH_CHECK_OFFSET, hasher.hash_check());
+
+        let mut result = Vec::with_capacity(HEADER_LEN + table.slots.len() * SLOT_LEN + table.entries.len() * ENTRY_LEN + data_len);
+        result.extend_from_slice(&header);
+
+        for slot in &table.slots {
+            result.extend_from_slice(&(slot.min_index as u64).to_le_bytes());
+            result.extend_from_slice(&(slot.max_index as u64).to_le_bytes());
+        }
+
+        let mut offset = 0;
+        for (key, value) in &table.entries {
+            let (key, value) = (key.as_ref(), value.as_ref());
+            result.extend_from_slice(&(offset as u64).to_le_bytes());
+            result.extend_from_slice(&(key.len() as u64).to_le_bytes());
+            result.extend_from_slice(&(value.len() as u64).to_le_bytes());
+            offset += key.len() + value.len();
+        }
+
+        for (key, value) in &table.entries {
+            result.extend_from_slice(key.as_ref());
+            result.extend_from_slice(value.as_ref());
+        }
+
+        result
+    }
+
+    /// Opens a view over a buffer produced by [`MappedMap::encode`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the buffer doesn't start with a valid header, was written by an incompatible version
+    /// of this crate, or doesn't have the size recorded in its header.
+    pub fn open(bytes: &'a [u8]) -> core::result::Result<Self, String> {
+        let header = Header::parse(bytes)?;
+
+        let slots_len = header.num_slots.checked_mul(SLOT_LEN);
+        let entries_len = header.num_entries.checked_mul(ENTRY_LEN);
+        let total_len = slots_len
+            .zip(entries_len)
+            .and_then(|(s, e)| HEADER_LEN.checked_add(s)?.checked_add(e)?.checked_add(header.data_len));
+
+        let (Some(slots_len), Some(total_len)) = (slots_len, total_len) else {
+            return Err("the sizes recorded in the header are too large".to_string());
+        };
+
+        if total_len != bytes.len() {
+            return Err("the buffer's length doesn't match the sizes recorded in its header".to_string());
+        }
+
+        let (slots, rest) = bytes[HEADER_LEN..].split_at(slots_len);
+        let (entries, data) = rest.split_at(header.num_entries * ENTRY_LEN);
+
+        Ok(Self {
+            hasher: header.hasher,
+            mask: (header.num_slots - 1) as u64,
+            len: header.num_entries,
+            slots,
+            entries,
+            data,
+        })
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    #[inline]
+    #[must_use]
+    pub fn get<Q>(&self, key: &Q) -> Option<&'a [u8]>
+    where
+        Q: ?Sized + AsRef<[u8]>,
+    {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    #[must_use]
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&'a [u8], &'a [u8])>
+    where
+        Q: ?Sized + AsRef<[u8]>,
+    {
+        let key = key.as_ref();
+
+        #[expect(clippy::cast_possible_truncation, reason = "The mask always fits in a usize")]
+        let slot_index = (self.hasher.hash(key) & self.mask) as usize;
+
+        let min = read_index(self.slots, slot_index * SLOT_LEN)?;
+        let max = read_index(self.slots, slot_index * SLOT_LEN + 8)?;
+
+        for index in min..max {
+            let (k, v) = self.entry(index)?;
+            if k == key {
+                return Some((k, v));
+            }
+        }
+
+        None
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    #[inline]
+    #[must_use]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: ?Sized + AsRef<[u8]>,
+    {
+        self.get_key_value(key).is_some()
+    }
+
+    /// Returns the number of entries in the map.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map contains no entries.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// An iterator visiting all entries in arbitrary order.
+    #[must_use]
+    pub const fn iter(&self) -> Iter<'_, 'a> {
+        Iter { map: self, index: 0 }
+    }
+
+    fn entry(&self, index: usize) -> Option<(&'a [u8], &'a [u8])> {
+        let start = index.checked_mul(ENTRY_LEN)?;
+        let offset = read_index(self.entries, start)?;
+        let key_len = read_index(self.entries, start + 8)?;
+        let value_len = read_index(self.entries, start + 16)?;
+
+        let key_end = offset.checked_add(key_len)?;
+        let key = self.data.get(offset..key_end)?;
+        let value = self.data.get(key_end..key_end.checked_add(value_len)?)?;
+
+        Some((key, value))
+    }
+}
+
+impl Len for MappedMap<'_> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl Debug for MappedMap<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'b, 'a> IntoIterator for &'b MappedMap<'a> {
+    type Item = (&'a [u8], &'a [u8]);
+    type IntoIter = Iter<'b, 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a [`MappedMap`].
+#[derive(Debug)]
+pub struct Iter<'b, 'a> {
+    map: &'b MappedMap<'a>,
+    index: usize,
+}
+
+impl<'a> Iterator for Iter<'_, 'a> {
+    type Item = (&'a [u8], &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.map.len {
+            return None;
+        }
+
+        let entry = self.map.entry(self.index);
+        self.index = if entry.is_some() { self.index + 1 } else { self.map.len };
+        entry
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.map.len - self.index))
+    }
+}
+
+impl FusedIterator for Iter<'_, '_> {}
+
+fn write_u64(bytes: &mut [u8], offset: usize, value: u64) {
+    bytes[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    fn sample(len: usize) -> Vec<(String, String)> {
+        (0..len).map(|i| (format!("key {i}"), format!("value {i}"))).collect()
+    }
+
+    #[test]
+    fn round_trip() {
+        for len in [0, 1, 2, 10, 1000] {
+            let input = sample(len);
+            let bytes = MappedMap::encode(input.clone(), 42);
+            let map = MappedMap::open(&bytes).unwrap();
+
+            assert_eq!(len, map.len());
+            assert_eq!(len == 0, map.is_empty());
+            assert_eq!(len, map.iter().count());
+
+            for (k, v) in &input {
+                assert_eq!(Some(v.as_bytes()), map.get(k));
+                assert!(map.contains_key(k));
+            }
+
+            assert_eq!(None, map.get("missing"));
+            assert_eq!(None, map.get(""));
+        }
+    }
+
+    #[test]
+    fn special_hashers() {
+        // same prefixes, so a right-hand subslice is selected
+        let input: Vec<_> = (0..100).map(|i| (format!("prefix-{i:03}"), i.to_string())).collect();
+        let bytes = MappedMap::encode(input.clone(), 1);
+        let map = MappedMap::open(&bytes).unwrap();
+        for (k, v) in &input {
+            assert_eq!(Some(v.as_bytes()), map.get(k));
+        }
+
+        // distinct lengths, so lengths are used as hash codes
+        let input: Vec<_> = (1..20).map(|i| ("x".repeat(i), i.to_string())).collect();
+        let bytes = MappedMap::encode(input.clone(), 1);
+        let map = MappedMap::open(&bytes).unwrap();
+        for (k, v) in &input {
+            assert_eq!(Some(v.as_bytes()), map.get(k));
+        }
+
+        assert_eq!(None, map.get("y"));
+        assert_eq!(None, map.get(&"x".repeat(30)));
+    }
+
+    #[test]
+    fn duplicates_and_determinism() {
+        let input = vec![("a", "1"), ("b", "2"), ("a", "3")];
+        let bytes = MappedMap::encode(input.clone(), 7);
+        assert_eq!(bytes, MappedMap::encode(input, 7));
+
+        let map = MappedMap::open(&bytes).unwrap();
+        assert_eq!(2, map.len());
+        assert_eq!(Some(b"3".as_slice()), map.get("a"));
+    }
+
+    #[test]
+    fn unaligned_buffers() {
+        let input = sample(50);
+        let bytes = MappedMap::encode(input.clone(), 42);
+
+        let mut shifted = vec![0];
+        shifted.extend_from_slice(&bytes);
+        let map = MappedMap::open(&shifted[1..]).unwrap();
+        for (k, v) in &input {
+            assert_eq!(Some(v.as_bytes()), map.get(k));
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_headers() {
+        let bytes = MappedMap::encode(sample(10), 42);
+
+        assert!(MappedMap::open(&bytes[..10]).is_err());
+        assert!(MappedMap::open(&bytes[..bytes.len() - 1]).is_err());
+
+        let mut b = bytes.clone();
+        b[0] = b'X';
+        assert!(MappedMap::open(&b).is_err());
+
+        let mut b = bytes.clone();
+        write_u64(&mut b, VERSION_OFFSET, VERSION + 1);
+        assert!(MappedMap::open(&b).is_err());
+
+        let mut b = bytes.clone();
+        write_u64(&mut b, KIND_OFFSET, 99);
+        assert!(MappedMap::open(&b).is_err());
+
+        let mut b = bytes.clone();
+        write_u64(&mut b, HASH_CHECK_OFFSET, 0);
+        assert!(MappedMap::open(&b).is_err());
+
+        let mut b = bytes.clone();
+        write_u64(&mut b, NUM_SLOTS_OFFSET, 3);
+        assert!(MappedMap::open(&b).is_err());
+
+        let mut b = bytes;
+        write_u64(&mut b, NUM_ENTRIES_OFFSET, u64::MAX);
+        assert!(MappedMap::open(&b).is_err());
+    }
+
+    #[test]
+    fn tolerates_corrupted_contents() {
+        let input = sample(10);
+        let mut bytes = MappedMap::encode(input.clone(), 42);
+        for b in &mut bytes[HEADER_LEN..] {
+            *b = 0xFF;
+        }
+
+        let map = MappedMap::open(&bytes).unwrap();
+        for (k, _) in &input {
+            assert_eq!(None, map.get(k));
+        }
+
+        assert_eq!(0, map.iter().count());
+    }
+
+    #[test]
+    fn debug() {
+        let bytes = MappedMap::encode(vec![("a", "b")], 42);
+        let map = MappedMap::open(&bytes).unwrap();
+        assert_eq!("{[97]: [98]}", format!("{map:?}"));
+    }
+}
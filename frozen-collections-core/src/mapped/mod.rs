@@ -0,0 +1,6 @@
+//! Read-only maps viewed directly over serialized buffers, such as memory-mapped files.
+
+pub use mapped_map::*;
+
+mod layout;
+mod mapped_map;
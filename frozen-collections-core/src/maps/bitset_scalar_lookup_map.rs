@@ -0,0 +1,293 @@
+use crate::maps::decl_macros::{
+    bitset_scalar_lookup_primary_funcs, common_primary_funcs, debug_trait_funcs, filter_funcs, get_disjoint_mut_funcs, index_trait_funcs,
+    into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs,
+    map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs, sorted_query_funcs,
+};
+use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::sets::BitsetView;
+use crate::traits::{Len, Map, MapExtras, MapIteration, MapQuery, Scalar};
+use crate::utils::{SortedAndDeduppedVec, into_ok, retain_flagged, retain_flags, scalar_bitset, try_map_entry_values};
+use core::fmt::{Debug, Formatter, Result};
+use core::ops::Index;
+use equivalent::Comparable;
+
+#[cfg(not(feature = "std"))]
+use {alloc::boxed::Box, alloc::vec::Vec};
+
+#[cfg(feature = "serde")]
+use {
+    crate::maps::decl_macros::serialize_trait_funcs,
+    serde::ser::SerializeMap,
+    serde::{Serialize, Serializer},
+};
+
+/// A map whose keys are a sparse range of values from a scalar, located with a bitset.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+#[doc = include_str!("../doc_snippets/about.md")]
+///
+#[derive(Clone)]
+pub struct BitsetScalarLookupMap<K, V> {
+    base: usize,
+    words: Box<[u64]>,
+    ranks: Box<[u32]>,
+    entries: Box<[(K, V)]>,
+}
+
+impl<K, V> BitsetScalarLookupMap<K, V> {
+    /// Creates a new `BitsetScalarLookupMap` from a list of entries.
+    ///
+    /// The map tracks the number of entries preceding each word of its bitset in a `u32`, so it may hold
+    /// at most `u32::MAX` entries.
+    #[must_use]
+    pub fn new(entries: Vec<(K, V)>) -> Self
+    where
+        K: Scalar,
+    {
+        let entries = SortedAndDeduppedVec::new(entries, |x, y| x.0.cmp(&y.0));
+        if entries.is_empty() {
+            return Self::default();
+        }
+
+        Self::from_sorted_and_dedupped(entries)
+    }
+
+    /// Creates a new frozen map.
+    #[must_use]
+    pub(crate) fn from_sorted_and_dedupped(entries: SortedAndDeduppedVec<(K, V)>) -> Self
+    where
+        K: Scalar,
+    {
+        let indices: Vec<_> = entries.iter().map(|entry| entry.0.index()).collect();
+        let (base, words, ranks) = scalar_bitset(&indices);
+
+        Self {
+            base,
+            words,
+            ranks,
+            entries: entries.into_boxed_slice(),
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> BitsetScalarLookupMap<K, U> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(
+        self,
+        f: impl FnMut(&K, V) -> core::result::Result<U, E>,
+    ) -> core::result::Result<BitsetScalarLookupMap<K, U>, E> {
+        Ok(BitsetScalarLookupMap {
+            base: self.base,
+            words: self.words,
+            ranks: self.ranks,
+            entries: try_map_entry_values(self.entries, f)?,
+        })
+    }
+
+    #[doc = include_str!("../doc_snippets/retain_into.md")]
+    #[must_use]
+    pub fn retain_into(self, f: impl FnMut(&K, &V) -> bool) -> Self
+    where
+        K: Scalar,
+    {
+        let Some(flags) = retain_flags(self.iter(), f) else {
+            return self;
+        };
+
+        let entries = retain_flagged(self.entries, flags);
+        if entries.is_empty() {
+            return Self::default();
+        }
+
+        Self::from_sorted_and_dedupped(SortedAndDeduppedVec::from_sorted_unique(entries, |x, y| x.0.cmp(&y.0)))
+    }
+
+    /// Creates a map like this one holding the given entries, a subset of this map's entries in iteration order.
+    #[allow(
+        clippy::unused_self,
+        reason = "Called through filter_funcs!, like the maps which reuse their hasher"
+    )]
+    pub(crate) fn with_retained(&self, entries: Vec<(K, V)>) -> Self
+    where
+        K: Scalar,
+    {
+        if entries.is_empty() {
+            return Self::default();
+        }
+
+        Self::from_sorted_and_dedupped(SortedAndDeduppedVec::from_sorted_unique(entries, |x, y| x.0.cmp(&y.0)))
+    }
+
+    filter_funcs!(K, K: Scalar);
+
+    bitset_scalar_lookup_primary_funcs!(|rank: u32| rank as usize);
+    common_primary_funcs!(non_const_len, entries);
+}
+
+impl<K, V> Default for BitsetScalarLookupMap<K, V> {
+    fn default() -> Self {
+        Self {
+            base: 0,
+            words: Box::new([]),
+            ranks: Box::new([]),
+            entries: Box::new([]),
+        }
+    }
+}
+
+impl<K> BitsetScalarLookupMap<K, ()> {
+    pub(crate) fn bitset(&self) -> BitsetView<'_, K> {
+        BitsetView::new(self.base, &self.words, &self.entries)
+    }
+}
+
+impl<K, V, Q> Map<K, V, Q> for BitsetScalarLookupMap<K, V> where Q: Scalar + Comparable<K> {}
+
+impl<K, V, Q> MapExtras<K, V, Q> for BitsetScalarLookupMap<K, V>
+where
+    Q: Scalar + Comparable<K>,
+{
+    map_extras_trait_funcs!();
+}
+
+impl<K, V, Q> MapQuery<Q, V> for BitsetScalarLookupMap<K, V>
+where
+    Q: Scalar + Comparable<K>,
+{
+    map_query_trait_funcs!();
+}
+
+impl<K, V> MapIteration<K, V> for BitsetScalarLookupMap<K, V> {
+    type Iterator<'a>
+        = Iter<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    type KeyIterator<'a>
+        = Keys<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    type ValueIterator<'a>
+        = Values<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    type MutIterator<'a>
+        = IterMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    type ValueMutIterator<'a>
+        = ValuesMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a;
+
+    map_iteration_trait_funcs!();
+}
+
+impl<K, V> Len for BitsetScalarLookupMap<K, V> {
+    len_trait_funcs!();
+}
+
+impl<K, V, Q> Index<&Q> for BitsetScalarLookupMap<K, V>
+where
+    Q: Comparable<K> + Scalar,
+{
+    index_trait_funcs!();
+}
+
+impl<K, V> IntoIterator for BitsetScalarLookupMap<K, V> {
+    into_iterator_trait_funcs!();
+}
+
+impl<'a, K, V> IntoIterator for &'a BitsetScalarLookupMap<K, V> {
+    into_iterator_trait_ref_funcs!();
+}
+
+impl<'a, K, V> IntoIterator for &'a mut BitsetScalarLookupMap<K, V> {
+    into_iterator_trait_mut_ref_funcs!();
+}
+
+impl<K, V, MT> PartialEq<MT> for BitsetScalarLookupMap<K, V>
+where
+    K: Scalar,
+    V: PartialEq,
+    MT: MapQuery<K, V>,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<K, V> Eq for BitsetScalarLookupMap<K, V>
+where
+    K: Scalar,
+    V: Eq,
+{
+}
+
+impl<K, V> Debug for BitsetScalarLookupMap<K, V>
+where
+    K: Debug,
+    V: Debug,
+{
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<K, V> Serialize for BitsetScalarLookupMap<K, V>
+where
+    K: Serialize + Scalar,
+    V: Serialize,
+{
+    serialize_trait_funcs!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_bitset_lookup() {
+        let entries = vec![(1, "a"), (10, "b"), (100, "c"), (1000, "d")];
+        let map = BitsetScalarLookupMap::new(entries);
+
+        assert_eq!(map.get(&1), Some(&"a"));
+        assert_eq!(map.get(&10), Some(&"b"));
+        assert_eq!(map.get(&100), Some(&"c"));
+        assert_eq!(map.get(&1000), Some(&"d"));
+
+        assert_eq!(map.get(&0), None);
+        assert_eq!(map.get(&2), None);
+        assert_eq!(map.get(&999), None);
+        assert_eq!(map.get(&1001), None);
+        assert_eq!(map.get(&usize::MAX), None);
+
+        assert_eq!(map.rank(&0), None);
+        assert_eq!(map.rank(&10), Some(1));
+        assert_eq!(map.rank(&11), None);
+        assert_eq!(map.predecessor(&5000), Some((&1000, &"d")));
+        assert_eq!(map.floor(&999), Some((&100, &"c")));
+        assert_eq!(map.ceiling(&999), Some((&1000, &"d")));
+
+        let d = map.diagnostics();
+        assert_eq!(1024, d.slots);
+    }
+
+    #[test]
+    fn test_empty() {
+        let map = BitsetScalarLookupMap::<i32, i32>::new(vec![]);
+        assert_eq!(map.get(&0), None);
+        assert_eq!(map.rank(&0), None);
+        assert!(map.is_empty());
+    }
+}
@@ -1,3 +1,104 @@
+macro_rules! bitset_scalar_lookup_primary_funcs {
+    ($rank_to_usize:expr) => {
+        #[inline]
+        fn search_sorted<Q>(&self, key: &Q) -> core::result::Result<usize, usize>
+        where
+            Q: Comparable<K> + Scalar,
+        {
+            let offset = key.index().wrapping_sub(self.base);
+            let word_index = offset / 64;
+            if word_index >= self.words.len() {
+                return Err(if key.index() < self.base { 0 } else { self.entries.len() });
+            }
+
+            // SAFETY: We are guaranteed that the index is valid because we checked it against the number of words
+            let (word, rank): (u64, usize) = unsafe {
+                (
+                    *self.words.get_unchecked(word_index),
+                    ($rank_to_usize)(*self.ranks.get_unchecked(word_index)),
+                )
+            };
+            let bit = 1 << (offset % 64);
+            let index_in_entries = rank + (word & (bit - 1)).count_ones() as usize;
+            if word & bit == 0 {
+                Err(index_in_entries)
+            } else {
+                Ok(index_in_entries)
+            }
+        }
+
+        #[doc = include_str!("../doc_snippets/get.md")]
+        #[inline]
+        pub fn get<Q>(&self, key: &Q) -> Option<&V>
+        where
+            Q: Comparable<K> + Scalar,
+        {
+            self.search_sorted(key).ok().map(|index_in_entries| {
+                // SAFETY: We are guaranteed that the index is valid because the bit for the key is set
+                let entry = unsafe { self.entries.get_unchecked(index_in_entries) };
+                &entry.1
+            })
+        }
+
+        #[doc = include_str!("../doc_snippets/get_mut.md")]
+        #[inline]
+        pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+        where
+            Q: Comparable<K> + Scalar,
+        {
+            self.search_sorted(key).ok().map(|index_in_entries| {
+                // SAFETY: We are guaranteed that the index is valid because the bit for the key is set
+                let entry = unsafe { self.entries.get_unchecked_mut(index_in_entries) };
+                &mut entry.1
+            })
+        }
+
+        #[doc = include_str!("../doc_snippets/get_key_value.md")]
+        #[inline]
+        pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+        where
+            Q: Comparable<K> + Scalar,
+        {
+            self.search_sorted(key).ok().map(|index_in_entries| {
+                // SAFETY: We are guaranteed that the index is valid because the bit for the key is set
+                let entry = unsafe { self.entries.get_unchecked(index_in_entries) };
+                (&entry.0, &entry.1)
+            })
+        }
+
+        #[doc = include_str!("../doc_snippets/contains_key.md")]
+        #[inline]
+        #[must_use]
+        pub fn contains_key<Q>(&self, key: &Q) -> bool
+        where
+            Q: Comparable<K> + Scalar,
+        {
+            self.search_sorted(key).is_ok()
+        }
+
+        #[doc = include_str!("../doc_snippets/select.md")]
+        #[inline]
+        #[must_use]
+        pub fn select(&self, sorted_index: usize) -> Option<(&K, &V)> {
+            self.entries.get(sorted_index).map(|entry| (&entry.0, &entry.1))
+        }
+
+        #[doc = include_str!("../doc_snippets/diagnostics.md")]
+        #[must_use]
+        pub fn diagnostics(&self) -> crate::diagnostics::Diagnostics {
+            use crate::diagnostics::{Diagnostics, HeapBytes, Strategy};
+
+            let mut d = Diagnostics::new(Strategy::BitsetScalarLookup, self.entries.len());
+            d.slots = self.words.len() * 64;
+            d.heap_bytes = self.words.heap_bytes() + self.ranks.heap_bytes() + self.entries.heap_bytes();
+            d
+        }
+
+        sorted_query_funcs!("Scalar");
+        get_disjoint_mut_funcs!("Scalar");
+    };
+}
+
 macro_rules! common_primary_funcs {
     ($const_len:ident, $($entries:ident)+) => {
         #[doc = include_str!("../doc_snippets/iter.md")]
@@ -150,6 +251,40 @@ macro_rules! dense_scalar_lookup_primary_funcs {
             self.get(key).is_some()
         }
 
+        #[inline]
+        fn search_sorted<Q>(&self, key: &Q) -> core::result::Result<usize, usize>
+        where
+            Q: Comparable<K> + Scalar,
+        {
+            let index = key.index();
+            if index < self.min || self.entries.is_empty() {
+                Err(0)
+            } else if index > self.max {
+                Err(self.entries.len())
+            } else {
+                Ok(index - self.min)
+            }
+        }
+
+        #[doc = include_str!("../doc_snippets/select.md")]
+        #[inline]
+        #[must_use]
+        pub fn select(&self, sorted_index: usize) -> Option<(&K, &V)> {
+            self.entries.get(sorted_index).map(|entry| (&entry.0, &entry.1))
+        }
+
+        #[doc = include_str!("../doc_snippets/diagnostics.md")]
+        #[must_use]
+        pub fn diagnostics(&self) -> crate::diagnostics::Diagnostics {
+            use crate::diagnostics::{Diagnostics, HeapBytes, Strategy};
+
+            let mut d = Diagnostics::new(Strategy::DenseScalarLookup, self.entries.len());
+            d.slots = self.entries.len();
+            d.heap_bytes = self.entries.heap_bytes();
+            d
+        }
+
+        sorted_query_funcs!("Scalar");
         get_disjoint_mut_funcs!("Scalar");
     };
 }
@@ -162,7 +297,7 @@ macro_rules! eytzinger_search_primary_funcs {
         where
             Q: ?Sized + Comparable<K>,
         {
-            if let Some(index) = eytzinger_search_by(&self.entries, |entry| key.compare(&entry.0).reverse()) {
+            if let Ok(index) = eytzinger_search_by(&self.entries, |entry| key.compare(&entry.0).reverse()) {
                 // SAFETY: We are guaranteed that the index is valid because eytzinger_search_by returns an in-range index
                 let entry = unsafe { self.entries.get_unchecked(index) };
                 Some(&entry.1)
@@ -177,7 +312,7 @@ macro_rules! eytzinger_search_primary_funcs {
         where
             Q: ?Sized + Comparable<K>,
         {
-            if let Some(index) = eytzinger_search_by(&self.entries, |entry| key.compare(&entry.0).reverse()) {
+            if let Ok(index) = eytzinger_search_by(&self.entries, |entry| key.compare(&entry.0).reverse()) {
                 // SAFETY: We are guaranteed that the index is valid because eytzinger_search_by returns an in-range index
                 let entry = unsafe { self.entries.get_unchecked_mut(index) };
                 Some(&mut entry.1)
@@ -192,7 +327,7 @@ macro_rules! eytzinger_search_primary_funcs {
         where
             Q: ?Sized + Comparable<K>,
         {
-            if let Some(index) = eytzinger_search_by(&self.entries, |entry| key.compare(&entry.0).reverse()) {
+            if let Ok(index) = eytzinger_search_by(&self.entries, |entry| key.compare(&entry.0).reverse()) {
                 // SAFETY: We are guaranteed that the index is valid because eytzinger_search_by returns an in-range index
                 let entry = unsafe { self.entries.get_unchecked(index) };
                 Some((&entry.0, &entry.1))
@@ -211,10 +346,107 @@ macro_rules! eytzinger_search_primary_funcs {
             self.get(key).is_some()
         }
 
+        #[doc = include_str!("../doc_snippets/range.md")]
+        #[must_use]
+        pub fn range<Q, R>(&self, range: R) -> Range<'_, K, V>
+        where
+            Q: ?Sized + Comparable<K>,
+            R: RangeBounds<Q>,
+        {
+            let span = eytzinger_range_by(&self.entries, &range, |key, entry| key.compare(&entry.0));
+            Range::new(&self.entries, span.start, span.end)
+        }
+
+        #[doc = include_str!("../doc_snippets/iter_sorted.md")]
+        #[must_use]
+        #[allow(clippy::missing_const_for_fn, reason = "Only const for the inline map")]
+        pub fn iter_sorted(&self) -> Range<'_, K, V> {
+            Range::new(&self.entries, 0, self.entries.len())
+        }
+
+        #[doc = include_str!("../doc_snippets/first_key_value.md")]
+        #[must_use]
+        pub fn first_key_value(&self) -> Option<(&K, &V)> {
+            self.iter_sorted().next()
+        }
+
+        #[doc = include_str!("../doc_snippets/last_key_value.md")]
+        #[must_use]
+        pub fn last_key_value(&self) -> Option<(&K, &V)> {
+            self.iter_sorted().next_back()
+        }
+
+        #[inline]
+        fn search_sorted<Q>(&self, key: &Q) -> core::result::Result<usize, usize>
+        where
+            Q: ?Sized + Comparable<K>,
+        {
+            let len = self.entries.len();
+            let to_sorted = |index| {
+                if index < len {
+                    eytzinger_to_sorted_index(index, len)
+                } else {
+                    len
+                }
+            };
+
+            eytzinger_search_by(&self.entries, |entry| key.compare(&entry.0).reverse())
+                .map(to_sorted)
+                .map_err(to_sorted)
+        }
+
+        #[doc = include_str!("../doc_snippets/select.md")]
+        #[inline]
+        #[must_use]
+        pub fn select(&self, sorted_index: usize) -> Option<(&K, &V)> {
+            (sorted_index < self.entries.len()).then(|| {
+                let index = sorted_to_eytzinger_index(sorted_index, self.entries.len());
+
+                // SAFETY: We are guaranteed that the index is valid because we checked the sorted index against the length
+                let entry = unsafe { self.entries.get_unchecked(index) };
+                (&entry.0, &entry.1)
+            })
+        }
+
+        #[doc = include_str!("../doc_snippets/diagnostics.md")]
+        #[must_use]
+        pub fn diagnostics(&self) -> crate::diagnostics::Diagnostics {
+            use crate::diagnostics::{Diagnostics, HeapBytes, Strategy};
+
+            // a search visits one entry per level of the implicit tree
+            let mut d = Diagnostics::new(Strategy::EytzingerSearch, self.entries.len());
+            d.longest_chain = (usize::BITS - self.entries.len().leading_zeros()) as usize;
+            d.heap_bytes = self.entries.heap_bytes();
+            d
+        }
+
+        sorted_query_funcs!("Ord");
         get_disjoint_mut_funcs!("Ord");
     };
 }
 
+macro_rules! filter_funcs {
+    ($key:ty $(, $($bounds:tt)+)?) => {
+        #[doc = include_str!("../doc_snippets/filter.md")]
+        #[must_use]
+        pub fn filter(&self, f: impl FnMut(&$key, &V) -> bool) -> Self
+        where
+            Self: Clone,
+            $key: Clone,
+            V: Clone,
+            $($($bounds)+)?
+        {
+            // only the retained entries are cloned, and the map is cloned as is when every entry is retained
+            let Some(flags) = $crate::utils::retain_flags(self.iter(), f) else {
+                return self.clone();
+            };
+
+            let retained = $crate::utils::retain_flagged(self.iter(), flags);
+            self.with_retained(retained.into_iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+        }
+    };
+}
+
 macro_rules! get_disjoint_mut_funcs {
     ("Ord") => {
         #[doc = include_str!("../doc_snippets/get_disjoint_mut.md")]
@@ -326,6 +558,68 @@ macro_rules! hash_primary_funcs {
             self.get(key).is_some()
         }
 
+        #[doc = include_str!("../doc_snippets/hash_key.md")]
+        #[inline]
+        #[must_use]
+        pub fn hash_key<Q>(&self, key: &Q) -> u64
+        where
+            Q: ?Sized,
+            H: Hasher<Q>,
+        {
+            self.hasher.hash_one(key)
+        }
+
+        #[doc = include_str!("../doc_snippets/get_with_hash.md")]
+        #[inline]
+        pub fn get_with_hash<Q>(&self, hash_code: u64, key: &Q) -> Option<&V>
+        where
+            Q: ?Sized + Equivalent<K>,
+        {
+            self.entries
+                .find(hash_code, |entry| key.equivalent(&entry.0))
+                .map(|(_, v)| v)
+        }
+
+        #[doc = include_str!("../doc_snippets/contains_with_hash.md")]
+        #[inline]
+        #[must_use]
+        pub fn contains_with_hash<Q>(&self, hash_code: u64, key: &Q) -> bool
+        where
+            Q: ?Sized + Equivalent<K>,
+        {
+            self.get_with_hash(hash_code, key).is_some()
+        }
+
+        #[doc = include_str!("../doc_snippets/get_many.md")]
+        pub fn get_many<'a, Q>(&'a self, keys: &[&Q], results: &mut [Option<&'a V>])
+        where
+            Q: ?Sized + Equivalent<K>,
+            H: Hasher<Q>,
+        {
+            assert_eq!(keys.len(), results.len(), "keys and results must have the same length");
+            self.find_many(keys.len(), |i| keys[i], |i, entry| results[i] = entry.map(|(_, v)| v));
+        }
+
+        #[doc = include_str!("../doc_snippets/contains_many.md")]
+        pub fn contains_many<Q>(&self, keys: &[&Q], results: &mut [bool])
+        where
+            Q: ?Sized + Equivalent<K>,
+            H: Hasher<Q>,
+        {
+            assert_eq!(keys.len(), results.len(), "keys and results must have the same length");
+            self.find_many(keys.len(), |i| keys[i], |i, entry| results[i] = entry.is_some());
+        }
+
+        /// Looks up a batch of keys, with `key` returning the key at a given position in the batch.
+        pub(crate) fn find_many<'a, 'b, Q>(&'a self, count: usize, key: impl Fn(usize) -> &'b Q, found: impl FnMut(usize, Option<&'a (K, V)>))
+        where
+            Q: ?Sized + Equivalent<K> + 'b,
+            H: Hasher<Q>,
+        {
+            self.entries
+                .find_many(count, |i| self.hasher.hash_one(key(i)), |i, entry| key(i).equivalent(&entry.0), found);
+        }
+
         #[doc = include_str!("../doc_snippets/get_disjoint_mut.md")]
         pub fn get_disjoint_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N]
         where
@@ -343,6 +637,18 @@ macro_rules! hash_primary_funcs {
         {
             get_disjoint_mut_funcs!(@unsafe_body, self, keys);
         }
+
+        #[doc = include_str!("../doc_snippets/diagnostics.md")]
+        #[must_use]
+        pub fn diagnostics(&self) -> crate::diagnostics::Diagnostics
+        where
+            H: Hasher<K>,
+        {
+            let mut d = self.entries.diagnostics();
+            d.key_hashing = Some(<H as Hasher<K>>::key_hashing(&self.hasher));
+            d.subslice_range = <H as Hasher<K>>::subslice_range(&self.hasher);
+            d
+        }
     };
 }
 
@@ -478,6 +784,74 @@ macro_rules! map_query_trait_funcs {
     };
 }
 
+macro_rules! sorted_query_funcs {
+    ("Ord") => {
+        sorted_query_funcs!(@funcs, Q: ?Sized + Comparable<K>);
+    };
+
+    ("Scalar") => {
+        sorted_query_funcs!(@funcs, Q: Comparable<K> + Scalar);
+    };
+
+    (@funcs, $($predicates:tt)+) => {
+        #[doc = include_str!("../doc_snippets/rank.md")]
+        #[inline]
+        #[must_use]
+        pub fn rank<Q>(&self, key: &Q) -> Option<usize>
+        where
+            $($predicates)+,
+        {
+            self.search_sorted(key).ok()
+        }
+
+        #[doc = include_str!("../doc_snippets/floor.md")]
+        #[inline]
+        pub fn floor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+        where
+            $($predicates)+,
+        {
+            match self.search_sorted(key) {
+                Ok(index) => self.select(index),
+                Err(index) => self.select(index.checked_sub(1)?),
+            }
+        }
+
+        #[doc = include_str!("../doc_snippets/ceiling.md")]
+        #[inline]
+        pub fn ceiling<Q>(&self, key: &Q) -> Option<(&K, &V)>
+        where
+            $($predicates)+,
+        {
+            match self.search_sorted(key) {
+                Ok(index) | Err(index) => self.select(index),
+            }
+        }
+
+        #[doc = include_str!("../doc_snippets/predecessor.md")]
+        #[inline]
+        pub fn predecessor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+        where
+            $($predicates)+,
+        {
+            match self.search_sorted(key) {
+                Ok(index) | Err(index) => self.select(index.checked_sub(1)?),
+            }
+        }
+
+        #[doc = include_str!("../doc_snippets/successor.md")]
+        #[inline]
+        pub fn successor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+        where
+            $($predicates)+,
+        {
+            match self.search_sorted(key) {
+                Ok(index) => self.select(index + 1),
+                Err(index) => self.select(index),
+            }
+        }
+    };
+}
+
 macro_rules! partial_eq_trait_funcs {
     () => {
         fn eq(&self, other: &MT) -> bool {
@@ -565,6 +939,17 @@ macro_rules! scan_primary_funcs {
         {
             get_disjoint_mut_funcs!(@unsafe_body, self, keys);
         }
+
+        #[doc = include_str!("../doc_snippets/diagnostics.md")]
+        #[must_use]
+        pub fn diagnostics(&self) -> crate::diagnostics::Diagnostics {
+            use crate::diagnostics::{Diagnostics, HeapBytes, Strategy};
+
+            let mut d = Diagnostics::new(Strategy::Scan, self.entries.len());
+            d.longest_chain = self.entries.len();
+            d.heap_bytes = self.entries.heap_bytes();
+            d
+        }
     };
 }
 
@@ -662,14 +1047,43 @@ macro_rules! sparse_scalar_lookup_primary_funcs {
             self.get(key).is_some()
         }
 
+        #[inline]
+        fn search_sorted<Q>(&self, key: &Q) -> core::result::Result<usize, usize>
+        where
+            Q: Comparable<K> + Scalar,
+        {
+            self.entries.binary_search_by(|entry| key.compare(&entry.0).reverse())
+        }
+
+        #[doc = include_str!("../doc_snippets/select.md")]
+        #[inline]
+        #[must_use]
+        pub fn select(&self, sorted_index: usize) -> Option<(&K, &V)> {
+            self.entries.get(sorted_index).map(|entry| (&entry.0, &entry.1))
+        }
+
+        #[doc = include_str!("../doc_snippets/diagnostics.md")]
+        #[must_use]
+        pub fn diagnostics(&self) -> crate::diagnostics::Diagnostics {
+            use crate::diagnostics::{Diagnostics, HeapBytes, Strategy};
+
+            let mut d = Diagnostics::new(Strategy::SparseScalarLookup, self.entries.len());
+            d.slots = self.lookup.len();
+            d.heap_bytes = self.lookup.heap_bytes() + self.entries.heap_bytes();
+            d
+        }
+
+        sorted_query_funcs!("Scalar");
         get_disjoint_mut_funcs!("Scalar");
     };
 }
 
+pub(crate) use bitset_scalar_lookup_primary_funcs;
 pub(crate) use common_primary_funcs;
 pub(crate) use debug_trait_funcs;
 pub(crate) use dense_scalar_lookup_primary_funcs;
 pub(crate) use eytzinger_search_primary_funcs;
+pub(crate) use filter_funcs;
 pub(crate) use get_disjoint_mut_funcs;
 pub(crate) use hash_primary_funcs;
 pub(crate) use index_trait_funcs;
@@ -682,6 +1096,7 @@ pub(crate) use map_iteration_trait_funcs;
 pub(crate) use map_query_trait_funcs;
 pub(crate) use partial_eq_trait_funcs;
 pub(crate) use scan_primary_funcs;
+pub(crate) use sorted_query_funcs;
 pub(crate) use sparse_scalar_lookup_primary_funcs;
 
 #[cfg(feature = "serde")]
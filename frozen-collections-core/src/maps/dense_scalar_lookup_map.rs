@@ -1,11 +1,12 @@
 use crate::maps::decl_macros::{
     common_primary_funcs, debug_trait_funcs, dense_scalar_lookup_primary_funcs, get_disjoint_mut_funcs, index_trait_funcs,
     into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs,
-    map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs,
+    map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs, sorted_query_funcs,
 };
 use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::sets::BitsetView;
 use crate::traits::{Len, Map, MapExtras, MapIteration, MapQuery, Scalar};
-use crate::utils::SortedAndDeduppedVec;
+use crate::utils::{SortedAndDeduppedVec, into_ok, try_map_entry_values};
 use core::fmt::{Debug, Formatter, Result};
 use core::ops::Index;
 use equivalent::Comparable;
@@ -74,6 +75,24 @@ impl<K, V> DenseScalarLookupMap<K, V> {
         }
     }
 
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> DenseScalarLookupMap<K, U> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(
+        self,
+        f: impl FnMut(&K, V) -> core::result::Result<U, E>,
+    ) -> core::result::Result<DenseScalarLookupMap<K, U>, E> {
+        Ok(DenseScalarLookupMap {
+            min: self.min,
+            max: self.max,
+            entries: try_map_entry_values(self.entries, f)?,
+        })
+    }
+
     dense_scalar_lookup_primary_funcs!();
     common_primary_funcs!(non_const_len, entries);
 }
@@ -88,6 +107,12 @@ impl<K, V> Default for DenseScalarLookupMap<K, V> {
     }
 }
 
+impl<K> DenseScalarLookupMap<K, ()> {
+    pub(crate) fn bitset(&self) -> BitsetView<'_, K> {
+        BitsetView::dense(self.min, &self.entries)
+    }
+}
+
 impl<K, V, Q> Map<K, V, Q> for DenseScalarLookupMap<K, V> where Q: Scalar + Comparable<K> {}
 
 impl<K, V, Q> MapExtras<K, V, Q> for DenseScalarLookupMap<K, V>
@@ -1,13 +1,16 @@
 use crate::maps::decl_macros::{
-    common_primary_funcs, debug_trait_funcs, eytzinger_search_primary_funcs, get_disjoint_mut_funcs, index_trait_funcs,
+    common_primary_funcs, debug_trait_funcs, eytzinger_search_primary_funcs, filter_funcs, get_disjoint_mut_funcs, index_trait_funcs,
     into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs,
-    map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs,
+    map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs, sorted_query_funcs,
 };
-use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Range, Values, ValuesMut};
 use crate::traits::{Len, Map, MapExtras, MapIteration, MapQuery};
-use crate::utils::{SortedAndDeduppedVec, eytzinger_layout, eytzinger_search_by};
+use crate::utils::{
+    SortedAndDeduppedVec, eytzinger_layout, eytzinger_range_by, eytzinger_search_by, eytzinger_to_sorted_index, into_ok, retain_flagged,
+    retain_flags, sorted_to_eytzinger_index, try_map_entry_values,
+};
 use core::fmt::{Debug, Formatter, Result};
-use core::ops::Index;
+use core::ops::{Index, RangeBounds};
 use equivalent::Comparable;
 
 #[cfg(not(feature = "std"))]
@@ -52,6 +55,52 @@ impl<K, V> EytzingerSearchMap<K, V> {
         }
     }
 
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> EytzingerSearchMap<K, U> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(
+        self,
+        f: impl FnMut(&K, V) -> core::result::Result<U, E>,
+    ) -> core::result::Result<EytzingerSearchMap<K, U>, E> {
+        Ok(EytzingerSearchMap {
+            entries: try_map_entry_values(self.entries, f)?,
+        })
+    }
+
+    #[doc = include_str!("../doc_snippets/retain_into.md")]
+    #[must_use]
+    pub fn retain_into(self, f: impl FnMut(&K, &V) -> bool) -> Self
+    where
+        K: Ord,
+    {
+        let Some(flags) = retain_flags(self.iter(), f) else {
+            return self;
+        };
+
+        let mut entries = retain_flagged(self.entries, flags);
+        entries.sort_unstable_by(|x, y| x.0.cmp(&y.0));
+        Self::from_sorted_and_dedupped(SortedAndDeduppedVec::from_sorted_unique(entries, |x, y| x.0.cmp(&y.0)))
+    }
+
+    /// Creates a map like this one holding the given entries, a subset of this map's entries in iteration order.
+    #[allow(
+        clippy::unused_self,
+        reason = "Called through filter_funcs!, like the maps which reuse their hasher"
+    )]
+    pub(crate) fn with_retained(&self, mut entries: Vec<(K, V)>) -> Self
+    where
+        K: Ord,
+    {
+        entries.sort_unstable_by(|x, y| x.0.cmp(&y.0));
+        Self::from_sorted_and_dedupped(SortedAndDeduppedVec::from_sorted_unique(entries, |x, y| x.0.cmp(&y.0)))
+    }
+
+    filter_funcs!(K, K: Ord);
+
     eytzinger_search_primary_funcs!();
     common_primary_funcs!(non_const_len, entries);
 }
@@ -1,19 +1,19 @@
 use crate::hash_tables::HashTable;
 use crate::hashers::BridgeHasher;
 use crate::maps::decl_macros::{
-    common_primary_funcs, debug_trait_funcs, get_disjoint_mut_funcs, hash_primary_funcs, index_trait_funcs, into_iterator_trait_funcs,
-    into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs,
-    map_query_trait_funcs, partial_eq_trait_funcs,
+    common_primary_funcs, debug_trait_funcs, filter_funcs, get_disjoint_mut_funcs, hash_primary_funcs, index_trait_funcs,
+    into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs,
+    map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs,
 };
 use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
 use crate::traits::{CollectionMagnitude, Hasher, Len, Map, MapExtras, MapIteration, MapQuery, SmallCollection};
-use crate::utils::DeduppedVec;
+use crate::utils::{DeduppedVec, into_ok, retain_flagged, retain_flags};
 use core::fmt::{Debug, Formatter, Result};
 use core::ops::Index;
 use equivalent::Equivalent;
 
 #[cfg(not(feature = "std"))]
-use {alloc::string::String, alloc::vec::Vec};
+use {alloc::boxed::Box, alloc::string::String, alloc::vec::Vec};
 
 #[cfg(feature = "serde")]
 use {
@@ -71,6 +71,68 @@ where
         })
     }
 
+    pub(crate) const fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> HashMap<K, U, CM, H> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(
+        self,
+        mut f: impl FnMut(&K, V) -> core::result::Result<U, E>,
+    ) -> core::result::Result<HashMap<K, U, CM, H>, E> {
+        Ok(HashMap {
+            entries: self.entries.try_map_entries(|(k, v)| {
+                let v = f(&k, v)?;
+                Ok((k, v))
+            })?,
+            hasher: self.hasher,
+        })
+    }
+
+    #[doc = include_str!("../doc_snippets/retain_into.md")]
+    #[must_use]
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "Guaranteed not to panic since fewer entries still fit in the collection magnitude"
+    )]
+    pub fn retain_into(self, f: impl FnMut(&K, &V) -> bool) -> Self
+    where
+        H: Hasher<K>,
+    {
+        let Some(flags) = retain_flags(self.iter(), f) else {
+            return self;
+        };
+
+        let (entries, hasher) = self.into_parts();
+        Self::from_dedupped(DeduppedVec::from_unique(retain_flagged(entries, flags)), hasher).unwrap()
+    }
+
+    /// Creates a map like this one holding the given entries, a subset of this map's entries in iteration order.
+    pub(crate) fn with_retained(&self, entries: Vec<(K, V)>) -> Self
+    where
+        H: Hasher<K> + Clone,
+    {
+        Self::from_dedupped(DeduppedVec::from_unique(entries), self.hasher.clone()).unwrap()
+    }
+
+    filter_funcs!(K, H: Hasher<K> + Clone);
+
+    /// Returns the entries in the order they are stored.
+    pub(crate) fn entries(&self) -> &[(K, V)] {
+        &self.entries.entries
+    }
+
+    /// Splits the map into its entries and its hasher.
+    pub(crate) fn into_parts(self) -> (Box<[(K, V)]>, H) {
+        (self.entries.entries, self.hasher)
+    }
+
     hash_primary_funcs!();
     common_primary_funcs!(non_const_len, entries entries);
 }
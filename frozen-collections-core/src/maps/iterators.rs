@@ -1,3 +1,4 @@
+use crate::utils::sorted_to_eytzinger_index;
 use core::fmt::{Debug, Formatter};
 use core::iter::FusedIterator;
 
@@ -60,6 +61,86 @@ where
     }
 }
 
+/// An iterator over a sorted range of the entries of a map.
+pub struct Range<'a, K, V> {
+    entries: &'a [(K, V)],
+    front: usize,
+    back: usize,
+}
+
+impl<'a, K, V> Range<'a, K, V> {
+    /// Creates an iterator over the entries in the `front..back` span of sorted positions, given entries laid out using the Eytzinger layout.
+    pub(crate) const fn new(entries: &'a [(K, V)], front: usize, back: usize) -> Self {
+        Self { entries, front, back }
+    }
+
+    fn get(&self, sorted_index: usize) -> (&'a K, &'a V) {
+        let index = sorted_to_eytzinger_index(sorted_index, self.entries.len());
+
+        // SAFETY: sorted positions within the span are always less than the number of entries
+        let entry = unsafe { self.entries.get_unchecked(index) };
+        (&entry.0, &entry.1)
+    }
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.front < self.back).then(|| {
+            let entry = self.get(self.front);
+            self.front += 1;
+            entry
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Range<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        (self.front < self.back).then(|| {
+            self.back -= 1;
+            self.get(self.back)
+        })
+    }
+}
+
+impl<K, V> ExactSizeIterator for Range<'_, K, V> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<K, V> FusedIterator for Range<'_, K, V> {}
+
+impl<K, V> Clone for Range<'_, K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries,
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
+impl<K, V> Debug for Range<'_, K, V>
+where
+    K: Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
 /// An iterator over the entries of a map providing mutable values.
 pub struct IterMut<'a, K, V> {
     inner: core::slice::IterMut<'a, (K, V)>,
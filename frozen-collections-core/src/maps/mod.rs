@@ -1,16 +1,22 @@
 //! Specialized read-only map types.
 
+pub use bitset_scalar_lookup_map::BitsetScalarLookupMap;
 pub use dense_scalar_lookup_map::DenseScalarLookupMap;
 pub use eytzinger_search_map::EytzingerSearchMap;
 pub use hash_map::HashMap;
 pub use iterators::*;
+pub use perfect_hash_map::PerfectHashMap;
 pub use scan_map::ScanMap;
+pub use simd_probe_map::SimdProbeMap;
 pub use sparse_scalar_lookup_map::SparseScalarLookupMap;
 
+mod bitset_scalar_lookup_map;
 pub(crate) mod decl_macros;
 mod dense_scalar_lookup_map;
 mod eytzinger_search_map;
 mod hash_map;
 mod iterators;
+mod perfect_hash_map;
 mod scan_map;
+mod simd_probe_map;
 mod sparse_scalar_lookup_map;
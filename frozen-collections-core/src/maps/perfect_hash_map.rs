@@ -0,0 +1,266 @@
+use crate::analyzers::{PerfectHashAnalysisResult, find_perfect_hash};
+use crate::hash_tables::PerfectHashTable;
+use crate::hashers::BridgeHasher;
+use crate::maps::decl_macros::{
+    common_primary_funcs, debug_trait_funcs, get_disjoint_mut_funcs, hash_primary_funcs, index_trait_funcs, into_iterator_trait_funcs,
+    into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs,
+    map_query_trait_funcs, partial_eq_trait_funcs,
+};
+use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::traits::{Hasher, Len, Map, MapExtras, MapIteration, MapQuery};
+use crate::utils::{DeduppedVec, into_ok};
+use core::fmt::{Debug, Formatter, Result};
+use core::ops::Index;
+use equivalent::Equivalent;
+
+#[cfg(not(feature = "std"))]
+use {alloc::boxed::Box, alloc::string::String, alloc::string::ToString, alloc::vec::Vec};
+
+#[cfg(feature = "serde")]
+use {
+    crate::maps::decl_macros::serialize_trait_funcs,
+    serde::ser::SerializeMap,
+    serde::{Serialize, Serializer},
+};
+
+/// A general-purpose map implemented using a minimal perfect hash table.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+#[doc = include_str!("../doc_snippets/about.md")]
+#[doc = include_str!("../doc_snippets/hash_warning.md")]
+///
+/// Every lookup inspects exactly one entry, at the cost of a more expensive construction.
+#[derive(Clone)]
+pub struct PerfectHashMap<K, V, H = BridgeHasher> {
+    entries: PerfectHashTable<(K, V)>,
+    hasher: H,
+}
+
+impl<K, V, H> PerfectHashMap<K, V, H> {
+    /// Creates a frozen map.
+    ///
+    /// # Errors
+    ///
+    /// Fails if no minimal perfect hash function could be found for the keys, which happens
+    /// when distinct keys produce identical hash codes.
+    pub fn with_hasher(entries: Vec<(K, V)>, hasher: H) -> core::result::Result<Self, String>
+    where
+        K: Eq,
+        H: Hasher<K>,
+    {
+        // the hash codes computed to find duplicates are reused to look for a perfect hash function
+        let (entries, hash_codes) = DeduppedVec::using_hash_with_codes(entries, |x| hasher.hash_one(&x.0), |x, y| x.0 == y.0);
+        let analysis = find_perfect_hash(&hash_codes).ok_or_else(|| "unable to find a perfect hash function for the keys".to_string())?;
+        Ok(Self::from_analysis(entries, analysis, hasher))
+    }
+
+    /// Creates a frozen map.
+    ///
+    /// This function assumes that the analysis was performed on the hash codes of the entries.
+    pub(crate) fn from_analysis(entries: DeduppedVec<(K, V)>, analysis: PerfectHashAnalysisResult, hasher: H) -> Self {
+        Self {
+            entries: PerfectHashTable::new(entries, analysis),
+            hasher,
+        }
+    }
+
+    pub(crate) const fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> PerfectHashMap<K, U, H> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(
+        self,
+        mut f: impl FnMut(&K, V) -> core::result::Result<U, E>,
+    ) -> core::result::Result<PerfectHashMap<K, U, H>, E> {
+        Ok(PerfectHashMap {
+            entries: self.entries.try_map_entries(|(k, v)| {
+                let v = f(&k, v)?;
+                Ok((k, v))
+            })?,
+            hasher: self.hasher,
+        })
+    }
+
+    /// Splits the map into its entries and its hasher.
+    pub(crate) fn into_parts(self) -> (Box<[(K, V)]>, H) {
+        (self.entries.entries, self.hasher)
+    }
+
+    hash_primary_funcs!();
+    common_primary_funcs!(non_const_len, entries entries);
+}
+
+impl<K, V, H> Default for PerfectHashMap<K, V, H>
+where
+    H: Default,
+{
+    fn default() -> Self {
+        Self {
+            entries: PerfectHashTable::default(),
+            hasher: H::default(),
+        }
+    }
+}
+
+impl<K, V, Q, H> Map<K, V, Q> for PerfectHashMap<K, V, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+}
+
+impl<K, V, Q, H> MapExtras<K, V, Q> for PerfectHashMap<K, V, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+    map_extras_trait_funcs!();
+}
+
+impl<K, V, Q, H> MapQuery<Q, V> for PerfectHashMap<K, V, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+    map_query_trait_funcs!();
+}
+
+impl<K, V, H> MapIteration<K, V> for PerfectHashMap<K, V, H> {
+    type Iterator<'a>
+        = Iter<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type KeyIterator<'a>
+        = Keys<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type ValueIterator<'a>
+        = Values<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type MutIterator<'a>
+        = IterMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type ValueMutIterator<'a>
+        = ValuesMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    map_iteration_trait_funcs!();
+}
+
+impl<K, V, H> Len for PerfectHashMap<K, V, H> {
+    len_trait_funcs!();
+}
+
+impl<Q, K, V, H> Index<&Q> for PerfectHashMap<K, V, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+    index_trait_funcs!();
+}
+
+impl<K, V, H> IntoIterator for PerfectHashMap<K, V, H> {
+    into_iterator_trait_funcs!();
+}
+
+impl<'a, K, V, H> IntoIterator for &'a PerfectHashMap<K, V, H> {
+    into_iterator_trait_ref_funcs!();
+}
+
+impl<'a, K, V, H> IntoIterator for &'a mut PerfectHashMap<K, V, H> {
+    into_iterator_trait_mut_ref_funcs!();
+}
+
+impl<K, V, MT, H> PartialEq<MT> for PerfectHashMap<K, V, H>
+where
+    K: PartialEq,
+    V: PartialEq,
+    MT: MapQuery<K, V>,
+    H: Hasher<K>,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<K, V, H> Eq for PerfectHashMap<K, V, H>
+where
+    K: Eq,
+    V: Eq,
+    H: Hasher<K>,
+{
+}
+
+impl<K, V, H> Debug for PerfectHashMap<K, V, H>
+where
+    K: Debug,
+    V: Debug,
+{
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<K, V, H> Serialize for PerfectHashMap<K, V, H>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    serialize_trait_funcs!();
+}
+
+#[cfg(test)]
+mod test {
+    use crate::hashers::BridgeHasher;
+    use crate::maps::PerfectHashMap;
+    use crate::traits::Hasher;
+
+    struct ConstantHasher;
+
+    impl Hasher<i32> for ConstantHasher {
+        fn hash_one(&self, _value: &i32) -> u64 {
+            42
+        }
+    }
+
+    #[test]
+    fn fails_with_identical_hash_codes() {
+        assert!(PerfectHashMap::with_hasher(vec![(1, 1)], ConstantHasher).is_ok());
+        assert!(PerfectHashMap::with_hasher(vec![(1, 1), (2, 2)], ConstantHasher).is_err());
+    }
+
+    #[test]
+    fn finds_all_entries() {
+        let input: Vec<(i32, i32)> = (0..3000).map(|i| (i, i * 2)).collect();
+        let map = PerfectHashMap::<_, _>::with_hasher(input, BridgeHasher::default()).unwrap();
+
+        assert_eq!(3000, map.len());
+        for i in 0..3000 {
+            assert_eq!(Some(&(i * 2)), map.get(&i));
+        }
+
+        assert_eq!(None, map.get(&3000));
+        assert_eq!(None, map.get(&-1));
+    }
+}
@@ -1,11 +1,11 @@
 use crate::maps::decl_macros::{
-    common_primary_funcs, debug_trait_funcs, get_disjoint_mut_funcs, index_trait_funcs, into_iterator_trait_funcs,
+    common_primary_funcs, debug_trait_funcs, filter_funcs, get_disjoint_mut_funcs, index_trait_funcs, into_iterator_trait_funcs,
     into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs, map_iteration_trait_funcs,
     map_query_trait_funcs, partial_eq_trait_funcs, scan_primary_funcs,
 };
 use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
 use crate::traits::{Len, Map, MapExtras, MapIteration, MapQuery};
-use crate::utils::DeduppedVec;
+use crate::utils::{DeduppedVec, into_ok, retain_flagged, retain_flags, try_map_entry_values};
 use core::fmt::{Debug, Formatter, Result};
 use core::ops::Index;
 use equivalent::Equivalent;
@@ -48,6 +48,40 @@ impl<K, V> ScanMap<K, V> {
         }
     }
 
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> ScanMap<K, U> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(self, f: impl FnMut(&K, V) -> core::result::Result<U, E>) -> core::result::Result<ScanMap<K, U>, E> {
+        Ok(ScanMap {
+            entries: try_map_entry_values(self.entries, f)?,
+        })
+    }
+
+    #[doc = include_str!("../doc_snippets/retain_into.md")]
+    #[must_use]
+    pub fn retain_into(self, f: impl FnMut(&K, &V) -> bool) -> Self {
+        let Some(flags) = retain_flags(self.iter(), f) else {
+            return self;
+        };
+
+        Self::from_dedupped(DeduppedVec::from_unique(retain_flagged(self.entries, flags)))
+    }
+
+    /// Creates a map like this one holding the given entries, a subset of this map's entries in iteration order.
+    #[allow(
+        clippy::unused_self,
+        reason = "Called through filter_funcs!, like the maps which reuse their hasher"
+    )]
+    pub(crate) fn with_retained(&self, entries: Vec<(K, V)>) -> Self {
+        Self::from_dedupped(DeduppedVec::from_unique(entries))
+    }
+
+    filter_funcs!(K);
+
     scan_primary_funcs!();
     common_primary_funcs!(non_const_len, entries);
 }
@@ -0,0 +1,299 @@
+use crate::hash_tables::SimdProbeTable;
+use crate::hashers::BridgeHasher;
+use crate::maps::decl_macros::{
+    common_primary_funcs, debug_trait_funcs, filter_funcs, get_disjoint_mut_funcs, hash_primary_funcs, index_trait_funcs,
+    into_iterator_trait_funcs, into_iterator_trait_mut_ref_funcs, into_iterator_trait_ref_funcs, len_trait_funcs, map_extras_trait_funcs,
+    map_iteration_trait_funcs, map_query_trait_funcs, partial_eq_trait_funcs,
+};
+use crate::maps::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::traits::{Hasher, Len, Map, MapExtras, MapIteration, MapQuery};
+use crate::utils::{DeduppedVec, into_ok, retain_flagged, retain_flags};
+use core::fmt::{Debug, Formatter, Result};
+use core::ops::Index;
+use equivalent::Equivalent;
+
+#[cfg(not(feature = "std"))]
+use {alloc::boxed::Box, alloc::vec::Vec};
+
+#[cfg(feature = "serde")]
+use {
+    crate::maps::decl_macros::serialize_trait_funcs,
+    serde::ser::SerializeMap,
+    serde::{Serialize, Serializer},
+};
+
+/// A map for small collections implemented by comparing hash tags in parallel.
+///
+#[doc = include_str!("../doc_snippets/private_api_warning.md")]
+#[doc = include_str!("../doc_snippets/about.md")]
+#[doc = include_str!("../doc_snippets/hash_warning.md")]
+///
+/// Lookups compare a one-byte tag of the key's hash code against many entries at once using SIMD
+/// instructions where available, and only compare keys whose tags match.
+#[derive(Clone)]
+pub struct SimdProbeMap<K, V, H = BridgeHasher> {
+    entries: SimdProbeTable<(K, V)>,
+    hasher: H,
+}
+
+impl<K, V, H> SimdProbeMap<K, V, H> {
+    /// Creates a frozen map.
+    #[must_use]
+    pub fn with_hasher(entries: Vec<(K, V)>, hasher: H) -> Self
+    where
+        K: Eq,
+        H: Hasher<K>,
+    {
+        let entries = DeduppedVec::using_hash(entries, |x| hasher.hash_one(&x.0), |x, y| x.0 == y.0);
+        Self::from_dedupped(entries, hasher)
+    }
+
+    /// Creates a frozen map.
+    pub(crate) fn from_dedupped(entries: DeduppedVec<(K, V)>, hasher: H) -> Self
+    where
+        H: Hasher<K>,
+    {
+        let c = &hasher;
+        let h = |entry: &(K, V)| c.hash_one(&entry.0);
+        Self {
+            entries: SimdProbeTable::new(entries, h),
+            hasher,
+        }
+    }
+
+    #[doc = include_str!("../doc_snippets/map_values.md")]
+    #[must_use]
+    pub fn map_values<U>(self, mut f: impl FnMut(&K, V) -> U) -> SimdProbeMap<K, U, H> {
+        into_ok(self.try_map_values(|k, v| Ok(f(k, v))))
+    }
+
+    #[doc = include_str!("../doc_snippets/try_map_values.md")]
+    pub fn try_map_values<U, E>(
+        self,
+        mut f: impl FnMut(&K, V) -> core::result::Result<U, E>,
+    ) -> core::result::Result<SimdProbeMap<K, U, H>, E> {
+        Ok(SimdProbeMap {
+            entries: self.entries.try_map_entries(|(k, v)| {
+                let v = f(&k, v)?;
+                Ok((k, v))
+            })?,
+            hasher: self.hasher,
+        })
+    }
+
+    #[doc = include_str!("../doc_snippets/retain_into.md")]
+    #[must_use]
+    pub fn retain_into(self, f: impl FnMut(&K, &V) -> bool) -> Self
+    where
+        H: Hasher<K>,
+    {
+        let Some(flags) = retain_flags(self.iter(), f) else {
+            return self;
+        };
+
+        let (entries, hasher) = self.into_parts();
+        Self::from_dedupped(DeduppedVec::from_unique(retain_flagged(entries, flags)), hasher)
+    }
+
+    /// Creates a map like this one holding the given entries, a subset of this map's entries in iteration order.
+    pub(crate) fn with_retained(&self, entries: Vec<(K, V)>) -> Self
+    where
+        H: Hasher<K> + Clone,
+    {
+        Self::from_dedupped(DeduppedVec::from_unique(entries), self.hasher.clone())
+    }
+
+    filter_funcs!(K, H: Hasher<K> + Clone);
+
+    pub(crate) const fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns the entries in the order they are stored.
+    pub(crate) fn entries(&self) -> &[(K, V)] {
+        &self.entries.entries
+    }
+
+    /// Splits the map into its entries and its hasher.
+    pub(crate) fn into_parts(self) -> (Box<[(K, V)]>, H) {
+        (self.entries.entries, self.hasher)
+    }
+
+    hash_primary_funcs!();
+    common_primary_funcs!(non_const_len, entries entries);
+}
+
+impl<K, V, H> Default for SimdProbeMap<K, V, H>
+where
+    H: Default,
+{
+    fn default() -> Self {
+        Self {
+            entries: SimdProbeTable::default(),
+            hasher: H::default(),
+        }
+    }
+}
+
+impl<K, V, Q, H> Map<K, V, Q> for SimdProbeMap<K, V, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+}
+
+impl<K, V, Q, H> MapExtras<K, V, Q> for SimdProbeMap<K, V, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+    map_extras_trait_funcs!();
+}
+
+impl<K, V, Q, H> MapQuery<Q, V> for SimdProbeMap<K, V, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+    map_query_trait_funcs!();
+}
+
+impl<K, V, H> MapIteration<K, V> for SimdProbeMap<K, V, H> {
+    type Iterator<'a>
+        = Iter<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type KeyIterator<'a>
+        = Keys<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type ValueIterator<'a>
+        = Values<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type MutIterator<'a>
+        = IterMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    type ValueMutIterator<'a>
+        = ValuesMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        H: 'a;
+
+    map_iteration_trait_funcs!();
+}
+
+impl<K, V, H> Len for SimdProbeMap<K, V, H> {
+    len_trait_funcs!();
+}
+
+impl<Q, K, V, H> Index<&Q> for SimdProbeMap<K, V, H>
+where
+    Q: ?Sized + Equivalent<K>,
+    H: Hasher<Q>,
+{
+    index_trait_funcs!();
+}
+
+impl<K, V, H> IntoIterator for SimdProbeMap<K, V, H> {
+    into_iterator_trait_funcs!();
+}
+
+impl<'a, K, V, H> IntoIterator for &'a SimdProbeMap<K, V, H> {
+    into_iterator_trait_ref_funcs!();
+}
+
+impl<'a, K, V, H> IntoIterator for &'a mut SimdProbeMap<K, V, H> {
+    into_iterator_trait_mut_ref_funcs!();
+}
+
+impl<K, V, MT, H> PartialEq<MT> for SimdProbeMap<K, V, H>
+where
+    K: PartialEq,
+    V: PartialEq,
+    MT: MapQuery<K, V>,
+    H: Hasher<K>,
+{
+    partial_eq_trait_funcs!();
+}
+
+impl<K, V, H> Eq for SimdProbeMap<K, V, H>
+where
+    K: Eq,
+    V: Eq,
+    H: Hasher<K>,
+{
+}
+
+impl<K, V, H> Debug for SimdProbeMap<K, V, H>
+where
+    K: Debug,
+    V: Debug,
+{
+    debug_trait_funcs!();
+}
+
+#[cfg(feature = "serde")]
+impl<K, V, H> Serialize for SimdProbeMap<K, V, H>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    serialize_trait_funcs!();
+}
+
+#[cfg(test)]
+mod test {
+    use crate::hashers::BridgeHasher;
+    use crate::maps::SimdProbeMap;
+    use crate::traits::Hasher;
+
+    struct ConstantHasher;
+
+    impl Hasher<i32> for ConstantHasher {
+        fn hash_one(&self, _value: &i32) -> u64 {
+            42
+        }
+    }
+
+    #[test]
+    fn tolerates_identical_tags() {
+        let input: Vec<(i32, i32)> = (0..40).map(|i| (i, i * 2)).collect();
+        let map = SimdProbeMap::with_hasher(input, ConstantHasher);
+
+        for i in 0..40 {
+            assert_eq!(Some(&(i * 2)), map.get(&i));
+        }
+
+        assert_eq!(None, map.get(&40));
+    }
+
+    #[test]
+    fn finds_all_entries() {
+        for len in 0..70 {
+            let input: Vec<(i32, i32)> = (0..len).map(|i| (i * 7, i)).collect();
+            let mut map = SimdProbeMap::<_, _>::with_hasher(input, BridgeHasher::default());
+
+            assert_eq!(usize::try_from(len).unwrap(), map.len());
+            for i in 0..len {
+                assert_eq!(Some(&i), map.get(&(i * 7)));
+                assert_eq!(Some(&mut i.clone()), map.get_mut(&(i * 7)));
+                assert_eq!(None, map.get(&(i * 7 + 1)));
+            }
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use core::fmt::{Debug, Formatter};
+
+/// A view over a set whose values are stored in a bitset.
+///
+/// Sets which use the bitset strategy, or whose values form a continuous range, expose this view through
+/// [`SetIteration::bitset`](crate::traits::SetIteration::bitset), which lets set operations between two such
+/// sets be computed a word at a time.
+pub struct BitsetView<'a, T> {
+    base_word: usize,
+    num_words: usize,
+    bits: Bits<'a>,
+    entries: &'a [(T, ())],
+}
+
+/// Where the bits of a view come from.
+#[derive(Clone, Copy, Debug)]
+enum Bits<'a> {
+    /// The words of a stored bitset.
+    Words(&'a [u64]),
+
+    /// A run of set bits, starting at the given bit of the first word, one for each entry.
+    Run(usize),
+}
+
+impl<'a, T> BitsetView<'a, T> {
+    /// Creates a view of a bitset whose first bit represents the scalar index `base`, which must be a multiple of 64.
+    pub(crate) fn new(base: usize, words: &'a [u64], entries: &'a [(T, ())]) -> Self {
+        debug_assert!(base.is_multiple_of(64), "Bitset must be word-aligned");
+
+        Self {
+            base_word: base / 64,
+            num_words: words.len(),
+            bits: Bits::Words(words),
+            entries,
+        }
+    }
+
+    /// Creates a view of a set whose values are the continuous range of scalar indices starting at `min`.
+    pub(crate) const fn dense(min: usize, entries: &'a [(T, ())]) -> Self {
+        let start = min % 64;
+
+        Self {
+            base_word: min / 64,
+            num_words: if entries.is_empty() {
+                0
+            } else {
+                (start + entries.len()).div_ceil(64)
+            },
+            bits: Bits::Run(start),
+            entries,
+        }
+    }
+
+    #[inline]
+    fn word(&self, word_index: usize) -> u64 {
+        let Some(i) = word_index.checked_sub(self.base_word).filter(|i| *i < self.num_words) else {
+            return 0;
+        };
+
+        match self.bits {
+            Bits::Words(words) => words[i],
+            Bits::Run(start) => {
+                // the run covers bits [start, start + len) counted from the first word
+                let first = start.saturating_sub(i * 64);
+                let end = (start + self.entries.len() - i * 64).min(64);
+                (u64::MAX >> (64 - (end - first))) << first
+            }
+        }
+    }
+
+    const fn end_word(&self) -> usize {
+        self.base_word + self.num_words
+    }
+}
+
+impl<T> Clone for BitsetView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BitsetView<'_, T> {}
+
+impl<T> Debug for BitsetView<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BitsetView")
+            .field("base_word", &self.base_word)
+            .field("bits", &self.bits)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Walks two bitsets a word at a time, yielding the values whose bits survive a bitwise operation.
+pub struct BitsetOp<'a, T> {
+    s1: BitsetView<'a, T>,
+    s2: BitsetView<'a, T>,
+    op: fn(u64, u64) -> u64,
+    next_word: usize,
+    end_word: usize,
+    w1: u64,
+    w2: u64,
+    rank1: usize,
+    rank2: usize,
+    pending: u64,
+}
+
+impl<'a, T> BitsetOp<'a, T> {
+    pub(crate) fn new(s1: BitsetView<'a, T>, s2: BitsetView<'a, T>, op: fn(u64, u64) -> u64) -> Self {
+        let (next_word, end_word) = match (s1.num_words == 0, s2.num_words == 0) {
+            (true, true) => (0, 0),
+            (true, false) => (s2.base_word, s2.end_word()),
+            (false, true) => (s1.base_word, s1.end_word()),
+            (false, false) => (s1.base_word.min(s2.base_word), s1.end_word().max(s2.end_word())),
+        };
+
+        Self {
+            s1,
+            s2,
+            op,
+            next_word,
+            end_word,
+            w1: 0,
+            w2: 0,
+            rank1: 0,
+            rank2: 0,
+            pending: 0,
+        }
+    }
+
+    pub(crate) fn union(s1: BitsetView<'a, T>, s2: BitsetView<'a, T>) -> Self {
+        Self::new(s1, s2, |w1, w2| w1 | w2)
+    }
+
+    pub(crate) fn intersection(s1: BitsetView<'a, T>, s2: BitsetView<'a, T>) -> Self {
+        Self::new(s1, s2, |w1, w2| w1 & w2)
+    }
+
+    pub(crate) fn difference(s1: BitsetView<'a, T>, s2: BitsetView<'a, T>) -> Self {
+        Self::new(s1, s2, |w1, w2| w1 & !w2)
+    }
+
+    pub(crate) const fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.s1.entries.len() - self.rank1) + (self.s2.entries.len() - self.rank2);
+        (0, Some(remaining))
+    }
+}
+
+impl<'a, T> Iterator for BitsetOp<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending == 0 {
+            if self.next_word >= self.end_word {
+                return None;
+            }
+
+            self.rank1 += self.w1.count_ones() as usize;
+            self.rank2 += self.w2.count_ones() as usize;
+            self.w1 = self.s1.word(self.next_word);
+            self.w2 = self.s2.word(self.next_word);
+            self.pending = (self.op)(self.w1, self.w2);
+            self.next_word += 1;
+        }
+
+        let bit = self.pending & self.pending.wrapping_neg();
+        self.pending ^= bit;
+
+        let below = bit - 1;
+        if self.w1 & bit == 0 {
+            Some(&self.s2.entries[self.rank2 + (self.w2 & below).count_ones() as usize].0)
+        } else {
+            Some(&self.s1.entries[self.rank1 + (self.w1 & below).count_ones() as usize].0)
+        }
+    }
+}
+
+impl<T> Clone for BitsetOp<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            s1: self.s1,
+            s2: self.s2,
+            op: self.op,
+            next_word: self.next_word,
+            end_word: self.end_word,
+            w1: self.w1,
+            w2: self.w2,
+            rank1: self.rank1,
+            rank2: self.rank2,
+            pending: self.pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn collect(op: BitsetOp<'_, usize>) -> Vec<usize> {
+        op.copied().collect()
+    }
+
+    #[test]
+    fn test_bitset_ops() {
+        // bits 1 and 3 of the word starting at 64, and bit 0 of the word starting at 128
+        let e1 = [(65, ()), (67, ()), (128, ())];
+        let s1 = BitsetView::new(64, &[0b1010, 0b1], &e1);
+
+        // bit 3 of the word starting at 0, and bit 3 of the word starting at 64
+        let e2 = [(3, ()), (67, ())];
+        let s2 = BitsetView::new(0, &[0b1000, 0b1000], &e2);
+
+        assert_eq!(vec![3, 65, 67, 128], collect(BitsetOp::union(s1, s2)));
+        assert_eq!(vec![67], collect(BitsetOp::intersection(s1, s2)));
+        assert_eq!(vec![65, 128], collect(BitsetOp::difference(s1, s2)));
+        assert_eq!(vec![3], collect(BitsetOp::difference(s2, s1)));
+        assert_eq!((0, Some(5)), BitsetOp::union(s1, s2).size_hint());
+
+        let empty = BitsetView::new(0, &[], &[]);
+        assert_eq!(vec![65, 67, 128], collect(BitsetOp::union(s1, empty)));
+        assert_eq!(vec![65, 67, 128], collect(BitsetOp::union(empty, s1)));
+        assert!(collect(BitsetOp::intersection(s1, empty)).is_empty());
+        assert!(collect(BitsetOp::union(empty, empty)).is_empty());
+    }
+
+    #[test]
+    fn test_dense_bitset_ops() {
+        // a run crossing the boundary between the words starting at 64 and 128
+        let e1: Vec<_> = (100..140).map(|x| (x, ())).collect();
+        let s1 = BitsetView::dense(100, &e1);
+
+        let e2 = [(3, ()), (99, ()), (100, ()), (127, ()), (128, ()), (139, ()), (140, ())];
+        let s2 = BitsetView::new(0, &[0b1000, (1 << 35) | (1 << 36) | (1 << 63), 0b1 | (1 << 11) | (1 << 12)], &e2);
+
+        assert_eq!(
+            (100..140).collect::<Vec<_>>(),
+            collect(BitsetOp::union(s1, BitsetView::new(0, &[], &[])))
+        );
+        assert_eq!(vec![100, 127, 128, 139], collect(BitsetOp::intersection(s1, s2)));
+        assert_eq!(vec![3, 99, 140], collect(BitsetOp::difference(s2, s1)));
+        assert_eq!(36, collect(BitsetOp::difference(s1, s2)).len());
+
+        // runs which start on a word boundary and fill whole words
+        let e3: Vec<_> = (64..192).map(|x| (x, ())).collect();
+        let s3 = BitsetView::dense(64, &e3);
+        assert_eq!(e3.iter().map(|e| e.0).collect::<Vec<_>>(), collect(BitsetOp::union(s3, s3)));
+        assert_eq!(vec![99, 100, 127, 128, 139, 140], collect(BitsetOp::intersection(s2, s3)));
+
+        let empty = BitsetView::<usize>::dense(5, &[]);
+        assert!(collect(BitsetOp::union(empty, empty)).is_empty());
+    }
+}
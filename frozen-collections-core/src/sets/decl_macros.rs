@@ -59,6 +59,22 @@ macro_rules! common_primary_funcs {
             self.len() == 0
         }
     };
+
+    (@len non_const_len) => {
+        #[doc = include_str!("../doc_snippets/len.md")]
+        #[inline]
+        #[must_use]
+        pub fn len(&self) -> usize {
+            self.map.len()
+        }
+
+        #[doc = include_str!("../doc_snippets/is_empty.md")]
+        #[inline]
+        #[must_use]
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+    };
 }
 
 macro_rules! debug_trait_funcs {
@@ -69,6 +85,27 @@ macro_rules! debug_trait_funcs {
     };
 }
 
+macro_rules! diagnostics_funcs {
+    () => {
+        #[doc = include_str!("../doc_snippets/diagnostics.md")]
+        #[must_use]
+        pub fn diagnostics(&self) -> crate::diagnostics::Diagnostics {
+            self.map.diagnostics()
+        }
+    };
+
+    (hashed) => {
+        #[doc = include_str!("../doc_snippets/diagnostics.md")]
+        #[must_use]
+        pub fn diagnostics(&self) -> crate::diagnostics::Diagnostics
+        where
+            H: Hasher<T>,
+        {
+            self.map.diagnostics()
+        }
+    };
+}
+
 macro_rules! hash_primary_funcs {
     () => {
         #[doc = include_str!("../doc_snippets/get_from_set.md")]
@@ -92,6 +129,38 @@ macro_rules! hash_primary_funcs {
         {
             self.get(value).is_some()
         }
+
+        #[doc = include_str!("../doc_snippets/contains_many_from_set.md")]
+        pub fn contains_many<Q>(&self, values: &[&Q], results: &mut [bool])
+        where
+            Q: ?Sized + Equivalent<T>,
+            H: Hasher<Q>,
+        {
+            self.map.contains_many(values, results);
+        }
+
+        #[doc = include_str!("../doc_snippets/hash_key.md")]
+        #[inline]
+        #[must_use]
+        pub fn hash_key<Q>(&self, value: &Q) -> u64
+        where
+            Q: ?Sized,
+            H: Hasher<Q>,
+        {
+            self.map.hash_key(value)
+        }
+
+        #[doc = include_str!("../doc_snippets/contains_with_hash_from_set.md")]
+        #[inline]
+        #[must_use]
+        pub fn contains_with_hash<Q>(&self, hash_code: u64, value: &Q) -> bool
+        where
+            Q: ?Sized + Equivalent<T>,
+        {
+            self.map.contains_with_hash(hash_code, value)
+        }
+
+        diagnostics_funcs!(hashed);
     };
 }
 
@@ -117,6 +186,142 @@ macro_rules! into_iterator_ref_trait_funcs {
     };
 }
 
+macro_rules! sorted_query_funcs {
+    ("Ord") => {
+        sorted_query_funcs!(@funcs, [], [Q: ?Sized + Comparable<T>]);
+    };
+
+    ("Scalar") => {
+        sorted_query_funcs!(@funcs, [T: Scalar], [T: Scalar, Q: Comparable<T> + Scalar]);
+    };
+
+    (@funcs, [$($select_predicates:tt)*], [$($predicates:tt)+]) => {
+        #[doc = include_str!("../doc_snippets/rank_from_set.md")]
+        #[inline]
+        #[must_use]
+        pub fn rank<Q>(&self, value: &Q) -> Option<usize>
+        where
+            $($predicates)+,
+        {
+            self.map.rank(value)
+        }
+
+        #[doc = include_str!("../doc_snippets/select_from_set.md")]
+        #[inline]
+        #[must_use]
+        pub fn select(&self, sorted_index: usize) -> Option<&T>
+        where
+            $($select_predicates)*
+        {
+            Some(self.map.select(sorted_index)?.0)
+        }
+
+        #[doc = include_str!("../doc_snippets/floor_from_set.md")]
+        #[inline]
+        pub fn floor<Q>(&self, value: &Q) -> Option<&T>
+        where
+            $($predicates)+,
+        {
+            Some(self.map.floor(value)?.0)
+        }
+
+        #[doc = include_str!("../doc_snippets/ceiling_from_set.md")]
+        #[inline]
+        pub fn ceiling<Q>(&self, value: &Q) -> Option<&T>
+        where
+            $($predicates)+,
+        {
+            Some(self.map.ceiling(value)?.0)
+        }
+
+        #[doc = include_str!("../doc_snippets/predecessor_from_set.md")]
+        #[inline]
+        pub fn predecessor<Q>(&self, value: &Q) -> Option<&T>
+        where
+            $($predicates)+,
+        {
+            Some(self.map.predecessor(value)?.0)
+        }
+
+        #[doc = include_str!("../doc_snippets/successor_from_set.md")]
+        #[inline]
+        pub fn successor<Q>(&self, value: &Q) -> Option<&T>
+        where
+            $($predicates)+,
+        {
+            Some(self.map.successor(value)?.0)
+        }
+    };
+}
+
+macro_rules! materialize_funcs {
+    () => {
+        /// Creates a new set holding the values in `self` or `other`.
+        ///
+        #[doc = include_str!("../doc_snippets/materialize.md")]
+        #[must_use]
+        pub fn union_set(&self, other: &Self) -> Self {
+            self.combine(other, SetOp::Union)
+        }
+
+        /// Creates a new set holding the values in both `self` and `other`.
+        ///
+        #[doc = include_str!("../doc_snippets/materialize.md")]
+        #[must_use]
+        pub fn intersection_set(&self, other: &Self) -> Self {
+            self.combine(other, SetOp::Intersection)
+        }
+
+        /// Creates a new set holding the values in `self` but not in `other`.
+        ///
+        #[doc = include_str!("../doc_snippets/materialize.md")]
+        #[must_use]
+        pub fn difference_set(&self, other: &Self) -> Self {
+            self.combine(other, SetOp::Difference)
+        }
+
+        /// Creates a new set holding the values in `self` or in `other` but not in both.
+        ///
+        #[doc = include_str!("../doc_snippets/materialize.md")]
+        #[must_use]
+        pub fn symmetric_difference_set(&self, other: &Self) -> Self {
+            self.combine(other, SetOp::SymmetricDifference)
+        }
+    };
+}
+
+macro_rules! ordered_primary_funcs {
+    () => {
+        #[doc = include_str!("../doc_snippets/range.md")]
+        #[must_use]
+        pub fn range<Q, R>(&self, range: R) -> Range<'_, T>
+        where
+            Q: ?Sized + Comparable<T>,
+            R: RangeBounds<Q>,
+        {
+            Range::new(self.map.range(range))
+        }
+
+        #[doc = include_str!("../doc_snippets/iter_sorted.md")]
+        #[must_use]
+        pub fn iter_sorted(&self) -> Range<'_, T> {
+            Range::new(self.map.iter_sorted())
+        }
+
+        #[doc = include_str!("../doc_snippets/first.md")]
+        #[must_use]
+        pub fn first(&self) -> Option<&T> {
+            Some(self.map.first_key_value()?.0)
+        }
+
+        #[doc = include_str!("../doc_snippets/last.md")]
+        #[must_use]
+        pub fn last(&self) -> Option<&T> {
+            Some(self.map.last_key_value()?.0)
+        }
+    };
+}
+
 macro_rules! partial_eq_trait_funcs {
     () => {
         fn eq(&self, other: &ST) -> bool {
@@ -186,13 +391,17 @@ pub(crate) use bitor_trait_funcs;
 pub(crate) use bitxor_trait_funcs;
 pub(crate) use common_primary_funcs;
 pub(crate) use debug_trait_funcs;
+pub(crate) use diagnostics_funcs;
 pub(crate) use hash_primary_funcs;
 pub(crate) use into_iterator_ref_trait_funcs;
 pub(crate) use into_iterator_trait_funcs;
+pub(crate) use materialize_funcs;
+pub(crate) use ordered_primary_funcs;
 pub(crate) use partial_eq_trait_funcs;
 pub(crate) use set_extras_trait_funcs;
 pub(crate) use set_iteration_trait_funcs;
 pub(crate) use set_query_trait_funcs;
+pub(crate) use sorted_query_funcs;
 pub(crate) use sub_trait_funcs;
 
 #[cfg(feature = "serde")]
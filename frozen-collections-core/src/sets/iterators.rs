@@ -1,3 +1,4 @@
+use crate::sets::BitsetOp;
 use crate::traits::{Set, SetIteration, SetOps};
 use core::cmp::{max, min};
 use core::fmt::{Debug, Formatter};
@@ -60,6 +61,62 @@ where
     }
 }
 
+/// An iterator over a sorted range of the values of a set.
+pub struct Range<'a, T> {
+    inner: crate::maps::Range<'a, T, ()>,
+}
+
+impl<'a, T> Range<'a, T> {
+    pub(crate) const fn new(inner: crate::maps::Range<'a, T, ()>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, T> Iterator for Range<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|entry| entry.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl<T> DoubleEndedIterator for Range<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|entry| entry.0)
+    }
+}
+
+impl<T> ExactSizeIterator for Range<'_, T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<T> FusedIterator for Range<'_, T> {}
+
+impl<T> Clone for Range<'_, T> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<T> Debug for Range<'_, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries((*self).clone()).finish()
+    }
+}
+
 /// A consuming iterator over the values of a set.
 #[derive(Debug)]
 pub struct IntoIter<T> {
@@ -114,6 +171,7 @@ where
     s1_iter: <S1 as SetIteration<T>>::Iterator<'a>,
     s2: &'a S2,
     s2_iter: <S2 as SetIteration<T>>::Iterator<'a>,
+    bitset_op: Option<BitsetOp<'a, T>>,
 }
 
 impl<'a, S1, S2, T> Union<'a, S1, S2, T>
@@ -127,6 +185,7 @@ where
             s1,
             s2_iter: s2.iter(),
             s2,
+            bitset_op: s1.bitset().zip(s2.bitset()).map(|(b1, b2)| BitsetOp::union(b1, b2)),
         }
     }
 }
@@ -140,6 +199,10 @@ where
 
     #[mutants::skip]
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(bitset_op) = &mut self.bitset_op {
+            return bitset_op.next();
+        }
+
         if self.s1.len() > self.s2.len() {
             let item = self.s1_iter.next();
             if item.is_some() {
@@ -168,6 +231,10 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        if let Some(bitset_op) = &self.bitset_op {
+            return bitset_op.size_hint();
+        }
+
         let h1 = self.s1_iter.size_hint();
         let h2 = self.s2_iter.size_hint();
 
@@ -195,6 +262,7 @@ where
             s1_iter: self.s1_iter.clone(),
             s2: self.s2,
             s2_iter: self.s2_iter.clone(),
+            bitset_op: self.bitset_op.clone(),
         }
     }
 }
@@ -303,6 +371,7 @@ where
     s1: &'a S1,
     s1_iter: <S1 as SetIteration<T>>::Iterator<'a>,
     s2: &'a S2,
+    bitset_op: Option<BitsetOp<'a, T>>,
 }
 
 impl<'a, S1, S2, T> Difference<'a, S1, S2, T>
@@ -315,6 +384,7 @@ where
             s1_iter: s1.iter(),
             s1,
             s2,
+            bitset_op: s1.bitset().zip(s2.bitset()).map(|(b1, b2)| BitsetOp::difference(b1, b2)),
         }
     }
 }
@@ -327,6 +397,10 @@ where
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(bitset_op) = &mut self.bitset_op {
+            return bitset_op.next();
+        }
+
         loop {
             let item = self.s1_iter.next()?;
             if !self.s2.contains(item) {
@@ -336,6 +410,10 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        if let Some(bitset_op) = &self.bitset_op {
+            return bitset_op.size_hint();
+        }
+
         let (_, upper) = self.s1_iter.size_hint();
         (0, upper)
     }
@@ -353,6 +431,7 @@ where
             s1: self.s1,
             s1_iter: self.s1_iter.clone(),
             s2: self.s2,
+            bitset_op: self.bitset_op.clone(),
         }
     }
 }
@@ -388,6 +467,7 @@ where
     s1_iter: <S1 as SetIteration<T>>::Iterator<'a>,
     s2: &'a S2,
     s2_iter: <S2 as SetIteration<T>>::Iterator<'a>,
+    bitset_op: Option<BitsetOp<'a, T>>,
 }
 
 impl<'a, S1, S2, T> Intersection<'a, S1, S2, T>
@@ -401,6 +481,7 @@ where
             s1,
             s2_iter: s2.iter(),
             s2,
+            bitset_op: s1.bitset().zip(s2.bitset()).map(|(b1, b2)| BitsetOp::intersection(b1, b2)),
         }
     }
 }
@@ -414,6 +495,10 @@ where
 
     #[mutants::skip]
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(bitset_op) = &mut self.bitset_op {
+            return bitset_op.next();
+        }
+
         if self.s1.len() < self.s2.len() {
             loop {
                 let item = self.s1_iter.next()?;
@@ -432,6 +517,10 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        if let Some(bitset_op) = &self.bitset_op {
+            return bitset_op.size_hint();
+        }
+
         (0, Some(min(self.s1.len(), self.s2.len())))
     }
 }
@@ -449,6 +538,7 @@ where
             s1_iter: self.s1_iter.clone(),
             s2: self.s2,
             s2_iter: self.s2_iter.clone(),
+            bitset_op: self.bitset_op.clone(),
         }
     }
 }
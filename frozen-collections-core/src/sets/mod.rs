@@ -1,6 +1,12 @@
 //! Specialized read-only set types.
 
+pub use bitset::BitsetView;
+pub use hash_set::HashSet;
 pub use iterators::*;
 
+pub(crate) use bitset::BitsetOp;
+
+mod bitset;
 pub(crate) mod decl_macros;
+mod hash_set;
 mod iterators;
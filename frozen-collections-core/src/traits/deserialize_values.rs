@@ -0,0 +1,158 @@
+use crate::traits::{Map, Scalar};
+use core::fmt::{Debug, Formatter, Result};
+use core::marker::PhantomData;
+use serde::de::{Error, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+#[cfg(not(feature = "std"))]
+use {alloc::boxed::Box, alloc::format, alloc::string::String, alloc::vec::Vec};
+
+/// A key type which can be read from a serialized map in order to look up entries.
+///
+/// Keys embedded in a frozen map, such as `&'static str`, can't always be deserialized
+/// directly, so keys are deserialized into an owned form and then looked up using a borrowed form.
+///
+/// This is implemented for strings and for all [`Scalar`] types which implement [`Debug`]. Implement it for
+/// your own key types to use them with [`DeserializeValues`].
+pub trait DeserializeKey<'de> {
+    /// The type keys are deserialized into, which is also used to name the offending key in errors.
+    type Owned: Deserialize<'de> + Debug;
+
+    /// The type used to look up deserialized keys in the map.
+    type Query: ?Sized;
+
+    /// Returns the form of a deserialized key used for lookups.
+    fn query(owned: &Self::Owned) -> &Self::Query;
+}
+
+impl DeserializeKey<'_> for &str {
+    type Owned = String;
+    type Query = str;
+
+    fn query(owned: &Self::Owned) -> &Self::Query {
+        owned
+    }
+}
+
+impl DeserializeKey<'_> for String {
+    type Owned = Self;
+    type Query = str;
+
+    fn query(owned: &Self::Owned) -> &Self::Query {
+        owned
+    }
+}
+
+impl DeserializeKey<'_> for Box<str> {
+    type Owned = String;
+    type Query = str;
+
+    fn query(owned: &Self::Owned) -> &Self::Query {
+        owned
+    }
+}
+
+impl<'de, S> DeserializeKey<'de> for S
+where
+    S: Scalar + Deserialize<'de> + Debug,
+{
+    type Owned = Self;
+    type Query = Self;
+
+    fn query(owned: &Self::Owned) -> &Self::Query {
+        owned
+    }
+}
+
+/// Fills the values of a map with a fixed set of keys from a serialized map.
+///
+/// This is useful for maps created by the `fz_*_map` macros, whose keys are fixed at build time.
+/// A clone of such a map can be given values loaded at runtime, for example from a configuration file.
+///
+/// The serialized map must contain every key of the map exactly once, and no other keys.
+/// If it doesn't, an error is returned and the map is left unchanged.
+pub trait DeserializeValues<'de, K, V> {
+    /// Replaces all the values of the map with values read from the deserializer.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the deserializer fails, or if the serialized map contains unknown or duplicate keys,
+    /// or doesn't contain all the keys of the map. The error names the first such key.
+    fn deserialize_values<D>(&mut self, deserializer: D) -> core::result::Result<(), D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+impl<'de, M, K, V> DeserializeValues<'de, K, V> for M
+where
+    M: Map<K, V, K::Query>,
+    K: DeserializeKey<'de> + Debug,
+    V: Deserialize<'de>,
+{
+    fn deserialize_values<D>(&mut self, deserializer: D) -> core::result::Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let entries = deserializer.deserialize_map(EntriesVisitor::<K, V> { marker: PhantomData })?;
+
+        // keys are told apart by the address of the matching entry, paired with the position of the serialized entry
+        let mut seen = Vec::with_capacity(entries.len());
+        for (index, (key, _)) in entries.iter().enumerate() {
+            let Some((k, _)) = self.get_key_value(K::query(key)) else {
+                return Err(D::Error::custom(format!("unknown key {key:?}")));
+            };
+
+            seen.push((core::ptr::from_ref(k).addr(), index));
+        }
+
+        seen.sort_unstable();
+        if let Some(run) = seen.chunk_by(|x, y| x.0 == y.0).find(|run| run.len() > 1) {
+            return Err(D::Error::custom(format!("duplicate key {:?}", entries[run[1].1].0)));
+        }
+
+        if seen.len() != self.len() {
+            if let Some(k) = self
+                .keys()
+                .find(|k| seen.binary_search_by_key(&core::ptr::from_ref(*k).addr(), |x| x.0).is_err())
+            {
+                return Err(D::Error::custom(format!("missing key {k:?}")));
+            }
+        }
+
+        for (key, value) in entries {
+            if let Some(v) = self.get_mut(K::query(&key)) {
+                *v = value;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct EntriesVisitor<K, V> {
+    marker: PhantomData<(K, V)>,
+}
+
+impl<'de, K, V> Visitor<'de> for EntriesVisitor<K, V>
+where
+    K: DeserializeKey<'de>,
+    V: Deserialize<'de>,
+{
+    type Value = Vec<(K::Owned, V)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<M>(self, mut map: M) -> core::result::Result<Self::Value, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let mut v = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(x) = map.next_entry()? {
+            v.push(x);
+        }
+
+        Ok(v)
+    }
+}
@@ -1,3 +1,6 @@
+use crate::diagnostics::KeyHashing;
+use core::ops::Range;
+
 /// Hashes values of a specific type.
 ///
 /// This provides a hashing mechanism which is orthogonal to the normal
@@ -10,4 +13,14 @@ where
 {
     /// Produce a hash value for the given value.
     fn hash_one(&self, value: &T) -> u64;
+
+    /// Describes how hash codes are derived from values, for use in diagnostics.
+    fn key_hashing(&self) -> KeyHashing {
+        KeyHashing::Whole
+    }
+
+    /// Returns the range of each value which is hashed, when only part of each value is hashed.
+    fn subslice_range(&self) -> Option<Range<usize>> {
+        None
+    }
 }
@@ -1,6 +1,8 @@
 //! Traits to support frozen collections.
 
 pub use crate::traits::collection_magnitude::{CollectionMagnitude, LargeCollection, MediumCollection, SmallCollection};
+#[cfg(feature = "serde")]
+pub use crate::traits::deserialize_values::{DeserializeKey, DeserializeValues};
 pub use crate::traits::hasher::Hasher;
 pub use crate::traits::len::Len;
 pub use crate::traits::map::Map;
@@ -8,13 +10,17 @@ pub use crate::traits::map_extras::MapExtras;
 pub use crate::traits::map_iteration::MapIteration;
 pub use crate::traits::map_query::MapQuery;
 pub use crate::traits::scalar::Scalar;
+pub use crate::traits::scalar_enum::ScalarEnum;
 pub use crate::traits::set::Set;
 pub use crate::traits::set_extras::SetExtras;
 pub use crate::traits::set_iteration::SetIteration;
 pub use crate::traits::set_ops::SetOps;
 pub use crate::traits::set_query::SetQuery;
+pub use crate::traits::string_key::{IntoStringKey, StringKey};
 
 mod collection_magnitude;
+#[cfg(feature = "serde")]
+mod deserialize_values;
 mod hasher;
 mod len;
 mod map;
@@ -22,8 +28,10 @@ mod map_extras;
 mod map_iteration;
 mod map_query;
 mod scalar;
+mod scalar_enum;
 mod set;
 mod set_extras;
 mod set_iteration;
 mod set_ops;
 mod set_query;
+mod string_key;
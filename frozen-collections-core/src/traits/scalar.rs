@@ -4,6 +4,25 @@ use core::num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroIsize, NonZeroU8, NonZ
 pub trait Scalar: Ord + Clone + Copy {
     /// Returns a value's index into its containing sequence.
     fn index(&self) -> usize;
+
+    /// Returns whether other values may share this value's index.
+    ///
+    /// Indices are unique for most types, but `u128` and `i128` have more values than there are
+    /// indices, so the values beyond the range of `usize` share the first or last index. Collections
+    /// holding a key with a shared index never look up their keys by index, and hash them instead.
+    #[inline]
+    fn index_is_shared(&self) -> bool {
+        false
+    }
+
+    /// Returns a hash code for the value.
+    ///
+    /// This is the value's index, except for types whose indices can be shared, which need to
+    /// fold all their bits into the hash code to keep values with a shared index apart.
+    #[inline]
+    fn hash_code(&self) -> u64 {
+        self.index() as u64
+    }
 }
 
 macro_rules! impl_unsigned_scalar {
@@ -68,6 +87,58 @@ macro_rules! impl_signed_nz_scalar {
     };
 }
 
+impl Scalar for u128 {
+    #[inline]
+    #[allow(clippy::cast_possible_truncation, reason = "Values beyond usize are saturated")]
+    fn index(&self) -> usize {
+        (*self).min(usize::MAX as Self) as usize
+    }
+
+    #[inline]
+    fn index_is_shared(&self) -> bool {
+        *self >= usize::MAX as Self
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation, reason = "Folding the two halves together")]
+    fn hash_code(&self) -> u64 {
+        (*self as u64) ^ ((*self >> 64) as u64)
+    }
+}
+
+impl Scalar for i128 {
+    #[inline]
+    #[allow(clippy::cast_possible_truncation, reason = "Values beyond isize are saturated")]
+    fn index(&self) -> usize {
+        ((*self).clamp(isize::MIN as Self, isize::MAX as Self) as isize).index()
+    }
+
+    #[inline]
+    fn index_is_shared(&self) -> bool {
+        *self <= isize::MIN as Self || *self >= isize::MAX as Self
+    }
+
+    #[inline]
+    #[allow(clippy::cast_sign_loss, reason = "Only the bits matter")]
+    fn hash_code(&self) -> u64 {
+        (*self as u128).hash_code()
+    }
+}
+
+impl Scalar for char {
+    #[inline]
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl Scalar for bool {
+    #[inline]
+    fn index(&self) -> usize {
+        usize::from(*self)
+    }
+}
+
 #[cfg(target_pointer_width = "64")]
 impl_unsigned_scalar!(u8, u16, u32, u64, usize);
 #[cfg(target_pointer_width = "64")]
@@ -132,6 +203,36 @@ mod tests {
         assert_eq!(40_i64.index(), 0x8000_0000_0000_0000 + 40);
     }
 
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_wide_scalar() {
+        assert_eq!(40_u128.index(), 40);
+        assert_eq!(u128::MAX.index(), usize::MAX);
+        assert_eq!((1_u128 << 64).index(), usize::MAX);
+        assert!(!40_u128.index_is_shared());
+        assert!(u128::MAX.index_is_shared());
+        assert_eq!(40_u128.hash_code(), 40);
+        assert_ne!((1_u128 << 64).hash_code(), (1_u128 << 65).hash_code());
+
+        assert_eq!((-40_i128).index(), 0x8000_0000_0000_0000 - 40);
+        assert_eq!(40_i128.index(), 0x8000_0000_0000_0000 + 40);
+        assert_eq!(i128::MIN.index(), 0);
+        assert_eq!(i128::MAX.index(), usize::MAX);
+        assert!(!(-40_i128).index_is_shared());
+        assert!(i128::MIN.index_is_shared());
+        assert!(i128::MAX.index_is_shared());
+    }
+
+    #[test]
+    fn test_char_and_bool_scalar() {
+        assert_eq!('a'.index(), 97);
+        assert_eq!(char::MAX.index(), 0x10_FFFF);
+        assert_eq!(false.index(), 0);
+        assert_eq!(true.index(), 1);
+        assert!(!char::MAX.index_is_shared());
+        assert_eq!('a'.hash_code(), 97);
+    }
+
     #[test]
     fn test_unsigned_nz_scalar() {
         assert_eq!(NonZeroU8::new(5).unwrap().index(), 5);
@@ -0,0 +1,10 @@
+use crate::traits::Scalar;
+
+/// A scalar enum whose variants can all be enumerated.
+///
+/// This is implemented by `#[derive(Scalar)]` for enums, and lets a
+/// [`FzEnumMap`](crate::fz_maps::FzEnumMap) verify that it has an entry for every variant.
+pub trait ScalarEnum: Scalar + 'static {
+    /// All the variants of the enum, in declaration order.
+    const VARIANTS: &'static [Self];
+}
@@ -1,3 +1,4 @@
+use crate::sets::BitsetView;
 use crate::sets::decl_macros::set_iteration_trait_funcs;
 use core::hash::BuildHasher;
 
@@ -12,6 +13,15 @@ pub trait SetIteration<T>: IntoIterator<Item = T> {
     #[doc = include_str!("../doc_snippets/iter.md")]
     #[must_use]
     fn iter(&self) -> Self::Iterator<'_>;
+
+    /// Returns a view of the set's values when they are stored in a bitset.
+    ///
+    /// When both sets involved in a set operation return a view, the operation is computed
+    /// a word at a time rather than by looking up values one by one.
+    #[must_use]
+    fn bitset(&self) -> Option<BitsetView<'_, T>> {
+        None
+    }
 }
 
 #[cfg(feature = "std")]
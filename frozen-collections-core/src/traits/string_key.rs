@@ -0,0 +1,110 @@
+use crate::hashers::SliceKey;
+use crate::keys::AsciiCaseInsensitive;
+use crate::traits::Len;
+use alloc::borrow::Cow;
+use core::hash::Hash;
+use equivalent::Equivalent;
+
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
+/// The key of a [`FzStringMap`](crate::fz_maps::FzStringMap) or [`FzStringSet`](crate::fz_sets::FzStringSet),
+/// which decides how keys are compared.
+///
+/// `Box<str>` keys are compared as-is, while [`AsciiCaseInsensitive<Box<str>>`](AsciiCaseInsensitive) keys are
+/// compared and hashed without regard to the case of ASCII letters. Either way, the collections are looked
+/// up with plain strings.
+pub trait StringKey: SliceKey<Item = u8> + Ord + Hash + Len + Clone {
+    /// The form of a string which is compared and hashed like the keys.
+    type Query: ?Sized + SliceKey<Item = u8> + Eq + Hash + Len + Equivalent<Self>;
+
+    /// Creates a key from a string.
+    fn from_str(s: &str) -> Self;
+
+    /// Views a string as a query, without copying it.
+    fn as_query(s: &str) -> &Self::Query;
+
+    /// Returns the bytes of the key as they are hashed, for use when analyzing a set of keys.
+    fn hashed_bytes(&self) -> Cow<'_, [u8]>;
+}
+
+impl StringKey for Box<str> {
+    type Query = str;
+
+    fn from_str(s: &str) -> Self {
+        Self::from(s)
+    }
+
+    #[inline]
+    fn as_query(s: &str) -> &str {
+        s
+    }
+
+    fn hashed_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.as_bytes())
+    }
+}
+
+impl StringKey for AsciiCaseInsensitive<Box<str>> {
+    type Query = AsciiCaseInsensitive<str>;
+
+    fn from_str(s: &str) -> Self {
+        Self(Box::from(s))
+    }
+
+    #[inline]
+    fn as_query(s: &str) -> &AsciiCaseInsensitive<str> {
+        AsciiCaseInsensitive::from_ref(s)
+    }
+
+    fn hashed_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Owned(self.as_str().as_bytes().to_ascii_lowercase())
+    }
+}
+
+/// A string which can be turned into the key of a [`FzStringMap`](crate::fz_maps::FzStringMap) or
+/// [`FzStringSet`](crate::fz_sets::FzStringSet).
+///
+/// Plain strings become `Box<str>` keys, and strings wrapped in [`AsciiCaseInsensitive`] become
+/// `AsciiCaseInsensitive<Box<str>>` keys, so the type of the strings given when creating a collection
+/// decides how its keys are compared.
+pub trait IntoStringKey {
+    /// The key the string turns into.
+    type Key: StringKey;
+
+    /// Turns the string into a key.
+    fn into_string_key(self) -> Self::Key;
+}
+
+impl<T> IntoStringKey for T
+where
+    T: AsRef<str>,
+{
+    type Key = Box<str>;
+
+    fn into_string_key(self) -> Box<str> {
+        Box::from(self.as_ref())
+    }
+}
+
+impl<S> IntoStringKey for AsciiCaseInsensitive<S>
+where
+    S: AsRef<str>,
+{
+    type Key = AsciiCaseInsensitive<Box<str>>;
+
+    fn into_string_key(self) -> Self::Key {
+        AsciiCaseInsensitive(Box::from(self.as_str()))
+    }
+}
+
+impl<S> IntoStringKey for &AsciiCaseInsensitive<S>
+where
+    S: ?Sized + AsRef<str>,
+{
+    type Key = AsciiCaseInsensitive<Box<str>>;
+
+    fn into_string_key(self) -> Self::Key {
+        AsciiCaseInsensitive(Box::from(self.as_str()))
+    }
+}
@@ -1,7 +1,7 @@
 //! Simple bit vectors.
 
 #[cfg(not(feature = "std"))]
-use alloc::boxed::Box;
+use {alloc::boxed::Box, alloc::vec::Vec};
 
 pub struct BitVec {
     bits: Box<[u64]>,
@@ -31,6 +31,40 @@ impl BitVec {
 
         (self.bits[index / 64] & (1 << (index % 64))) != 0
     }
+
+    pub(crate) fn into_words(self) -> Box<[u64]> {
+        self.bits
+    }
+}
+
+/// Lays out a sorted list of scalar indices as a bitset.
+///
+/// The bitset starts at the multiple of 64 at or below the smallest index, so that the words of different
+/// bitsets line up. This returns the index of the first bit, the words of the bitset, and the number of
+/// indices which precede each word. There may be at most `u32::MAX` indices.
+pub fn scalar_bitset(indices: &[usize]) -> (usize, Box<[u64]>, Box<[u32]>) {
+    let Some(&min) = indices.first() else {
+        return (0, Box::new([]), Box::new([]));
+    };
+
+    let base = min - (min % 64);
+    let num_bits = indices[indices.len() - 1] - base + 1;
+
+    let mut bits = BitVec::with_capacity(num_bits);
+    bits.clear_all();
+    for index in indices {
+        bits.set(index - base);
+    }
+
+    let words = bits.into_words();
+    let mut ranks = Vec::with_capacity(words.len());
+    let mut rank = 0;
+    for word in &words {
+        ranks.push(rank);
+        rank += word.count_ones();
+    }
+
+    (base, words, ranks.into_boxed_slice())
 }
 
 #[cfg(test)]
@@ -56,6 +90,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_scalar_bitset() {
+        let (base, words, ranks) = scalar_bitset(&[65, 70, 130, 300]);
+        assert_eq!(64, base);
+        assert_eq!(4, words.len());
+        assert_eq!(0b10_0001 << 1, words[0]);
+        assert_eq!(1 << 2, words[1]);
+        assert_eq!(0, words[2]);
+        assert_eq!(1 << (300 - 256), words[3]);
+        assert_eq!([0, 2, 3, 3], *ranks);
+
+        let (base, words, ranks) = scalar_bitset(&[]);
+        assert_eq!(0, base);
+        assert!(words.is_empty());
+        assert!(ranks.is_empty());
+    }
+
     #[test]
     #[should_panic(expected = "Out of bounds")]
     fn get_panic() {
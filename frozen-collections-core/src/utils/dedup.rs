@@ -46,14 +46,14 @@ impl<T> DeduppedVec<T> {
 
     pub fn using_hash(mut entries: Vec<T>, hasher: impl Fn(&T) -> u64, eq: impl Fn(&T, &T) -> bool) -> Self {
         if entries.len() >= 2 {
-            let mut dupes = Vec::new();
+            let mut dupes = HashbrownSet::new();
             let mut keep = HashbrownTable::with_capacity(entries.len());
             for (index, value) in entries.iter().enumerate() {
                 let hash = hasher(value);
 
                 let r = keep.find_entry(hash, |other| eq(value, &entries[*other]));
                 if let Ok(entry) = r {
-                    dupes.push(*entry.get());
+                    _ = dupes.insert(*entry.get());
                     _ = entry.remove();
                 }
 
@@ -75,6 +75,19 @@ impl<T> DeduppedVec<T> {
         Self { inner: entries }
     }
 
+    /// Wraps entries which are already known to be free of duplicates.
+    /// Removes duplicates like [`Self::using_hash`], also returning the hash code of every remaining entry.
+    pub fn using_hash_with_codes(entries: Vec<T>, hasher: impl Fn(&T) -> u64, eq: impl Fn(&T, &T) -> bool) -> (Self, Vec<u64>) {
+        let entries: Vec<_> = entries.into_iter().map(|entry| (hasher(&entry), entry)).collect();
+        let entries = DeduppedVec::using_hash(entries, |x| x.0, |x, y| eq(&x.1, &y.1));
+        let (hash_codes, entries) = entries.inner.into_iter().unzip();
+        (Self { inner: entries }, hash_codes)
+    }
+
+    pub const fn from_unique(entries: Vec<T>) -> Self {
+        Self { inner: entries }
+    }
+
     pub fn into_boxed_slice(self) -> Box<[T]> {
         self.inner.into()
     }
@@ -140,6 +153,16 @@ impl<T> SortedAndDeduppedVec<T> {
         Self { inner: entries }
     }
 
+    /// Wraps entries which are already known to be sorted and free of duplicates.
+    pub fn from_sorted_unique(entries: Vec<T>, cmp: impl Fn(&T, &T) -> Ordering) -> Self {
+        debug_assert!(
+            entries.is_sorted_by(|x, y| cmp(x, y) == Ordering::Less),
+            "Entries must be sorted and unique"
+        );
+
+        Self { inner: entries }
+    }
+
     pub fn into_boxed_slice(self) -> Box<[T]> {
         self.inner.into()
     }
@@ -226,6 +249,14 @@ mod tests {
         assert_eq!(entries.inner, vec![(1, "one last")]);
     }
 
+    #[test]
+    fn test_dedup_using_hash_with_codes() {
+        let vec = vec![(1, "one"), (2, "two"), (1, "one last"), (3, "three")];
+        let (entries, hash_codes) = DeduppedVec::using_hash_with_codes(vec, |x| x.0 * 100, |x, y| x.0.eq(&y.0));
+        assert_eq!(entries.inner, vec![(2, "two"), (1, "one last"), (3, "three")]);
+        assert_eq!(hash_codes, vec![200, 100, 300]);
+    }
+
     #[test]
     fn test_find_duplicate_no_duplicates() {
         let vec = vec![1, 2, 3];
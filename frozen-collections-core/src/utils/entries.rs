@@ -0,0 +1,54 @@
+//! Transformation of the entries stored by frozen collections.
+
+use core::convert::Infallible;
+
+#[cfg(not(feature = "std"))]
+use {alloc::boxed::Box, alloc::vec::Vec};
+
+/// Transforms the value of every entry, leaving each entry at its position and stopping at the first error.
+pub fn try_map_entry_values<K, V, U, E>(entries: Box<[(K, V)]>, mut f: impl FnMut(&K, V) -> Result<U, E>) -> Result<Box<[(K, U)]>, E> {
+    entries
+        .into_iter()
+        .map(|(k, v)| {
+            let v = f(&k, v)?;
+            Ok((k, v))
+        })
+        .collect()
+}
+
+/// Unwraps the result of a transformation which can't fail.
+pub fn into_ok<T>(result: Result<T, Infallible>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(never) => match never {},
+    }
+}
+
+/// Evaluates the predicate for every entry, returning which entries to retain, or `None` when all of them are retained.
+pub fn retain_flags<'a, K, V>(entries: impl Iterator<Item = (&'a K, &'a V)>, mut f: impl FnMut(&K, &V) -> bool) -> Option<Vec<bool>>
+where
+    K: 'a,
+    V: 'a,
+{
+    let flags: Vec<bool> = entries.map(|(k, v)| f(k, v)).collect();
+    if flags.iter().all(|retain| *retain) { None } else { Some(flags) }
+}
+
+/// Keeps the entries whose flag is set.
+pub fn retain_flagged<T>(entries: impl IntoIterator<Item = T>, flags: Vec<bool>) -> Vec<T> {
+    entries
+        .into_iter()
+        .zip(flags)
+        .filter_map(|(entry, retain)| retain.then_some(entry))
+        .collect()
+}
+
+/// Returns a function assigning consecutive slots to the keys of a set, in the set's iteration order.
+pub fn assign_slots<K>() -> impl FnMut(&K, ()) -> usize {
+    let mut next = 0;
+    move |_, ()| {
+        let slot = next;
+        next += 1;
+        slot
+    }
+}
@@ -4,24 +4,13 @@
 //! This code is adapted and heavily modified from <https://github.com/main--/rust-eytzinger/blob/master/src/lib.rs>
 
 use core::cmp::Ordering;
+use core::ops::{Bound, Range, RangeBounds};
 
 /// Sorts the slice in-place using the Eytzinger layout.
 pub fn eytzinger_layout<T>(sorted_entries: &mut [T]) {
-    const fn get_eytzinger_index(original_index: usize, slice_len: usize) -> usize {
-        let ipk = (original_index + 2).next_power_of_two().trailing_zeros() as usize;
-        let li = original_index + 1 - (1 << (ipk - 1));
-        let zk = li * 2 + 1;
-        let last_power_of_two = (slice_len + 2).next_power_of_two() / 2;
-        let y = (last_power_of_two >> (ipk - 1)) * zk;
-        let kp = y >> 1;
-        let x = kp + last_power_of_two; // (1+k) * last_power_of_two
-        let x = x.saturating_sub(slice_len + 1);
-        y - x - 1
-    }
-
     let mut map = hashbrown::HashMap::new();
     for mut i in 0..sorted_entries.len() {
-        let mut target = get_eytzinger_index(i, sorted_entries.len());
+        let mut target = eytzinger_to_sorted_index(i, sorted_entries.len());
         if target < i {
             target = map.remove(&target).unwrap();
         }
@@ -39,28 +28,117 @@ pub fn eytzinger_layout<T>(sorted_entries: &mut [T]) {
     }
 }
 
+/// Returns the position in sorted order of the entry found at `index` in a slice laid out using the Eytzinger layout.
+#[must_use]
+pub const fn eytzinger_to_sorted_index(index: usize, slice_len: usize) -> usize {
+    let ipk = (index + 2).next_power_of_two().trailing_zeros() as usize;
+    let li = index + 1 - (1 << (ipk - 1));
+    let zk = li * 2 + 1;
+    let last_power_of_two = (slice_len + 2).next_power_of_two() / 2;
+    let y = (last_power_of_two >> (ipk - 1)) * zk;
+    let kp = y >> 1;
+    let x = kp + last_power_of_two; // (1+k) * last_power_of_two
+    let x = x.saturating_sub(slice_len + 1);
+    y - x - 1
+}
+
+/// Returns the index in a slice laid out using the Eytzinger layout of the entry found at position `sorted_index` in sorted order.
+///
+/// This is the inverse of [`eytzinger_to_sorted_index`].
+#[must_use]
+pub const fn sorted_to_eytzinger_index(sorted_index: usize, slice_len: usize) -> usize {
+    let last_power_of_two = (slice_len + 2).next_power_of_two() / 2;
+
+    // number of entries present in the last, partially filled, level of the tree
+    let num_leaves = slice_len + 1 - last_power_of_two;
+
+    // in-order position (1-based) of the entry within a perfect tree which has all of its leaves present
+    let y = if sorted_index < 2 * num_leaves {
+        sorted_index + 1
+    } else {
+        2 * (sorted_index + 1 - num_leaves)
+    };
+
+    let height = y.trailing_zeros();
+    let depth = last_power_of_two.trailing_zeros() - height;
+    let li = y >> (height + 1);
+    (1 << depth) - 1 + li
+}
+
+/// Returns the position in sorted order of the first entry for which the predicate returns `false`.
+///
+/// The slice must have been previously sorted with the `eytzinger_layout` method and must be partitioned
+/// according to the predicate, meaning all entries for which the predicate returns `true` come before all
+/// entries for which it returns `false` in sorted order.
+#[inline]
+pub fn eytzinger_partition_point<T>(data: &[T], pred: impl Fn(&T) -> bool) -> usize {
+    let mut i = 0;
+    let mut result = None;
+    while let Some(v) = data.get(i) {
+        if pred(v) {
+            i = 2 * i + 2;
+        } else {
+            result = Some(i);
+            i = 2 * i + 1;
+        }
+    }
+
+    result.map_or(data.len(), |index| eytzinger_to_sorted_index(index, data.len()))
+}
+
+/// Returns the span of positions in sorted order of the entries that fall within the given range.
+///
+/// The slice must have been previously sorted with the `eytzinger_layout` method. The `compare` function
+/// compares a bound of the range against an entry of the slice. An empty span is returned if the start
+/// of the range is greater than its end.
+pub fn eytzinger_range_by<T, Q, R>(data: &[T], range: &R, compare: impl Fn(&Q, &T) -> Ordering) -> Range<usize>
+where
+    Q: ?Sized,
+    R: RangeBounds<Q>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(key) => eytzinger_partition_point(data, |entry| compare(key, entry) == Ordering::Greater),
+        Bound::Excluded(key) => eytzinger_partition_point(data, |entry| compare(key, entry) != Ordering::Less),
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(key) => eytzinger_partition_point(data, |entry| compare(key, entry) != Ordering::Less),
+        Bound::Excluded(key) => eytzinger_partition_point(data, |entry| compare(key, entry) == Ordering::Greater),
+        Bound::Unbounded => data.len(),
+    };
+
+    start..end.max(start)
+}
+
 /// Searches for a given key in the slice.
 ///
 /// The slice must have been previously sorted with the `eytzinger` method.
+///
+/// If a matching entry is found, [`Ok`] is returned containing the index of the entry in the slice. Otherwise,
+/// [`Err`] is returned containing the index in the slice of the first entry greater than the key, or the length
+/// of the slice if there is no such entry. Both are indices into the slice as laid out, which can be turned into
+/// positions in sorted order with [`eytzinger_to_sorted_index`].
 #[inline]
-pub fn eytzinger_search_by<'a, T: 'a>(data: &'a [T], f: impl Fn(&'a T) -> Ordering) -> Option<usize> {
+pub fn eytzinger_search_by<'a, T: 'a>(data: &'a [T], f: impl Fn(&'a T) -> Ordering) -> Result<usize, usize> {
     let mut i = 0;
-    loop {
-        match data.get(i) {
-            Some(v) => {
-                let order = f(v);
-                if order == Ordering::Equal {
-                    return Some(i);
-                }
-
-                // Leverage the fact Ordering is defined as -1/0/1
-                let o = order as usize;
-                let o = (o >> 1) & 1;
-                i = 2 * i + 1 + o;
-            }
-            None => return None,
+    while let Some(v) = data.get(i) {
+        let order = f(v);
+        if order == Ordering::Equal {
+            return Ok(i);
         }
+
+        // Leverage the fact Ordering is defined as -1/0/1
+        let o = order as usize;
+        let o = (o >> 1) & 1;
+        i = 2 * i + 1 + o;
     }
+
+    // Strip the trailing right turns from the search path to recover the last
+    // node where the search went left, which is the first entry greater than the key.
+    let p = i + 1;
+    let j = p >> (1 + (!p).trailing_zeros());
+    if j == 0 { Err(data.len()) } else { Err(j - 1) }
 }
 
 /*
@@ -95,3 +173,91 @@ pub fn eytzinger_search_by<'a, T: 'a, F>(data: &'a [T], f: impl Fn(&'a T) -> Ord
     }
 }
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_conversions_round_trip() {
+        for len in 1..200 {
+            for sorted_index in 0..len {
+                let index = sorted_to_eytzinger_index(sorted_index, len);
+                assert!(index < len);
+                assert_eq!(sorted_index, eytzinger_to_sorted_index(index, len));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sorted_to_eytzinger_index_matches_layout() {
+        for len in 1..200 {
+            let mut entries: Vec<usize> = (0..len).collect();
+            eytzinger_layout(&mut entries);
+
+            for sorted_index in 0..len {
+                assert_eq!(sorted_index, entries[sorted_to_eytzinger_index(sorted_index, len)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_by() {
+        for len in 0..100 {
+            let mut entries: Vec<usize> = (0..len).map(|x| x * 2).collect();
+            eytzinger_layout(&mut entries);
+
+            for key in 0..=len * 2 {
+                let expected = (0..len).filter(|x| x * 2 < key).count();
+                match eytzinger_search_by(&entries, |x| x.cmp(&key)) {
+                    Ok(index) => {
+                        assert_eq!(key, entries[index]);
+                        assert_eq!(expected, eytzinger_to_sorted_index(index, len));
+                    }
+                    Err(index) => {
+                        assert!(key % 2 == 1 || key == len * 2);
+                        if index == len {
+                            assert_eq!(expected, len);
+                        } else {
+                            assert!(entries[index] > key);
+                            assert_eq!(expected, eytzinger_to_sorted_index(index, len));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_partition_point() {
+        for len in 0..100 {
+            let mut entries: Vec<usize> = (0..len).map(|x| x * 2).collect();
+            eytzinger_layout(&mut entries);
+
+            for key in 0..=len * 2 {
+                let expected = (0..len).filter(|x| x * 2 < key).count();
+                assert_eq!(expected, eytzinger_partition_point(&entries, |x| *x < key));
+            }
+        }
+    }
+
+    #[test]
+    fn test_range_by() {
+        let mut entries: Vec<usize> = (0..10).map(|x| x * 2).collect();
+        eytzinger_layout(&mut entries);
+
+        let compare = |key: &usize, entry: &usize| key.cmp(entry);
+        assert_eq!(0..10, eytzinger_range_by(&entries, &(..), compare));
+        assert_eq!(2..5, eytzinger_range_by(&entries, &(4..10), compare));
+        assert_eq!(2..6, eytzinger_range_by(&entries, &(4..=10), compare));
+        assert_eq!(3..6, eytzinger_range_by(&entries, &(5..=10), compare));
+        assert_eq!(
+            3..6,
+            eytzinger_range_by(&entries, &(Bound::Excluded(4), Bound::Included(10)), compare)
+        );
+        assert_eq!(0..3, eytzinger_range_by(&entries, &(..5), compare));
+        assert_eq!(7..10, eytzinger_range_by(&entries, &(13..), compare));
+        assert_eq!(10..10, eytzinger_range_by(&entries, &(100..), compare));
+        assert!(eytzinger_range_by(&entries, &(Bound::Included(10), Bound::Excluded(4)), compare).is_empty());
+    }
+}
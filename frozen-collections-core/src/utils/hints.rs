@@ -3,30 +3,44 @@
 #[cold]
 pub const fn cold() {}
 
-/*
-/// Prefetch the cache line where the given data lives.
-pub fn prefetch_for_read<T>(s: &[T], index: usize) {
-    let ptr = unsafe { s.as_ptr().add(index) as *const u64 };
-    prefetch_for_read_ptr(ptr);
-}
+/// The number of probes hashed and prefetched together by the batch lookup functions.
+pub const PROBE_BATCH: usize = 16;
 
 /// Prefetch the cache line where the given data lives.
-pub fn prefetch_for_read_ptr<T>(ptr: *const T) {
-    #[cfg(target_arch = "x86_64")]
-    unsafe {
-        core::arch::x86_64::_mm_prefetch::<{core::arch::x86_64::_MM_HINT_T0}>(ptr as *const i8);
-    }
-    #[cfg(target_arch = "x86")]
+///
+/// This is only a hint, so the pointer doesn't need to be valid for reads.
+#[inline]
+pub fn prefetch_for_read<T>(ptr: *const T) {
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse"))]
+    // SAFETY: SSE is statically enabled, and prefetching never faults
     unsafe {
-        core::arch::x86::_mm_prefetch::<{core::arch::x86::_MM_HINT_T0}>(ptr as *const i8);
+        core::arch::x86_64::_mm_prefetch::<{ core::arch::x86_64::_MM_HINT_T0 }>(ptr.cast());
     }
-    #[cfg(target_arch = "aarch64")]
+
+    #[cfg(all(target_arch = "x86", target_feature = "sse"))]
+    // SAFETY: SSE is statically enabled, and prefetching never faults
     unsafe {
-        core::arch::aarch64::_prefetch::<{core::arch::aarch64::_PREFETCH_READ}, {core::arch::aarch64::_PREFETCH_LOCALITY3}>(ptr as *const i8);
+        core::arch::x86::_mm_prefetch::<{ core::arch::x86::_MM_HINT_T0 }>(ptr.cast());
     }
-    #[cfg(not(any(target_arch = "x86_64", target_arch = "x86", target_arch = "aarch64")))]
+
+    #[cfg(not(all(any(target_arch = "x86_64", target_arch = "x86"), target_feature = "sse")))]
     {
-        // Do nothing.
+        // no stable prefetch intrinsic on this target
+        _ = ptr;
+    }
+}
+
+/// Hashes a number of probes in batches of [`PROBE_BATCH`], passing each batch to `f` along with
+/// the index of its first probe.
+#[inline]
+pub fn for_each_probe_batch(count: usize, hash_code: impl Fn(usize) -> u64, mut f: impl FnMut(usize, &[u64])) {
+    let mut hash_codes = [0; PROBE_BATCH];
+    for start in (0..count).step_by(PROBE_BATCH) {
+        let batch = &mut hash_codes[..PROBE_BATCH.min(count - start)];
+        for (i, h) in batch.iter_mut().enumerate() {
+            *h = hash_code(start + i);
+        }
+
+        f(start, batch);
     }
 }
-*/
@@ -0,0 +1,101 @@
+//! Merging of sorted sequences.
+
+use core::cmp::Ordering;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// The set operations which can be computed by merging sorted sequences.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SetOp {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+/// Merges two sorted sequences of unique values, keeping the values selected by the set operation.
+///
+/// The result is sorted and free of duplicates.
+pub fn merge_sorted<'a, T>(mut a: impl Iterator<Item = &'a T>, mut b: impl Iterator<Item = &'a T>, op: SetOp) -> Vec<T>
+where
+    T: Ord + Clone + 'a,
+{
+    let keep_a = matches!(op, SetOp::Union | SetOp::Difference | SetOp::SymmetricDifference);
+    let keep_b = matches!(op, SetOp::Union | SetOp::SymmetricDifference);
+    let keep_both = matches!(op, SetOp::Union | SetOp::Intersection);
+
+    let mut result = Vec::new();
+    let mut x = a.next();
+    let mut y = b.next();
+
+    loop {
+        match (x, y) {
+            (Some(vx), Some(vy)) => match vx.cmp(vy) {
+                Ordering::Less => {
+                    if keep_a {
+                        result.push(vx.clone());
+                    }
+                    x = a.next();
+                }
+                Ordering::Greater => {
+                    if keep_b {
+                        result.push(vy.clone());
+                    }
+                    y = b.next();
+                }
+                Ordering::Equal => {
+                    if keep_both {
+                        result.push(vx.clone());
+                    }
+                    x = a.next();
+                    y = b.next();
+                }
+            },
+
+            (Some(vx), None) => {
+                if keep_a {
+                    result.push(vx.clone());
+                    result.extend(a.cloned());
+                }
+                break;
+            }
+
+            (None, Some(vy)) => {
+                if keep_b {
+                    result.push(vy.clone());
+                    result.extend(b.cloned());
+                }
+                break;
+            }
+
+            (None, None) => break,
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_merge_sorted() {
+        let a = [1, 3, 5, 7];
+        let b = [2, 3, 6, 7, 8];
+
+        assert_eq!(vec![1, 2, 3, 5, 6, 7, 8], merge_sorted(a.iter(), b.iter(), SetOp::Union));
+        assert_eq!(vec![3, 7], merge_sorted(a.iter(), b.iter(), SetOp::Intersection));
+        assert_eq!(vec![1, 5], merge_sorted(a.iter(), b.iter(), SetOp::Difference));
+        assert_eq!(vec![2, 6, 8], merge_sorted(b.iter(), a.iter(), SetOp::Difference));
+        assert_eq!(vec![1, 2, 5, 6, 8], merge_sorted(a.iter(), b.iter(), SetOp::SymmetricDifference));
+
+        let empty: [i32; 0] = [];
+        assert_eq!(vec![1, 3, 5, 7], merge_sorted(a.iter(), empty.iter(), SetOp::Union));
+        assert_eq!(vec![1, 3, 5, 7], merge_sorted(empty.iter(), a.iter(), SetOp::SymmetricDifference));
+        assert!(merge_sorted(empty.iter(), a.iter(), SetOp::Difference).is_empty());
+        assert!(merge_sorted(a.iter(), empty.iter(), SetOp::Intersection).is_empty());
+    }
+}
@@ -2,10 +2,18 @@
 
 pub use bitvec::*;
 pub use dedup::*;
+pub use entries::*;
 pub use eytzinger::*;
 pub use hints::*;
+pub use merge::*;
+pub use simd::*;
+pub use sorted_order::*;
 
 mod bitvec;
 mod dedup;
+mod entries;
 mod eytzinger;
 mod hints;
+mod merge;
+mod simd;
+mod sorted_order;
@@ -0,0 +1,132 @@
+//! Matches one-byte tags against groups of tags in parallel.
+//!
+//! SSE2 is used on x86 targets, and every other target falls back to SWAR (SIMD within a register)
+//! processing of 64-bit words, which is portable and still avoids per-byte branches.
+
+/// The number of tags examined at once.
+pub const TAG_GROUP_WIDTH: usize = 16;
+
+/// Derives a one-byte tag from a hash code.
+#[inline]
+#[must_use]
+pub const fn hash_tag(hash_code: u64) -> u8 {
+    // the multiply folds the low bits of the hash code into the top byte, which matters for
+    // hashers such as the scalar hasher that only produce small values
+    (hash_code.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 56) as u8
+}
+
+/// Returns the number of tag slots needed to hold a given number of entries.
+#[must_use]
+pub const fn tag_slots(len: usize) -> usize {
+    len.div_ceil(TAG_GROUP_WIDTH) * TAG_GROUP_WIDTH
+}
+
+/// Returns a bit mask with bit `i` set whenever `group[i] == tag`.
+#[inline]
+#[must_use]
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+pub fn match_tag_group(group: &[u8; TAG_GROUP_WIDTH], tag: u8) -> u16 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{_mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_set1_epi8};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{_mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_set1_epi8};
+
+    // SAFETY: SSE2 is statically enabled, and the group is exactly 16 bytes long, which is what an unaligned load reads
+    let group = unsafe { _mm_loadu_si128(group.as_ptr().cast()) };
+
+    #[expect(clippy::cast_possible_wrap, reason = "Only the bit pattern matters")]
+    // SAFETY: SSE2 is statically enabled
+    let needle = unsafe { _mm_set1_epi8(tag as i8) };
+
+    // SAFETY: SSE2 is statically enabled
+    let equal = unsafe { _mm_cmpeq_epi8(group, needle) };
+
+    // SAFETY: SSE2 is statically enabled
+    let matches = unsafe { _mm_movemask_epi8(equal) };
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "Only the low 16 bits are ever set"
+    )]
+    let matches = matches as u16;
+    matches
+}
+
+/// Returns a bit mask with bit `i` set whenever `group[i] == tag`.
+#[inline]
+#[must_use]
+#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2")))]
+pub fn match_tag_group(group: &[u8; TAG_GROUP_WIDTH], tag: u8) -> u16 {
+    let (lo, hi) = group.split_at(TAG_GROUP_WIDTH / 2);
+    let lo = u64::from_le_bytes(lo.try_into().unwrap_or_default());
+    let hi = u64::from_le_bytes(hi.try_into().unwrap_or_default());
+
+    swar_match(lo, tag) | (swar_match(hi, tag) << 8)
+}
+
+/// Returns a bit mask with bit `i` set whenever byte `i` of the word equals the tag.
+#[inline]
+#[cfg_attr(
+    all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"),
+    allow(dead_code, reason = "Fallback path")
+)]
+const fn swar_match(word: u64, tag: u8) -> u16 {
+    const LOW_SEVEN_BITS: u64 = 0x7F7F_7F7F_7F7F_7F7F;
+    const GATHER: u64 = 0x0102_0408_1020_4080;
+
+    // bytes equal to the tag become zero
+    let x = word ^ (tag as u64 * 0x0101_0101_0101_0101);
+
+    // set the high bit of every zero byte, without any carries leaking between bytes
+    let zeros = !(((x & LOW_SEVEN_BITS) + LOW_SEVEN_BITS) | x | LOW_SEVEN_BITS);
+
+    // gather the high bit of every byte into the top byte of the word
+    ((zeros >> 7).wrapping_mul(GATHER) >> 56) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference_match(group: &[u8; TAG_GROUP_WIDTH], tag: u8) -> u16 {
+        let mut result = 0;
+        for (i, t) in group.iter().enumerate() {
+            if *t == tag {
+                result |= 1 << i;
+            }
+        }
+
+        result
+    }
+
+    #[test]
+    fn match_tag_group_finds_all_matches() {
+        let group: [u8; TAG_GROUP_WIDTH] = [0, 1, 2, 0x80, 0xFF, 1, 0x7F, 0x81, 0, 0, 3, 1, 0xFE, 0x80, 1, 0];
+        for tag in 0..=u8::MAX {
+            assert_eq!(reference_match(&group, tag), match_tag_group(&group, tag), "tag {tag}");
+        }
+    }
+
+    #[test]
+    fn swar_match_finds_all_matches() {
+        let group: [u8; TAG_GROUP_WIDTH] = [0, 1, 2, 0x80, 0xFF, 1, 0x7F, 0x81, 0, 0, 3, 1, 0xFE, 0x80, 1, 0];
+        let lo = u64::from_le_bytes([0, 1, 2, 0x80, 0xFF, 1, 0x7F, 0x81]);
+        let hi = u64::from_le_bytes([0, 0, 3, 1, 0xFE, 0x80, 1, 0]);
+        for tag in 0..=u8::MAX {
+            assert_eq!(
+                reference_match(&group, tag),
+                swar_match(lo, tag) | (swar_match(hi, tag) << 8),
+                "tag {tag}"
+            );
+        }
+    }
+
+    #[test]
+    fn tag_slots_rounds_up_to_groups() {
+        assert_eq!(0, tag_slots(0));
+        assert_eq!(16, tag_slots(1));
+        assert_eq!(16, tag_slots(16));
+        assert_eq!(32, tag_slots(17));
+    }
+}
@@ -0,0 +1,133 @@
+//! Order-based lookups over entries which are stored out of order.
+
+use crate::diagnostics::HeapBytes;
+use core::cmp::Ordering;
+use equivalent::Comparable;
+
+#[cfg(not(feature = "std"))]
+use {alloc::boxed::Box, alloc::vec::Vec};
+
+/// The positions of a collection's entries, sorted by key.
+///
+/// Hashed collections store their entries in whatever order suits their hash table. Capturing the
+/// sorted order once lets them answer order-based lookups with a binary search rather than a scan.
+#[derive(Clone, Debug, Default)]
+pub struct SortedOrder {
+    positions: Box<[usize]>,
+}
+
+impl SortedOrder {
+    /// Captures the sorted order of the given entries, which must have unique keys.
+    pub fn new<K, V>(entries: &[(K, V)]) -> Self
+    where
+        K: Ord,
+    {
+        let mut positions: Vec<usize> = (0..entries.len()).collect();
+        positions.sort_unstable_by(|x, y| entries[*x].0.cmp(&entries[*y].0));
+
+        Self {
+            positions: positions.into_boxed_slice(),
+        }
+    }
+
+    /// Returns the entry at the given position in sorted order.
+    pub fn select<'a, K, V>(&self, entries: &'a [(K, V)], sorted_index: usize) -> Option<(&'a K, &'a V)> {
+        let entry = &entries[*self.positions.get(sorted_index)?];
+        Some((&entry.0, &entry.1))
+    }
+
+    /// Returns the position in sorted order of the entry with the given key.
+    pub fn rank<K, V, Q>(&self, entries: &[(K, V)], key: &Q) -> Option<usize>
+    where
+        Q: ?Sized + Comparable<K>,
+    {
+        let index = self.count_less(entries, key);
+        let position = *self.positions.get(index)?;
+        (key.compare(&entries[position].0) == Ordering::Equal).then_some(index)
+    }
+
+    /// Returns the entry with the largest key less than or equal to the given key.
+    pub fn floor<'a, K, V, Q>(&self, entries: &'a [(K, V)], key: &Q) -> Option<(&'a K, &'a V)>
+    where
+        Q: ?Sized + Comparable<K>,
+    {
+        self.select(entries, self.count_less_or_equal(entries, key).checked_sub(1)?)
+    }
+
+    /// Returns the entry with the smallest key greater than or equal to the given key.
+    pub fn ceiling<'a, K, V, Q>(&self, entries: &'a [(K, V)], key: &Q) -> Option<(&'a K, &'a V)>
+    where
+        Q: ?Sized + Comparable<K>,
+    {
+        self.select(entries, self.count_less(entries, key))
+    }
+
+    /// Returns the entry with the largest key strictly less than the given key.
+    pub fn predecessor<'a, K, V, Q>(&self, entries: &'a [(K, V)], key: &Q) -> Option<(&'a K, &'a V)>
+    where
+        Q: ?Sized + Comparable<K>,
+    {
+        self.select(entries, self.count_less(entries, key).checked_sub(1)?)
+    }
+
+    /// Returns the entry with the smallest key strictly greater than the given key.
+    pub fn successor<'a, K, V, Q>(&self, entries: &'a [(K, V)], key: &Q) -> Option<(&'a K, &'a V)>
+    where
+        Q: ?Sized + Comparable<K>,
+    {
+        self.select(entries, self.count_less_or_equal(entries, key))
+    }
+
+    fn count_less<K, V, Q>(&self, entries: &[(K, V)], key: &Q) -> usize
+    where
+        Q: ?Sized + Comparable<K>,
+    {
+        self.positions
+            .partition_point(|position| key.compare(&entries[*position].0) == Ordering::Greater)
+    }
+
+    fn count_less_or_equal<K, V, Q>(&self, entries: &[(K, V)], key: &Q) -> usize
+    where
+        Q: ?Sized + Comparable<K>,
+    {
+        self.positions
+            .partition_point(|position| key.compare(&entries[*position].0) != Ordering::Less)
+    }
+}
+
+impl HeapBytes for SortedOrder {
+    fn heap_bytes(&self) -> usize {
+        self.positions.heap_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_order() {
+        let entries = [(30, 'c'), (10, 'a'), (50, 'e'), (20, 'b'), (40, 'd')];
+        let order = SortedOrder::new(&entries);
+
+        for (index, expected) in [10, 20, 30, 40, 50].iter().enumerate() {
+            assert_eq!(Some(expected), order.select(&entries, index).map(|(k, _)| k));
+            assert_eq!(Some(index), order.rank(&entries, expected));
+        }
+
+        assert_eq!(None, order.select(&entries, 5));
+        assert_eq!(None, order.rank(&entries, &25));
+        assert_eq!(None, order.rank(&entries, &60));
+
+        assert_eq!(Some((&20, &'b')), order.floor(&entries, &25));
+        assert_eq!(Some((&20, &'b')), order.floor(&entries, &20));
+        assert_eq!(None, order.floor(&entries, &5));
+        assert_eq!(Some((&30, &'c')), order.ceiling(&entries, &25));
+        assert_eq!(Some((&30, &'c')), order.ceiling(&entries, &30));
+        assert_eq!(None, order.ceiling(&entries, &55));
+        assert_eq!(Some((&20, &'b')), order.predecessor(&entries, &30));
+        assert_eq!(None, order.predecessor(&entries, &10));
+        assert_eq!(Some((&40, &'d')), order.successor(&entries, &30));
+        assert_eq!(None, order.successor(&entries, &50));
+    }
+}
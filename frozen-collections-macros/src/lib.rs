@@ -10,8 +10,9 @@
 //! </div>
 
 use frozen_collections_core::macros::{
-    derive_scalar_macro, fz_hash_map_macro, fz_hash_set_macro, fz_ordered_map_macro, fz_ordered_set_macro, fz_scalar_map_macro,
-    fz_scalar_set_macro, fz_string_map_macro, fz_string_set_macro,
+    derive_scalar_macro, fz_bytes_map_macro, fz_bytes_set_macro, fz_enum_map_macro, fz_hash_map_macro, fz_hash_set_macro,
+    fz_ordered_map_macro, fz_ordered_set_macro, fz_prefix_map_macro, fz_scalar_map_macro, fz_scalar_set_macro, fz_string_map_macro,
+    fz_string_set_macro,
 };
 use proc_macro::TokenStream;
 use proc_macro_error2::proc_macro_error;
@@ -64,6 +65,38 @@ pub fn fz_string_set(item: TokenStream) -> TokenStream {
         .into()
 }
 
+#[proc_macro]
+#[proc_macro_error]
+pub fn fz_bytes_map(item: TokenStream) -> TokenStream {
+    fz_bytes_map_macro(item.into())
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn fz_bytes_set(item: TokenStream) -> TokenStream {
+    fz_bytes_set_macro(item.into())
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn fz_prefix_map(item: TokenStream) -> TokenStream {
+    fz_prefix_map_macro(item.into())
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn fz_enum_map(item: TokenStream) -> TokenStream {
+    fz_enum_map_macro(item.into())
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
 #[proc_macro]
 #[proc_macro_error]
 pub fn fz_scalar_map(item: TokenStream) -> TokenStream {
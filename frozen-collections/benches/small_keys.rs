@@ -0,0 +1,13 @@
+#![expect(missing_docs, reason = "Benchmark")]
+
+use core::hint::black_box;
+use core::ops::Add;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use frozen_collections::hashers::BridgeHasher;
+use frozen_collections::{FzScalarSet, FzStringSet, fz_scalar_set, fz_string_set};
+
+include!(concat!(env!("OUT_DIR"), "/small_scalar.rs"));
+include!(concat!(env!("OUT_DIR"), "/small_string.rs"));
+
+criterion_group!(benches, small_scalar, small_string,);
+criterion_main!(benches);
@@ -13,6 +13,9 @@ const MEDIUM: usize = 16;
 const LARGE: usize = 256;
 const HUGE: usize = 1000;
 
+// sizes in the range where SIMD tag probing is used
+const SMALL_SIZES: [usize; 4] = [8, 16, 32, 64];
+
 fn emit_benchmark_preamble(name: &str) -> BufWriter<File> {
     let out_dir = env::var_os("OUT_DIR").unwrap();
     let dest_path = Path::new(&out_dir).join(format!("{name}.rs"));
@@ -52,6 +55,91 @@ fn emit_loop(file: &mut BufWriter<File>, name: &str) {
     writeln!(file, "    }});").unwrap();
 }
 
+fn emit_map_loop(file: &mut BufWriter<File>, name: &str) {
+    writeln!(
+        file,
+        "    group.bench_with_input(BenchmarkId::new(\"{name}\", size), &size, |b, _| {{"
+    )
+    .unwrap();
+    writeln!(file, "        b.iter(|| {{").unwrap();
+    writeln!(file, "            for key in &probe {{").unwrap();
+    writeln!(file, "                _ = black_box(m.contains_key(key));").unwrap();
+    writeln!(file, "            }}").unwrap();
+    writeln!(file, "        }});").unwrap();
+    writeln!(file, "    }});").unwrap();
+}
+
+fn emit_small_suite(file: &mut BufWriter<File>) {
+    writeln!(file, "    let map_input: Vec<_> = input.iter().map(|x| (*x, ())).collect();").unwrap();
+
+    writeln!(
+        file,
+        "    let m = frozen_collections::maps::HashMap::<_, _>::with_hasher(map_input.clone(), BridgeHasher::default()).unwrap();"
+    )
+    .unwrap();
+    emit_map_loop(file, "HashMap");
+
+    writeln!(file, "    let m = frozen_collections::maps::ScanMap::new(map_input.clone());").unwrap();
+    emit_map_loop(file, "ScanMap");
+
+    writeln!(
+        file,
+        "    let m = frozen_collections::maps::SimdProbeMap::<_, _>::with_hasher(map_input, BridgeHasher::default());"
+    )
+    .unwrap();
+    emit_map_loop(file, "SimdProbeMap");
+}
+
+fn emit_small_scalar_suite(file: &mut BufWriter<File>, size: usize, literal_producer: impl Fn(&mut BufWriter<File>, usize)) {
+    writeln!(file, "    let frozen = fz_scalar_set!({{").unwrap();
+    literal_producer(file, size);
+    writeln!(file, "    }});").unwrap();
+
+    writeln!(file, "    let input: Vec<i32> = frozen.clone().into_iter().collect();").unwrap();
+    writeln!(file, "    let size = input.len();").unwrap();
+    writeln!(file, "    let mut probe = Vec::new();").unwrap();
+    writeln!(file, "    for i in &input {{").unwrap();
+    writeln!(file, "        probe.push(*i);").unwrap();
+    writeln!(file, "        probe.push(-(*i));").unwrap();
+    writeln!(file, "    }}").unwrap();
+
+    emit_small_suite(file);
+
+    writeln!(file, "    let s = FzScalarSet::new(input);").unwrap();
+    emit_loop(file, "FzScalarSet");
+
+    writeln!(file, "    let s = frozen;").unwrap();
+    emit_loop(file, "fz_scalar_set");
+}
+
+fn emit_small_string_suite(file: &mut BufWriter<File>, size: usize, literal_producer: impl Fn(&mut BufWriter<File>, usize)) {
+    writeln!(file, "    let frozen = fz_string_set!({{").unwrap();
+    literal_producer(file, size);
+    writeln!(file, "    }});").unwrap();
+
+    writeln!(file, "    let input: Vec<&str> = frozen.clone().into_iter().collect();").unwrap();
+    writeln!(file, "    let size = input.len();").unwrap();
+    writeln!(file, "    let mut probe = Vec::new();").unwrap();
+    writeln!(file, "    for s in &input {{").unwrap();
+    writeln!(file, "        probe.push((*s).to_string());").unwrap();
+    writeln!(file, "        probe.push((*s).to_string().add(\"Hello\"));").unwrap();
+    writeln!(file, "    }}").unwrap();
+
+    writeln!(file, "    let mut tmp: Vec<&str> = Vec::new();").unwrap();
+    writeln!(file, "    for x in &probe {{").unwrap();
+    writeln!(file, "        tmp.push(x.as_str());").unwrap();
+    writeln!(file, "    }}").unwrap();
+    writeln!(file, "    let probe = tmp;").unwrap();
+
+    emit_small_suite(file);
+
+    writeln!(file, "    let s = FzStringSet::new(input);").unwrap();
+    emit_loop(file, "FzStringSet");
+
+    writeln!(file, "    let s = frozen;").unwrap();
+    emit_loop(file, "fz_string_set");
+}
+
 fn emit_scalar_suite(file: &mut BufWriter<File>, size: usize, literal_producer: impl Fn(&mut BufWriter<File>, usize)) {
     writeln!(file, "    let frozen = fz_scalar_set!({{").unwrap();
     literal_producer(file, size);
@@ -374,6 +462,52 @@ fn emit_ordered_benchmark() {
     emit_benchmark_postamble(file);
 }
 
+fn emit_small_scalar_benchmark() {
+    fn random_producer(file: &mut BufWriter<File>, size: usize) {
+        let mut rng = ChaChaRng::seed_from_u64(0x1234_5678);
+
+        for _ in 0..size {
+            let x: i32 = rng.random();
+            writeln!(file, "        {x},").unwrap();
+        }
+    }
+
+    let mut file = emit_benchmark_preamble("small_scalar");
+
+    for size in SMALL_SIZES {
+        emit_small_scalar_suite(&mut file, size, random_producer);
+    }
+
+    emit_benchmark_postamble(file);
+}
+
+fn emit_small_string_benchmark() {
+    fn random_producer(file: &mut BufWriter<File>, size: usize) {
+        let mut rng = ChaChaRng::seed_from_u64(0x1234_5678);
+
+        for _ in 0..size {
+            let len: u32 = rng.random();
+            let len = (len % 10) + 5;
+            let mut s = String::new();
+            for _ in 0..len {
+                let x: u8 = rng.random();
+                let x = (x % 26) + 97;
+                s.push(x as char);
+            }
+
+            writeln!(file, "        \"{s}\",").unwrap();
+        }
+    }
+
+    let mut file = emit_benchmark_preamble("small_string");
+
+    for size in SMALL_SIZES {
+        emit_small_string_suite(&mut file, size, random_producer);
+    }
+
+    emit_benchmark_postamble(file);
+}
+
 fn main() {
     emit_dense_scalar_benchmark();
     emit_sparse_scalar_benchmark();
@@ -382,6 +516,8 @@ fn main() {
     emit_random_string_benchmark();
     emit_ordered_benchmark();
     emit_hashed_benchmark();
+    emit_small_scalar_benchmark();
+    emit_small_string_benchmark();
 
     println!("cargo::rerun-if-changed=build.rs");
 }
@@ -27,9 +27,9 @@
 //! # Handling Compile-Time Data
 //!
 //! If you know the keys and values that will be in your collection at compile time, you can use
-//! one of eight macros to create frozen collections: [`fz_hash_map!`], [`fz_ordered_map!`],
-//! [`fz_scalar_map!`], [`fz_string_map!`], [`fz_hash_set!`], [`fz_ordered_set!`],
-//! [`fz_scalar_set!`], or [`fz_string_set!`]. These macros analyze the data you provide
+//! one of ten macros to create frozen collections: [`fz_hash_map!`], [`fz_ordered_map!`],
+//! [`fz_scalar_map!`], [`fz_string_map!`], [`fz_bytes_map!`], [`fz_hash_set!`], [`fz_ordered_set!`],
+//! [`fz_scalar_set!`], [`fz_string_set!`], or [`fz_bytes_set!`]. These macros analyze the data you provide
 //! and return a custom implementation type optimized for the data. All the
 //! possible types implement the [`Map`] or [`Set`] traits.
 //!
@@ -124,6 +124,171 @@
 //! }
 //! ```
 //!
+//! The long form also accepts an `#[analysis_report]` attribute, which attaches a report to the
+//! generated type alias as documentation. The report names the implementation type selected by the
+//! macro, describes its layout, and explains why it was chosen, so hovering over the alias in an
+//! IDE shows how lookups will be performed:
+//!
+//! ```rust
+//! use frozen_collections::*;
+//!
+//! fz_string_map!(#[analysis_report] static MAP: MyReportedMapType<&'static str, i32>, {
+//!     "Alice": 1,
+//!     "Bob": 2,
+//!     "Sandy": 3,
+//!     "Tom": 4,
+//! });
+//! ```
+//!
+//! ## Ignoring Case
+//!
+//! The [`fz_string_map`] and [`fz_string_set`] macros accept an `#[ignore_ascii_case]` attribute, in
+//! both forms, which makes the collection compare and hash its keys without regard to the case of
+//! ASCII letters. The keys are wrapped in [`AsciiCaseInsensitive`], while lookups are done with plain
+//! strings. Keys which only differ by case are considered duplicates.
+//!
+//! ```rust
+//! use frozen_collections::*;
+//!
+//! let headers = fz_string_set!(#[ignore_ascii_case] {
+//!     "Accept",
+//!     "Content-Length",
+//!     "Content-Type",
+//!     "Host",
+//! });
+//!
+//! assert!(headers.contains("content-type"));
+//! assert!(headers.contains("HOST"));
+//! ```
+//!
+//! ## Prefix Queries
+//!
+//! The [`fz_prefix_map`] macro creates a [`FzPrefixMap`], whose string keys are kept in sorted order
+//! so that, on top of exact lookups, it can find the longest key which is a prefix of some input and
+//! list all the keys which start with a given prefix. This suits routing tables and command dispatch.
+//!
+//! ```rust
+//! use frozen_collections::*;
+//!
+//! let routes = fz_prefix_map!({
+//!     "/": "home",
+//!     "/api": "api",
+//!     "/api/users": "users",
+//! });
+//!
+//! assert_eq!(Some((&"/api/users", &"users")), routes.longest_prefix_match("/api/users/42"));
+//! assert_eq!(2, routes.keys_with_prefix("/api").count());
+//! ```
+//!
+//! ## Exhaustive Enum Maps
+//!
+//! The [`fz_enum_map`] macro creates a [`FzEnumMap`], which has an entry for every variant of an enum
+//! whose `Scalar` trait is derived. Leaving out a variant is a compile-time error which lists the
+//! missing variants, and since every key is present, lookups return values rather than options.
+//!
+//! ```rust
+//! use frozen_collections::*;
+//!
+//! #[derive(Scalar, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+//! enum Level {
+//!     Debug,
+//!     Info,
+//!     Error,
+//! }
+//!
+//! let prefixes = fz_enum_map!({
+//!     Level::Debug: "[d]",
+//!     Level::Info: "[i]",
+//!     Level::Error: "[e]",
+//! });
+//!
+//! assert_eq!(&"[i]", prefixes.get(&Level::Info));
+//! assert_eq!("[e]", prefixes[&Level::Error]);
+//! ```
+//!
+//! ## Loading Entries from a File
+//!
+//! Instead of listing the entries of a collection in the macro invocation, both forms
+//! can read them from a JSON, TOML, or CSV file when the macro is expanded. This requires
+//! the `include` feature:
+//!
+//! ```rust,ignore
+//! use frozen_collections::*;
+//!
+//! fz_string_map!(static COUNTRIES: Countries<&'static str, &'static str>, include "data/countries.json");
+//!
+//! let mime_types = fz_string_set!(include "data/mime_types.txt" as csv);
+//! ```
+//!
+//! The path is relative to the directory holding your crate's `Cargo.toml` file, and the format
+//! is determined from the file's extension unless given explicitly with `as json`, `as toml`,
+//! or `as csv`. Your crate is rebuilt whenever the file changes.
+//!
+//! - A JSON file holds either an object or an array. For maps, arrays contain `[key, value]` pairs, while
+//!   for sets they contain the keys. The keys of an object are used as the keys of a set.
+//! - A TOML file holds a table whose keys are used as the collection's keys.
+//! - A CSV file has no header and holds a key and a value on each line, or just the key for sets.
+//!   Values which look like numbers or booleans are treated as such, while other values are strings.
+//!
+//! Keys and values must be strings, numbers, or booleans, and each key may only appear once in a file.
+//! Integer values are written as floating-point numbers when the value type is `f32` or `f64`, or when
+//! another value in the file is a floating-point number, so `1` and `2.5` both become `f64` values.
+//!
+//! ## Reproducible Output
+//!
+//! The hashed collections generated by the macros use a seed picked at random when the crate is
+//! built, so their layout and iteration order, as well as the generated code, change from one build
+//! to the next. To make builds reproducible, pin the seed with a `#[seed = N]` attribute, in either
+//! form, or for every macro invocation at once with the `FROZEN_COLLECTIONS_SEED` environment variable,
+//! which holds a decimal number or a hexadecimal one prefixed with `0x`. The attribute takes precedence
+//! over the environment variable. Collections whose keys aren't literals are laid out when they're created
+//! at runtime, and normally use the randomly seeded `DefaultBuildHasher`. Once the seed is pinned, they use
+//! a `FixedState` hasher with that seed instead, and their type names it as their `BuildHasher`.
+//!
+//! ```rust
+//! use frozen_collections::*;
+//!
+//! let fruits = fz_string_set!(#[seed = 42] {
+//!     "apple",
+//!     "banana",
+//!     "cherry",
+//! });
+//!
+//! assert!(fruits.contains("banana"));
+//! ```
+//!
+//! Cargo doesn't know the macros read `FROZEN_COLLECTIONS_SEED`, so crates which were already built
+//! must be rebuilt after changing it. Build scripts can pin the seed with
+//! [`CollectionEmitter::seed`](emit::CollectionEmitter::seed).
+//!
+//! ## Custom Hashers
+//!
+//! The long forms of `fz_hash_map!`, `fz_hash_set!`, `fz_string_map!`, and `fz_string_set!` accept a
+//! `#[hasher = H]` attribute, where `H` is the path of a type implementing `BuildHasher` and `Default`.
+//! The generated collection is then a `maps::HashMap` or `sets::HashSet` whose hasher is
+//! `hashers::BridgeHasher<H>`, or, with `#[ignore_ascii_case]`, an `FzStringMap` or `FzStringSet` of
+//! `AsciiCaseInsensitive` keys parameterized by `H`. Since the hasher can't run while the code is being
+//! generated, the collection's layout is computed when it's created at runtime, and static instances are
+//! initialized lazily. `H` is created with `Default`, so `#[hasher]` can't be combined with `#[seed]`.
+//!
+//! ```rust
+//! use frozen_collections::*;
+//! use std::collections::hash_map::DefaultHasher;
+//! use std::hash::BuildHasherDefault;
+//!
+//! type SipHasher = BuildHasherDefault<DefaultHasher>;
+//!
+//! fz_hash_map!(#[hasher = SipHasher] static CODES: Codes<&'static str, i32>, {
+//!     "apple": 1,
+//!     "banana": 2,
+//!     "cherry": 3,
+//! });
+//!
+//! assert_eq!(Some(&2), CODES.get("banana"));
+//! ```
+//!
+//! Build scripts can select a hasher with [`CollectionEmitter::hasher`](emit::CollectionEmitter::hasher).
+//!
 //! # Using in a Build Script
 //!
 //! You can use the [`CollectionEmitter`](emit::CollectionEmitter) struct to initialize a frozen collection from a build
@@ -136,9 +301,11 @@
 //!
 //! If you don't know the exact keys and values that will be in your collection at compile time,
 //! you use the dedicated map and collection types to hold your data: [`FzHashMap`], [`FzOrderedMap`], [`FzScalarMap`],
-//! [`FzStringMap`], [`FzHashSet`], [`FzOrderedSet`], [`FzScalarSet`], or [`FzStringSet`]. These
-//! types analyze the data you provide at runtime and determine the best strategy to handle your
-//! data dynamically.
+//! [`FzStringMap`], [`FzHashSet`], [`FzOrderedSet`], [`FzScalarSet`], or [`FzStringSet`]. String
+//! collections created with keys wrapped in [`AsciiCaseInsensitive`] ignore the ASCII case of their
+//! keys, [`FzBytesMap`] and [`FzBytesSet`] hold byte slice keys, and [`FzPrefixMap`]
+//! supports prefix queries. These types analyze the data you provide at runtime and determine the
+//! best strategy to handle your data dynamically.
 //!
 //! ```rust
 //! use frozen_collections::*;
@@ -156,6 +323,10 @@
 //! Note that in general, if possible, it's more efficient to use the macros to create your frozen
 //! collection instances.
 //!
+//! When several sets of values are associated with the same keys, a [`KeyIndex`](columns::KeyIndex)
+//! created from a frozen set maps each key to a dense slot, and any number of [`Column`](columns::Column)s
+//! then hold the values for these slots, so the keys are only analyzed and stored once.
+//!
 //! # Traits
 //!
 //! The maps produced by this crate implement the following traits:
@@ -193,7 +364,7 @@
 //!
 //! The available implementation strategies are:
 //!
-//! - **Scalar as Hash**. When the keys are of an integer or enum type, this uses the keys themselves
+//! - **Scalar as Hash**. When the keys are of an integer, `char`, `bool`, or enum type, this uses the keys themselves
 //!   as hash codes, avoiding the overhead of hashing.
 //!
 //! - **Length as Hash**. When the keys are of a string type, the lengths of the keys
@@ -205,6 +376,10 @@
 //! - **Sparse Scalar Lookup**. When the keys represent a sparse range of integer or enum values,
 //!   lookups use a sparse array instead of hashing.
 //!
+//! - **Bitset Scalar Lookup**. When the values of a set represent a sparse range of integer or enum values,
+//!   they are tracked in a bitset which takes a fraction of the space of a sparse array. Set operations
+//!   between two such sets are computed a word at a time.
+//!
 //! - **Left-Hand Substring Hashing**. When the keys are of a string type, this uses sub-slices of
 //!   the keys for hashing, reducing the overhead of hashing.
 //!
@@ -228,6 +403,20 @@
 //! - **Eytzinger Search**. For larger collections where the keys implement the [`Ord`] trait,
 //!   a cache-friendly Eytzinger search is used.
 //!
+//! Every collection has a `diagnostics` method which reports the strategy that was picked along
+//! with statistics such as the number of hash collisions, which helps when tuning performance:
+//!
+//! ```rust
+//! use frozen_collections::FzStringMap;
+//! use frozen_collections::diagnostics::Strategy;
+//!
+//! let map = FzStringMap::new(vec![("Alice", 1), ("Bob", 2), ("Sandy", 3), ("Tom", 4)]);
+//! let diagnostics = map.diagnostics();
+//!
+//! assert_eq!(diagnostics.len, 4);
+//! println!("{:?} with {} collisions", diagnostics.strategy, diagnostics.collisions);
+//! ```
+//!
 //! # Cargo Features
 //!
 //! You can specify the following features when you include the `frozen_collections` crate in your
@@ -237,10 +426,16 @@
 //! - **`emit`**. Enables the [`CollectionEmitter`](emit::CollectionEmitter) struct that lets you create frozen collections from a build script.
 //! - **`serde`**. Enables serialization and deserialization support for the frozen collections.
 //! - **`std`**. Enables small features only available when building with the standard library.
+//! - **`include`**. Lets the macros read the entries of a collection from a JSON, TOML, or CSV file.
 //!
-//! All features are enabled by default.
+//! All features except `include` are enabled by default.
+
+pub use frozen_collections_core::traits::{
+    IntoStringKey, Map, MapExtras, MapIteration, MapQuery, Scalar, ScalarEnum, Set, SetExtras, SetIteration, SetOps, SetQuery, StringKey,
+};
 
-pub use frozen_collections_core::traits::{Map, MapExtras, MapIteration, MapQuery, Scalar, Set, SetExtras, SetIteration, SetOps, SetQuery};
+#[cfg(feature = "serde")]
+pub use frozen_collections_core::traits::{DeserializeKey, DeserializeValues};
 
 #[doc(hidden)]
 pub use frozen_collections_core::traits::{CollectionMagnitude, Hasher, LargeCollection, Len, MediumCollection, SmallCollection};
@@ -749,7 +944,7 @@ pub use frozen_collections_macros::fz_ordered_set;
 ///     Person::Bob: 2,
 /// });
 ///
-/// // Scalar maps can also be used with any integer type as key.
+/// // Scalar maps can also be used with any integer type, `char`, or `bool` as key.
 /// //
 /// // This declares a global static map. This results in a static variable called MY_INT_MAP of type MyIntMapType.
 /// fz_scalar_map!(static MY_INT_MAP: MyIntMapType<i32, i32>, {
@@ -870,7 +1065,7 @@ pub use frozen_collections_macros::fz_scalar_map;
 ///     Person::Bob,
 /// });
 ///
-/// // Scalar sets can also be used with any integer type as value.
+/// // Scalar sets can also be used with any integer type, `char`, or `bool` as value.
 /// //
 /// // This declares a global static set. This results in a static variable called MY_INT_SET of type MyIntSetType.
 /// fz_scalar_set!(static MY_INT_SET: MyIntMapType<i32>, {
@@ -1075,11 +1270,229 @@ pub use frozen_collections_macros::fz_string_map;
 #[cfg(feature = "macros")]
 pub use frozen_collections_macros::fz_string_set;
 
-/// Implements the `Scalar` trait for an enum.
+/// Creates an efficient map with a fixed set of byte string keys.
+///
+/// The concrete type used to implement the map is based on an analysis of the input you
+/// provide. Although the types vary, they all implement the [`Map`] trait, so refer to the
+/// trait for API documentation.
+///
+/// Byte string literals are turned into byte slices, so the keys of the map are of type `&[u8]`.
+///
+/// # Example
+///
+/// ```
+/// use frozen_collections::*;
+///
+/// // Declare a global static map. This results in a static variable called MY_MAP_0 of type MyMapType0.
+/// fz_bytes_map!(static MY_MAP_0: MyMapType0<&'static [u8], i32>, {
+///     b"GET": 1,
+///     b"POST": 2,
+/// });
+///
+/// fn variables() {
+///     // Declare a local map. This results in a local variable called my_map_1 of type MyMapType1.
+///     fz_bytes_map!(let my_map_1: MyMapType1<&'static [u8], i32>, {
+///         b"GET": 1,
+///         b"POST": 2,
+///     });
+///
+///     // Declare a local map. This results in a local variable called my_map_2 of an unknown type.
+///     let my_map_2 = fz_bytes_map!({
+///         b"GET": 1,
+///         b"POST": 2,
+///     });
+///
+///     assert_eq!(Some(&1), MY_MAP_0.get(b"GET".as_slice()));
+///     assert_eq!(Some(&2), my_map_1.get(b"POST".as_slice()));
+///     assert_eq!(None, my_map_2.get(b"PUT".as_slice()));
+/// }
+/// #
+/// # fn main() {
+/// #     variables();
+/// # }
+/// ```
+#[cfg(feature = "macros")]
+pub use frozen_collections_macros::fz_bytes_map;
+
+/// Creates an efficient set with a fixed set of byte string values.
+///
+/// The concrete type used to implement the set is based on an analysis of the input you
+/// provide. Although the types vary, they all implement the [`Set`] trait, so refer to the
+/// trait for API documentation.
+///
+/// Byte string literals are turned into byte slices, so the values of the set are of type `&[u8]`.
+///
+/// # Example
+///
+/// ```
+/// use frozen_collections::*;
+///
+/// // Declare a global static set. This results in a static variable called MY_SET_0 of type MySetType0.
+/// fz_bytes_set!(static MY_SET_0: MySetType0<&'static [u8]>, {
+///     b"GET",
+///     b"POST",
+/// });
+///
+/// fn variables() {
+///     // Declare a local set. This results in a local variable called my_set_1 of an unknown type.
+///     let my_set_1 = fz_bytes_set!({
+///         b"GET",
+///         b"POST",
+///     });
+///
+///     assert!(MY_SET_0.contains(b"GET".as_slice()));
+///     assert!(!my_set_1.contains(b"PUT".as_slice()));
+/// }
+/// #
+/// # fn main() {
+/// #     variables();
+/// # }
+/// ```
+#[cfg(feature = "macros")]
+pub use frozen_collections_macros::fz_bytes_set;
+
+/// Creates a map with a fixed set of string keys which supports prefix queries.
+///
+/// Unlike the other macros, this one always produces a [`FzPrefixMap`], whose keys are sorted when
+/// the map is created. Besides the usual lookups, the map can find the longest key which is a prefix
+/// of some input with [`FzPrefixMap::longest_prefix_match`], and can list the keys which start with a
+/// given prefix with [`FzPrefixMap::keys_with_prefix`].
+///
+/// # Example
+///
+/// ```
+/// use frozen_collections::*;
+///
+/// // Declare a global static map. This results in a static variable called MY_MAP_0 of type MyMapType0.
+/// fz_prefix_map!(static MY_MAP_0: MyMapType0<&'static str, i32>, {
+///     "git": 1,
+///     "git commit": 2,
+///     "git config": 3,
+/// });
+///
+/// fn variables() {
+///     // Declare a local map. This results in a local variable called my_map_1 of an unknown type.
+///     let my_map_1 = fz_prefix_map!({
+///         "/": 1,
+///         "/api": 2,
+///     });
 ///
-/// Implementing the `Scalar` trait for an enum allows you to use the enum with the [`fz_scalar_map`]
-/// and [`fz_scalar_set`] macros. The `Scalar` macro can only be used with enums that only include
-/// unit variants without explicit discriminants.
+///     assert_eq!(Some(&2), MY_MAP_0.longest_prefix_match("git commit -m").map(|x| x.1));
+///     assert_eq!(2, MY_MAP_0.keys_with_prefix("git c").count());
+///     assert_eq!(Some(&2), my_map_1.longest_prefix_match("/api/users").map(|x| x.1));
+///     assert_eq!(Some(&1), my_map_1.get("/"));
+/// }
+/// #
+/// # fn main() {
+/// #     variables();
+/// # }
+/// ```
+#[cfg(feature = "macros")]
+pub use frozen_collections_macros::fz_prefix_map;
+
+/// Creates a map with an entry for every variant of an enum.
+///
+/// Unlike the other macros, this one always produces a [`FzEnumMap`]. The keys must be the variants of
+/// an enum which derives the `Scalar` trait, and every variant must be given an entry: the keys are
+/// turned into the arms of a `match`, so the compiler rejects the map and lists the missing variants
+/// otherwise. Since every key is present, [`FzEnumMap::get`] returns a value rather than an option.
+/// Keys are usually written as paths such as `Suit::Clubs`, and a lone lowercase name is rejected since
+/// it would match every variant.
+///
+/// # Example
+///
+/// ```
+/// use frozen_collections::*;
+///
+/// #[derive(Scalar, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// // Declare a global static map. This results in a static variable called MY_MAP_0 of type MyMapType0.
+/// fz_enum_map!(static MY_MAP_0: MyMapType0<Suit, char>, {
+///     Suit::Clubs: '♣',
+///     Suit::Diamonds: '♦',
+///     Suit::Hearts: '♥',
+///     Suit::Spades: '♠',
+/// });
+///
+/// fn variables() {
+///     // Declare a local map. This results in a local variable called my_map_1 of an unknown type.
+///     let my_map_1 = fz_enum_map!({
+///         Suit::Clubs: false,
+///         Suit::Diamonds: true,
+///         Suit::Hearts: true,
+///         Suit::Spades: false,
+///     });
+///
+///     assert_eq!(&'♥', MY_MAP_0.get(&Suit::Hearts));
+///     assert!(my_map_1[&Suit::Diamonds]);
+/// }
+/// #
+/// # fn main() {
+/// #     variables();
+/// # }
+/// ```
+///
+/// Leaving out a variant fails to compile:
+///
+/// ```compile_fail
+/// use frozen_collections::*;
+///
+/// #[derive(Scalar, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// let map = fz_enum_map!({
+///     Suit::Clubs: 1,
+///     Suit::Hearts: 3,
+/// });
+/// ```
+#[cfg(feature = "macros")]
+pub use frozen_collections_macros::fz_enum_map;
+
+/// Implements the `Scalar` trait for an enum or a newtype struct.
+///
+/// Implementing the `Scalar` trait for a type allows you to use the type with the [`fz_scalar_map`]
+/// and [`fz_scalar_set`] macros. The `Scalar` macro can be used with enums that only include
+/// unit variants, and with tuple structs that have a single field which is itself `Scalar`.
+///
+/// Enum variants are indexed by their declaration order unless the enum has explicit discriminants,
+/// in which case they are indexed by their discriminant values, so the spread of the values is
+/// taken into account when choosing a collection layout. Newtype structs use the index of their
+/// field.
+///
+/// # Example
+///
+/// ```
+/// use frozen_collections::*;
+///
+/// #[derive(Scalar, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+/// #[repr(u16)]
+/// enum Opcode {
+///     Nop = 0x10,
+///     Load = 0x20,
+///     Store = 0x30,
+/// }
+///
+/// #[derive(Scalar, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+/// struct UserId(u32);
+///
+/// let opcodes = fz_scalar_map!({ Opcode::Nop: "nop", Opcode::Load: "load", Opcode::Store: "store" });
+/// let users = fz_scalar_map!({ UserId(1): "Alice", UserId(2): "Bob" });
+///
+/// assert_eq!(0x20, Opcode::Load.index());
+/// assert_eq!(Some(&"load"), opcodes.get(&Opcode::Load));
+/// assert_eq!(Some(&"Bob"), users.get(&UserId(2)));
+/// ```
 #[cfg(feature = "macros")]
 pub use frozen_collections_macros::Scalar;
 
@@ -1092,6 +1505,21 @@ pub mod emit {
 pub use frozen_collections_core::fz_maps::*;
 pub use frozen_collections_core::fz_sets::*;
 
+/// Reports describing how frozen collections store their entries and look up keys.
+pub mod diagnostics {
+    pub use frozen_collections_core::diagnostics::*;
+}
+
+/// Dense key indexes shared by any number of columns of values.
+pub mod columns {
+    pub use frozen_collections_core::columns::*;
+}
+
+/// Read-only maps viewed directly over serialized buffers, such as memory-mapped files.
+pub mod mapped {
+    pub use frozen_collections_core::mapped::*;
+}
+
 #[doc(hidden)]
 pub mod sets {
     pub use frozen_collections_core::sets::*;
@@ -1128,3 +1556,4 @@ pub mod foldhash {
 }
 
 pub use frozen_collections_core::DefaultBuildHasher;
+pub use frozen_collections_core::keys::AsciiCaseInsensitive;
@@ -0,0 +1,213 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use frozen_collections_core::macros::{fz_bytes_map_macro, fz_bytes_set_macro};
+use frozen_collections_macros::*;
+use quote::quote;
+use std::collections::BTreeMap as StdBTreeMap;
+use std::collections::BTreeSet as StdBTreeSet;
+
+macro_rules! test_bytes {
+    ( $type:ty, $( $arg:expr ),* $(,)?) => {
+        {
+            _ = fz_bytes_set_macro(quote!({
+                $(
+                    $arg,
+                )*
+            })).unwrap();
+
+            let s0 = fz_bytes_set!({
+                $(
+                    $arg,
+                )*
+            });
+
+            let v: Vec<&[u8]> = vec![
+                $(
+                    $arg,
+                )*
+            ];
+
+            let mut s2 = StdBTreeSet::new();
+            for x in v.into_iter() {
+                _ = s2.insert(x);
+            }
+
+            _ = fz_bytes_set_macro(quote!(static _S3: Foo< $type >, {
+                $(
+                    $arg,
+                )*
+            })).unwrap();
+
+            fz_bytes_set!(static _S3: Foo< $type >, {
+                $(
+                    $arg,
+                )*
+            });
+
+            _ = fz_bytes_set_macro(quote!(let s4: Bar< $type >, {
+                $(
+                    $arg,
+                )*
+            })).unwrap();
+
+            fz_bytes_set!(let s4: Bar< $type >, {
+                $(
+                    $arg,
+                )*
+            });
+
+            _ = fz_bytes_set_macro(quote!(let mut s5: Baz< $type >, {
+                $(
+                    $arg,
+                )*
+            })).unwrap();
+
+            fz_bytes_set!(let mut s5: Baz< $type >, {
+                $(
+                    $arg,
+                )*
+            });
+
+            assert_eq!(s0, s2);
+            // assert_eq!(s0, S3);
+            assert_eq!(s0, s4);
+            assert_eq!(s0, s5);
+        }
+
+        {
+            _ = fz_bytes_map_macro(quote!({
+                $(
+                    $arg: 42,
+                )*
+            })).unwrap();
+
+            let m0 = fz_bytes_map!({
+                $(
+                    $arg: 42,
+                )*
+            });
+
+            let v: Vec<(&[u8], i32)> = vec![
+                $(
+                    ($arg, 42),
+                )*
+            ];
+
+            let mut m2 = StdBTreeMap::new();
+            for x in v.into_iter() {
+                _ = m2.insert(x.0, x.1);
+            }
+
+            _ = fz_bytes_map_macro(quote!(static _M3: Foo< $type, i32 >, {
+                $(
+                    $arg: 42,
+                )*
+            })).unwrap();
+
+            fz_bytes_map!(static _M3: Foo< $type, i32 >, {
+                $(
+                    $arg: 42,
+                )*
+            });
+
+            _ = fz_bytes_map_macro(quote!(let m4: Bar< $type, i32 >, {
+                $(
+                    $arg: 42,
+                )*
+            })).unwrap();
+
+            fz_bytes_map!(let m4: Bar< $type, i32 >, {
+                $(
+                    $arg: 42,
+                )*
+            });
+
+            _ = fz_bytes_map_macro(quote!(let mut m5: Baz< $type, i32 >, {
+                $(
+                    $arg: 42,
+                )*
+            })).unwrap();
+
+            fz_bytes_map!(let mut m5: Baz< $type, i32 >, {
+                $(
+                    $arg: 42,
+                )*
+            });
+
+            assert_eq!(m0, m2);
+            // assert_eq!(m0, M3);
+            assert_eq!(m0, m4);
+            assert_eq!(m0, m5);
+        }
+    }
+}
+
+#[test]
+fn bytes() {
+    test_bytes!(&'static [u8], b"0");
+    test_bytes!(&'static [u8], b"0", b"1");
+    test_bytes!(&'static [u8], b"0", b"1", b"2");
+    test_bytes!(&'static [u8], b"0", b"1", b"2", b"3");
+    test_bytes!(&'static [u8], b"0", b"1", b"2", b"3", b"4");
+    test_bytes!(&'static [u8], b"0", b"1", b"2", b"3", b"4", b"5");
+    test_bytes!(&'static [u8], b"0", b"1", b"2", b"3", b"4", b"5", b"6");
+    test_bytes!(&'static [u8], b"0", b"1", b"2", b"3", b"4", b"5", b"6", b"7");
+    test_bytes!(&'static [u8], b"0", b"1", b"2", b"3", b"4", b"5", b"6", b"7", b"8", b"9", b"10");
+
+    // test duplicate logic
+    test_bytes!(&'static [u8], b"0", b"1", b"0", b"0");
+
+    test_bytes!(
+        &'static [u8],
+        b"ColorRed",
+        b"ColorGreen",
+        b"ColorBlue",
+        b"ColorYellow",
+        b"ColorCyan",
+        b"ColorMagenta"
+    );
+
+    test_bytes!(
+        &'static [u8],
+        b"RedColor",
+        b"GreenColor",
+        b"BlueColor",
+        b"YellowColor",
+        b"CyanColor",
+        b"MagentaColor"
+    );
+
+    test_bytes!(
+        &'static [u8],
+        b"\x00\x01",
+        b"\xff\xfe",
+        b"\x80",
+        b"\x00",
+        b"\xc3\x28",
+        b"\xe2\x82",
+        b"\xf0\x9f\x98"
+    );
+}
+
+#[test]
+fn non_literal_key_bytes_map() {
+    let s0: &[u8] = b"Zero";
+    let s1: &[u8] = b"One";
+    let s2: &[u8] = b"Two";
+    let s3: &[u8] = b"Three";
+    let m = fz_bytes_map!({s0: 2, s1: 3, s2: 4, s3: 3});
+    assert_eq!(4, m.len());
+    assert!(m.contains_key(s0));
+    assert!(m.contains_key(s1));
+    assert!(!m.contains_key(b"Foo".as_slice()));
+
+    let m = fz_bytes_map!({s0: 0, s1: 1, b"Two": 2, b"Three": 3, b"Four": 4});
+    assert_eq!(Some(&0), m.get(b"Zero".as_slice()));
+    assert_eq!(Some(&4), m.get(b"Four".as_slice()));
+    assert_eq!(None, m.get(b"Five".as_slice()));
+
+    let s = fz_bytes_set!({ s0, b"One" });
+    assert!(s.contains(b"One".as_slice()));
+
+    assert!(fz_bytes_map_macro(quote!({s0: 2, s1: 3, s2: 4, s3: 3})).is_ok());
+}
@@ -4,7 +4,7 @@ use frozen_collections::*;
 use frozen_collections_core::macros::derive_scalar_macro;
 use quote::quote;
 
-#[derive(Scalar, Copy, Ord, PartialOrd, Eq, PartialEq, Clone)]
+#[derive(Scalar, Copy, Ord, PartialOrd, Eq, PartialEq, Clone, Debug)]
 enum Color {
     Red,
     Green,
@@ -26,3 +26,135 @@ fn test_derive_scalar() {
     assert_eq!(1, Color::index(&Color::Green));
     assert_eq!(2, Color::index(&Color::Blue));
 }
+
+#[derive(Scalar, Copy, Ord, PartialOrd, Eq, PartialEq, Clone, Debug)]
+#[repr(u16)]
+enum Opcode {
+    Nop = 0x10,
+    Load = 0x20,
+    Store = 0x30,
+    Halt = 0xFF00,
+}
+
+#[derive(Scalar, Copy, Ord, PartialOrd, Eq, PartialEq, Clone, Debug)]
+#[repr(i8)]
+enum Direction {
+    Back = -1,
+    Stay,
+    Forward,
+}
+
+#[derive(Scalar, Copy, Ord, PartialOrd, Eq, PartialEq, Clone, Debug)]
+struct UserId(u32);
+
+#[derive(Scalar, Copy, Ord, PartialOrd, Eq, PartialEq, Clone, Debug)]
+struct Wrapper<T>(T);
+
+#[test]
+fn test_derive_scalar_discriminants() {
+    _ = derive_scalar_macro(quote!(
+        #[repr(u16)]
+        enum Opcode {
+            Nop = 0x10,
+            Load = 0x20,
+            Store = 0x30,
+            Halt = 0xFF00,
+        }
+    ))
+    .unwrap();
+
+    assert_eq!(0x10, Opcode::Nop.index());
+    assert_eq!(0x20, Opcode::Load.index());
+    assert_eq!(0xFF00, Opcode::Halt.index());
+    assert!(Direction::Back.index() < Direction::Stay.index());
+    assert_eq!(Direction::Stay.index() + 1, Direction::Forward.index());
+
+    let m = fz_scalar_map!({ Opcode::Nop: 1, Opcode::Load: 2, Opcode::Store: 3, Opcode::Halt: 4 });
+    assert_eq!(Some(&2), m.get(&Opcode::Load));
+    assert_eq!(Some(&4), m.get(&Opcode::Halt));
+
+    let m = FzScalarMap::new(vec![(Opcode::Nop, 1), (Opcode::Load, 2), (Opcode::Store, 3)]);
+    assert_eq!(Some(&3), m.get(&Opcode::Store));
+    assert_eq!(None, m.get(&Opcode::Halt));
+
+    let s = fz_scalar_set!({ Direction::Back, Direction::Forward });
+    assert!(s.contains(&Direction::Back));
+    assert!(!s.contains(&Direction::Stay));
+
+    // sets of every variant form a continuous range, so set operations between them use bitsets
+    let all = FzScalarSet::new(vec![Color::Red, Color::Green, Color::Blue]);
+    let some = FzScalarSet::new(vec![Color::Blue, Color::Red]);
+    assert!(all.bitset().is_some());
+    assert!(some.bitset().is_some());
+    assert_eq!(vec![&Color::Red, &Color::Blue], all.intersection(&some).collect::<Vec<_>>());
+    assert_eq!(vec![&Color::Green], all.difference(&some).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_derive_scalar_newtypes() {
+    _ = derive_scalar_macro(quote!(
+        struct UserId(u32);
+    ))
+    .unwrap();
+
+    assert_eq!(42, UserId(42).index());
+    assert_eq!(7, Wrapper(7_u8).index());
+    assert_eq!(Opcode::Load.index(), Wrapper(Opcode::Load).index());
+    assert!(Wrapper(u128::MAX).index_is_shared());
+
+    let m = fz_scalar_map!({ UserId(1): "alice", UserId(2): "bob", UserId(1000): "carol" });
+    assert_eq!(Some(&"bob"), m.get(&UserId(2)));
+    assert_eq!(None, m.get(&UserId(3)));
+
+    let m = FzScalarMap::new(vec![(UserId(10), 1), (UserId(11), 2), (UserId(12), 3)]);
+    assert_eq!(Some(&2), m.get(&UserId(11)));
+    assert_eq!(None, m.get(&UserId(13)));
+}
+
+#[test]
+fn test_enum_map() {
+    assert_eq!(&[Color::Red, Color::Green, Color::Blue], Color::VARIANTS);
+    assert_eq!(&[Opcode::Nop, Opcode::Load, Opcode::Store, Opcode::Halt], Opcode::VARIANTS);
+
+    let mut m = fz_enum_map!({ Color::Red: 1, Color::Green: 2, Color::Blue: 3 });
+    assert_eq!(&2, m.get(&Color::Green));
+    assert_eq!(3, m[&Color::Blue]);
+    m[&Color::Red] = 10;
+    assert_eq!(10, m[&Color::Red]);
+    assert_eq!(3, m.len());
+
+    // explicit discriminants are spread out, but every variant is still present
+    let m = fz_enum_map!({
+        Opcode::Nop: "nop",
+        Opcode::Load: "load",
+        Opcode::Store: "store",
+        Opcode::Halt: "halt",
+        Opcode::Nop: "noop",
+    });
+    assert_eq!(&"halt", m.get(&Opcode::Halt));
+    assert_eq!(&"noop", m.get(&Opcode::Nop));
+
+    fz_enum_map!(static DIRECTIONS: Directions<Direction, i8>, {
+        Direction::Back: -1,
+        Direction::Stay: 0,
+        Direction::Forward: 1,
+    });
+    assert_eq!(&-1, DIRECTIONS.get(&Direction::Back));
+
+    // values are evaluated once, so they may move non-Copy captured variables
+    let s = String::from("x");
+    let m = fz_enum_map!({ Color::Red: s, Color::Green: String::new(), Color::Blue: "b".to_string() });
+    assert_eq!("x", m[&Color::Red]);
+    assert_eq!("", m[&Color::Green]);
+    assert_eq!("b", m[&Color::Blue]);
+
+    let m = FzEnumMap::new(vec![(Color::Red, 1), (Color::Blue, 3)]);
+    assert_eq!("missing entries for variants [Green]", m.unwrap_err());
+
+    let m = FzEnumMap::from_fn(|c: Color| c.index());
+    assert_eq!(vec![0, 1, 2], m.values().copied().collect::<Vec<_>>());
+
+    let m = m.map_values(|_, v| v * 2);
+    assert_eq!(&4, m.get(&Color::Blue));
+    assert_eq!(Err(4), m.try_map_values(|_, v| if v < 4 { Ok(v) } else { Err(v) }));
+}
@@ -474,3 +474,38 @@ fn hash_string() {
         "13"
     );
 }
+
+type SipHasher = core::hash::BuildHasherDefault<std::collections::hash_map::DefaultHasher>;
+
+#[test]
+fn custom_hasher() {
+    fz_hash_map!(#[hasher = SipHasher] static M: MyMap<i32, &'static str>, { 1: "one", 2: "two", 3: "three", 4: "four", 5: "five" });
+    let m: &frozen_collections::maps::HashMap<
+        i32,
+        &str,
+        frozen_collections::SmallCollection,
+        frozen_collections::hashers::BridgeHasher<SipHasher>,
+    > = &M;
+    assert_eq!(5, m.len());
+    assert_eq!(Some(&"three"), M.get(&3));
+    assert_eq!(None, M.get(&6));
+
+    let x = Person { name: "Olivia", age: 25 };
+    fz_hash_set!(#[hasher = core::hash::BuildHasherDefault::<std::collections::hash_map::DefaultHasher>] let s: MySet<Person>, { x });
+    assert!(s.contains(&Person { name: "Olivia", age: 25 }));
+    assert!(!s.contains(&Person { name: "Olivia", age: 26 }));
+}
+
+#[test]
+fn seeded_non_literal_keys() {
+    let (ten, twenty, thirty, forty) = (10, 20, 30, 40);
+    let map = fz_hash_map!(#[seed = 42] { ten: "a", twenty: "b", thirty: "c", forty: "d" });
+    let _: &frozen_collections::FzHashMap<i32, &str, frozen_collections::foldhash::FixedState> = &map;
+    assert_eq!(Some(&"c"), map.get(&30));
+    assert_eq!(None, map.get(&50));
+
+    let (west, east, north, south) = ("w", "e", "n", "s");
+    let set = fz_string_set!(#[seed = 42] { west, east, north, south });
+    assert!(set.contains("n"));
+    assert!(!set.contains("x"));
+}
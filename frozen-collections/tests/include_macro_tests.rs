@@ -0,0 +1,65 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use frozen_collections::*;
+
+fz_string_map!(static COUNTRIES: Countries<&'static str, &'static str>, include "tests/data/countries.json");
+fz_scalar_map!(static HTTP_STATUS: HttpStatus<u16, &'static str>, include "tests/data/http_status.toml");
+
+#[test]
+fn json() {
+    assert_eq!(5, COUNTRIES.len());
+    assert_eq!(Some(&"Canada"), COUNTRIES.get("CA"));
+    assert_eq!(Some(&"United States"), COUNTRIES.get("US"));
+    assert_eq!(None, COUNTRIES.get("XX"));
+
+    let s = fz_scalar_set!(include "tests/data/primes.json");
+    assert_eq!(10, s.len());
+    assert!(s.contains(&29));
+    assert!(!s.contains(&4));
+
+    let s = fz_string_set!(include "tests/data/countries.json");
+    assert!(s.contains("JP"));
+    assert!(!s.contains("Japan"));
+
+    fz_scalar_map!(let m: Limits<i32, f64>, include "tests/data/limits.json");
+    assert_eq!(Some(&1.5), m.get(&1));
+    assert_eq!(Some(&-3.0), m.get(&2));
+    assert_eq!(Some(&1000.0), m.get(&3));
+}
+
+#[test]
+fn toml() {
+    assert_eq!(4, HTTP_STATUS.len());
+    assert_eq!(Some(&"Not Found"), HTTP_STATUS.get(&404));
+    assert_eq!(None, HTTP_STATUS.get(&403));
+
+    let m = fz_hash_map!(include "tests/data/http_status.toml");
+    assert_eq!(Some(&"OK"), m.get(&"200"));
+
+    fz_string_map!(let m: Weights<&'static str, f64>, include "tests/data/weights.toml");
+    assert_eq!(Some(&1.0), m.get("kg"));
+    assert_eq!(Some(&0.001), m.get("g"));
+    assert_eq!(Some(&0.0), m.get("lb"));
+}
+
+#[test]
+fn csv() {
+    let m = fz_string_map!(include "tests/data/mime_types.csv");
+    assert_eq!(4, m.len());
+    assert_eq!(Some(&"text/html"), m.get("html"));
+    assert_eq!(Some(&"application/gzip"), m.get("tar.gz"));
+
+    fz_ordered_set!(let s: Extensions<&'static str>, include "tests/data/mime_types.csv" as csv);
+    assert_eq!(4, s.len());
+    assert!(s.contains(&"png"));
+    assert!(!s.contains(&"image/png"));
+
+    fz_string_map!(let m: Rates<&'static str, f32>, include "tests/data/rates.csv");
+    assert_eq!(Some(&1.0), m.get("usd"));
+    assert_eq!(Some(&0.92), m.get("eur"));
+    assert_eq!(Some(&150.0), m.get("jpy"));
+
+    let m = fz_string_map!(include "tests/data/rates.csv");
+    assert_eq!(Some(&1.0), m.get("usd"));
+    assert_eq!(Some(&0.92), m.get("eur"));
+}
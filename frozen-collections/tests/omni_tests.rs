@@ -3,15 +3,18 @@
 mod common;
 
 use common::*;
+use core::cell::Cell;
 use frozen_collections::*;
-use frozen_collections_core::hashers::BridgeHasher;
+use frozen_collections_core::hashers::{BridgeHasher, LengthHasher};
 use frozen_collections_core::inline_maps::InlineEytzingerSearchMap;
 use frozen_collections_core::inline_sets::InlineEytzingerSearchSet;
 use frozen_collections_core::macros::fz_scalar_map_macro;
 use frozen_collections_core::maps::*;
+use frozen_collections_core::sets::HashSet;
 use hashbrown::HashMap as HashbrownMap;
 use hashbrown::HashSet as HashbrownSet;
 use quote::quote;
+use std::rc::Rc;
 
 macro_rules! test_str {
     ( $( $input:expr ),* ; $( $other:literal ),*) => {
@@ -121,6 +124,25 @@ macro_rules! test_all {
         test_map_iter_mut(&mut m, &map_reference);
         test_map_serialization::<_, _, _, FzHashMap<_, _>>(&m);
 
+        let s = HashSet::new(m);
+        test_set(&s, &set_reference, &set_other);
+        test_set_ops(&s, &set_reference, &set_other);
+        test_set_iter(&s, &set_reference);
+        test_set_serialization::<_, _, FzHashSet<_>>(&s);
+        let mut m = PerfectHashMap::<_, _>::with_hasher(map_input.clone(), BridgeHasher::default()).unwrap();
+        test_map(&m, &map_reference, &map_other);
+        test_map_ops(&m, &map_reference);
+        test_map_iter(&m, &map_reference);
+        test_map_iter_mut(&mut m, &map_reference);
+        test_map_serialization::<_, _, _, FzHashMap<_, _>>(&m);
+
+        let mut m = SimdProbeMap::<_, _>::with_hasher(map_input.clone(), BridgeHasher::default());
+        test_map(&m, &map_reference, &map_other);
+        test_map_ops(&m, &map_reference);
+        test_map_iter(&m, &map_reference);
+        test_map_iter_mut(&mut m, &map_reference);
+        test_map_serialization::<_, _, _, FzHashMap<_, _>>(&m);
+
         let mut m = FzOrderedMap::new(map_input.clone());
         test_map(&m, &map_reference, &map_other);
         test_map_ops(&m, &map_reference);
@@ -379,6 +401,7 @@ fn test_common() {
 
 #[test]
 fn test_set_defaults() {
+    test_set_default::<HashSet<i32>, i32>();
     test_set_default::<FzHashSet<i32>, i32>();
     test_set_default::<FzOrderedSet<i32>, i32>();
     test_set_default::<FzScalarSet<i32>, i32>();
@@ -412,6 +435,11 @@ fn test_set_empties() {
     test_set_empty(&FzHashSet::<i32>::default());
     test_set_empty(&FzHashSet::<i32>::from(FzHashMap::new(vec![])));
 
+    test_set_empty(&HashSet::<i32>::default());
+    test_set_empty(&HashSet::new(
+        HashMap::<i32, ()>::with_hasher(vec![], BridgeHasher::default()).unwrap(),
+    ));
+
     test_set_empty(&FzOrderedSet::<i32>::default());
     test_set_empty(&FzOrderedSet::<i32>::from(FzOrderedMap::new(vec![])));
 
@@ -575,7 +603,7 @@ fn eytzinger_search() {
     test_map_iter_mut(&mut m, &map_reference);
     test_map_serialization::<_, _, _, FzOrderedMap<_, _>>(&m);
 
-    let s = InlineEytzingerSearchSet::<i32, 10>::new(m);
+    let s = InlineEytzingerSearchSet::<i32, 10>::new(m.clone());
     let set_reference = HashbrownSet::<i32>::from_iter(s.clone());
     let set_other = HashbrownSet::<i32>::from_iter(s.clone());
 
@@ -583,4 +611,999 @@ fn eytzinger_search() {
     test_set_iter(&s, &set_reference);
     test_set_ops(&s, &set_reference, &set_other);
     test_set_serialization::<_, _, FzOrderedSet<_>>(&s);
+
+    assert!(m.iter_sorted().map(|x| *x.0).eq(1..=10));
+    assert!(m.range(3..6).map(|x| *x.0).eq(3..6));
+    assert_eq!(Some((&1, &())), m.first_key_value());
+    assert_eq!(Some((&10, &())), m.last_key_value());
+
+    assert!(s.iter_sorted().copied().eq(1..=10));
+    assert!(s.range(4..).copied().eq(4..=10));
+    assert_eq!(Some(&1), s.first());
+    assert_eq!(Some(&10), s.last());
+
+    assert_eq!(Some((&5, &())), m.floor(&5));
+    assert_eq!(Some((&10, &())), m.floor(&11));
+    assert_eq!(None, m.ceiling(&11));
+    assert_eq!(Some((&4, &())), m.predecessor(&5));
+    assert_eq!(Some((&1, &())), m.successor(&0));
+    assert_eq!(None, s.predecessor(&1));
+    assert_eq!(Some(&10), s.successor(&9));
+
+    assert_eq!(Some(4), m.rank(&5));
+    assert_eq!(None, m.rank(&11));
+    assert_eq!(Some((&5, &())), m.select(4));
+    assert_eq!(Some(9), s.rank(&10));
+    assert_eq!(Some(&10), s.select(9));
+    assert_eq!(None, s.select(10));
+}
+
+#[test]
+fn ordered_range_queries() {
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+    use std::collections::{BTreeMap, BTreeSet};
+
+    for len in 0..40 {
+        let input: Vec<(i32, i32)> = (0..len).map(|x| (x * 2, x)).collect();
+        let reference = BTreeMap::from_iter(input.clone());
+        let m = FzOrderedMap::new(input.clone());
+        let em = EytzingerSearchMap::new(input.clone());
+
+        assert!(m.iter_sorted().eq(reference.iter()));
+        assert!(m.iter_sorted().rev().eq(reference.iter().rev()));
+        assert!(em.iter_sorted().eq(reference.iter()));
+        assert_eq!(reference.len(), m.iter_sorted().len());
+        assert_eq!(reference.first_key_value(), m.first_key_value());
+        assert_eq!(reference.last_key_value(), m.last_key_value());
+        assert_eq!(reference.first_key_value(), em.first_key_value());
+        assert_eq!(reference.last_key_value(), em.last_key_value());
+
+        for start in -1..=len * 2 + 1 {
+            for end in start..=len * 2 + 1 {
+                assert!(m.range(start..end).eq(reference.range(start..end)));
+                assert!(m.range(start..=end).eq(reference.range(start..=end)));
+                assert!(
+                    m.range((Excluded(start), Included(end)))
+                        .eq(reference.range((Excluded(start), Included(end))))
+                );
+                assert!(em.range(start..end).rev().eq(reference.range(start..end).rev()));
+                assert_eq!(reference.range(start..end).count(), m.range(start..end).len());
+            }
+
+            assert!(m.range(start..).eq(reference.range(start..)));
+            assert!(m.range(..start).eq(reference.range(..start)));
+            assert!(
+                m.range((Excluded(start), Unbounded))
+                    .eq(reference.range((Excluded(start), Unbounded)))
+            );
+        }
+
+        assert_eq!(0, m.range((Included(10), Excluded(5))).count());
+
+        let set_reference = BTreeSet::from_iter(input.iter().map(|x| x.0));
+        let s = FzOrderedSet::new(input.iter().map(|x| x.0).collect());
+
+        assert!(s.iter_sorted().eq(set_reference.iter()));
+        assert_eq!(set_reference.first(), s.first());
+        assert_eq!(set_reference.last(), s.last());
+        assert!(s.range(3..=11).eq(set_reference.range(3..=11)));
+        assert!(s.range(..7).rev().eq(set_reference.range(..7).rev()));
+    }
+
+    let m = FzOrderedMap::new(vec![("b", 2), ("d", 4), ("a", 1), ("c", 3)]);
+    assert_eq!(vec![(&"b", &2), (&"c", &3)], m.range("b".."d").collect::<Vec<_>>());
+    assert_eq!("[(\"a\", 1), (\"b\", 2)]", format!("{:?}", m.range(.."c")));
+}
+
+#[test]
+fn nearest_key_queries() {
+    use core::ops::Bound::{Excluded, Unbounded};
+    use std::collections::BTreeMap;
+
+    let key_sets: Vec<Vec<i32>> = vec![
+        vec![],
+        vec![5],
+        (0..40).collect(),                            // dense
+        (0..40).map(|x| x * 3).collect(),             // sparse
+        (0..40).map(|x| x * 1_000_003 - 7).collect(), // hashed
+        (0..500).map(|x| x * 65_537 - 7).collect(),   // hashed, beyond SIMD probing
+    ];
+
+    for keys in key_sets {
+        let input: Vec<(i32, i32)> = keys.iter().map(|x| (*x, x + 1)).collect();
+        let reference = BTreeMap::from_iter(input.clone());
+        let om = FzOrderedMap::new(input.clone());
+        let sm = FzScalarMap::new(input.clone());
+        let os = FzOrderedSet::new(keys.clone());
+        let ss = FzScalarSet::new(keys.clone());
+
+        let min = keys.first().copied().unwrap_or_default() - 2;
+        let max = keys.last().copied().unwrap_or_default() + 2;
+        let probes = (min..=max)
+            .step_by(usize::try_from((max - min) / 200).unwrap_or(1).max(1))
+            .chain(keys.iter().copied());
+
+        for k in probes {
+            let floor = reference.range(..=k).next_back();
+            let ceiling = reference.range(k..).next();
+            let predecessor = reference.range(..k).next_back();
+            let successor = reference.range((Excluded(k), Unbounded)).next();
+
+            assert_eq!(floor, om.floor(&k));
+            assert_eq!(ceiling, om.ceiling(&k));
+            assert_eq!(predecessor, om.predecessor(&k));
+            assert_eq!(successor, om.successor(&k));
+
+            assert_eq!(floor, sm.floor(&k));
+            assert_eq!(ceiling, sm.ceiling(&k));
+            assert_eq!(predecessor, sm.predecessor(&k));
+            assert_eq!(successor, sm.successor(&k));
+
+            assert_eq!(floor.map(|x| x.0), os.floor(&k));
+            assert_eq!(ceiling.map(|x| x.0), os.ceiling(&k));
+            assert_eq!(predecessor.map(|x| x.0), os.predecessor(&k));
+            assert_eq!(successor.map(|x| x.0), os.successor(&k));
+
+            assert_eq!(floor.map(|x| x.0), ss.floor(&k));
+            assert_eq!(ceiling.map(|x| x.0), ss.ceiling(&k));
+            assert_eq!(predecessor.map(|x| x.0), ss.predecessor(&k));
+            assert_eq!(successor.map(|x| x.0), ss.successor(&k));
+        }
+    }
+}
+
+#[test]
+fn rank_and_select() {
+    let key_sets: Vec<Vec<i32>> = vec![
+        vec![],
+        vec![5],
+        (0..40).collect(),                            // dense
+        (0..40).map(|x| x * 3).collect(),             // sparse
+        (0..40).map(|x| x * 1_000_003 - 7).collect(), // hashed
+        (0..500).map(|x| x * 65_537 - 7).collect(),   // hashed, beyond SIMD probing
+    ];
+
+    for keys in key_sets {
+        let input: Vec<(i32, i32)> = keys.iter().map(|x| (*x, x + 1)).collect();
+        let om = FzOrderedMap::new(input.clone());
+        let sm = FzScalarMap::new(input.clone());
+        let os = FzOrderedSet::new(keys.clone());
+        let ss = FzScalarSet::new(keys.clone());
+
+        for (i, (k, v)) in input.iter().enumerate() {
+            assert_eq!(Some(i), om.rank(k));
+            assert_eq!(Some(i), sm.rank(k));
+            assert_eq!(Some(i), os.rank(k));
+            assert_eq!(Some(i), ss.rank(k));
+
+            assert_eq!(Some((k, v)), om.select(i));
+            assert_eq!(Some((k, v)), sm.select(i));
+            assert_eq!(Some(k), os.select(i));
+            assert_eq!(Some(k), ss.select(i));
+
+            if !keys.contains(&(k + 1)) {
+                assert_eq!(None, om.rank(&(k + 1)));
+                assert_eq!(None, sm.rank(&(k + 1)));
+            }
+        }
+
+        assert_eq!(None, om.select(keys.len()));
+        assert_eq!(None, sm.select(keys.len()));
+        assert_eq!(None, os.select(keys.len()));
+        assert_eq!(None, ss.select(keys.len()));
+        assert_eq!(None, om.rank(&-100));
+        assert_eq!(None, sm.rank(&-100));
+    }
+}
+
+#[test]
+fn perfect_hashing() {
+    let keys: Vec<i32> = (0..3000).map(|x| x * 7919).collect();
+    let other: Vec<i32> = (0..3000).map(|x| x * 7919 + 1).collect();
+
+    let set_reference = HashbrownSet::from_iter(keys.clone());
+    let set_other = HashbrownSet::from_iter(other.clone());
+    let map_reference = HashbrownMap::from_iter(keys.iter().map(|x| (*x, ())));
+    let map_other = HashbrownMap::from_iter(other.iter().map(|x| (*x, ())));
+    let map_input: Vec<_> = keys.iter().map(|x| (*x, ())).collect();
+
+    let mut m = FzHashMap::new(map_input.clone());
+    test_map(&m, &map_reference, &map_other);
+    test_map_ops(&m, &map_reference);
+    test_map_iter(&m, &map_reference);
+    test_map_iter_mut(&mut m, &map_reference);
+
+    let s = FzHashSet::new(keys.clone());
+    test_set(&s, &set_reference, &set_other);
+    test_set_ops(&s, &set_reference, &set_other);
+    test_set_iter(&s, &set_reference);
+
+    let mut m = PerfectHashMap::<_, _>::with_hasher(map_input, BridgeHasher::default()).unwrap();
+    test_map(&m, &map_reference, &map_other);
+    test_map_ops(&m, &map_reference);
+    test_map_iter(&m, &map_reference);
+    test_map_iter_mut(&mut m, &map_reference);
+
+    let set_reference = HashbrownSet::from_iter(keys.iter().map(|x| format!("key {x}").into_boxed_str()));
+    let set_other = HashbrownSet::from_iter(other.iter().map(|x| format!("key {x}").into_boxed_str()));
+    let map_reference = HashbrownMap::from_iter(keys.iter().map(|x| (format!("key {x}").into_boxed_str(), ())));
+    let map_other = HashbrownMap::from_iter(other.iter().map(|x| (format!("key {x}").into_boxed_str(), ())));
+    let map_input: Vec<_> = keys.iter().map(|x| (format!("key {x}"), ())).collect();
+
+    let mut m = FzStringMap::new(map_input);
+    test_map(&m, &map_reference, &map_other);
+    test_map_ops(&m, &map_reference);
+    test_map_iter(&m, &map_reference);
+    test_map_iter_mut(&mut m, &map_reference);
+
+    let s = FzStringSet::from(m);
+    test_set(&s, &set_reference, &set_other);
+    test_set_ops(&s, &set_reference, &set_other);
+    test_set_iter(&s, &set_reference);
+}
+
+#[test]
+fn perfect_hashing_many_keys() {
+    // every key appears twice, with the later value winning, so deduplication must keep each hash code with its entry
+    let input: Vec<_> = (0..100_000usize).chain(0..100_000).enumerate().map(|(i, k)| (k * 31, i)).collect();
+
+    let m = PerfectHashMap::<_, _>::with_hasher(input.clone(), BridgeHasher::default()).unwrap();
+    assert_eq!(100_000, m.len());
+    for k in (0..100_000usize).step_by(997) {
+        assert_eq!(Some(&(k + 100_000)), m.get(&(k * 31)));
+        assert_eq!(None, m.get(&(k * 31 + 1)));
+    }
+
+    let m = FzHashMap::new(input);
+    assert_eq!(100_000, m.len());
+    assert_eq!(Some(&199_999), m.get(&(99_999 * 31)));
+}
+
+#[test]
+fn simd_probing() {
+    for len in [8, 15, 16, 17, 40, 64] {
+        let keys: Vec<i32> = (0..len).map(|x| x * 7919).collect();
+        let other: Vec<i32> = (0..len).map(|x| x * 7919 + 1).collect();
+
+        let set_reference = HashbrownSet::from_iter(keys.clone());
+        let set_other = HashbrownSet::from_iter(other.clone());
+        let map_reference = HashbrownMap::from_iter(keys.iter().map(|x| (*x, ())));
+        let map_other = HashbrownMap::from_iter(other.iter().map(|x| (*x, ())));
+        let map_input: Vec<_> = keys.iter().map(|x| (*x, ())).collect();
+
+        let mut m = FzScalarMap::new(map_input.clone());
+        test_map(&m, &map_reference, &map_other);
+        test_map_ops(&m, &map_reference);
+        test_map_iter(&m, &map_reference);
+        test_map_iter_mut(&mut m, &map_reference);
+
+        let s = FzScalarSet::new(keys.clone());
+        test_set(&s, &set_reference, &set_other);
+        test_set_ops(&s, &set_reference, &set_other);
+        test_set_iter(&s, &set_reference);
+
+        let mut m = SimdProbeMap::<_, _>::with_hasher(map_input, BridgeHasher::default());
+        test_map(&m, &map_reference, &map_other);
+        test_map_ops(&m, &map_reference);
+        test_map_iter(&m, &map_reference);
+        test_map_iter_mut(&mut m, &map_reference);
+
+        let set_reference = HashbrownSet::from_iter(keys.iter().map(|x| format!("k{x}").into_boxed_str()));
+        let set_other = HashbrownSet::from_iter(other.iter().map(|x| format!("k{x}").into_boxed_str()));
+        let map_reference = HashbrownMap::from_iter(keys.iter().map(|x| (format!("k{x}").into_boxed_str(), ())));
+        let map_other = HashbrownMap::from_iter(other.iter().map(|x| (format!("k{x}").into_boxed_str(), ())));
+        let map_input: Vec<_> = keys.iter().map(|x| (format!("k{x}"), ())).collect();
+
+        let mut m = FzStringMap::new(map_input);
+        test_map(&m, &map_reference, &map_other);
+        test_map_ops(&m, &map_reference);
+        test_map_iter(&m, &map_reference);
+        test_map_iter_mut(&mut m, &map_reference);
+
+        let s = FzStringSet::from(m);
+        test_set(&s, &set_reference, &set_other);
+        test_set_ops(&s, &set_reference, &set_other);
+        test_set_iter(&s, &set_reference);
+    }
+}
+
+#[test]
+fn length_hashing() {
+    use frozen_collections::diagnostics::KeyHashing;
+
+    for len in [1, 2, 3, 16, 100] {
+        // every key has a different length, while the other keys share lengths with the keys
+        let keys: Vec<_> = (1..=len).map(|x| "a".repeat(x)).collect();
+        let other: Vec<_> = (1..=len + 1).map(|x| "b".repeat(x)).collect();
+
+        let set_reference = HashbrownSet::from_iter(keys.iter().map(|x| x.clone().into_boxed_str()));
+        let set_other = HashbrownSet::from_iter(other.iter().map(|x| x.clone().into_boxed_str()));
+        let map_reference = HashbrownMap::from_iter(keys.iter().map(|x| (x.clone().into_boxed_str(), ())));
+        let map_other = HashbrownMap::from_iter(other.iter().map(|x| (x.clone().into_boxed_str(), ())));
+        let map_input: Vec<_> = keys.iter().map(|x| (x.clone(), ())).collect();
+
+        let mut m = FzStringMap::new(map_input);
+        assert_eq!(Some(KeyHashing::Length), m.diagnostics().key_hashing);
+        assert_eq!(0, m.diagnostics().collisions);
+        test_map(&m, &map_reference, &map_other);
+        test_map_ops(&m, &map_reference);
+        test_map_iter(&m, &map_reference);
+        test_map_iter_mut(&mut m, &map_reference);
+
+        let s = FzStringSet::new(keys.clone());
+        assert_eq!(Some(KeyHashing::Length), s.diagnostics().key_hashing);
+        test_set(&s, &set_reference, &set_other);
+        test_set_ops(&s, &set_reference, &set_other);
+        test_set_iter(&s, &set_reference);
+    }
+
+    // too many keys of the same length to use lengths as hash codes
+    let m = FzStringMap::new(vec![("aa", 1), ("bb", 2), ("ccc", 3)]);
+    assert_ne!(Some(KeyHashing::Length), m.diagnostics().key_hashing);
+}
+
+#[test]
+fn deserialize_values() {
+    fz_string_map!(static CONFIG: Config<&'static str, i32>, { "alpha": 0, "beta": 0, "gamma": 0 });
+
+    let mut m = CONFIG.clone();
+    m.deserialize_values(&mut serde_json::Deserializer::from_str(r#"{"gamma": 3, "alpha": 1, "beta": 2}"#))
+        .unwrap();
+    assert_eq!(Some(&1), m.get("alpha"));
+    assert_eq!(Some(&2), m.get("beta"));
+    assert_eq!(Some(&3), m.get("gamma"));
+    assert_eq!(Some(&0), CONFIG.get("alpha"));
+
+    for (json, message) in [
+        (r#"{"alpha": 4, "beta": 5}"#, r#"missing key "gamma""#),
+        (r#"{"alpha": 4, "beta": 5, "gamma": 6, "delta": 7}"#, r#"unknown key "delta""#),
+        (r#"{"alpha": 4, "beta": 5, "beta": 6}"#, r#"duplicate key "beta""#),
+        (r#"{"alpha": 4, "beta": 5, "gamma": "six"}"#, "invalid type"),
+        (r#"["alpha", "beta", "gamma"]"#, "expected a map"),
+    ] {
+        let err = m.deserialize_values(&mut serde_json::Deserializer::from_str(json)).unwrap_err();
+        assert!(err.to_string().contains(message), "{err}");
+        assert_eq!(Some(&1), m.get("alpha"));
+        assert_eq!(Some(&2), m.get("beta"));
+    }
+
+    let mut m = fz_scalar_map!({ 1: "", 2: "", 300: "" });
+    m.deserialize_values(&mut serde_json::Deserializer::from_str(r#"{"1": "a", "2": "b", "300": "c"}"#))
+        .unwrap();
+    assert_eq!(Some(&"c"), m.get(&300));
+    let err = m
+        .deserialize_values(&mut serde_json::Deserializer::from_str(r#"{"1": "a", "2": "b", "3": "c"}"#))
+        .unwrap_err();
+    assert_eq!("unknown key 3", err.to_string());
+
+    let mut m = HashMap::<Box<str>, _>::with_hasher(vec![("x".into(), 0), ("y".into(), 0)], BridgeHasher::default()).unwrap();
+    m.deserialize_values(&mut serde_json::Deserializer::from_str(r#"{"x": 1, "y": 2}"#))
+        .unwrap();
+    assert_eq!(Some(&2), m.get("y"));
+
+    let mut m = ScanMap::new(vec![(10, 0), (20, 0)]);
+    m.deserialize_values(&mut serde_json::Deserializer::from_str(r#"{"20": 2, "10": 1}"#))
+        .unwrap();
+    assert_eq!(Some(&1), m.get(&10));
+    let err = m
+        .deserialize_values(&mut serde_json::Deserializer::from_str(r#"{"20": 2}"#))
+        .unwrap_err();
+    assert_eq!("missing key 10", err.to_string());
+}
+
+#[test]
+fn diagnostics() {
+    use frozen_collections::diagnostics::{KeyHashing, Strategy};
+
+    let m = FzScalarMap::new((0..100).map(|x| (x, x)).collect());
+    let d = m.diagnostics();
+    assert_eq!(Strategy::DenseScalarLookup, d.strategy);
+    assert_eq!(None, d.key_hashing);
+    assert_eq!(100, d.len);
+    assert_eq!(100, d.slots);
+    assert_eq!(0, d.collisions);
+    assert_eq!(1, d.longest_chain);
+    assert_eq!(100 * size_of::<(i32, i32)>(), d.heap_bytes);
+
+    let d = FzScalarSet::new((0..100).collect()).diagnostics();
+    assert_eq!(Strategy::DenseScalarLookup, d.strategy);
+    assert_eq!(100 * size_of::<i32>(), d.heap_bytes);
+
+    let d = FzScalarMap::new((0..100).map(|x| (x * 3, x)).collect()).diagnostics();
+    assert_eq!(Strategy::SparseScalarLookup, d.strategy);
+    assert_eq!(298, d.slots);
+
+    let d = FzScalarSet::new((0..100).map(|x| x * 3).collect()).diagnostics();
+    assert_eq!(Strategy::BitsetScalarLookup, d.strategy);
+    assert_eq!(320, d.slots);
+    assert_eq!(5 * size_of::<u64>() + 5 * size_of::<u32>() + 100 * size_of::<i32>(), d.heap_bytes);
+
+    let d = FzScalarMap::new((0..100).map(|x| (x * 1000, x)).collect()).diagnostics();
+    assert_eq!(Strategy::Hash, d.strategy);
+    assert_eq!(Some(KeyHashing::Scalar), d.key_hashing);
+
+    let d = FzStringMap::new((0..100).map(|x| (format!("prefix-{x:03}-suffix"), x)).collect()).diagnostics();
+    assert_eq!(Strategy::Hash, d.strategy);
+    assert_eq!(Some(KeyHashing::LeftSubslice), d.key_hashing);
+    assert_eq!(Some(8..10), d.subslice_range);
+    assert!(d.longest_chain > 1);
+
+    let s = FzStringSet::new((0..100).map(|x| format!("prefix-{x:03}-suffix")).collect());
+    assert_eq!(d.subslice_range, s.diagnostics().subslice_range);
+
+    let d = FzHashMap::new((0..5000).map(|x| ((x, x), x)).collect()).diagnostics();
+    assert_eq!(Strategy::PerfectHash, d.strategy);
+    assert_eq!(Some(KeyHashing::Whole), d.key_hashing);
+    assert_eq!(5000, d.slots);
+    assert_eq!(0, d.collisions);
+    assert_eq!(1, d.longest_chain);
+
+    let d = FzOrderedSet::new((0..7).collect()).diagnostics();
+    assert_eq!(Strategy::EytzingerSearch, d.strategy);
+    assert_eq!(3, d.longest_chain);
+
+    let m = HashMap::<_, _>::with_hasher((0..100).map(|x| (x, x)).collect(), BridgeHasher::default()).unwrap();
+    let d = m.diagnostics();
+    assert_eq!(Strategy::Hash, d.strategy);
+    assert!(d.slots.is_power_of_two());
+    assert!(d.collisions < d.len);
+    assert!(d.longest_chain >= 1);
+
+    let d = HashMap::<_, _, SmallCollection, _>::with_hasher(vec![("a", 1), ("b", 2), ("c", 3), ("dd", 4)], LengthHasher)
+        .unwrap()
+        .diagnostics();
+    assert_eq!(Some(KeyHashing::Length), d.key_hashing);
+    assert_eq!(2, d.collisions);
+    assert_eq!(3, d.longest_chain);
+
+    let m = fz_string_map!({ "first": 1, "second": 2, "third": 3 });
+    let d = m.diagnostics();
+    assert_eq!(Strategy::Scan, d.strategy);
+    assert_eq!(3, d.longest_chain);
+    assert_eq!(0, d.heap_bytes);
+}
+
+#[test]
+fn case_insensitive_strings() {
+    use frozen_collections::diagnostics::KeyHashing;
+
+    let keys = [
+        "Accept",
+        "Accept-Encoding",
+        "Content-Length",
+        "Content-Type",
+        "Host",
+        "User-Agent",
+        "Cookie",
+        "Connection",
+    ];
+
+    let m = FzStringMap::new(keys.iter().enumerate().map(|(i, k)| (AsciiCaseInsensitive(*k), i)).collect());
+    assert_eq!(keys.len(), m.len());
+    for (i, k) in keys.iter().enumerate() {
+        assert_eq!(Some(&i), m.get(k));
+        assert_eq!(Some(&i), m.get(k.to_ascii_uppercase()));
+        assert_eq!(Some(&i), m.get(k.to_ascii_lowercase()));
+        assert_eq!(Some(&i), MapQuery::get(&m, &AsciiCaseInsensitive(k.to_ascii_lowercase())));
+    }
+
+    assert_eq!(None, m.get("Referer"));
+    assert_ne!(Some(KeyHashing::Length), m.diagnostics().key_hashing);
+
+    let m = FzStringMap::new(vec![
+        (AsciiCaseInsensitive("Alpha"), 1),
+        (AsciiCaseInsensitive("ALPHA"), 2),
+        (AsciiCaseInsensitive("beta"), 3),
+    ]);
+    assert_eq!(2, m.len());
+    assert_eq!(Some(&3), m.get("BETA"));
+
+    let s = FzStringSet::new(keys.iter().map(|x| AsciiCaseInsensitive(x.to_ascii_lowercase())).collect());
+    assert!(s.contains("CONTENT-TYPE"));
+    assert!(!s.contains("content-typ"));
+
+    let other = FzStringSet::new(vec![AsciiCaseInsensitive("HOST"), AsciiCaseInsensitive("Referer")]);
+    assert_eq!(1, s.intersection(&other).count());
+    assert_eq!(keys.len() + 1, s.union(&other).count());
+}
+
+#[test]
+fn bytes() {
+    use frozen_collections::diagnostics::KeyHashing;
+
+    let keys: [&[u8]; 8] = [
+        b"\x00",
+        b"\x00\x01",
+        b"\xff\xfe\xfd",
+        b"GET",
+        b"POST",
+        b"\x80\x81\x82\x83",
+        b"HEAD",
+        b"OPTIONS",
+    ];
+
+    let m = FzBytesMap::new(keys.iter().enumerate().map(|(i, k)| (*k, i)).collect());
+    assert_eq!(keys.len(), m.len());
+    for (i, k) in keys.iter().enumerate() {
+        assert_eq!(Some(&i), m.get(k));
+    }
+
+    assert_eq!(None, m.get(b"PUT"));
+    assert_eq!(None, m.get(b""));
+
+    let m = FzBytesMap::new(vec![
+        (b"A".to_vec(), 1),
+        (b"BB".to_vec(), 2),
+        (b"CCC".to_vec(), 3),
+        (b"A".to_vec(), 4),
+    ]);
+    assert_eq!(3, m.len());
+    assert_eq!(Some(&4), m.get(b"A"));
+    assert_eq!(Some(KeyHashing::Length), m.diagnostics().key_hashing);
+
+    let s = FzBytesSet::new(keys.to_vec());
+    assert!(s.contains(b"GET"));
+    assert!(!s.contains(b"GE"));
+
+    let other = FzBytesSet::new(vec![b"GET".as_slice(), b"PUT".as_slice()]);
+    assert_eq!(1, s.intersection(&other).count());
+    assert_eq!(keys.len() + 1, s.union(&other).count());
+}
+
+#[test]
+fn wide_and_non_integer_scalars() {
+    let uuids = [
+        0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8_u128,
+        0x550e_8400_e29b_41d4_a716_4466_5544_0000,
+        0x6ba7_b810_9dad_11d1_80b4_00c0_4fd4_30c8,
+        0x6ba7_b811_9dad_11d1_80b4_00c0_4fd4_30c8,
+        0x6ba7_b812_9dad_11d1_80b4_00c0_4fd4_30c8,
+    ];
+
+    let m = FzScalarMap::new(uuids.iter().enumerate().map(|(i, k)| (*k, i)).collect());
+    for (i, k) in uuids.iter().enumerate() {
+        assert_eq!(Some(&i), m.get(k));
+    }
+    assert_eq!(None, m.get(&0x6ba7_b813_9dad_11d1_80b4_00c0_4fd4_30c8));
+
+    // keys beyond the range of usize share an index, so lookups must still compare keys
+    let m = FzScalarMap::new(vec![(0_u128, 0), (1, 1), (2, 2), (u128::MAX, 3)]);
+    assert_eq!(Some(&3), m.get(&u128::MAX));
+    assert_eq!(None, m.get(&(1_u128 << 64)));
+    assert_eq!(None, m.get(&3));
+
+    let m = FzScalarMap::new(vec![(i128::MIN, 0), (-1, 1), (0, 2), (1, 3)]);
+    assert_eq!(Some(&0), m.get(&i128::MIN));
+    assert_eq!(None, m.get(&(i128::MIN + 1)));
+    assert_eq!(Some(&3), m.get(&1));
+
+    let m = fz_scalar_map!({ 'a': 1, 'b': 2, 'c': 3, 'z': 26 });
+    assert_eq!(Some(&2), m.get(&'b'));
+    assert_eq!(None, m.get(&'d'));
+
+    let s = fz_scalar_set!({ true });
+    assert!(s.contains(&true));
+    assert!(!s.contains(&false));
+
+    let s = FzScalarSet::new(vec!['\u{1F600}', 'x', '\u{10FFFF}']);
+    assert!(s.contains(&'\u{10FFFF}'));
+    assert!(!s.contains(&'y'));
+}
+
+#[test]
+fn prefix_map() {
+    let m1: FzPrefixMap<Box<str>, i32> = FzPrefixMap::from([("/api".into(), 1), ("/".into(), 2), ("/api/users".into(), 3)]);
+    let m2 = FzStringMap::new(vec![("/", 2), ("/api", 1), ("/api/users", 3)]);
+    assert_eq_map(&m1, &m2);
+
+    let json = serde_json::to_string(&m1).unwrap();
+    let m3: FzPrefixMap<Box<str>, i32> = serde_json::from_str(&json).unwrap();
+    assert_eq_map(&m1, &m3);
+    assert_eq!(Some(&3), m3.longest_prefix_match("/api/users/7").map(|x| x.1));
+
+    let m: serde_json::Result<FzPrefixMap<String, i32>> = serde_json::from_str("[\"123\": 2]");
+    assert!(m.is_err());
+}
+
+#[test]
+fn materialized_set_ops() {
+    use std::collections::BTreeSet;
+
+    fn expected(a: &[i32], b: &[i32]) -> [Vec<i32>; 4] {
+        let a: BTreeSet<_> = a.iter().copied().collect();
+        let b: BTreeSet<_> = b.iter().copied().collect();
+        [
+            a.union(&b).copied().collect(),
+            a.intersection(&b).copied().collect(),
+            a.difference(&b).copied().collect(),
+            a.symmetric_difference(&b).copied().collect(),
+        ]
+    }
+
+    fn sorted(s: impl IntoIterator<Item = i32>) -> Vec<i32> {
+        let mut v: Vec<_> = s.into_iter().collect();
+        v.sort_unstable();
+        v
+    }
+
+    fn names(v: &[i32]) -> Vec<String> {
+        v.iter().map(|x| format!("key-{x}")).collect()
+    }
+
+    fn sorted_names(s: impl IntoIterator<Item = impl ToString>) -> Vec<String> {
+        let mut v: Vec<_> = s.into_iter().map(|x| x.to_string()).collect();
+        v.sort_unstable();
+        v
+    }
+
+    let inputs: [(&[i32], &[i32]); 5] = [
+        (&[1, 2, 3, 4], &[3, 4, 5, 6]),
+        (&[1, 50, 100, 150, 250], &[50, 60, 250, 300]),
+        (&[-5, 1_000_000, 7, 42], &[42, 7, 99_999_999]),
+        (&[1, 2, 3], &[]),
+        (&[], &[]),
+    ];
+
+    for (a, b) in inputs {
+        let [union, intersection, difference, symmetric_difference] = expected(a, b);
+
+        let s1 = FzOrderedSet::new(a.to_vec());
+        let s2 = FzOrderedSet::new(b.to_vec());
+        assert_eq!(union, sorted(s1.union_set(&s2)));
+        assert_eq!(intersection, sorted(s1.intersection_set(&s2)));
+        assert_eq!(difference, sorted(s1.difference_set(&s2)));
+        assert_eq!(symmetric_difference, sorted(s1.symmetric_difference_set(&s2)));
+        for x in &union {
+            assert!(s1.union_set(&s2).contains(x));
+        }
+
+        let s1 = FzScalarSet::new(a.to_vec());
+        let s2 = FzScalarSet::new(b.to_vec());
+        assert_eq!(union, sorted(s1.union_set(&s2)));
+        assert_eq!(intersection, sorted(s1.intersection_set(&s2)));
+        assert_eq!(difference, sorted(s1.difference_set(&s2)));
+        assert_eq!(symmetric_difference, sorted(s1.symmetric_difference_set(&s2)));
+        for x in &union {
+            assert!(s1.union_set(&s2).contains(x));
+        }
+
+        let s1 = FzHashSet::new(a.to_vec());
+        let s2 = FzHashSet::new(b.to_vec());
+        assert_eq!(union, sorted(s1.union_set(&s2)));
+        assert_eq!(intersection, sorted(s1.intersection_set(&s2)));
+        assert_eq!(difference, sorted(s1.difference_set(&s2)));
+        assert_eq!(symmetric_difference, sorted(s1.symmetric_difference_set(&s2)));
+        for x in &union {
+            assert!(s1.union_set(&s2).contains(x));
+        }
+
+        let s1 = FzStringSet::new(names(a));
+        let s2 = FzStringSet::new(names(b));
+        assert_eq!(sorted_names(names(&union)), sorted_names(s1.union_set(&s2).iter()));
+        assert_eq!(sorted_names(names(&intersection)), sorted_names(s1.intersection_set(&s2).iter()));
+        assert_eq!(sorted_names(names(&difference)), sorted_names(s1.difference_set(&s2).iter()));
+        assert_eq!(
+            sorted_names(names(&symmetric_difference)),
+            sorted_names(s1.symmetric_difference_set(&s2).iter())
+        );
+        for x in names(&union) {
+            assert!(s1.union_set(&s2).contains(x));
+        }
+    }
+
+    // the result of a hash set operation keeps the hasher of the left-hand set
+    let s1 = FzHashSet::with_hasher(vec![1, 2, 3], foldhash::FixedState::with_seed(42));
+    let s2 = FzHashSet::with_hasher(vec![3, 4], foldhash::FixedState::with_seed(42));
+    let s3 = s1.union_set(&s2);
+    assert_eq!(4, s3.len());
+    assert!(s3.contains(&4));
+}
+
+#[test]
+fn batch_lookups() {
+    for size in [0, 3, 40, 1000] {
+        let input: Vec<_> = (0..size).map(|x| (x * 2, x)).collect();
+        let probes: Vec<_> = (0..size * 2 + 5).collect();
+        let probe_refs: Vec<_> = probes.iter().collect();
+
+        let m = FzHashMap::new(input.clone());
+        let mut values = vec![None; probes.len()];
+        let mut found = vec![false; probes.len()];
+        m.get_many(&probe_refs, &mut values);
+        m.contains_many(&probe_refs, &mut found);
+        for (i, probe) in probes.iter().enumerate() {
+            assert_eq!(m.get(probe), values[i]);
+            assert_eq!(m.contains_key(probe), found[i]);
+        }
+
+        let m = HashMap::<_, _, LargeCollection>::with_hasher(input.clone(), BridgeHasher::default()).unwrap();
+        m.get_many(&probe_refs, &mut values);
+        for (i, probe) in probes.iter().enumerate() {
+            assert_eq!(m.get(probe), values[i]);
+        }
+
+        let s = FzHashSet::new(input.iter().map(|x| x.0).collect());
+        s.contains_many(&probe_refs, &mut found);
+        for (i, probe) in probes.iter().enumerate() {
+            assert_eq!(s.contains(probe), found[i]);
+        }
+
+        let string_input: Vec<_> = input.iter().map(|x| (format!("k{}", x.0), x.1)).collect();
+        let string_probes: Vec<_> = probes.iter().map(|x| format!("k{x}")).collect();
+
+        let m = FzStringMap::new(string_input.clone());
+        m.get_many(&string_probes, &mut values);
+        m.contains_many(&string_probes, &mut found);
+        for (i, probe) in string_probes.iter().enumerate() {
+            assert_eq!(m.get(probe), values[i]);
+            assert_eq!(m.contains_key(probe), found[i]);
+        }
+
+        let s = FzStringSet::new(string_input.iter().map(|x| x.0.as_str()).collect());
+        s.contains_many(&string_probes, &mut found);
+        for (i, probe) in string_probes.iter().enumerate() {
+            assert_eq!(s.contains(probe), found[i]);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "keys and results must have the same length")]
+fn batch_lookups_length_mismatch() {
+    let m = FzHashMap::new(vec![(1, 1)]);
+    let mut values = [None; 1];
+    m.get_many(&[&1, &2], &mut values);
+}
+
+#[test]
+fn lookups_with_hash() {
+    let tenant1 = FzHashMap::with_hasher(vec![(1, "a"), (2, "b"), (3, "c")], foldhash::FixedState::with_seed(42));
+    let tenant2 = FzHashMap::with_hasher((0..100).map(|x| (x, "z")).collect(), foldhash::FixedState::with_seed(42));
+    for key in [0, 1, 3, 99, 100] {
+        let hash_code = tenant1.hash_key(&key);
+        assert_eq!(hash_code, tenant2.hash_key(&key));
+        assert_eq!(tenant1.get(&key), tenant1.get_with_hash(hash_code, &key));
+        assert_eq!(tenant2.get(&key), tenant2.get_with_hash(hash_code, &key));
+        assert_eq!(tenant2.contains_key(&key), tenant2.contains_with_hash(hash_code, &key));
+    }
+
+    let input: Vec<_> = (0..1000).map(|x| (x * 2, x)).collect();
+    let m = HashMap::<_, _, LargeCollection>::with_hasher(input.clone(), BridgeHasher::default()).unwrap();
+    let s = FzHashSet::new(input.iter().map(|x| x.0).collect());
+    for key in 0..2000 {
+        assert_eq!(m.get(&key), m.get_with_hash(m.hash_key(&key), &key));
+        assert_eq!(s.contains(&key), s.contains_with_hash(s.hash_key(&key), &key));
+    }
+
+    let m = FzStringMap::new(vec![("alpha", 1), ("beta", 2), ("gamma", 3), ("delta", 4), ("epsilon", 5)]);
+    let s = FzStringSet::new(vec!["alpha", "beta", "gamma", "delta", "epsilon"]);
+    for key in ["alpha", "delta", "omega", ""] {
+        assert_eq!(m.get(key), m.get_with_hash(m.hash_key(key), key));
+        assert_eq!(m.contains_key(key), m.contains_with_hash(m.hash_key(key), key));
+        assert_eq!(s.contains(key), s.contains_with_hash(s.hash_key(key), key));
+    }
+
+    // string maps laid out differently still share hash codes when their hash builders match
+    let bh = foldhash::FixedState::with_seed(42);
+    let tenant1 = FzStringMap::with_hasher(vec![("a", 1), ("bb", 2), ("ccc", 3)], bh);
+    let tenant2 = FzStringMap::with_hasher((0..500).map(|x| (format!("{x:x}"), x)).collect(), bh);
+    let tenant3 = FzStringSet::with_hasher((0..40).map(|x| format!("key-{x}")).collect(), bh);
+    assert_ne!(tenant1.diagnostics().key_hashing, tenant2.diagnostics().key_hashing);
+    for key in ["a", "bb", "ff", "1f3", "key-7", "zzz"] {
+        let hash_code = tenant1.hash_key(key);
+        assert_eq!(hash_code, tenant2.hash_key(key));
+        assert_eq!(hash_code, tenant3.hash_key(key));
+        assert_eq!(tenant1.get(key), tenant1.get_with_hash(hash_code, key));
+        assert_eq!(tenant2.get(key), tenant2.get_with_hash(hash_code, key));
+        assert_eq!(tenant3.contains(key), tenant3.contains_with_hash(hash_code, key));
+    }
+}
+
+fn assert_dense<K, M>(index: &columns::KeyIndex<M>)
+where
+    M: MapIteration<K, usize> + Len,
+{
+    let mut slots: Vec<_> = index.slots().values().copied().collect();
+    slots.sort_unstable();
+    assert_eq!((0..index.len()).collect::<Vec<_>>(), slots);
+}
+
+#[test]
+fn key_index_and_columns() {
+    let index = columns::KeyIndex::from(FzHashSet::new((0..1000).collect()));
+    assert_dense(&index);
+    let squares = index.column(|x| x * x);
+    let names = index.column(|x| format!("#{x}"));
+    for key in 0..1000 {
+        assert_eq!(Some(&(key * key)), index.get(&squares, &key));
+        assert_eq!(Some(&format!("#{key}")), index.get(&names, &key));
+    }
+    assert_eq!(None, index.get(&squares, &1000));
+
+    let index = columns::KeyIndex::from(FzOrderedSet::new(vec!["b", "c", "a"]));
+    assert_dense(&index);
+    let upper = index.column(|x| x.to_uppercase());
+    assert_eq!(Some(&"A".to_string()), index.get(&upper, &"a"));
+    assert_eq!(None, index.slot(&"d"));
+
+    let index = columns::KeyIndex::from(FzScalarSet::new(vec![10, 20, 30, 40]));
+    assert_dense(&index);
+    let mut column = index.column(|x| x / 10);
+    *index.get_mut(&mut column, &30).unwrap() += 100;
+    assert_eq!(Some(&103), index.get(&column, &30));
+    assert_eq!(None, index.get(&column, &31));
+
+    let index = columns::KeyIndex::from(FzStringSet::new(vec!["alpha", "beta", "gamma", "delta"]));
+    assert_dense(&index);
+    let lengths = index.column(Len::len);
+    assert_eq!(Some(&5), index.get(&lengths, &"gamma"));
+    assert_eq!(None, index.get(&lengths, &"omega"));
+
+    let index = columns::KeyIndex::from(FzBytesSet::new(vec![b"ab".as_slice(), b"cde".as_slice()]));
+    assert_dense(&index);
+    let lengths = index.column(Len::len);
+    assert_eq!(Some(&3), index.get(&lengths, &b"cde"));
+
+    let index = columns::KeyIndex::from(FzStringSet::new(vec![AsciiCaseInsensitive("Alpha"), AsciiCaseInsensitive("Beta")]));
+    assert_dense(&index);
+    let lengths = index.column(|x| x.0.len());
+    assert_eq!(Some(&4), index.get(&lengths, &"BETA"));
+
+    let m = FzHashMap::new((0..100).map(|x| (x, x * 3)).collect());
+    let (index, values) = m.into_index_and_column();
+    assert_dense(&index);
+    assert_eq!(100, values.len());
+    for key in 0..100 {
+        assert_eq!(Some(&(key * 3)), index.get(&values, &key));
+    }
+
+    let index = columns::KeyIndex::from(fz_string_set!({ "red", "green", "blue" }));
+    assert_dense(&index);
+    let lengths = index.column(Len::len);
+    assert_eq!(Some(&5), index.get(&lengths, "green"));
+    assert_eq!(None, index.get(&lengths, "cyan"));
+
+    let index = columns::KeyIndex::from(fz_scalar_set!({ 1, 2, 3, 4 }));
+    assert_dense(&index);
+    let doubled = index.column(|x| x * 2);
+    assert_eq!(Some(&8), index.get(&doubled, &4));
+
+    let index = columns::KeyIndex::from(fz_hash_set!({ 100, 200, 300, 400, 500, 600, 700, 800, 900, 1000, 1100, 1200 }));
+    assert_dense(&index);
+    let tenths = index.column(|x| x / 10);
+    assert_eq!(Some(&70), index.get(&tenths, &700));
+    assert_eq!(None, index.get(&tenths, &750));
+
+    let slots = fz_string_map!({ "red": 2_usize, "green": 0_usize, "blue": 1_usize });
+    let index = columns::KeyIndex::from_slots(slots).unwrap();
+    let hex = index.column(|_| 0);
+    assert_eq!(3, hex.len());
+    assert_eq!(Some(0), index.slot(&"green"));
+
+    let slots = fz_string_map!({ "red": 2_usize, "green": 3_usize });
+    assert!(columns::KeyIndex::from_slots(slots).is_err());
+}
+
+#[test]
+fn layout_preserving_transformations() {
+    use frozen_collections::diagnostics::Strategy;
+
+    let m = FzHashMap::new((0..2000).map(|x| (x, x)).collect());
+    assert_eq!(Strategy::PerfectHash, m.diagnostics().strategy);
+    let doubled = m.clone().map_values(|_, v| i64::from(v) * 2);
+    assert_eq!(Strategy::PerfectHash, doubled.diagnostics().strategy);
+    for key in 0..2000 {
+        assert_eq!(Some(&(i64::from(key) * 2)), doubled.get(&key));
+    }
+
+    let mut calls = 0;
+    let r = m.clone().try_map_values(|k, v| {
+        calls += 1;
+        if *k == 1500 { Err(format!("bad key {k}")) } else { Ok(v) }
+    });
+    assert_eq!("bad key 1500", r.unwrap_err());
+    assert_eq!(m.keys().position(|k| *k == 1500).unwrap() + 1, calls);
+    assert_eq!(Ok(m.clone()), m.clone().try_map_values(|_, v| Ok::<_, String>(v)));
+
+    assert_eq!(m, m.clone().retain_into(|_, _| true));
+    let evens = m.filter(|k, _| k % 2 == 0);
+    assert_eq!(1000, evens.len());
+    assert_eq!(Some(&1998), evens.get(&1998));
+    assert_eq!(None, evens.get(&1999));
+    assert!(m.retain_into(|_, _| false).is_empty());
+
+    let m = FzStringMap::new((0..2000).map(|x| (format!("{:x}", x * 2_654_435_761_u64), x)).collect());
+    assert_eq!(Strategy::PerfectHash, m.diagnostics().strategy);
+    let lengths = m.clone().map_values(|k, _| k.len());
+    assert_eq!(Strategy::PerfectHash, lengths.diagnostics().strategy);
+    for (k, v) in &lengths {
+        assert_eq!(k.len(), *v);
+    }
+
+    let small = m.filter(|_, v| *v < 10);
+    assert_eq!(10, small.len());
+    assert_eq!(Some(&3), small.get(format!("{:x}", 3 * 2_654_435_761_u64)));
+    assert_eq!(None, small.get(format!("{:x}", 30 * 2_654_435_761_u64)));
+    assert_eq!(m, m.clone().retain_into(|_, _| true));
+
+    let m = FzStringMap::new(vec![("apple", 1), ("banana", 2), ("cherry", 3)]);
+    let r: Result<FzStringMap<_, u8>, _> = m.clone().try_map_values(|_, v| u8::try_from(v * 100));
+    assert!(r.is_err());
+    let m = m.retain_into(|k, _| k.as_ref() != "banana");
+    assert_eq!(2, m.len());
+    assert_eq!(None, m.get("banana"));
+    assert_eq!(Some(&3), m.get("cherry"));
+
+    let m = FzStringMap::new(vec![
+        (AsciiCaseInsensitive("Alpha"), 1),
+        (AsciiCaseInsensitive("Beta"), 2),
+        (AsciiCaseInsensitive("Gamma"), 3),
+    ]);
+    let m = m.map_values(|_, v| v * 10).retain_into(|_, v| *v > 10);
+    assert_eq!(Some(&20), m.get("BETA"));
+    assert_eq!(None, m.get("alpha"));
+
+    let m = FzBytesMap::new(vec![(b"ab".as_slice(), 1), (b"cde".as_slice(), 2)]);
+    let m = m.map_values(|k, v| k.len() + v).filter(|_, v| *v == 5);
+    assert_eq!(1, m.len());
+    assert_eq!(Some(&5), m.get(b"cde"));
+
+    let m = FzScalarMap::new((0..100).map(|x| (x, x)).collect());
+    assert_eq!(Strategy::DenseScalarLookup, m.diagnostics().strategy);
+    let squares = m.clone().map_values(|k, _| k * k);
+    assert_eq!(Strategy::DenseScalarLookup, squares.diagnostics().strategy);
+    assert_eq!(Some(&81), squares.get(&9));
+    let sparse = m.filter(|k, _| k % 3 == 0);
+    assert_eq!(34, sparse.len());
+    assert_ne!(Strategy::DenseScalarLookup, sparse.diagnostics().strategy);
+    assert_eq!(Some(&99), sparse.get(&99));
+    assert_eq!(None, sparse.get(&98));
+    assert_eq!(m, m.clone().retain_into(|_, _| true));
+
+    let m = FzOrderedMap::new(vec![("c", 3), ("a", 1), ("b", 2), ("d", 4)]);
+    let m = m.map_values(|_, v| v * 2).retain_into(|k, _| *k != "b");
+    assert_eq!(vec![(&"a", &2), (&"c", &6), (&"d", &8)], m.iter_sorted().collect::<Vec<_>>());
+    assert_eq!(Some((&"a", &2)), m.floor(&"b"));
+
+    let m = FzPrefixMap::new(vec![("/", 0), ("/api", 1), ("/api/users", 2)]);
+    let m = m.map_values(|k, _| k.len()).filter(|k, _| *k != "/api");
+    assert_eq!(Some((&"/", &1)), m.longest_prefix_match("/api/orders"));
+
+    let m = HashMap::<_, _, SmallCollection>::with_hasher((0..10).map(|x| (x, x)).collect(), BridgeHasher::default()).unwrap();
+    let m = m.map_values(|_, v| v + 1).retain_into(|k, _| *k < 5);
+    assert_eq!(5, m.len());
+    assert_eq!(Some(&5), m.get(&4));
+    assert_eq!(None, m.get(&5));
+
+    let m = ScanMap::new(vec![(1, "a"), (2, "b"), (3, "c")]);
+    let m = m.map_values(|_, v| v.to_uppercase()).filter(|k, _| *k != 2);
+    assert_eq!(Some(&"C".to_string()), m.get(&3));
+    assert_eq!(None, m.get(&2));
+
+    let m = DenseScalarLookupMap::new((10..20).map(|x| (x, x)).collect()).unwrap();
+    let m = m.try_map_values(|_, v| u8::try_from(v)).unwrap();
+    assert_eq!(Some(&15_u8), m.get(&15));
+
+    let m = SparseScalarLookupMap::new(vec![(1, 1), (5, 5), (9, 9)]);
+    assert!(m.retain_into(|_, _| false).is_empty());
+
+    let clones = Rc::new(Cell::new(0));
+    let m = FzHashMap::new((0..100).map(|x| (x, CountedClone(Rc::clone(&clones)))).collect());
+    let evens = m.filter(|k, v| {
+        assert_eq!(0, v.0.get());
+        k % 2 == 0
+    });
+    assert_eq!(50, evens.len());
+    assert_eq!(50, clones.get());
+    assert_eq!(m.len(), m.filter(|_, _| true).len());
+    assert_eq!(150, clones.get());
+}
+
+struct CountedClone(Rc<Cell<usize>>);
+
+impl Clone for CountedClone {
+    fn clone(&self) -> Self {
+        self.0.set(self.0.get() + 1);
+        Self(Rc::clone(&self.0))
+    }
 }
@@ -516,3 +516,111 @@ fn ordered_string() {
         "13"
     );
 }
+
+#[test]
+fn ordered_range() {
+    let pa = Person { name: "A", age: 1 };
+    let pb = Person { name: "B", age: 2 };
+    let pc = Person { name: "C", age: 3 };
+    let pd = Person { name: "D", age: 4 };
+    let pe = Person { name: "E", age: 5 };
+
+    let m = fz_ordered_map!({
+        Person { name: "E", age: 5 }: 5,
+        Person { name: "A", age: 1 }: 1,
+        Person { name: "D", age: 4 }: 4,
+        Person { name: "B", age: 2 }: 2,
+        Person { name: "C", age: 3 }: 3,
+    });
+
+    let reference = StdBTreeMap::from([(pa, 1), (pb, 2), (pc, 3), (pd, 4), (pe, 5)]);
+    assert!(m.iter_sorted().eq(reference.iter()));
+    assert!(m.range(pb..=pd).eq(reference.range(pb..=pd)));
+    assert!(m.range(..pc).rev().eq(reference.range(..pc).rev()));
+    assert_eq!(reference.first_key_value(), m.first_key_value());
+    assert_eq!(reference.last_key_value(), m.last_key_value());
+
+    let s = fz_ordered_set!({
+        Person { name: "D", age: 4 },
+        Person { name: "A", age: 1 },
+        Person { name: "E", age: 5 },
+        Person { name: "C", age: 3 },
+        Person { name: "B", age: 2 },
+    });
+
+    let reference = StdBTreeSet::from([pa, pb, pc, pd, pe]);
+    assert!(s.iter_sorted().eq(reference.iter()));
+    assert!(s.range(pb..pd).eq(reference.range(pb..pd)));
+    assert_eq!(reference.first(), s.first());
+    assert_eq!(reference.last(), s.last());
+}
+
+#[test]
+fn ordered_range_literal_keys() {
+    let m = fz_ordered_map!({ 10: "a", 2000: "b", 30000: "c", 400000: "d", 5000000: "e" });
+    let reference = StdBTreeMap::from([(10, "a"), (2000, "b"), (30000, "c"), (400_000, "d"), (5_000_000, "e")]);
+    assert!(m.iter_sorted().eq(reference.iter()));
+    assert!(m.range(..=30000).eq(reference.range(..=30000)));
+    assert!(m.range(2000..400_000).eq(reference.range(2000..400_000)));
+    assert_eq!(reference.first_key_value(), m.first_key_value());
+    assert_eq!(reference.last_key_value(), m.last_key_value());
+
+    let m = fz_ordered_map!({ "b": 2, "a": 1 });
+    let reference = StdBTreeMap::from([("a", 1), ("b", 2)]);
+    assert!(m.iter_sorted().eq(reference.iter()));
+    assert!(m.range("b"..).eq(reference.range("b"..)));
+
+    fz_ordered_set!(static S: Words<&'static str>, { "delta", "alpha", "charlie" });
+    let reference = StdBTreeSet::from(["alpha", "charlie", "delta"]);
+    assert!(S.iter_sorted().eq(reference.iter()));
+    assert!(S.range("b".."d").eq(reference.range("b".."d")));
+    assert_eq!(reference.first(), S.first());
+    assert_eq!(reference.last(), S.last());
+
+    let s = fz_ordered_set!({ b"zz", b"a", b"mm" });
+    assert_eq!(Some(&b"a".as_slice()), s.first());
+    assert_eq!(Some(&b"zz".as_slice()), s.last());
+
+    let x = 5;
+    let s = fz_ordered_set!({ 9, x, 1 });
+    assert!(s.iter_sorted().eq([1, 5, 9].iter()));
+}
+
+#[test]
+fn ordered_nearest_literal_keys() {
+    let m = fz_ordered_map!({ "a": 1, "c": 3, "e": 5, "g": 7, "i": 9 });
+    let reference = StdBTreeMap::from([("a", 1), ("c", 3), ("e", 5), ("g", 7), ("i", 9)]);
+    for k in ["", "a", "b", "c", "d", "e", "f", "g", "h", "i", "j"] {
+        assert_eq!(reference.range(..=k).next_back(), m.floor(k));
+        assert_eq!(reference.range(k..).next(), m.ceiling(k));
+        assert_eq!(reference.range(..k).next_back(), m.predecessor(k));
+        assert_eq!(reference.range(k..).find(|(key, _)| **key > k), m.successor(k));
+    }
+
+    let s = fz_ordered_set!({ 30, 10, 20 });
+    assert_eq!(Some(&10), s.floor(&15));
+    assert_eq!(Some(&20), s.ceiling(&15));
+    assert_eq!(Some(&20), s.predecessor(&30));
+    assert_eq!(None, s.successor(&30));
+    assert_eq!(None, s.floor(&5));
+    assert_eq!(None, s.ceiling(&31));
+}
+
+#[test]
+fn ordered_rank_select_literal_keys() {
+    let m = fz_ordered_map!({ 40: 'd', 10: 'a', 30: 'c', 20: 'b' });
+    for (i, (k, v)) in [(10, 'a'), (20, 'b'), (30, 'c'), (40, 'd')].iter().enumerate() {
+        assert_eq!(Some(i), m.rank(k));
+        assert_eq!(Some((k, v)), m.select(i));
+    }
+    assert_eq!(None, m.rank(&25));
+    assert_eq!(None, m.select(4));
+
+    let s = fz_ordered_set!({ "pear", "apple", "fig" });
+    for (i, v) in ["apple", "fig", "pear"].iter().enumerate() {
+        assert_eq!(Some(i), s.rank(*v));
+        assert_eq!(Some(v), s.select(i));
+    }
+    assert_eq!(None, s.rank("kiwi"));
+    assert_eq!(None, s.select(3));
+}
@@ -1,5 +1,6 @@
 #![expect(missing_docs, reason = "Tests")]
 
+use frozen_collections::{FzScalarSet, Set, SetIteration, SetOps};
 use frozen_collections_core::macros::{fz_scalar_map_macro, fz_scalar_set_macro};
 use frozen_collections_macros::*;
 use quote::quote;
@@ -299,6 +300,37 @@ fn scalar_usize() {
     test_scalar!(usize, 0_usize, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13);
 }
 
+#[test]
+fn scalar_i128() {
+    test_scalar!(i128, 0_i128);
+    test_scalar!(i128, 0_i128, 1,);
+    test_scalar!(i128, 0_i128, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13);
+    test_scalar!(i128, 0_i128, 1, 2, 170_141_183_460_469_231_731_687_303_715_884_105_727);
+}
+
+#[test]
+fn scalar_u128() {
+    test_scalar!(u128, 0_u128);
+    test_scalar!(u128, 0_u128, 1,);
+    test_scalar!(u128, 0_u128, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13);
+    test_scalar!(u128, 0_u128, 1, 2, 340_282_366_920_938_463_463_374_607_431_768_211_455);
+}
+
+#[test]
+fn scalar_char() {
+    test_scalar!(char, 'a');
+    test_scalar!(char, 'a', 'b', 'c', 'd');
+    test_scalar!(char, 'a', 'c', 'e', 'g', 'i', 'k', 'm', 'o', 'q', 's', 'u', 'w', 'y');
+    test_scalar!(char, 'a', 'Z', '\u{1F600}', '\u{10FFFF}');
+}
+
+#[test]
+fn scalar_bool() {
+    test_scalar!(bool, false);
+    test_scalar!(bool, true);
+    test_scalar!(bool, false, true);
+}
+
 #[test]
 fn scalar_extra() {
     // test sparse case
@@ -341,3 +373,79 @@ fn duplicates() {
     let map = fz_scalar_map!({0: 1, 1: 2, 2: 3, 3: 4, 4: 5, 0: 2});
     assert_eq!(&2, map.get(&0).unwrap());
 }
+
+#[test]
+fn nearest() {
+    // dense
+    let m = fz_scalar_map!({ 10: 1, 11: 2, 12: 3, 13: 4, 14: 5, 15: 6, 16: 7, 17: 8, 18: 9 });
+    assert_eq!(None, m.floor(&9));
+    assert_eq!(Some((&12, &3)), m.floor(&12));
+    assert_eq!(Some((&18, &9)), m.floor(&100));
+    assert_eq!(Some((&10, &1)), m.ceiling(&-5));
+    assert_eq!(None, m.ceiling(&19));
+    assert_eq!(Some((&11, &2)), m.predecessor(&12));
+    assert_eq!(None, m.predecessor(&10));
+    assert_eq!(Some((&13, &4)), m.successor(&12));
+    assert_eq!(None, m.successor(&18));
+    assert_eq!(Some(2), m.rank(&12));
+    assert_eq!(None, m.rank(&19));
+    assert_eq!(Some((&12, &3)), m.select(2));
+    assert_eq!(None, m.select(9));
+
+    // sparse
+    let s = fz_scalar_set!({ 10, 20, 30, 40, 50, 60, 70, 80, 90 });
+    assert_eq!(Some(&20), s.floor(&25));
+    assert_eq!(Some(&30), s.ceiling(&25));
+    assert_eq!(Some(&20), s.predecessor(&30));
+    assert_eq!(Some(&40), s.successor(&30));
+    assert_eq!(None, s.floor(&5));
+    assert_eq!(None, s.successor(&90));
+    assert_eq!(Some(2), s.rank(&30));
+    assert_eq!(None, s.rank(&35));
+    assert_eq!(Some(&40), s.select(3));
+}
+
+#[test]
+fn bitset_set_ops() {
+    fn check<S1: Set<i32>, S2: Set<i32>>(s1: &S1, s2: &S2) {
+        assert!(s1.bitset().is_some());
+        assert!(s2.bitset().is_some());
+
+        let r1: StdBTreeSet<i32> = s1.iter().copied().collect();
+        let r2: StdBTreeSet<i32> = s2.iter().copied().collect();
+
+        assert_eq!(r1.union(&r2).collect::<Vec<_>>(), s1.union(s2).collect::<Vec<_>>());
+        assert_eq!(r1.intersection(&r2).collect::<Vec<_>>(), s1.intersection(s2).collect::<Vec<_>>());
+        assert_eq!(r1.difference(&r2).collect::<Vec<_>>(), s1.difference(s2).collect::<Vec<_>>());
+        assert_eq!(r2.difference(&r1).collect::<Vec<_>>(), s2.difference(s1).collect::<Vec<_>>());
+
+        let mut sd: Vec<_> = s1.symmetric_difference(s2).collect();
+        sd.sort();
+        assert_eq!(r1.symmetric_difference(&r2).collect::<Vec<_>>(), sd);
+    }
+
+    let s1 = fz_scalar_set!({ -70, -3, 0, 1, 5, 63, 64, 65, 127, 200 });
+    let s2 = fz_scalar_set!({ -3, 2, 5, 64, 130, 190, 200, 250 });
+    let s3 = FzScalarSet::new(vec![300, 310, 320, 330, 340, 350]);
+    let s4 = FzScalarSet::new(vec![1, 3, 5, 7, 9, 63, 64, 65]);
+
+    check(&s1, &s2);
+    check(&s2, &s1);
+    check(&s1, &s3);
+    check(&s3, &s4);
+    check(&s4, &s1);
+    check(&s1, &s1);
+
+    // continuous ranges expose a bitset view without storing one
+    let s5 = fz_scalar_set!({ 1, 2, 3 });
+    let s6 = FzScalarSet::new((60..140).collect());
+    check(&s5, &s4);
+    check(&s6, &s1);
+    check(&s5, &s6);
+    check(&s6, &s6);
+
+    // sets which aren't stored as bitsets take the regular path
+    let s7 = FzScalarSet::new(vec![1, 1_000_000, 2_000_000_000]);
+    assert!(s7.bitset().is_none());
+    assert_eq!(vec![&1], s4.intersection(&s7).collect::<Vec<_>>());
+}
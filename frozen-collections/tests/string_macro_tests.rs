@@ -225,3 +225,102 @@ fn non_literal_key_string_map() {
 
     assert!(fz_string_map_macro(quote!({s0: 2, s1: 3, s2: 4, s3: 3})).is_ok());
 }
+
+#[test]
+fn ignore_ascii_case() {
+    use frozen_collections::{AsciiCaseInsensitive, MapQuery};
+
+    let s = fz_string_set!(#[ignore_ascii_case] { "Alpha", "Beta", "ALPHA" });
+    assert_eq!(2, s.len());
+    assert!(s.contains("alpha"));
+    assert!(s.contains(&AsciiCaseInsensitive("bETA")));
+    assert!(!s.contains("Gamma"));
+
+    let m = fz_string_map!(#[ignore_ascii_case] {
+        "Accept": 1,
+        "Accept-Encoding": 2,
+        "Content-Length": 3,
+        "Content-Type": 4,
+        "Host": 5,
+        "User-Agent": 6,
+        "Connection": 7,
+        "Cookie": 8,
+    });
+    assert_eq!(8, m.len());
+    assert_eq!(Some(&4), m.get("content-type"));
+    assert_eq!(Some(&6), m.get("USER-AGENT"));
+    assert_eq!(Some(&1), m.get(&AsciiCaseInsensitive("ACCEPT")));
+    assert_eq!(None, m.get("Referer"));
+
+    fz_string_map!(#[ignore_ascii_case] static M: Headers<&'static str, i32>, {
+        "Accept": 1,
+        "Host": 2,
+        "Cookie": 3,
+        "Connection": 4,
+        "Upgrade": 5,
+    });
+    assert_eq!(Some(&2), M.get("HOST"));
+    assert_eq!(None, M.get("Hostess"));
+
+    let s0 = "Zero";
+    let s1 = "One";
+    let m = fz_string_map!(#[ignore_ascii_case] { s0: 0, s1: 1, "Two": 2, "Three": 3 });
+    assert_eq!(Some(&0), m.get("ZERO"));
+    assert_eq!(Some(&2), m.get("two"));
+    assert_eq!(Some(&3), MapQuery::get(&m, &AsciiCaseInsensitive("THREE")));
+    assert_eq!(None, m.get("Four"));
+
+    let s = fz_string_set!(#[ignore_ascii_case] { s0, s1 });
+    assert!(s.contains("one"));
+
+    assert!(fz_string_map_macro(quote!(#[ignore_ascii_case] { s0: 0, s1: 1, "Two": 2, "Three": 3 })).is_ok());
+}
+
+#[test]
+fn prefix_map() {
+    const STATUS: &str = "status";
+
+    let m = fz_prefix_map!({
+        "/": 0,
+        "/api": 1,
+        "/api/users": 2,
+        "/api/orders": 3,
+        "/static": 4,
+    });
+
+    assert_eq!(5, m.len());
+    assert_eq!(Some(&2), m.get("/api/users"));
+    assert_eq!(Some((&"/api/users", &2)), m.longest_prefix_match("/api/users/42"));
+    assert_eq!(Some((&"/api", &1)), m.longest_prefix_match("/api/products"));
+    assert_eq!(Some((&"/", &0)), m.longest_prefix_match("/index.html"));
+    assert_eq!(None, m.longest_prefix_match("index.html"));
+    assert_eq!(
+        vec![&"/api", &"/api/orders", &"/api/users"],
+        m.keys_with_prefix("/api").collect::<Vec<_>>()
+    );
+
+    fz_prefix_map!(static COMMANDS: Commands<&'static str, i32>, { "commit": 0, "config": 1, STATUS: 2 });
+    assert_eq!(Some(&2), COMMANDS.longest_prefix_match("status --short").map(|x| x.1));
+    assert_eq!(2, COMMANDS.keys_with_prefix("co").count());
+
+    fz_prefix_map!(let mut m: Routes<&'static str, i32>, { "a": 0, "ab": 1, "abc": 2 });
+    if let Some(v) = m.get_mut("ab") {
+        *v = 10;
+    }
+    assert_eq!(Some(&10), m.longest_prefix_match("abd").map(|x| x.1));
+}
+
+#[test]
+fn custom_hasher() {
+    type SipHasher = core::hash::BuildHasherDefault<std::collections::hash_map::DefaultHasher>;
+
+    fz_string_map!(#[hasher = SipHasher] static M: MyMap<&'static str, i32>, { "Alpha": 1, "Beta": 2, "Gamma": 3, "Delta": 4 });
+    assert_eq!(Some(&2), M.get("Beta"));
+    assert_eq!(None, M.get("beta"));
+
+    let s0 = "Zero";
+    fz_string_set!(#[hasher = SipHasher] #[ignore_ascii_case] let s: MySet<&'static str>, { s0, "One", "Two" });
+    assert!(s.contains("ZERO"));
+    assert!(s.contains("two"));
+    assert!(!s.contains("Three"));
+}